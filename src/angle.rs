@@ -0,0 +1,207 @@
+//! This module provides the [Angle](struct.Angle.html) type for angles with a unit, like the
+//! `orient` of markers, the hues of colors and the angles of transforms and lighting filters.
+//!
+//! # Examples
+//! ## Converting between units
+//! ```
+//! use svg_definitions::angle::{Angle, AngleUnit};
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::transform::Transform;
+//!
+//! let quarter = Angle::turns(0.25);
+//! assert_eq!(quarter.to_string(), "0.25turn");
+//! assert_eq!(quarter.to(AngleUnit::Grad), Angle::gradians(100.0));
+//!
+//! // Transforms and lighting filters take plain degrees
+//! let group = SVGElem::new(Tag::G).set(Attr::Transform, Transform::Rotate(quarter.to_degrees(), 0.0, 0.0));
+//! # assert_eq!(group.get_attributes()[&Attr::Transform], "rotate(90)");
+//! ```
+
+use std::f64::consts::PI;
+use std::fmt;
+use std::str::FromStr;
+
+closed_enum! {
+    /// The unit of an [Angle](struct.Angle.html)
+    pub enum AngleUnit {
+        /// Degrees, where a full turn is 360
+        Deg = "deg",
+
+        /// Radians, where a full turn is 2π
+        Rad = "rad",
+
+        /// Gradians, where a full turn is 400
+        Grad = "grad",
+
+        /// Turns
+        Turn = "turn",
+    }
+}
+
+impl AngleUnit {
+    /// Returns the amount of this unit in a full turn
+    pub fn per_turn(&self) -> f64 {
+        match self {
+            AngleUnit::Deg => 360.0,
+            AngleUnit::Rad => 2.0 * PI,
+            AngleUnit::Grad => 400.0,
+            AngleUnit::Turn => 1.0,
+        }
+    }
+}
+
+/// The error returned when parsing a value which is not an angle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidAngle(pub String);
+
+impl fmt::Display for InvalidAngle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid angle {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidAngle {}
+
+/// A number with an angle unit, e.g. `45deg` or `0.5turn`
+///
+/// # Note / Arguments
+/// Angles are formatted with their unit, which `orient` and CSS accept. Attributes like
+/// `rotate`, `azimuth` and `transform` only take numbers in degrees, which
+/// [to_degrees](#method.to_degrees) returns. Equality compares the value and the unit, so
+/// convert angles to the same unit first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Angle {
+    pub value: f64,
+    pub unit: AngleUnit,
+}
+
+impl Angle {
+    /// Creates a new angle
+    #[inline]
+    pub fn new(value: f64, unit: AngleUnit) -> Angle {
+        Angle { value, unit }
+    }
+
+    /// Creates an angle in degrees
+    #[inline]
+    pub fn degrees(value: f64) -> Angle {
+        Angle::new(value, AngleUnit::Deg)
+    }
+
+    /// Creates an angle in radians
+    #[inline]
+    pub fn radians(value: f64) -> Angle {
+        Angle::new(value, AngleUnit::Rad)
+    }
+
+    /// Creates an angle in gradians
+    #[inline]
+    pub fn gradians(value: f64) -> Angle {
+        Angle::new(value, AngleUnit::Grad)
+    }
+
+    /// Creates an angle in turns
+    #[inline]
+    pub fn turns(value: f64) -> Angle {
+        Angle::new(value, AngleUnit::Turn)
+    }
+
+    /// Returns the angle converted to another unit
+    pub fn to(&self, unit: AngleUnit) -> Angle {
+        if unit == self.unit {
+            return *self;
+        }
+        Angle::new(self.value / self.unit.per_turn() * unit.per_turn(), unit)
+    }
+
+    /// Returns the angle in degrees
+    #[inline]
+    pub fn to_degrees(&self) -> f64 {
+        self.to(AngleUnit::Deg).value
+    }
+
+    /// Returns the angle in radians
+    #[inline]
+    pub fn to_radians(&self) -> f64 {
+        self.to(AngleUnit::Rad).value
+    }
+
+    /// Returns the angle in gradians
+    #[inline]
+    pub fn to_gradians(&self) -> f64 {
+        self.to(AngleUnit::Grad).value
+    }
+
+    /// Returns the angle in turns
+    #[inline]
+    pub fn to_turns(&self) -> f64 {
+        self.to(AngleUnit::Turn).value
+    }
+}
+
+impl fmt::Display for Angle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+impl FromStr for Angle {
+    type Err = InvalidAngle;
+
+    /// Parses an angle, where a number without unit is in degrees, units are case insensitive
+    /// and surrounding whitespace is ignored
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::angle::Angle;
+    ///
+    /// assert_eq!("90".parse(), Ok(Angle::degrees(90.0)));
+    /// assert_eq!(" 1.5RAD".parse(), Ok(Angle::radians(1.5)));
+    /// assert!("90px".parse::<Angle>().is_err());
+    /// ```
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        let split = trimmed
+            .rfind(|c: char| c.is_ascii_digit() || c == '.')
+            .map_or(0, |index| index + 1);
+
+        let unit = match &trimmed[split..] {
+            "" => Some(AngleUnit::Deg),
+            unit => AngleUnit::ALL
+                .iter()
+                .copied()
+                .find(|known| known.name().eq_ignore_ascii_case(unit)),
+        };
+        match (trimmed[..split].parse::<f64>(), unit) {
+            (Ok(number), Some(unit)) if number.is_finite() => Ok(Angle::new(number, unit)),
+            _ => Err(InvalidAngle(value.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Angle, AngleUnit, InvalidAngle};
+
+    #[test]
+    fn test_angle() {
+        for unit in AngleUnit::ALL {
+            let angle = Angle::new(-0.5, *unit);
+            assert_eq!(angle.to_string().parse(), Ok(angle));
+            assert!((angle.to_turns() * unit.per_turn() + 0.5).abs() < 1e-12);
+        }
+
+        let right = Angle::degrees(90.0);
+        assert!((right.to_radians() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(right.to_gradians(), 100.0);
+        assert_eq!(right.to_turns(), 0.25);
+        assert_eq!(right.to(AngleUnit::Deg), right);
+        assert_eq!(Angle::gradians(-200.0).to_degrees(), -180.0);
+
+        assert_eq!("1e1deg".parse(), Ok(Angle::degrees(10.0)));
+        assert_eq!(".5Turn".parse(), Ok(Angle::turns(0.5)));
+        for value in &["", "deg", "1 deg", "1degs", "1e", "nan", "inf"] {
+            assert_eq!(value.parse::<Angle>(), Err(InvalidAngle(value.to_string())));
+        }
+    }
+}
@@ -0,0 +1,1687 @@
+//! This module evaluates SMIL animations, so a static frame of an animated SVG can be rendered,
+//! e.g. for a GIF or a sequence of PNG images. It also provides the
+//! [AnimateBuilder](struct.AnimateBuilder.html),
+//! [AnimateTransformBuilder](struct.AnimateTransformBuilder.html),
+//! [AnimateMotionBuilder](struct.AnimateMotionBuilder.html), the
+//! [CssAnimation](struct.CssAnimation.html) alternative to SMIL and prefab animations like
+//! [draw_on](fn.draw_on.html).
+//!
+//! # Examples
+//! ## Sampling the frames of a fade out
+//! ```
+//! use svg_definitions::animation;
+//! use svg_definitions::prelude::*;
+//!
+//! let document = SVGDoc::new(
+//!     SVGElem::new(Tag::Svg).append(
+//!         SVGElem::new(Tag::Rect).append(
+//!             SVGElem::new(Tag::Animate)
+//!                 .set(Attr::AttributeName, "opacity")
+//!                 .set(Attr::From, 1)
+//!                 .set(Attr::To, 0)
+//!                 .set(Attr::Dur, "1s"),
+//!         ),
+//!     ),
+//! );
+//!
+//! let frames: Vec<SVGElem> = (0..10)
+//!     .map(|frame| animation::sample(&document, frame as f64 / 10.0))
+//!     .collect();
+//! # assert_eq!(frames[5].to_string(), "<svg><rect opacity=\"0.5\"/></svg>");
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::attributes::Attribute;
+use crate::color::Color;
+use crate::document::Document;
+use crate::easing::{cubic_bezier, Easing};
+use crate::geometry;
+use crate::path::PathDefinitionString;
+use crate::tag_name::TagName;
+use crate::transform::Transform;
+use crate::tree::ElementPath;
+use crate::Element;
+
+/// How often an animation repeats, i.e. the `repeatCount` attribute
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RepeatCount {
+    /// Repeats a number of times, which can be fractional
+    Count(f64),
+
+    /// Repeats forever
+    Indefinite,
+}
+
+impl fmt::Display for RepeatCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepeatCount::Count(count) => write!(f, "{}", count),
+            RepeatCount::Indefinite => f.write_str("indefinite"),
+        }
+    }
+}
+
+/// What happens to the value when an animation ends, i.e. the `fill` attribute of an animation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FillMode {
+    /// The value is reset to the value before the animation
+    Remove,
+
+    /// The value keeps the last value of the animation
+    Freeze,
+}
+
+impl fmt::Display for FillMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FillMode::Remove => "remove",
+            FillMode::Freeze => "freeze",
+        })
+    }
+}
+
+/// A SMIL clock value, i.e. the value of the `dur`, `begin`, `end` and `repeatDur` attributes
+///
+/// # Note / Arguments
+/// Clock values are written in seconds, e.g. `1.5s`, and parsed from full clock values like
+/// `00:01:30.5`, partial clock values like `01:30` and time counts with the metrics `h`, `min`,
+/// `s` and `ms`, where a count without a metric is in seconds.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use svg_definitions::animation::ClockValue;
+/// use svg_definitions::prelude::*;
+///
+/// let clock_value: ClockValue = "00:01:30".parse().unwrap();
+/// assert_eq!(Duration::from(clock_value), Duration::from_secs(90));
+/// assert_eq!(clock_value, "1.5min".parse().unwrap());
+///
+/// let animation = SVGElem::new(Tag::Animate).set(Attr::Dur, ClockValue::from(Duration::from_millis(250)));
+/// assert_eq!(animation.to_string(), "<animate dur=\"0.25s\"/>");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ClockValue(Duration);
+
+impl ClockValue {
+    /// Creates a clock value from a number of seconds
+    ///
+    /// # Panics
+    /// If the amount of seconds is negative or not finite.
+    pub fn from_secs_f64(seconds: f64) -> ClockValue {
+        ClockValue(Duration::from_secs_f64(seconds))
+    }
+
+    /// Returns the clock value as a number of seconds
+    pub fn as_secs_f64(&self) -> f64 {
+        self.0.as_secs_f64()
+    }
+}
+
+impl From<Duration> for ClockValue {
+    fn from(duration: Duration) -> Self {
+        ClockValue(duration)
+    }
+}
+
+impl From<ClockValue> for Duration {
+    fn from(clock_value: ClockValue) -> Self {
+        clock_value.0
+    }
+}
+
+impl fmt::Display for ClockValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}s", (self.as_secs_f64() * 1e4).round() / 1e4)
+    }
+}
+
+/// The error returned when parsing a text which is not a clock value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidClockValue(pub String);
+
+impl fmt::Display for InvalidClockValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid clock value {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidClockValue {}
+
+impl FromStr for ClockValue {
+    type Err = InvalidClockValue;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        // Numbers in clock values are unsigned decimals without exponents
+        let number = |text: &str| {
+            let (integer, fraction) = match text.find('.') {
+                Some(index) => (&text[..index], Some(&text[index + 1..])),
+                None => (text, None),
+            };
+            let is_digits =
+                |digits: &str| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+            if is_digits(integer) && fraction.is_none_or(is_digits) {
+                text.parse::<f64>().ok()
+            } else {
+                None
+            }
+        };
+        // Minutes and seconds of clock values are written with two digits and are below 60
+        let sixty = |text: &str| {
+            number(text).filter(|value| text.find('.').unwrap_or(text.len()) == 2 && *value < 60.0)
+        };
+
+        let text = value.trim();
+        let seconds = if text.contains(':') {
+            match text.split(':').collect::<Vec<_>>()[..] {
+                [hours, minutes, seconds] if !hours.contains('.') => number(hours)
+                    .zip(sixty(minutes).filter(|_| !minutes.contains('.')))
+                    .zip(sixty(seconds))
+                    .map(|((hours, minutes), seconds)| hours * 3600.0 + minutes * 60.0 + seconds),
+                [minutes, seconds] if !minutes.contains('.') => sixty(minutes)
+                    .zip(sixty(seconds))
+                    .map(|(minutes, seconds)| minutes * 60.0 + seconds),
+                _ => None,
+            }
+        } else {
+            let (count, factor) = if let Some(count) = text.strip_suffix("ms") {
+                (count, 0.001)
+            } else if let Some(count) = text.strip_suffix("min") {
+                (count, 60.0)
+            } else if let Some(count) = text.strip_suffix('h') {
+                (count, 3600.0)
+            } else {
+                (text.strip_suffix('s').unwrap_or(text), 1.0)
+            };
+            number(count).map(|count| count * factor)
+        };
+
+        seconds
+            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+            .map(ClockValue)
+            .ok_or_else(|| InvalidClockValue(String::from(value)))
+    }
+}
+
+/// Implements the methods shared by the animation builders, which have an `animation` element
+/// and an optional `easing`
+macro_rules! timing_methods {
+    ($builder:ident) => {
+        impl $builder {
+            /// Sets an attribute of the animation element, e.g. `additive`
+            pub fn set<T>(mut self, attribute: Attribute, value: T) -> Self
+            where
+                T: ToString,
+            {
+                self.animation = self.animation.set(attribute, value);
+                self
+            }
+
+            /// Animates the element with a certain id instead of the parent
+            pub fn target(self, id: &str) -> Self {
+                self.set(Attribute::Href, format!("#{}", id))
+            }
+
+            /// Sets the duration of one repetition of the animation
+            pub fn duration<T: Into<ClockValue>>(self, duration: T) -> Self {
+                self.set(Attribute::Dur, duration.into())
+            }
+
+            /// Sets the time after the document starts at which the animation begins
+            pub fn begin<T: Into<ClockValue>>(self, offset: T) -> Self {
+                self.set(Attribute::Begin, offset.into())
+            }
+
+            /// Begins the animation a time after the animation with a certain id ends, to chain
+            /// animations
+            pub fn begin_after<T: Into<ClockValue>>(self, id: &str, offset: T) -> Self {
+                self.set(Attribute::Begin, format!("{}.end+{}", id, offset.into()))
+            }
+
+            /// Sets the time after the document starts at which the animation ends
+            pub fn end<T: Into<ClockValue>>(self, offset: T) -> Self {
+                self.set(Attribute::End, offset.into())
+            }
+
+            /// Sets the total time the animation repeats for, which can end the animation in
+            /// the middle of a repetition
+            pub fn repeat_duration<T: Into<ClockValue>>(self, duration: T) -> Self {
+                self.set(Attribute::RepeatDur, duration.into())
+            }
+
+            /// Sets how often the animation repeats
+            pub fn repeat(self, count: RepeatCount) -> Self {
+                self.set(Attribute::RepeatCount, count)
+            }
+
+            /// Sets what happens to the value when the animation ends
+            pub fn fill(self, fill: FillMode) -> Self {
+                self.set(Attribute::Fill, fill)
+            }
+
+            /// Sets the pacing of the animation between its values
+            pub fn easing(mut self, easing: Easing) -> Self {
+                self.easing = Some(easing);
+                self
+            }
+        }
+    };
+}
+
+/// AnimateBuilder assembles an `<animate>` or `<set>` element from typed timing and values
+///
+/// # Note / Arguments
+/// The animation applies to its parent, unless a [target](#method.target) is set. Values are
+/// set with [from](#method.from), [to](#method.to) and [by](#method.by), or with
+/// [values](#method.values), which take precedence. An [easing](#method.easing) is applied to
+/// the values when the animation is built, see
+/// [Easing::apply](../easing/enum.Easing.html#method.apply).
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use svg_definitions::animation::{AnimateBuilder, FillMode, RepeatCount};
+/// use svg_definitions::prelude::*;
+///
+/// let pulse = AnimateBuilder::new(Attr::R)
+///     .values(&[5, 8, 5])
+///     .duration(Duration::from_millis(1500))
+///     .begin(Duration::from_secs(1))
+///     .repeat(RepeatCount::Indefinite)
+///     .build();
+///
+/// assert_eq!(
+///     pulse.to_string(),
+///     "<animate attributeName=\"r\" begin=\"1s\" dur=\"1.5s\" repeatCount=\"indefinite\" \
+///      values=\"5; 8; 5\"/>"
+/// );
+///
+/// let show = AnimateBuilder::new_set(Attr::Visibility, "visible")
+///     .begin_after("pulse", Duration::from_millis(250))
+///     .fill(FillMode::Freeze)
+///     .build();
+///
+/// assert_eq!(
+///     show.to_string(),
+///     "<set attributeName=\"visibility\" begin=\"pulse.end+0.25s\" fill=\"freeze\" to=\"visible\"/>"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnimateBuilder {
+    animation: Element,
+    easing: Option<Easing>,
+}
+
+timing_methods!(AnimateBuilder);
+
+impl AnimateBuilder {
+    /// Creates a new builder for an `<animate>` element changing a certain attribute
+    pub fn new(attribute: Attribute) -> AnimateBuilder {
+        AnimateBuilder {
+            animation: Element::new(TagName::Animate).set(Attribute::AttributeName, attribute),
+            easing: None,
+        }
+    }
+
+    /// Creates a new builder for a `<set>` element, which sets an attribute to a value without
+    /// interpolation
+    pub fn new_set<T>(attribute: Attribute, to: T) -> AnimateBuilder
+    where
+        T: ToString,
+    {
+        AnimateBuilder {
+            animation: Element::new(TagName::Set)
+                .set(Attribute::AttributeName, attribute)
+                .set(Attribute::To, to),
+            easing: None,
+        }
+    }
+
+    /// Sets the value at the start of the animation
+    pub fn from<T: ToString>(self, value: T) -> Self {
+        self.set(Attribute::From, value)
+    }
+
+    /// Sets the value at the end of the animation
+    pub fn to<T: ToString>(self, value: T) -> Self {
+        self.set(Attribute::To, value)
+    }
+
+    /// Sets the value at the end of the animation relative to the value at the start
+    pub fn by<T: ToString>(self, value: T) -> Self {
+        self.set(Attribute::By, value)
+    }
+
+    /// Sets the values the animation passes through, evenly spaced in time
+    pub fn values<T: ToString>(self, values: &[T]) -> Self {
+        let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+        self.set(Attribute::Values, values.join("; "))
+    }
+
+    /// Assembles the animation element
+    pub fn build(self) -> Element {
+        match self.easing {
+            Some(easing) if self.animation.tag_name != TagName::Set => easing.apply(self.animation),
+            _ => self.animation,
+        }
+    }
+}
+
+/// AnimateTransformBuilder assembles an `<animateTransform>` element from typed keyframes
+///
+/// # Note / Arguments
+/// The keyframes are evenly spaced in time and the `type` of the animation is taken from the
+/// first keyframe. By default the animated transform replaces the `transform` of the element,
+/// [additive](#method.additive) adds it instead.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use svg_definitions::animation::{AnimateTransformBuilder, RepeatCount};
+/// use svg_definitions::transform::Transform;
+///
+/// let spin = AnimateTransformBuilder::new()
+///     .keyframe(Transform::Rotate(0.0, 12.0, 12.0))
+///     .keyframe(Transform::Rotate(360.0, 12.0, 12.0))
+///     .duration(Duration::from_secs(2))
+///     .repeat(RepeatCount::Indefinite)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     spin.to_string(),
+///     "<animateTransform attributeName=\"transform\" dur=\"2s\" repeatCount=\"indefinite\" \
+///      type=\"rotate\" values=\"0 12 12; 360 12 12\"/>"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnimateTransformBuilder {
+    animation: Element,
+    easing: Option<Easing>,
+    keyframes: Vec<Transform>,
+}
+
+timing_methods!(AnimateTransformBuilder);
+
+impl AnimateTransformBuilder {
+    /// Creates a new builder for an `<animateTransform>` element without keyframes
+    pub fn new() -> AnimateTransformBuilder {
+        AnimateTransformBuilder {
+            animation: Element::new(TagName::AnimateTransform)
+                .set(Attribute::AttributeName, Attribute::Transform),
+            easing: None,
+            keyframes: Vec::new(),
+        }
+    }
+
+    /// Adds a keyframe, which has to be of the same kind as the other keyframes
+    pub fn keyframe(mut self, transform: Transform) -> Self {
+        self.keyframes.push(transform);
+        self
+    }
+
+    /// Adds the animated transform to the `transform` of the element instead of replacing it
+    pub fn additive(self) -> Self {
+        self.set(Attribute::Additive, "sum")
+    }
+
+    /// Assembles the animation element, or returns an error if a keyframe is a `Matrix`, which
+    /// cannot be animated, or if the keyframes are of different kinds
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::animation::{AnimateTransformBuilder, InvalidKeyframe};
+    /// use svg_definitions::transform::Transform;
+    ///
+    /// let result = AnimateTransformBuilder::new()
+    ///     .keyframe(Transform::SkewX(10.0))
+    ///     .keyframe(Transform::SkewY(10.0))
+    ///     .build();
+    /// assert_eq!(result.unwrap_err(), InvalidKeyframe::MixedKinds { index: 1 });
+    /// ```
+    pub fn build(self) -> Result<Element, InvalidKeyframe> {
+        let mut kind = None;
+        let mut values = Vec::with_capacity(self.keyframes.len());
+        for (index, keyframe) in self.keyframes.iter().enumerate() {
+            let (name, value) = match *keyframe {
+                Transform::Translate(x, y) => ("translate", format!("{} {}", x, y)),
+                Transform::Scale(x, y) => ("scale", format!("{} {}", x, y)),
+                Transform::Rotate(angle, cx, cy) => ("rotate", format!("{} {} {}", angle, cx, cy)),
+                Transform::SkewX(angle) => ("skewX", angle.to_string()),
+                Transform::SkewY(angle) => ("skewY", angle.to_string()),
+                Transform::Matrix(..) => return Err(InvalidKeyframe::Matrix { index }),
+            };
+            if *kind.get_or_insert(name) != name {
+                return Err(InvalidKeyframe::MixedKinds { index });
+            }
+            values.push(value);
+        }
+
+        let mut animation = self.animation;
+        if let Some(kind) = kind {
+            animation = animation
+                .set(Attribute::Type, kind)
+                .set(Attribute::Values, values.join("; "));
+        }
+        Ok(match self.easing {
+            Some(easing) => easing.apply(animation),
+            None => animation,
+        })
+    }
+}
+
+/// The error returned when the keyframes of an
+/// [AnimateTransformBuilder](struct.AnimateTransformBuilder.html) cannot be animated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidKeyframe {
+    /// The keyframe at an index is a `Matrix`, which `<animateTransform>` cannot animate
+    Matrix { index: usize },
+
+    /// The keyframe at an index is of another kind than the first keyframe
+    MixedKinds { index: usize },
+}
+
+impl fmt::Display for InvalidKeyframe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidKeyframe::Matrix { index } => {
+                write!(
+                    f,
+                    "keyframe {} is a matrix, which cannot be animated",
+                    index
+                )
+            }
+            InvalidKeyframe::MixedKinds { index } => write!(
+                f,
+                "keyframe {} is of another kind than the first keyframe",
+                index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidKeyframe {}
+
+impl Default for AnimateTransformBuilder {
+    fn default() -> Self {
+        AnimateTransformBuilder::new()
+    }
+}
+
+/// How an element is turned while it moves along a path, i.e. the `rotate` attribute of an
+/// `<animateMotion>` element
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MotionRotate {
+    /// Turns the element in the direction of the path
+    Auto,
+
+    /// Turns the element in the opposite direction of the path
+    AutoReverse,
+
+    /// Keeps the element at a fixed angle in degrees
+    Angle(f64),
+}
+
+impl fmt::Display for MotionRotate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MotionRotate::Auto => f.write_str("auto"),
+            MotionRotate::AutoReverse => f.write_str("auto-reverse"),
+            MotionRotate::Angle(angle) => write!(f, "{}", angle),
+        }
+    }
+}
+
+/// AnimateMotionBuilder assembles an `<animateMotion>` element which moves an element along a
+/// path
+///
+/// # Note / Arguments
+/// The path is either given as path data with [along_path](#method.along_path), or as a
+/// reference to a `<path>` element elsewhere in the document with
+/// [along_reference](#method.along_reference), which takes precedence. An
+/// [easing](#method.easing) paces the progress along the path through `keyPoints`.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use svg_definitions::animation::{AnimateMotionBuilder, MotionRotate};
+/// use svg_definitions::prelude::*;
+///
+/// let orbit = AnimateMotionBuilder::new()
+///     .along_reference("orbit")
+///     .rotate(MotionRotate::Auto)
+///     .duration(Duration::from_secs(4))
+///     .build();
+///
+/// assert_eq!(
+///     orbit.to_string(),
+///     "<animateMotion dur=\"4s\" rotate=\"auto\"><mpath href=\"#orbit\"/></animateMotion>"
+/// );
+///
+/// let slide = AnimateMotionBuilder::new()
+///     .along_path(PathData::new().move_to((0.0, 0.0)).line_to((100.0, 0.0)))
+///     .build();
+/// # assert_eq!(slide.get_attributes()[&Attr::Path], "M 0.00 0.00 L 100.00 0.00");
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnimateMotionBuilder {
+    animation: Element,
+    easing: Option<Easing>,
+}
+
+timing_methods!(AnimateMotionBuilder);
+
+impl AnimateMotionBuilder {
+    /// Creates a new builder for an `<animateMotion>` element without a path
+    pub fn new() -> AnimateMotionBuilder {
+        AnimateMotionBuilder {
+            animation: Element::new(TagName::AnimateMotion),
+            easing: None,
+        }
+    }
+
+    /// Moves the element along path data
+    pub fn along_path(self, path: PathDefinitionString) -> Self {
+        self.set(Attribute::Path, path)
+    }
+
+    /// Moves the element along the `<path>` element with a certain id
+    pub fn along_reference(mut self, id: &str) -> Self {
+        self.animation
+            .children
+            .retain(|child| child.tag_name != TagName::Mpath);
+        self.animation = self
+            .animation
+            .append(Element::new(TagName::Mpath).set(Attribute::Href, format!("#{}", id)));
+        self
+    }
+
+    /// Sets how the element is turned while it moves
+    pub fn rotate(self, rotate: MotionRotate) -> Self {
+        self.set(Attribute::Rotate, rotate)
+    }
+
+    /// Assembles the animation element
+    pub fn build(self) -> Element {
+        let easing = match self.easing {
+            Some(easing) => easing,
+            None => return self.animation,
+        };
+
+        // The easing is applied to the progress along the path, from 0 to 1
+        let mut animation = easing.apply(self.animation.set(Attribute::Values, "0; 1"));
+        if let Some(progress) = animation.attributes.remove(&Attribute::Values) {
+            let key_points: Vec<String> = progress
+                .split(';')
+                .map(|point| {
+                    let point: f64 = point.trim().parse().unwrap_or(0.0);
+                    point.clamp(0.0, 1.0).to_string()
+                })
+                .collect();
+            animation.set_attribute(Attribute::KeyPoints, key_points.join("; "));
+        }
+        animation
+    }
+}
+
+impl Default for AnimateMotionBuilder {
+    fn default() -> Self {
+        AnimateMotionBuilder::new()
+    }
+}
+
+/// CssAnimation assembles a CSS animation, with a `<style>` element holding the `@keyframes` and
+/// a class which is assigned to the animated elements, as an alternative to SMIL
+///
+/// # Note / Arguments
+/// The animation name is used both for the keyframes and for the class, so it has to be a
+/// valid CSS identifier. Keyframes are given at an offset between 0 and 1 of the duration, and
+/// properties given at the same offset are combined into one keyframe. The
+/// [easing](#method.easing) is written as a CSS timing function, see
+/// [Easing::to_css](../easing/enum.Easing.html#method.to_css), and defaults to `ease`.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+///
+/// use svg_definitions::animation::{CssAnimation, FillMode};
+/// use svg_definitions::easing::Easing;
+/// use svg_definitions::prelude::*;
+///
+/// let fade_in = CssAnimation::new("fade-in")
+///     .keyframe(0.0, "opacity", 0)
+///     .keyframe(0.0, "transform", "translateY(4px)")
+///     .keyframe(1.0, "opacity", 1)
+///     .keyframe(1.0, "transform", "none")
+///     .duration(Duration::from_millis(300))
+///     .easing(Easing::EASE_OUT)
+///     .fill(FillMode::Freeze);
+///
+/// let rect = fade_in.assign(SVGElem::new(Tag::Rect).set(Attr::Class, "card"));
+/// assert_eq!(rect.to_string(), "<rect class=\"card fade-in\"/>");
+/// assert_eq!(
+///     fade_in.build().to_string(),
+///     "<style>@keyframes fade-in { 0% { opacity: 0; transform: translateY(4px); } \
+///      100% { opacity: 1; transform: none; } } .fade-in { animation: fade-in 0.3s \
+///      cubic-bezier(0, 0, 0.58, 1) forwards; }</style>"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CssAnimation {
+    name: String,
+    keyframes: Vec<(f64, Vec<(String, String)>)>,
+    duration: ClockValue,
+    delay: Option<ClockValue>,
+    easing: Option<Easing>,
+    repeat: Option<RepeatCount>,
+    fill: Option<FillMode>,
+}
+
+impl CssAnimation {
+    /// Creates a new CSS animation with a certain name, without keyframes and with a duration
+    /// of 1 second
+    pub fn new(name: &str) -> CssAnimation {
+        CssAnimation {
+            name: String::from(name),
+            keyframes: Vec::new(),
+            duration: ClockValue(Duration::from_secs(1)),
+            delay: None,
+            easing: None,
+            repeat: None,
+            fill: None,
+        }
+    }
+
+    /// Sets a property to a value at an offset between 0 and 1 of the duration
+    ///
+    /// # Panics
+    /// If the offset is not between 0 and 1.
+    pub fn keyframe<T: ToString>(mut self, offset: f64, property: &str, value: T) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&offset),
+            "The offset of a keyframe has to be between 0 and 1"
+        );
+
+        let declaration = (String::from(property), value.to_string());
+        match self
+            .keyframes
+            .iter()
+            .position(|(other, _)| *other >= offset)
+        {
+            Some(index) if self.keyframes[index].0 == offset => {
+                let declarations = &mut self.keyframes[index].1;
+                match declarations.iter_mut().find(|(other, _)| other == property) {
+                    Some(existing) => *existing = declaration,
+                    None => declarations.push(declaration),
+                }
+            }
+            Some(index) => self.keyframes.insert(index, (offset, vec![declaration])),
+            None => self.keyframes.push((offset, vec![declaration])),
+        }
+        self
+    }
+
+    /// Sets the duration of one repetition of the animation
+    pub fn duration<T: Into<ClockValue>>(mut self, duration: T) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    /// Sets the time after the element appears at which the animation begins
+    pub fn delay<T: Into<ClockValue>>(mut self, delay: T) -> Self {
+        self.delay = Some(delay.into());
+        self
+    }
+
+    /// Sets the pacing of the animation between its keyframes
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    /// Sets how often the animation repeats
+    pub fn repeat(mut self, count: RepeatCount) -> Self {
+        self.repeat = Some(count);
+        self
+    }
+
+    /// Sets whether the properties keep the values of the last keyframe when the animation ends
+    pub fn fill(mut self, fill: FillMode) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Returns the value of the `animation` property of the animation
+    fn shorthand(&self) -> String {
+        let mut parts = vec![self.name.clone(), self.duration.to_string()];
+        if let Some(easing) = &self.easing {
+            parts.push(easing.to_css());
+        }
+        if let Some(delay) = self.delay {
+            parts.push(delay.to_string());
+        }
+        match self.repeat {
+            Some(RepeatCount::Count(count)) => parts.push(count.to_string()),
+            Some(RepeatCount::Indefinite) => parts.push(String::from("infinite")),
+            None => {}
+        }
+        if self.fill == Some(FillMode::Freeze) {
+            parts.push(String::from("forwards"));
+        }
+        parts.join(" ")
+    }
+
+    /// Adds the class of the animation to an element, which starts the animation on it
+    pub fn assign(&self, mut element: Element) -> Element {
+        let class = match element.attributes.get(&Attribute::Class) {
+            Some(class) if class.split_whitespace().any(|name| name == self.name) => {
+                return element
+            }
+            Some(class) if !class.trim().is_empty() => format!("{} {}", class.trim(), self.name),
+            _ => self.name.clone(),
+        };
+        element.set_attribute(Attribute::Class, class);
+        element
+    }
+
+    /// Assembles the `<style>` element with the keyframes and the rule for the class of the
+    /// animation, which only has to be added to a document once
+    pub fn build(&self) -> Element {
+        let keyframes: Vec<String> = self
+            .keyframes
+            .iter()
+            .map(|(offset, declarations)| {
+                let declarations: Vec<String> = declarations
+                    .iter()
+                    .map(|(property, value)| format!("{}: {};", property, value))
+                    .collect();
+                format!(
+                    "{}% {{ {} }}",
+                    (offset * 1e6).round() / 1e4,
+                    declarations.join(" ")
+                )
+            })
+            .collect();
+
+        Element::new(TagName::Style).set_inner(&format!(
+            "@keyframes {} {{ {} }} .{} {{ animation: {}; }}",
+            self.name,
+            keyframes.join(" "),
+            self.name,
+            self.shorthand()
+        ))
+    }
+}
+
+/// Returns a static snapshot of a document at a time in seconds, with the values of its
+/// `<animate>`, `<animateTransform>` and `<set>` elements applied and all animation elements
+/// removed
+///
+/// # Note / Arguments
+/// Animations begin at a clock value, e.g. `2s`, and animations which begin on an event are
+/// left out. Timing supports `dur`, `repeatCount`, `repeatDur`, `end` and `fill="freeze"`, and
+/// values support `values`, `from`, `to`, `by`, `keyTimes` and every `calcMode`, where `paced`
+/// is treated as `linear`. Numbers, lengths, lists of numbers and hexadecimal and `rgb()`
+/// colors are interpolated, other values change halfway. `additive="sum"` is supported, but
+/// `accumulate` and `<animateMotion>` are not.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::animation;
+/// use svg_definitions::prelude::*;
+///
+/// let document = SVGDoc::new(
+///     SVGElem::new(Tag::Svg).append(
+///         SVGElem::new(Tag::G).append(
+///             SVGElem::new(Tag::AnimateTransform)
+///                 .set(Attr::AttributeName, "transform")
+///                 .set(Attr::Type, "rotate")
+///                 .set(Attr::Values, "0 5 5; 360 5 5")
+///                 .set(Attr::Begin, "1s")
+///                 .set(Attr::Dur, "4s")
+///                 .set(Attr::RepeatCount, "indefinite"),
+///         ),
+///     ),
+/// );
+///
+/// assert_eq!(animation::sample(&document, 0.0).to_string(), "<svg><g/></svg>");
+/// assert_eq!(
+///     animation::sample(&document, 11.0).to_string(),
+///     "<svg><g transform=\"rotate(180 5 5)\"/></svg>"
+/// );
+/// ```
+pub fn sample(document: &Document, time: f64) -> Element {
+    let mut root = document.get_root().clone();
+
+    let mut animations = Vec::new();
+    let mut ids = HashMap::new();
+    collect(&root, &ElementPath::new(), &mut animations, &mut ids);
+
+    for (path, animation) in animations {
+        let href = animation
+            .get_attributes()
+            .get(&Attribute::Href)
+            .or_else(|| animation.get_attributes().get(&Attribute::XlinkHref));
+        let target = match href {
+            Some(href) => href
+                .trim()
+                .strip_prefix('#')
+                .and_then(|id| ids.get(id))
+                .cloned(),
+            None => path.parent(),
+        };
+        let target = match target.and_then(|target| root.get_path_mut(&target)) {
+            Some(target) => target,
+            None => continue,
+        };
+
+        if let Some((attribute, value)) = evaluate(&animation, target, time) {
+            target.set_attribute(attribute, value);
+        }
+    }
+
+    remove_animations(&mut root);
+    root
+}
+
+/// Sets up the dashes of an element, so its stroke is hidden by moving the dash pattern by
+/// one dash, and returns the length of the dash
+fn prepare_draw_on(element: &mut Element) -> String {
+    let length = match element.attributes.get(&Attribute::PathLength) {
+        Some(path_length) => geometry::user_units(path_length),
+        None => geometry::total_length(element),
+    };
+
+    // The length is rounded up, so no dot of the stroke is visible before the animation
+    let length = match length {
+        Some(length) => ((length * 100.0).ceil() / 100.0).to_string(),
+        None => {
+            element.set_attribute(Attribute::PathLength, String::from("1"));
+            String::from("1")
+        }
+    };
+    element.set_attribute(Attribute::StrokeDasharray, length.clone());
+    element.set_attribute(Attribute::StrokeDashoffset, length.clone());
+    length
+}
+
+/// Animates drawing the stroke of an element from its start to its end with SMIL, the classic
+/// line drawing effect
+///
+/// # Note / Arguments
+/// The stroke is hidden by a single dash which is as long as the element, which is moved into
+/// view by an `<animate>` of the `stroke-dashoffset` over `duration` seconds. The length is
+/// measured with [geometry::total_length](../geometry/fn.total_length.html) or taken from the
+/// `pathLength` attribute. Elements which cannot be measured get a `pathLength` of 1. Any
+/// existing dashes of the element are replaced.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::animation;
+/// use svg_definitions::prelude::*;
+///
+/// let line = SVGElem::new(Tag::Path).set(Attr::D, "M 0 0 H 30 V 40").set(Attr::Stroke, "black");
+///
+/// assert_eq!(
+///     animation::draw_on(line, 2.0).to_string(),
+///     "<path d=\"M 0 0 H 30 V 40\" stroke=\"black\" stroke-dasharray=\"70\" \
+///      stroke-dashoffset=\"70\"><animate attributeName=\"stroke-dashoffset\" dur=\"2s\" \
+///      fill=\"freeze\" from=\"70\" to=\"0\"/></path>"
+/// );
+/// ```
+pub fn draw_on(mut element: Element, duration: f64) -> Element {
+    let length = prepare_draw_on(&mut element);
+    element.append(
+        Element::new(TagName::Animate)
+            .set(Attribute::AttributeName, "stroke-dashoffset")
+            .set(Attribute::From, length)
+            .set(Attribute::To, 0)
+            .set(
+                Attribute::Dur,
+                format!("{}s", (duration * 1e4).round() / 1e4),
+            )
+            .set(Attribute::Fill, "freeze"),
+    )
+}
+
+/// Animates drawing the stroke of an element from its start to its end with a CSS animation,
+/// returning the element and the `<style>` element with the `draw-on` keyframes
+///
+/// # Note / Arguments
+/// The dashes are set up as with [draw_on](fn.draw_on.html), and the `animation` property is
+/// added to the `style` of the element. The keyframes are the same for every element, so the
+/// `<style>` element only has to be added to a document once.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::animation;
+/// use svg_definitions::prelude::*;
+///
+/// let circle = SVGElem::new(Tag::Circle).set(Attr::R, 10).set(Attr::Stroke, "black");
+/// let (circle, style) = animation::draw_on_css(circle, 1.5);
+///
+/// assert_eq!(circle.get_attributes()[&Attr::StrokeDasharray], "62.84");
+/// assert_eq!(circle.get_attributes()[&Attr::Style], "animation: draw-on 1.5s linear forwards");
+/// assert_eq!(
+///     style.to_string(),
+///     "<style>@keyframes draw-on { to { stroke-dashoffset: 0; } }</style>"
+/// );
+/// ```
+pub fn draw_on_css(mut element: Element, duration: f64) -> (Element, Element) {
+    prepare_draw_on(&mut element);
+
+    let animation = format!(
+        "animation: draw-on {}s linear forwards",
+        (duration * 1e4).round() / 1e4
+    );
+    let style = match element.attributes.get(&Attribute::Style) {
+        Some(style) if !style.trim().is_empty() => {
+            format!("{}; {}", style.trim().trim_end_matches(';'), animation)
+        }
+        _ => animation,
+    };
+    element.set_attribute(Attribute::Style, style);
+
+    let keyframes = Element::new(TagName::Style)
+        .set_inner("@keyframes draw-on { to { stroke-dashoffset: 0; } }");
+    (element, keyframes)
+}
+
+/// Collects the animation elements in document order together with the paths of all ids
+fn collect(
+    element: &Element,
+    path: &ElementPath,
+    animations: &mut Vec<(ElementPath, Element)>,
+    ids: &mut HashMap<String, ElementPath>,
+) {
+    if is_animation(&element.tag_name) {
+        animations.push((path.clone(), element.clone()));
+    }
+    if let Some(id) = element.attributes.get(&Attribute::Id) {
+        ids.entry(id.trim().to_string())
+            .or_insert_with(|| path.clone());
+    }
+
+    for (index, child) in element.children.iter().enumerate() {
+        collect(child, &path.child(index), animations, ids);
+    }
+}
+
+fn is_animation(tag_name: &TagName) -> bool {
+    matches!(
+        tag_name,
+        TagName::Animate | TagName::AnimateTransform | TagName::AnimateMotion | TagName::Set
+    )
+}
+
+fn remove_animations(element: &mut Element) {
+    element
+        .children
+        .retain(|child| !is_animation(&child.tag_name));
+    for child in element.children.iter_mut() {
+        remove_animations(child);
+    }
+}
+
+/// Returns the attribute of the target set by an animation at a time, or None if the animation
+/// has no effect at that time
+fn evaluate(animation: &Element, target: &Element, time: f64) -> Option<(Attribute, String)> {
+    let get = |attribute: Attribute| {
+        animation
+            .attributes
+            .get(&attribute)
+            .map(|value| value.trim())
+    };
+    let attribute = Attribute::custom(get(Attribute::AttributeName)?);
+    let base = target.attributes.get(&attribute).map(|value| value.trim());
+
+    let begin = match get(Attribute::Begin) {
+        Some(begin) => begin.split(';').find_map(clock_value)?,
+        None => 0.0,
+    };
+    let duration = get(Attribute::Dur)
+        .and_then(clock_value)
+        .filter(|dur| *dur > 0.0);
+
+    let repeat_count = get(Attribute::RepeatCount).map(|count| match count {
+        "indefinite" => f64::INFINITY,
+        count => count.parse().unwrap_or(1.0),
+    });
+    let repeat_duration = get(Attribute::RepeatDur).map(|dur| match dur {
+        "indefinite" => f64::INFINITY,
+        dur => clock_value(dur).unwrap_or(f64::INFINITY),
+    });
+    let mut active = match (duration, repeat_count, repeat_duration) {
+        (None, _, _) => f64::INFINITY,
+        (Some(dur), None, None) => dur,
+        (Some(dur), count, repeat) => {
+            (dur * count.unwrap_or(f64::INFINITY)).min(repeat.unwrap_or(f64::INFINITY))
+        }
+    };
+    if let Some(end) = get(Attribute::End).and_then(|end| end.split(';').find_map(clock_value)) {
+        active = active.min(end - begin);
+    }
+
+    let local = time - begin;
+    if local < 0.0 {
+        return None;
+    }
+    let frozen = local >= active;
+    if frozen && get(Attribute::Fill) != Some("freeze") {
+        return None;
+    }
+
+    if animation.tag_name == TagName::Set {
+        return Some((attribute, get(Attribute::To)?.to_string()));
+    }
+
+    // Without a duration, an animation cannot progress
+    let duration = duration?;
+    let progress = if frozen {
+        match active % duration {
+            0.0 => 1.0,
+            rest => rest / duration,
+        }
+    } else {
+        (local % duration) / duration
+    };
+
+    let is_transform = animation.tag_name == TagName::AnimateTransform;
+    let transform_type = get(Attribute::Type).unwrap_or("translate");
+    let additive = get(Attribute::Additive) == Some("sum");
+
+    let values: Vec<String> = match (
+        get(Attribute::Values),
+        get(Attribute::From),
+        get(Attribute::To),
+        get(Attribute::By),
+    ) {
+        (Some(values), ..) => values
+            .split(';')
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect(),
+        (None, Some(from), Some(to), _) => vec![from.to_string(), to.to_string()],
+        (None, Some(from), None, Some(by)) => vec![from.to_string(), add(from, by)?],
+        (None, None, Some(to), _) if !is_transform => vec![base?.to_string(), to.to_string()],
+        (None, None, None, Some(by)) => {
+            let (text, numbers) = split_numbers(by);
+            let zero = join_numbers(&text, &vec![0.0; numbers.len()]);
+            return value_at(&[zero, by.to_string()], progress, animation).and_then(|value| {
+                combine(&attribute, base, &value, true, is_transform, transform_type)
+            });
+        }
+        _ => return None,
+    };
+
+    let value = value_at(&values, progress, animation)?;
+    combine(
+        &attribute,
+        base,
+        &value,
+        additive,
+        is_transform,
+        transform_type,
+    )
+}
+
+/// Combines the animated value with the base value of the attribute
+fn combine(
+    attribute: &Attribute,
+    base: Option<&str>,
+    value: &str,
+    additive: bool,
+    is_transform: bool,
+    transform_type: &str,
+) -> Option<(Attribute, String)> {
+    let value = if is_transform {
+        let transform = format!("{}({})", transform_type, value);
+        match base {
+            Some(base) if additive && !base.is_empty() => format!("{} {}", base, transform),
+            _ => transform,
+        }
+    } else {
+        match base {
+            Some(base) if additive => add(base, value).unwrap_or_else(|| value.to_string()),
+            _ => value.to_string(),
+        }
+    };
+
+    Some((attribute.clone(), value))
+}
+
+/// Returns the value of a list of values at a progress from 0 to 1 through the simple duration
+fn value_at(values: &[String], progress: f64, animation: &Element) -> Option<String> {
+    let get = |attribute: Attribute| {
+        animation
+            .attributes
+            .get(&attribute)
+            .map(|value| value.trim())
+    };
+    let calc_mode = get(Attribute::CalcMode).unwrap_or("linear");
+    let discrete = calc_mode == "discrete" || values.len() == 1;
+
+    let key_times: Vec<f64> = match get(Attribute::KeyTimes) {
+        Some(key_times) => key_times
+            .split(';')
+            .map(|time| time.trim().parse().ok())
+            .collect::<Option<Vec<f64>>>()
+            .filter(|key_times| key_times.len() == values.len())?,
+        None if discrete => (0..values.len())
+            .map(|index| index as f64 / values.len() as f64)
+            .collect(),
+        None => (0..values.len())
+            .map(|index| index as f64 / (values.len() - 1) as f64)
+            .collect(),
+    };
+
+    if discrete {
+        let index = key_times
+            .iter()
+            .rposition(|time| *time <= progress)
+            .unwrap_or(0);
+        return values.get(index).cloned();
+    }
+    if values.len() < 2 {
+        return None;
+    }
+
+    let segment = key_times
+        .windows(2)
+        .position(|times| progress < times[1])
+        .unwrap_or(values.len() - 2);
+    let (start, end) = (key_times[segment], key_times[segment + 1]);
+    let mut fraction = if end > start {
+        ((progress - start) / (end - start)).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+
+    if calc_mode == "spline" {
+        let splines: Vec<Vec<f64>> = get(Attribute::KeySplines)?
+            .split(';')
+            .map(|spline| {
+                spline
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|number| !number.is_empty())
+                    .map(|number| number.parse().ok())
+                    .collect::<Option<Vec<f64>>>()
+            })
+            .collect::<Option<Vec<Vec<f64>>>>()?;
+        match splines.get(segment).map(Vec::as_slice) {
+            Some(&[x1, y1, x2, y2]) => fraction = cubic_bezier((x1, y1), (x2, y2), fraction),
+            _ => return None,
+        }
+    }
+
+    Some(interpolate(
+        &values[segment],
+        &values[segment + 1],
+        fraction,
+    ))
+}
+
+/// Interpolates between two values, or switches halfway if they cannot be interpolated
+pub(crate) fn interpolate(from: &str, to: &str, fraction: f64) -> String {
+    if let (Ok(from), Ok(to)) = (Color::parse(from), Color::parse(to)) {
+        return from.mix(&to, fraction).to_string();
+    }
+
+    let (from_text, from_numbers) = split_numbers(from);
+    let (to_text, to_numbers) = split_numbers(to);
+    if from_text != to_text || from_numbers.is_empty() {
+        return String::from(if fraction < 0.5 { from } else { to });
+    }
+
+    let numbers: Vec<f64> = from_numbers
+        .iter()
+        .zip(to_numbers.iter())
+        .map(|(from, to)| from + (to - from) * fraction)
+        .collect();
+    join_numbers(&from_text, &numbers)
+}
+
+/// Adds the numbers of two values with the same form, e.g. `10px` and `5px`
+fn add(base: &str, value: &str) -> Option<String> {
+    let (base_text, base_numbers) = split_numbers(base);
+    let (text, numbers) = split_numbers(value);
+    if base_text != text {
+        return None;
+    }
+
+    let sums: Vec<f64> = base_numbers
+        .iter()
+        .zip(numbers.iter())
+        .map(|(base, number)| base + number)
+        .collect();
+    Some(join_numbers(&text, &sums))
+}
+
+/// Splits a value into the text around its numbers and the numbers, e.g. `10px 5px` into
+/// `["", "px ", "px"]` and `[10, 5]`
+fn split_numbers(value: &str) -> (Vec<String>, Vec<f64>) {
+    let mut text = vec![String::new()];
+    let mut numbers = Vec::new();
+    let bytes = value.as_bytes();
+
+    let mut index = 0;
+    while index < value.len() {
+        let start = index;
+        let mut end = index;
+        if matches!(bytes[end], b'+' | b'-') {
+            end += 1;
+        }
+        let digits_start = end;
+        while end < bytes.len() && (bytes[end].is_ascii_digit() || bytes[end] == b'.') {
+            end += 1;
+        }
+        let mantissa = &value[digits_start..end];
+        let is_number = mantissa.bytes().any(|byte| byte.is_ascii_digit())
+            && mantissa.matches('.').count() <= 1
+            // A number right after a letter is part of a name, e.g. `h1`
+            && !(start > 0 && (bytes[start - 1].is_ascii_alphabetic() || bytes[start - 1] == b'#'));
+
+        if is_number {
+            if end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+                let mut exponent = end + 1;
+                if exponent < bytes.len() && matches!(bytes[exponent], b'+' | b'-') {
+                    exponent += 1;
+                }
+                if exponent < bytes.len() && bytes[exponent].is_ascii_digit() {
+                    end = exponent;
+                    while end < bytes.len() && bytes[end].is_ascii_digit() {
+                        end += 1;
+                    }
+                }
+            }
+            numbers.push(value[start..end].parse().unwrap_or(0.0));
+            text.push(String::new());
+            index = end;
+        } else {
+            let character = value[index..].chars().next().unwrap_or(' ');
+            text.last_mut()
+                .expect("text is never empty")
+                .push(character);
+            index += character.len_utf8();
+        }
+    }
+
+    (text, numbers)
+}
+
+fn join_numbers(text: &[String], numbers: &[f64]) -> String {
+    let mut value = text[0].clone();
+    for (number, text) in numbers.iter().zip(text[1..].iter()) {
+        value.push_str(&((number * 1e4).round() / 1e4).to_string());
+        value.push_str(text);
+    }
+    value
+}
+
+/// Parses a SMIL clock value into seconds, e.g. `1.5s`, `200ms`, `2min` or `01:30`, allowing a
+/// sign for offsets
+///
+/// Like [ClockValue](struct.ClockValue.html), this follows the syntax strictly, so `1:30` and
+/// `10 s` are not clock values.
+fn clock_value(value: &str) -> Option<f64> {
+    let value = value.trim();
+    match value.strip_prefix('-') {
+        Some(value) => clock_value(value).map(|seconds| -seconds),
+        None => value
+            .strip_prefix('+')
+            .unwrap_or(value)
+            .trim()
+            .parse::<ClockValue>()
+            .ok()
+            .map(|clock_value| clock_value.as_secs_f64()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clock_value, cubic_bezier, interpolate, sample};
+    use crate::attributes::Attribute;
+    use crate::document::Document;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(clock_value("1.5s"), Some(1.5));
+        assert_eq!(clock_value("250ms"), Some(0.25));
+        assert_eq!(clock_value("01:30"), Some(90.0));
+        assert_eq!(clock_value("click"), None);
+
+        assert_eq!(interpolate("10px", "20px", 0.25), "12.5px");
+        assert_eq!(interpolate("0 0; 10 -10", "10 10; 0 0", 0.5), "5 5; 5 -5");
+        assert_eq!(interpolate("#000", "rgb(255, 0, 100)", 0.5), "#800032");
+        assert_eq!(interpolate("hidden", "visible", 0.4), "hidden");
+        assert_eq!(interpolate("h1", "h2", 0.6), "h2");
+
+        assert!((cubic_bezier((0.0, 0.0), (1.0, 1.0), 0.3) - 0.3).abs() < 1e-6);
+        assert!(cubic_bezier((0.0, 0.0), (0.0, 1.0), 0.2) > 0.2);
+    }
+
+    #[test]
+    fn test_sample() {
+        let animate =
+            |name: &str| Element::new(TagName::Animate).set(Attribute::AttributeName, name);
+        let document = Document::new(
+            Element::new(TagName::Svg)
+                .append(
+                    Element::new(TagName::Rect)
+                        .set(Attribute::Id, "box")
+                        .set(Attribute::X, 10)
+                        .append(
+                            animate("x")
+                                .set(Attribute::By, 10)
+                                .set(Attribute::Dur, "2s")
+                                .set(Attribute::Fill, "freeze"),
+                        ),
+                )
+                .append(
+                    animate("fill")
+                        .set(Attribute::Href, "#box")
+                        .set(Attribute::Values, "red; #00ff00; blue")
+                        .set(Attribute::KeyTimes, "0; 0.8; 1")
+                        .set(Attribute::CalcMode, "discrete")
+                        .set(Attribute::Dur, "10s")
+                        .set(Attribute::RepeatCount, 2),
+                )
+                .append(
+                    Element::new(TagName::Set)
+                        .set(Attribute::Href, "#box")
+                        .set(Attribute::AttributeName, "visibility")
+                        .set(Attribute::To, "hidden")
+                        .set(Attribute::Begin, "click; 15s"),
+                ),
+        );
+        let rect = |time: f64| {
+            let svg = sample(&document, time);
+            assert_eq!(svg.get_children().len(), 1);
+            svg.get_children()[0].clone()
+        };
+        let attribute =
+            |time: f64, attribute: Attribute| rect(time).get_attributes().get(&attribute).cloned();
+
+        assert_eq!(attribute(1.0, Attribute::X).as_deref(), Some("15"));
+        assert_eq!(attribute(5.0, Attribute::X).as_deref(), Some("20"));
+        assert_eq!(attribute(1.0, Attribute::Fill).as_deref(), Some("red"));
+        assert_eq!(attribute(9.0, Attribute::Fill).as_deref(), Some("#00ff00"));
+        assert_eq!(attribute(18.5, Attribute::Fill).as_deref(), Some("#00ff00"));
+        assert_eq!(attribute(20.0, Attribute::Fill), None);
+        assert_eq!(attribute(14.0, Attribute::Visibility), None);
+        assert_eq!(
+            attribute(15.0, Attribute::Visibility).as_deref(),
+            Some("hidden")
+        );
+        assert!(rect(0.0).get_children().is_empty());
+
+        // Values with only separators do not animate
+        let empty = Document::new(
+            Element::new(TagName::Svg).append(
+                Element::new(TagName::Rect).append(
+                    animate("x")
+                        .set(Attribute::Values, " ; ")
+                        .set(Attribute::Dur, "1s"),
+                ),
+            ),
+        );
+        assert!(sample(&empty, 0.5).get_children()[0]
+            .get_attributes()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_draw_on() {
+        use super::{draw_on, draw_on_css};
+
+        let measured = draw_on(
+            Element::new(TagName::Path)
+                .set(Attribute::D, "M 0 0 L 1 1")
+                .set(Attribute::StrokeDasharray, "4 2"),
+            0.5,
+        );
+        assert_eq!(
+            measured.get_attributes()[&Attribute::StrokeDasharray],
+            "1.42"
+        );
+        assert_eq!(
+            measured.get_children()[0].get_attributes()[&Attribute::Dur],
+            "0.5s"
+        );
+
+        let normalized = draw_on(
+            Element::new(TagName::Path)
+                .set(Attribute::D, "M 0 0 L 1 1")
+                .set(Attribute::PathLength, 100),
+            1.0,
+        );
+        assert_eq!(
+            normalized.get_attributes()[&Attribute::StrokeDashoffset],
+            "100"
+        );
+
+        let (unmeasured, _) = draw_on_css(
+            Element::new(TagName::Text).set(Attribute::Style, "stroke: red;"),
+            3.0,
+        );
+        let attributes = unmeasured.get_attributes();
+        assert_eq!(attributes[&Attribute::PathLength], "1");
+        assert_eq!(attributes[&Attribute::StrokeDasharray], "1");
+        assert_eq!(
+            attributes[&Attribute::Style],
+            "stroke: red; animation: draw-on 3s linear forwards"
+        );
+    }
+
+    #[test]
+    fn test_animate_builder() {
+        use super::{AnimateBuilder, FillMode, RepeatCount};
+        use crate::easing::Easing;
+        use std::time::Duration;
+
+        let animation = AnimateBuilder::new(Attribute::Opacity)
+            .target("logo")
+            .from(0)
+            .to(1)
+            .duration(Duration::from_secs(2))
+            .repeat(RepeatCount::Count(2.5))
+            .fill(FillMode::Remove)
+            .easing(Easing::EASE_IN)
+            .build();
+        assert_eq!(
+            animation.to_string(),
+            "<animate attributeName=\"opacity\" calcMode=\"spline\" dur=\"2s\" fill=\"remove\" \
+             href=\"#logo\" keySplines=\"0.42 0 1 1\" keyTimes=\"0; 1\" repeatCount=\"2.5\" \
+             values=\"0; 1\"/>"
+        );
+
+        // Easings do not apply to <set>, which has no interpolation
+        let set = AnimateBuilder::new_set(Attribute::Fill, "red")
+            .easing(Easing::EASE_IN)
+            .build();
+        assert_eq!(set.to_string(), "<set attributeName=\"fill\" to=\"red\"/>");
+    }
+
+    #[test]
+    fn test_animate_transform_and_motion_builders() {
+        use super::{AnimateMotionBuilder, AnimateTransformBuilder, MotionRotate};
+        use crate::easing::Easing;
+        use crate::path::PathDefinitionString;
+        use crate::transform::Transform;
+        use std::time::Duration;
+
+        let grow = AnimateTransformBuilder::new()
+            .keyframe(Transform::Scale(1.0, 1.0))
+            .keyframe(Transform::Scale(2.0, 1.5))
+            .additive()
+            .duration(Duration::from_millis(500))
+            .easing(Easing::Linear)
+            .build()
+            .unwrap();
+        assert_eq!(
+            grow.to_string(),
+            "<animateTransform additive=\"sum\" attributeName=\"transform\" calcMode=\"linear\" \
+             dur=\"0.5s\" keyTimes=\"0; 1\" type=\"scale\" values=\"1 1; 2 1.5\"/>"
+        );
+
+        // The builder drives a sampled frame like a handwritten animation
+        let document = Document::new(
+            Element::new(TagName::Svg).append(
+                Element::new(TagName::Rect).append(
+                    AnimateTransformBuilder::new()
+                        .keyframe(Transform::Translate(0.0, 0.0))
+                        .keyframe(Transform::Translate(10.0, 20.0))
+                        .duration(Duration::from_secs(1))
+                        .build()
+                        .unwrap(),
+                ),
+            ),
+        );
+        assert_eq!(
+            sample(&document, 0.5).to_string(),
+            "<svg><rect transform=\"translate(5 10)\"/></svg>"
+        );
+
+        let motion = AnimateMotionBuilder::new()
+            .along_reference("a")
+            .along_reference("b")
+            .rotate(MotionRotate::Angle(45.0))
+            .easing(Easing::EASE_OUT)
+            .build();
+        assert_eq!(
+            motion.to_string(),
+            "<animateMotion calcMode=\"spline\" keyPoints=\"0; 1\" keySplines=\"0 0 0.58 1\" \
+             keyTimes=\"0; 1\" rotate=\"45\"><mpath href=\"#b\"/></animateMotion>"
+        );
+
+        // Springs overshoot, but the progress stays on the path
+        let path: PathDefinitionString = "M 0 0 L 10 0".parse().unwrap();
+        let motion = AnimateMotionBuilder::new()
+            .along_path(path)
+            .easing(Easing::spring())
+            .build();
+        let key_points = &motion.get_attributes()[&Attribute::KeyPoints];
+        assert!(key_points
+            .split("; ")
+            .all(|point| (0.0..=1.0).contains(&point.parse::<f64>().unwrap())));
+        assert!(!motion.get_attributes().contains_key(&Attribute::Values));
+    }
+
+    #[test]
+    fn test_animate_transform_builder_mixed_kinds() {
+        use super::{AnimateTransformBuilder, InvalidKeyframe};
+        use crate::transform::Transform;
+
+        assert_eq!(
+            AnimateTransformBuilder::new()
+                .keyframe(Transform::SkewX(10.0))
+                .keyframe(Transform::SkewY(10.0))
+                .build()
+                .unwrap_err(),
+            InvalidKeyframe::MixedKinds { index: 1 }
+        );
+        assert_eq!(
+            AnimateTransformBuilder::new()
+                .keyframe(Transform::Matrix(1.0, 0.0, 0.0, 1.0, 0.0, 0.0))
+                .build()
+                .unwrap_err(),
+            InvalidKeyframe::Matrix { index: 0 }
+        );
+        assert!(AnimateTransformBuilder::new().build().is_ok());
+    }
+
+    #[test]
+    fn test_css_animation() {
+        use super::{CssAnimation, RepeatCount};
+        use std::time::Duration;
+
+        let blink = CssAnimation::new("blink")
+            .keyframe(1.0, "opacity", 1)
+            .keyframe(0.5, "opacity", 0)
+            .keyframe(0.0, "opacity", 0.5)
+            .keyframe(0.0, "opacity", 1)
+            .delay(Duration::from_millis(100))
+            .repeat(RepeatCount::Indefinite);
+        assert_eq!(
+            blink.build().get_inner().as_deref(),
+            Some(
+                "@keyframes blink { 0% { opacity: 1; } 50% { opacity: 0; } 100% { opacity: 1; } } \
+                 .blink { animation: blink 1s 0.1s infinite; }"
+            )
+        );
+
+        let circle = blink.assign(blink.assign(Element::new(TagName::Circle)));
+        assert_eq!(circle.to_string(), "<circle class=\"blink\"/>");
+    }
+
+    #[test]
+    fn test_clock_value() {
+        use super::{AnimateBuilder, ClockValue, InvalidClockValue};
+        use std::time::Duration;
+
+        let seconds = |value: &str| value.parse::<ClockValue>().map(|c| c.as_secs_f64());
+        assert_eq!(seconds("02:30:03"), Ok(9003.0));
+        assert_eq!(seconds("50:00:10.25"), Ok(180010.25));
+        assert_eq!(seconds("02:33"), Ok(153.0));
+        assert_eq!(seconds("00:10.5"), Ok(10.5));
+        assert_eq!(seconds("3.2h"), Ok(11520.0));
+        assert_eq!(seconds("45min"), Ok(2700.0));
+        assert_eq!(seconds(" 30s "), Ok(30.0));
+        assert_eq!(seconds("5ms"), Ok(0.005));
+        assert_eq!(seconds("12.467"), Ok(12.467));
+        for invalid in &[
+            "",
+            "s",
+            "-1s",
+            "1e3s",
+            "1:30",
+            "00:60",
+            "1.5:00",
+            "1:00:00:00",
+            "10 s",
+            "99999999999999999999999s",
+        ] {
+            assert_eq!(
+                invalid.parse::<ClockValue>(),
+                Err(InvalidClockValue(invalid.to_string()))
+            );
+        }
+
+        // Negative offsets are still understood when sampling
+        assert_eq!(clock_value("-1.5s"), Some(-1.5));
+        assert_eq!(clock_value("-99999999999999999999999s"), None);
+        let huge = Document::new(
+            Element::new(TagName::Svg).append(
+                Element::new(TagName::Rect).append(
+                    Element::new(TagName::Animate)
+                        .set(Attribute::AttributeName, "x")
+                        .set(Attribute::To, 10)
+                        .set(Attribute::Dur, "99999999999999999999999s"),
+                ),
+            ),
+        );
+        assert!(sample(&huge, 1.0).get_children()[0]
+            .get_attributes()
+            .is_empty());
+
+        let animation = AnimateBuilder::new(Attribute::X)
+            .duration("01:30".parse::<ClockValue>().unwrap())
+            .end(Duration::from_secs(100))
+            .repeat_duration(ClockValue::from_secs_f64(0.5))
+            .build();
+        assert_eq!(
+            animation.to_string(),
+            "<animate attributeName=\"x\" dur=\"90s\" end=\"100s\" repeatDur=\"0.5s\"/>"
+        );
+    }
+}
@@ -3,7 +3,7 @@
 //! # Note
 //! In the [crate::prelude](../prelude/index.html) the name for
 //! [Attribute](enum.Attribute.html) is [Attr](../prelude/index.html) and for
-//! [AttributeValue](enum.AttributeValue.html) is [AttrValue](../prelude/index.html)
+//! [AttributeValue](struct.AttributeValue.html) is [AttrValue](../prelude/index.html)
 //!
 //! # Examples
 //! ## 1) Setting attributes of a circle
@@ -35,1045 +35,1661 @@
 //!         .close_path()
 //!     );
 //! ```
+//!
+//! ## 3) Setting custom attributes
+//! ```
+//! use svg_definitions::prelude::*;
+//!
+//! let group = SVGElem::new(Tag::G)
+//!     .set(Attr::Custom(String::from("data-layer")), "background")
+//!     .set(Attr::custom("aria-hidden"), true);
+//!
+//! assert_eq!(group.to_string(), "<g aria-hidden=\"true\" data-layer=\"background\"/>");
+//! ```
 
+use std::borrow::Borrow;
 use std::clone::Clone;
-use std::convert::From;
-use std::hash::Hash;
+use std::cmp::Ordering;
+#[cfg(feature = "serde")]
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::keywords::KeywordValue;
+use crate::length::Length;
+use crate::tag_name::TagName;
 
-/// An attribute to an Element
-#[derive(PartialEq, Eq, Debug, Clone, Hash)]
-pub enum Attribute {
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/accent-height)
-    AccentHeight,
+named_enum! {
+    /// An attribute to an Element
+    ///
+    /// # Note
+    /// Attributes can be looked up by their names with `Attribute::from_name("stroke-width")` or
+    /// parsed with `"stroke-width".parse()`. With the feature "serde" enabled, they are
+    /// serialized as their names.
+    ///
+    /// Attributes which are not part of SVG, e.g. `data-*`, `aria-*` or `inkscape:label`, are
+    /// represented by [Custom](enum.Attribute.html#variant.Custom) with their full name.
+    #[derive(PartialEq, Eq, Debug, Clone, Hash)]
+    pub enum Attribute {
+        /// An attribute which is not part of SVG, with its full name including any prefix
+        ///
+        /// # Note
+        /// This should not be used for the names of SVG attributes, since e.g. `Custom("fill")` is a
+        /// different attribute than `Fill`. Use [custom](enum.Attribute.html#method.custom) when
+        /// the name is not known in advance.
+        Custom(String);
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/accumulate)
-    Accumulate,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/accent-height)
+        AccentHeight = "accent-height",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/additive)
-    Additive,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/accumulate)
+        Accumulate = "accumulate",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/alignment-baseline)
-    AlignmentBaseline,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/additive)
+        Additive = "additive",
 
-    /// No MDN Documentation available for this attribute
-    AllowReorder,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/alignment-baseline)
+        AlignmentBaseline = "alignment-baseline",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/alphabetic)
-    Alphabetic,
+        /// No MDN Documentation available for this attribute
+        AllowReorder = "allowReorder",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/amplitude)
-    Amplitude,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/alphabetic)
+        Alphabetic = "alphabetic",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/arabic-form)
-    ArabicForm,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/amplitude)
+        Amplitude = "amplitude",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/ascent)
-    Ascent,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/arabic-form)
+        ArabicForm = "arabic-form",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/attributeName)
-    AttributeName,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/ascent)
+        Ascent = "ascent",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/attributeType)
-    AttributeType,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/attributeName)
+        AttributeName = "attributeName",
 
-    /// No MDN Documentation available for this attribute
-    AutoReverse,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/attributeType)
+        AttributeType = "attributeType",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/azimuth)
-    Azimuth,
+        /// No MDN Documentation available for this attribute
+        AutoReverse = "autoReverse",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/baseFrequency)
-    BaseFrequency,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/azimuth)
+        Azimuth = "azimuth",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/baseline-shift)
-    BaselineShift,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/baseFrequency)
+        BaseFrequency = "baseFrequency",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/baseProfile)
-    BaseProfile,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/baseline-shift)
+        BaselineShift = "baseline-shift",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/bbox)
-    Bbox,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/baseProfile)
+        BaseProfile = "baseProfile",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/begin)
-    Begin,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/bbox)
+        Bbox = "bbox",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/bias)
-    Bias,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/begin)
+        Begin = "begin",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/by)
-    By,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/bias)
+        Bias = "bias",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/calcMode)
-    CalcMode,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/by)
+        By = "by",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cap-height)
-    CapHeight,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/calcMode)
+        CalcMode = "calcMode",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/class)
-    Class,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cap-height)
+        CapHeight = "cap-height",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clip)
-    Clip,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/class)
+        Class = "class",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clipPathUnits)
-    ClipPathUnits,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clip)
+        Clip = "clip",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clip-path)
-    ClipPath,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clipPathUnits)
+        ClipPathUnits = "clipPathUnits",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clip-rule)
-    ClipRule,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clip-path)
+        ClipPath = "clip-path",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color)
-    Color,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/clip-rule)
+        ClipRule = "clip-rule",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-interpolation)
-    ColorInterpolation,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color)
+        Color = "color",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-interpolation-filters)
-    ColorInterpolationfilters,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-interpolation)
+        ColorInterpolation = "color-interpolation",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-profile)
-    ColorProfile,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-interpolation-filters)
+        ColorInterpolationfilters = "color-interpolation-filters",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-rendering)
-    ColorRendering,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-profile)
+        ColorProfile = "color-profile",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/contentScriptType)
-    ContentScriptType,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/color-rendering)
+        ColorRendering = "color-rendering",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/contentStyleType)
-    ContentStyleType,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/contentScriptType)
+        ContentScriptType = "contentScriptType",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cursor)
-    Cursor,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/contentStyleType)
+        ContentStyleType = "contentStyleType",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cx)
-    Cx,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cursor)
+        Cursor = "cursor",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cy)
-    Cy,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cx)
+        Cx = "cx",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/d)
-    D,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/cy)
+        Cy = "cy",
 
-    /// No MDN Documentation available for this attribute
-    Decelerate,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/d)
+        D = "d",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/descent)
-    Descent,
+        /// No MDN Documentation available for this attribute
+        Decelerate = "decelerate",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/diffuseConstant)
-    DiffuseConstant,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/descent)
+        Descent = "descent",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/direction)
-    Direction,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/diffuseConstant)
+        DiffuseConstant = "diffuseConstant",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/display)
-    Display,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/direction)
+        Direction = "direction",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/divisor)
-    Divisor,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/display)
+        Display = "display",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dominant-baseline)
-    DominantBaseline,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/divisor)
+        Divisor = "divisor",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dur)
-    Dur,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dominant-baseline)
+        DominantBaseline = "dominant-baseline",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dx)
-    Dx,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dur)
+        Dur = "dur",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dy)
-    Dy,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dx)
+        Dx = "dx",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/edgeMode)
-    EdgeMode,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/dy)
+        Dy = "dy",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/elevation)
-    Elevation,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/edgeMode)
+        EdgeMode = "edgeMode",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/enable-background)
-    EnableBackground,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/elevation)
+        Elevation = "elevation",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/end)
-    End,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/enable-background)
+        EnableBackground = "enable-background",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/exponent)
-    Exponent,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/end)
+        End = "end",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/externalResourcesRequired)
-    ExternalResourcesRequired,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/exponent)
+        Exponent = "exponent",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill)
-    Fill,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/externalResourcesRequired)
+        ExternalResourcesRequired = "externalResourcesRequired",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-opacity)
-    FillOpacity,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill)
+        Fill = "fill",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule)
-    FillRule,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-opacity)
+        FillOpacity = "fill-opacity",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filter)
-    Filter,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule)
+        FillRule = "fill-rule",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterRes)
-    FilterRes,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filter)
+        Filter = "filter",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterUnits)
-    FilterUnits,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterRes)
+        FilterRes = "filterRes",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/flood-color)
-    FloodColor,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterUnits)
+        FilterUnits = "filterUnits",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/flood-opacity)
-    FloodOpacity,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/flood-color)
+        FloodColor = "flood-color",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-family)
-    FontFamily,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/flood-opacity)
+        FloodOpacity = "flood-opacity",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-size)
-    FontSize,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-family)
+        FontFamily = "font-family",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-size-adjust)
-    FontSizeadjust,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-size)
+        FontSize = "font-size",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-stretch)
-    FontStretch,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-size-adjust)
+        FontSizeadjust = "font-size-adjust",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-style)
-    FontStyle,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-stretch)
+        FontStretch = "font-stretch",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-variant)
-    FontVariant,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-style)
+        FontStyle = "font-style",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-weight)
-    FontWeight,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-variant)
+        FontVariant = "font-variant",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/format)
-    Format,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/font-weight)
+        FontWeight = "font-weight",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/from)
-    From,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/format)
+        Format = "format",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fr)
-    Fr,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/from)
+        From = "from",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fx)
-    Fx,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fr)
+        Fr = "fr",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fy)
-    Fy,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fx)
+        Fx = "fx",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/g1)
-    G1,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fy)
+        Fy = "fy",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/g2)
-    G2,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/g1)
+        G1 = "g1",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/glyph-name)
-    GlyphName,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/g2)
+        G2 = "g2",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/glyph-orientation-horizontal)
-    GlyphOrientationhorizontal,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/glyph-name)
+        GlyphName = "glyph-name",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/glyph-orientation-vertical)
-    GlyphOrientationvertical,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/glyph-orientation-horizontal)
+        GlyphOrientationhorizontal = "glyph-orientation-horizontal",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/glyphRef)
-    GlyphRef,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/glyph-orientation-vertical)
+        GlyphOrientationvertical = "glyph-orientation-vertical",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/gradientTransform)
-    GradientTransform,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/glyphRef)
+        GlyphRef = "glyphRef",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/gradientUnits)
-    GradientUnits,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/gradientTransform)
+        GradientTransform = "gradientTransform",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/hanging)
-    Hanging,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/gradientUnits)
+        GradientUnits = "gradientUnits",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/height)
-    Height,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/hanging)
+        Hanging = "hanging",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/href)
-    Href,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/height)
+        Height = "height",
 
-    /// No MDN Documentation available for this attribute
-    Hreflang,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/href)
+        Href = "href",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/horiz-adv-x)
-    HorizAdvx,
+        /// No MDN Documentation available for this attribute
+        Hreflang = "hreflang",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/horiz-origin-x)
-    HorizOriginx,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/horiz-adv-x)
+        HorizAdvx = "horiz-adv-x",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/id)
-    Id,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/horiz-origin-x)
+        HorizOriginx = "horiz-origin-x",
 
-    /// No MDN Documentation available for this attribute
-    Ideographic,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/id)
+        Id = "id",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/image-rendering)
-    ImageRendering,
+        /// No MDN Documentation available for this attribute
+        Ideographic = "ideographic",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in)
-    In,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/image-rendering)
+        ImageRendering = "image-rendering",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in2)
-    In2,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in)
+        In = "in",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/intercept)
-    Intercept,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in2)
+        In2 = "in2",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k)
-    K,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/intercept)
+        Intercept = "intercept",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k1)
-    K1,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k)
+        K = "k",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k2)
-    K2,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k1)
+        K1 = "k1",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k3)
-    K3,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k2)
+        K2 = "k2",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k4)
-    K4,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k3)
+        K3 = "k3",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/kernelMatrix)
-    KernelMatrix,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/k4)
+        K4 = "k4",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/kernelUnitLength)
-    KernelUnitLength,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/kernelMatrix)
+        KernelMatrix = "kernelMatrix",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/kerning)
-    Kerning,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/kernelUnitLength)
+        KernelUnitLength = "kernelUnitLength",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/keyPoints)
-    KeyPoints,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/kerning)
+        Kerning = "kerning",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/keySplines)
-    KeySplines,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/keyPoints)
+        KeyPoints = "keyPoints",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/keyTimes)
-    KeyTimes,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/keySplines)
+        KeySplines = "keySplines",
 
-    /// No MDN Documentation available for this attribute
-    Lang,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/keyTimes)
+        KeyTimes = "keyTimes",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/lengthAdjust)
-    LengthAdjust,
+        /// No MDN Documentation available for this attribute
+        Lang = "lang",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/letter-spacing)
-    LetterSpacing,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/lengthAdjust)
+        LengthAdjust = "lengthAdjust",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/lighting-color)
-    LightingColor,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/letter-spacing)
+        LetterSpacing = "letter-spacing",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/limitingConeAngle)
-    LimitingConeAngle,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/lighting-color)
+        LightingColor = "lighting-color",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/local)
-    Local,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/limitingConeAngle)
+        LimitingConeAngle = "limitingConeAngle",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/marker-end)
-    MarkerEnd,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/local)
+        Local = "local",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/marker-mid)
-    MarkerMid,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/marker-end)
+        MarkerEnd = "marker-end",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/marker-start)
-    MarkerStart,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/marker-mid)
+        MarkerMid = "marker-mid",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/markerHeight)
-    MarkerHeight,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/marker-start)
+        MarkerStart = "marker-start",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/markerUnits)
-    MarkerUnits,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/markerHeight)
+        MarkerHeight = "markerHeight",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/markerWidth)
-    MarkerWidth,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/markerUnits)
+        MarkerUnits = "markerUnits",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mask)
-    Mask,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/markerWidth)
+        MarkerWidth = "markerWidth",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/maskContentUnits)
-    MaskContentUnits,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mask)
+        Mask = "mask",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/maskUnits)
-    MaskUnits,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/maskContentUnits)
+        MaskContentUnits = "maskContentUnits",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mathematical)
-    Mathematical,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/maskUnits)
+        MaskUnits = "maskUnits",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/max)
-    Max,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mathematical)
+        Mathematical = "mathematical",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/media)
-    Media,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/max)
+        Max = "max",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/method)
-    Method,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/media)
+        Media = "media",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/min)
-    Min,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/method)
+        Method = "method",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mode)
-    Mode,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/min)
+        Min = "min",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/name)
-    Name,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mode)
+        Mode = "mode",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/numOctaves)
-    NumOctaves,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/name)
+        Name = "name",
 
-    /// No MDN Documentation available for this attribute
-    Offset,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/numOctaves)
+        NumOctaves = "numOctaves",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/opacity)
-    Opacity,
+        /// No MDN Documentation available for this attribute
+        Offset = "offset",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/operator)
-    Operator,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/opacity)
+        Opacity = "opacity",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/order)
-    Order,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/operator)
+        Operator = "operator",
 
-    /// No MDN Documentation available for this attribute
-    Orient,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/order)
+        Order = "order",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/orientation)
-    Orientation,
+        /// No MDN Documentation available for this attribute
+        Orient = "orient",
 
-    /// No MDN Documentation available for this attribute
-    Origin,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/orientation)
+        Orientation = "orientation",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/overflow)
-    Overflow,
+        /// No MDN Documentation available for this attribute
+        Origin = "origin",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/overline-position)
-    OverlinePosition,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/overflow)
+        Overflow = "overflow",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/overline-thickness)
-    OverlineThickness,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/overline-position)
+        OverlinePosition = "overline-position",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/panose-1)
-    Panose1,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/overline-thickness)
+        OverlineThickness = "overline-thickness",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/paint-order)
-    PaintOrder,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/panose-1)
+        Panose1 = "panose-1",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/path)
-    Path,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/paint-order)
+        PaintOrder = "paint-order",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pathLength)
-    PathLength,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/path)
+        Path = "path",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/patternContentUnits)
-    PatternContentUnits,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pathLength)
+        PathLength = "pathLength",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/patternTransform)
-    PatternTransform,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/patternContentUnits)
+        PatternContentUnits = "patternContentUnits",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/patternUnits)
-    PatternUnits,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/patternTransform)
+        PatternTransform = "patternTransform",
 
-    /// No MDN Documentation available for this attribute
-    Ping,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/patternUnits)
+        PatternUnits = "patternUnits",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointer-events)
-    PointerEvents,
+        /// No MDN Documentation available for this attribute
+        Ping = "ping",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/points)
-    Points,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointer-events)
+        PointerEvents = "pointer-events",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointsAtX)
-    PointsAtX,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/points)
+        Points = "points",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointsAtY)
-    PointsAtY,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointsAtX)
+        PointsAtX = "pointsAtX",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointsAtZ)
-    PointsAtZ,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointsAtY)
+        PointsAtY = "pointsAtY",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/preserveAlpha)
-    PreserveAlpha,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/pointsAtZ)
+        PointsAtZ = "pointsAtZ",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/preserveAspectRatio)
-    PreserveAspectRatio,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/preserveAlpha)
+        PreserveAlpha = "preserveAlpha",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/primitiveUnits)
-    PrimitiveUnits,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/preserveAspectRatio)
+        PreserveAspectRatio = "preserveAspectRatio",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/r)
-    R,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/primitiveUnits)
+        PrimitiveUnits = "primitiveUnits",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/radius)
-    Radius,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/r)
+        R = "r",
 
-    /// No MDN Documentation available for this attribute
-    ReferrerPolicy,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/radius)
+        Radius = "radius",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/refX)
-    RefX,
+        /// No MDN Documentation available for this attribute
+        ReferrerPolicy = "referrerPolicy",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/refY)
-    RefY,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/refX)
+        RefX = "refX",
 
-    /// No MDN Documentation available for this attribute
-    Rel,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/refY)
+        RefY = "refY",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/rendering-intent)
-    RenderingIntent,
+        /// No MDN Documentation available for this attribute
+        Rel = "rel",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/repeatCount)
-    RepeatCount,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/rendering-intent)
+        RenderingIntent = "rendering-intent",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/repeatDur)
-    RepeatDur,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/repeatCount)
+        RepeatCount = "repeatCount",
 
-    /// No MDN Documentation available for this attribute
-    RequiredExtensions,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/repeatDur)
+        RepeatDur = "repeatDur",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/requiredFeatures)
-    RequiredFeatures,
+        /// No MDN Documentation available for this attribute
+        RequiredExtensions = "requiredExtensions",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/restart)
-    Restart,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/requiredFeatures)
+        RequiredFeatures = "requiredFeatures",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/result)
-    Result,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/restart)
+        Restart = "restart",
 
-    /// No MDN Documentation available for this attribute
-    Rotate,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/result)
+        Result = "result",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/rx)
-    Rx,
+        /// No MDN Documentation available for this attribute
+        Rotate = "rotate",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/ry)
-    Ry,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/rx)
+        Rx = "rx",
 
-    /// No MDN Documentation available for this attribute
-    Slope,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/ry)
+        Ry = "ry",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/spacing)
-    Spacing,
+        /// No MDN Documentation available for this attribute
+        Slope = "slope",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/specularConstant)
-    SpecularConstant,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/spacing)
+        Spacing = "spacing",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/specularExponent)
-    SpecularExponent,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/specularConstant)
+        SpecularConstant = "specularConstant",
 
-    /// No MDN Documentation available for this attribute
-    Speed,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/specularExponent)
+        SpecularExponent = "specularExponent",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/spreadMethod)
-    SpreadMethod,
+        /// No MDN Documentation available for this attribute
+        Speed = "speed",
 
-    /// No MDN Documentation available for this attribute
-    StartOffset,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/spreadMethod)
+        SpreadMethod = "spreadMethod",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stdDeviation)
-    StdDeviation,
+        /// No MDN Documentation available for this attribute
+        StartOffset = "startOffset",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stemh)
-    Stemh,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stdDeviation)
+        StdDeviation = "stdDeviation",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stemv)
-    Stemv,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stemh)
+        Stemh = "stemh",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stitchTiles)
-    StitchTiles,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stemv)
+        Stemv = "stemv",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stop-color)
-    StopColor,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stitchTiles)
+        StitchTiles = "stitchTiles",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stop-opacity)
-    StopOpacity,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stop-color)
+        StopColor = "stop-color",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/strikethrough-position)
-    StrikethroughPosition,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stop-opacity)
+        StopOpacity = "stop-opacity",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/strikethrough-thickness)
-    StrikethroughThickness,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/strikethrough-position)
+        StrikethroughPosition = "strikethrough-position",
 
-    /// No MDN Documentation available for this attribute
-    String,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/strikethrough-thickness)
+        StrikethroughThickness = "strikethrough-thickness",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke)
-    Stroke,
+        /// No MDN Documentation available for this attribute
+        String = "string",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-dasharray)
-    StrokeDasharray,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke)
+        Stroke = "stroke",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-dashoffset)
-    StrokeDashoffset,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-dasharray)
+        StrokeDasharray = "stroke-dasharray",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-linecap)
-    StrokeLinecap,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-dashoffset)
+        StrokeDashoffset = "stroke-dashoffset",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-linejoin)
-    StrokeLinejoin,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-linecap)
+        StrokeLinecap = "stroke-linecap",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-miterlimit)
-    StrokeMiterlimit,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-linejoin)
+        StrokeLinejoin = "stroke-linejoin",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-opacity)
-    StrokeOpacity,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-miterlimit)
+        StrokeMiterlimit = "stroke-miterlimit",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-width)
-    StrokeWidth,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-opacity)
+        StrokeOpacity = "stroke-opacity",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/style)
-    Style,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/stroke-width)
+        StrokeWidth = "stroke-width",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/surfaceScale)
-    SurfaceScale,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/style)
+        Style = "style",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/systemLanguage)
-    SystemLanguage,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/surfaceScale)
+        SurfaceScale = "surfaceScale",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/tabindex)
-    Tabindex,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/systemLanguage)
+        SystemLanguage = "systemLanguage",
 
-    /// No MDN Documentation available for this attribute
-    TableValues,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/tabindex)
+        Tabindex = "tabindex",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/target)
-    Target,
+        /// No MDN Documentation available for this attribute
+        TableValues = "tableValues",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/targetX)
-    TargetX,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/target)
+        Target = "target",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/targetY)
-    TargetY,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/targetX)
+        TargetX = "targetX",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/text-anchor)
-    TextAnchor,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/targetY)
+        TargetY = "targetY",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/text-decoration)
-    TextDecoration,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/text-anchor)
+        TextAnchor = "text-anchor",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/text-rendering)
-    TextRendering,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/text-decoration)
+        TextDecoration = "text-decoration",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/textLength)
-    TextLength,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/text-rendering)
+        TextRendering = "text-rendering",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/to)
-    To,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/textLength)
+        TextLength = "textLength",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform)
-    Transform,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/to)
+        To = "to",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/type)
-    Type,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform)
+        Transform = "transform",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/u1)
-    U1,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/type)
+        Type = "type",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/u2)
-    U2,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/u1)
+        U1 = "u1",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/underline-position)
-    UnderlinePosition,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/u2)
+        U2 = "u2",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/underline-thickness)
-    UnderlineThickness,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/underline-position)
+        UnderlinePosition = "underline-position",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/unicode)
-    Unicode,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/underline-thickness)
+        UnderlineThickness = "underline-thickness",
 
-    /// No MDN Documentation available for this attribute
-    UnicodeBidi,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/unicode)
+        Unicode = "unicode",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/unicode-range)
-    UnicodeRange,
+        /// No MDN Documentation available for this attribute
+        UnicodeBidi = "unicode-bidi",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/units-per-em)
-    UnitsPerem,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/unicode-range)
+        UnicodeRange = "unicode-range",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/v-alphabetic)
-    VAlphabetic,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/units-per-em)
+        UnitsPerem = "units-per-em",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/v-hanging)
-    VHanging,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/v-alphabetic)
+        VAlphabetic = "v-alphabetic",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/v-ideographic)
-    VIdeographic,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/v-hanging)
+        VHanging = "v-hanging",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/v-mathematical)
-    VMathematical,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/v-ideographic)
+        VIdeographic = "v-ideographic",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/values)
-    Values,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/v-mathematical)
+        VMathematical = "v-mathematical",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/vector-effect)
-    VectorEffect,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/values)
+        Values = "values",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/version)
-    Version,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/vector-effect)
+        VectorEffect = "vector-effect",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/vert-adv-y)
-    VertAdvy,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/version)
+        Version = "version",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/vert-origin-x)
-    VertOriginx,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/vert-adv-y)
+        VertAdvy = "vert-adv-y",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/vert-origin-y)
-    VertOriginy,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/vert-origin-x)
+        VertOriginx = "vert-origin-x",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/viewBox)
-    ViewBox,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/vert-origin-y)
+        VertOriginy = "vert-origin-y",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/viewTarget)
-    ViewTarget,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/viewBox)
+        ViewBox = "viewBox",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/visibility)
-    Visibility,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/viewTarget)
+        ViewTarget = "viewTarget",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/width)
-    Width,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/visibility)
+        Visibility = "visibility",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/widths)
-    Widths,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/width)
+        Width = "width",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/word-spacing)
-    WordSpacing,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/widths)
+        Widths = "widths",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/writing-mode)
-    WritingMode,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/word-spacing)
+        WordSpacing = "word-spacing",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x)
-    X,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/writing-mode)
+        WritingMode = "writing-mode",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x-height)
-    XHeight,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x)
+        X = "x",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x1)
-    X1,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x-height)
+        XHeight = "x-height",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x2)
-    X2,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x1)
+        X1 = "x1",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xChannelSelector)
-    XChannelSelector,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/x2)
+        X2 = "x2",
 
-    /// No MDN Documentation available for this attribute
-    XlinkActuate,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xChannelSelector)
+        XChannelSelector = "xChannelSelector",
 
-    /// No MDN Documentation available for this attribute
-    XlinkArcrole,
+        /// No MDN Documentation available for this attribute
+        XlinkActuate = "xlink:actuate",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:href)
-    XlinkHref,
+        /// No MDN Documentation available for this attribute
+        XlinkArcrole = "xlink:arcrole",
 
-    /// No MDN Documentation available for this attribute
-    XlinkRole,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:href)
+        XlinkHref = "xlink:href",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:show)
-    XlinkShow,
+        /// No MDN Documentation available for this attribute
+        XlinkRole = "xlink:role",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:title)
-    XlinkTitle,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:show)
+        XlinkShow = "xlink:show",
 
-    /// No MDN Documentation available for this attribute
-    XlinkType,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:title)
+        XlinkTitle = "xlink:title",
 
-    /// No MDN Documentation available for this attribute
-    XmlBase,
+        /// No MDN Documentation available for this attribute
+        XlinkType = "xlink:type",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:lang)
-    XmlLang,
+        /// No MDN Documentation available for this attribute
+        XmlBase = "xml:base",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:space)
-    XmlSpace,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:lang)
+        XmlLang = "xml:lang",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/y)
-    Y,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:space)
+        XmlSpace = "xml:space",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/y1)
-    Y1,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/y)
+        Y = "y",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/y2)
-    Y2,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/y1)
+        Y1 = "y1",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/yChannelSelector)
-    YChannelSelector,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/y2)
+        Y2 = "y2",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/z)
-    Z,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/yChannelSelector)
+        YChannelSelector = "yChannelSelector",
 
-    /// No MDN Documentation available for this attribute
-    ZoomAndPan,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/z)
+        Z = "z",
 
-    /// For all attributes which are not direct svg attributes
-    UnmappedAttribute(String),
+        /// No MDN Documentation available for this attribute
+        ZoomAndPan = "zoomAndPan",
+    }
 }
 
-// Implementation of Attribute
-impl ToString for Attribute {
-    fn to_string(&self) -> String {
+impl Attribute {
+    /// Returns the attribute with a certain name, which is a
+    /// [Custom](enum.Attribute.html#variant.Custom) attribute if it is not part of SVG
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// assert_eq!(Attr::custom("fill"), Attr::Fill);
+    /// assert_eq!(Attr::custom("data-id"), Attr::Custom(String::from("data-id")));
+    /// ```
+    pub fn custom(name: &str) -> Attribute {
+        Attribute::from_name(name).unwrap_or_else(|| Attribute::Custom(String::from(name)))
+    }
+
+    /// Returns the attribute with a certain name like [custom](#method.custom), but also matches
+    /// the names of SVG attributes written in a different case, as found in legacy files
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// assert_eq!(Attr::lenient("viewbox"), Attr::ViewBox);
+    /// assert_eq!(Attr::lenient("cLip-path"), Attr::ClipPath);
+    /// assert_eq!(Attr::lenient("Data-Id"), Attr::Custom(String::from("Data-Id")));
+    /// ```
+    pub fn lenient(name: &str) -> Attribute {
+        Attribute::from_name_ignore_case(name)
+            .unwrap_or_else(|| Attribute::Custom(String::from(name)))
+    }
+
+    /// Returns the SVG attribute with a name which is equal to `name` when ignoring ASCII case, or
+    /// None if there is no such attribute
+    pub fn from_name_ignore_case(name: &str) -> Option<Attribute> {
+        Attribute::from_name(name).or_else(|| {
+            Attribute::iter()
+                .find(|attribute| attribute.name().eq_ignore_ascii_case(name))
+                .cloned()
+        })
+    }
+
+    /// Returns whether this attribute is a presentation attribute, meaning it can also be
+    /// specified as a CSS property with the same name
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// assert!(Attr::Fill.is_presentation());
+    /// assert!(!Attr::Cx.is_presentation());
+    /// ```
+    pub fn is_presentation(&self) -> bool {
+        use Attribute::*;
+
+        matches!(
+            self,
+            AlignmentBaseline
+                | BaselineShift
+                | Clip
+                | ClipPath
+                | ClipRule
+                | Color
+                | ColorInterpolation
+                | ColorInterpolationfilters
+                | ColorProfile
+                | ColorRendering
+                | Cursor
+                | Direction
+                | Display
+                | DominantBaseline
+                | EnableBackground
+                | Fill
+                | FillOpacity
+                | FillRule
+                | Filter
+                | FloodColor
+                | FloodOpacity
+                | FontFamily
+                | FontSize
+                | FontSizeadjust
+                | FontStretch
+                | FontStyle
+                | FontVariant
+                | FontWeight
+                | GlyphOrientationhorizontal
+                | GlyphOrientationvertical
+                | ImageRendering
+                | Kerning
+                | LetterSpacing
+                | LightingColor
+                | MarkerEnd
+                | MarkerMid
+                | MarkerStart
+                | Mask
+                | Opacity
+                | Overflow
+                | PaintOrder
+                | PointerEvents
+                | StopColor
+                | StopOpacity
+                | Stroke
+                | StrokeDasharray
+                | StrokeDashoffset
+                | StrokeLinecap
+                | StrokeLinejoin
+                | StrokeMiterlimit
+                | StrokeOpacity
+                | StrokeWidth
+                | TextAnchor
+                | TextDecoration
+                | TextRendering
+                | UnicodeBidi
+                | VectorEffect
+                | Visibility
+                | WordSpacing
+                | WritingMode
+        )
+    }
+
+    /// Returns whether this attribute is an animation timing attribute, which controls when and
+    /// how often an animation element plays
+    ///
+    /// # Note
+    /// The `fill` attribute of animation elements is a timing attribute as well, so
+    /// [Fill](enum.Attribute.html#variant.Fill) is both a presentation and a timing attribute.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// assert!(Attr::Dur.is_animation_timing());
+    /// assert!(!Attr::AttributeName.is_animation_timing());
+    /// ```
+    pub fn is_animation_timing(&self) -> bool {
+        use Attribute::*;
+
+        matches!(
+            self,
+            Begin | Dur | End | Fill | Max | Min | RepeatCount | RepeatDur | Restart
+        )
+    }
+
+    /// Returns whether this attribute configures filter primitives and light sources, meaning it
+    /// is only used on `fe*` elements
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// assert!(Attr::StdDeviation.is_filter_primitive());
+    /// assert!(!Attr::Width.is_filter_primitive());
+    /// ```
+    pub fn is_filter_primitive(&self) -> bool {
+        use Attribute::*;
+
+        matches!(
+            self,
+            Amplitude
+                | Azimuth
+                | BaseFrequency
+                | Bias
+                | DiffuseConstant
+                | Divisor
+                | EdgeMode
+                | Elevation
+                | Exponent
+                | In
+                | In2
+                | Intercept
+                | K1
+                | K2
+                | K3
+                | K4
+                | KernelMatrix
+                | KernelUnitLength
+                | LimitingConeAngle
+                | Mode
+                | NumOctaves
+                | Operator
+                | Order
+                | PointsAtX
+                | PointsAtY
+                | PointsAtZ
+                | PreserveAlpha
+                | Result
+                | Slope
+                | SpecularConstant
+                | SpecularExponent
+                | StdDeviation
+                | StitchTiles
+                | SurfaceScale
+                | TableValues
+                | TargetX
+                | TargetY
+                | XChannelSelector
+                | YChannelSelector
+                | Z
+        )
+    }
+
+    /// Returns whether this attribute has an effect on an element with a certain tag name
+    ///
+    /// # Note / Arguments
+    /// Core attributes, like `id`, and [Custom](enum.Attribute.html#variant.Custom) attributes
+    /// apply to every element, and every attribute applies to
+    /// [Custom](../tag_name/enum.TagName.html#variant.Custom) elements. Attributes which are
+    /// deprecated, like those of SVG fonts, are assumed to apply to every element.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// assert!(Attr::R.applies_to(&Tag::Circle));
+    /// assert!(!Attr::R.applies_to(&Tag::Rect));
+    /// assert!(Attr::Fill.applies_to(&Tag::G));
+    /// assert!(Attr::Id.applies_to(&Tag::Title));
+    /// ```
+    pub fn applies_to(&self, tag_name: &TagName) -> bool {
         use Attribute::*;
+        use TagName as T;
+
+        let animation = matches!(
+            tag_name,
+            T::Animate | T::AnimateMotion | T::AnimateTransform | T::Discard | T::Set
+        );
+        let filter_primitive =
+            !matches!(tag_name, T::Custom(_)) && tag_name.to_string().starts_with("fe");
+        let gradient = matches!(tag_name, T::LinearGradient | T::RadialGradient);
+        let viewport = matches!(
+            tag_name,
+            T::Svg | T::Symbol | T::Image | T::ForeignObject | T::Pattern | T::Marker | T::View
+        );
+
+        if let T::Custom(_) = tag_name {
+            return true;
+        }
+        if animation && self.is_animation_timing() {
+            return true;
+        }
+        if self.is_presentation() {
+            return !animation
+                && !matches!(
+                    tag_name,
+                    T::Desc | T::Title | T::Metadata | T::Script | T::Style
+                );
+        }
+        if self.is_animation_timing() {
+            return false;
+        }
+        if self.is_filter_primitive() {
+            return filter_primitive;
+        }
+
+        match self {
+            Class | Id | Lang | Style | Tabindex | XmlBase | XmlLang | XmlSpace | Custom(_) => true,
+            Cx | Cy => matches!(tag_name, T::Circle | T::Ellipse | T::RadialGradient),
+            R => matches!(tag_name, T::Circle | T::RadialGradient),
+            Fr | Fx | Fy => *tag_name == T::RadialGradient,
+            Rx | Ry => matches!(tag_name, T::Rect | T::Ellipse),
+            X | Y => {
+                filter_primitive
+                    || viewport
+                    || matches!(
+                        tag_name,
+                        T::Rect | T::Use | T::Text | T::Tspan | T::Mask | T::Filter
+                    )
+            }
+            Width | Height => {
+                filter_primitive
+                    || viewport
+                    || matches!(tag_name, T::Rect | T::Use | T::Mask | T::Filter)
+            }
+            X1 | X2 | Y1 | Y2 => matches!(tag_name, T::Line | T::LinearGradient),
+            D => *tag_name == T::Path,
+            Points => matches!(tag_name, T::Polygon | T::Polyline),
+            PathLength => matches!(
+                tag_name,
+                T::Path | T::Circle | T::Ellipse | T::Line | T::Polygon | T::Polyline | T::Rect
+            ),
+            Href | XlinkHref => {
+                animation
+                    || gradient
+                    || matches!(
+                        tag_name,
+                        T::A | T::Use
+                            | T::Image
+                            | T::Pattern
+                            | T::Filter
+                            | T::FeImage
+                            | T::TextPath
+                            | T::Mpath
+                            | T::Script
+                    )
+            }
+            Transform => matches!(
+                tag_name,
+                T::A | T::Circle
+                    | T::ClipPath
+                    | T::Ellipse
+                    | T::ForeignObject
+                    | T::G
+                    | T::Image
+                    | T::Line
+                    | T::Path
+                    | T::Polygon
+                    | T::Polyline
+                    | T::Rect
+                    | T::Svg
+                    | T::Switch
+                    | T::Text
+                    | T::Use
+            ),
+            ViewBox => matches!(
+                tag_name,
+                T::Svg | T::Symbol | T::Marker | T::Pattern | T::View
+            ),
+            PreserveAspectRatio => viewport || *tag_name == T::FeImage,
+            GradientTransform | GradientUnits | SpreadMethod => gradient,
+            PatternContentUnits | PatternTransform | PatternUnits => *tag_name == T::Pattern,
+            MaskContentUnits | MaskUnits => *tag_name == T::Mask,
+            ClipPathUnits => *tag_name == T::ClipPath,
+            FilterRes | FilterUnits | PrimitiveUnits => *tag_name == T::Filter,
+            MarkerHeight | MarkerUnits | MarkerWidth | Orient => *tag_name == T::Marker,
+            RefX | RefY => matches!(tag_name, T::Marker | T::Symbol),
+            Offset => matches!(
+                tag_name,
+                T::Stop | T::FeFuncA | T::FeFuncB | T::FeFuncG | T::FeFuncR
+            ),
+            Dx | Dy => matches!(tag_name, T::Text | T::Tspan | T::FeOffset | T::FeDropShadow),
+            Rotate => matches!(tag_name, T::Text | T::Tspan | T::AnimateMotion),
+            LengthAdjust | TextLength => matches!(tag_name, T::Text | T::Tspan | T::TextPath),
+            Method | Spacing | StartOffset => *tag_name == T::TextPath,
+            Hreflang | Ping | Rel | Target => *tag_name == T::A,
+            ReferrerPolicy => matches!(tag_name, T::A | T::Image),
+            Accumulate | Additive | AttributeName | AttributeType | By | CalcMode | From
+            | KeySplines | KeyTimes | To => animation,
+            Values => animation || *tag_name == T::FeColorMatrix,
+            KeyPoints | Origin | Path => *tag_name == T::AnimateMotion,
+            Type => matches!(
+                tag_name,
+                T::Script
+                    | T::Style
+                    | T::AnimateTransform
+                    | T::FeColorMatrix
+                    | T::FeTurbulence
+                    | T::FeFuncA
+                    | T::FeFuncB
+                    | T::FeFuncG
+                    | T::FeFuncR
+            ),
+            Media => *tag_name == T::Style,
+            BaseProfile | ContentScriptType | ContentStyleType | Version | ZoomAndPan => {
+                *tag_name == T::Svg
+            }
+            ViewTarget => *tag_name == T::View,
+            RequiredExtensions | RequiredFeatures | SystemLanguage => !matches!(
+                tag_name,
+                T::Desc | T::Title | T::Metadata | T::Script | T::Style
+            ),
+            _ => true,
+        }
+    }
+}
+
+// Implementation of Attribute
+impl Attribute {
+    /// Returns the attribute with a namespace prefix, e.g. `inkscape:label`
+    ///
+    /// # Note / Arguments
+    /// The namespace of the prefix should be declared on the element or one of its ancestors,
+    /// see [set_namespace](../struct.Element.html#method.set_namespace).
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// assert_eq!(Attr::namespaced("xlink", "href"), Attr::XlinkHref);
+    /// assert_eq!(
+    ///     Attr::namespaced("inkscape", "label"),
+    ///     Attr::Custom(String::from("inkscape:label"))
+    /// );
+    /// ```
+    pub fn namespaced(prefix: &str, name: &str) -> Attribute {
+        Attribute::custom(&format!("{}:{}", prefix, name))
+    }
+
+    /// Returns the namespace prefix of this attribute, e.g. `xlink` for `xlink:href`
+    pub fn prefix(&self) -> Option<&str> {
+        self.name().split_once(':').map(|(prefix, _)| prefix)
+    }
+
+    /// Returns the name of this attribute without its namespace prefix, e.g. `href` for
+    /// `xlink:href`
+    pub fn local_name(&self) -> &str {
+        let name = self.name();
+        name.split_once(':')
+            .map_or(name, |(_, local_name)| local_name)
+    }
+
+    /// Returns whether this attribute declares a namespace, meaning it is `xmlns` or `xmlns:*`
+    pub fn is_namespace_declaration(&self) -> bool {
+        self.name() == "xmlns" || self.prefix() == Some("xmlns")
+    }
+}
+
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The error returned when parsing an unknown attribute name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownAttribute(pub String);
+
+impl fmt::Display for UnknownAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown attribute {:?}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownAttribute {}
+
+impl FromStr for Attribute {
+    type Err = UnknownAttribute;
+
+    /// Parses an attribute name, e.g. `stroke-width`
+    ///
+    /// # Note
+    /// Unknown names are an error instead of a [Custom](enum.Attribute.html#variant.Custom)
+    /// attribute, which can be created with [custom](enum.Attribute.html#method.custom).
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// assert_eq!("stroke-width".parse(), Ok(Attr::StrokeWidth));
+    /// assert!("stroke_width".parse::<Attr>().is_err());
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match Attribute::custom(name) {
+            Attribute::Custom(name) => Err(UnknownAttribute(name)),
+            attribute => Ok(attribute),
+        }
+    }
+}
+
+impl TryFrom<&str> for Attribute {
+    type Error = UnknownAttribute;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
 
-        std::string::String::from(match self {
-            AccentHeight => "accent-height",
-            Accumulate => "accumulate",
-            Additive => "additive",
-            AlignmentBaseline => "alignment-baseline",
-            AllowReorder => "allowReorder",
-            Alphabetic => "alphabetic",
-            Amplitude => "amplitude",
-            ArabicForm => "arabic-form",
-            Ascent => "ascent",
-            AttributeName => "attributeName",
-            AttributeType => "attributeType",
-            AutoReverse => "autoReverse",
-            Azimuth => "azimuth",
-            BaseFrequency => "baseFrequency",
-            BaselineShift => "baseline-shift",
-            BaseProfile => "baseProfile",
-            Bbox => "bbox",
-            Begin => "begin",
-            Bias => "bias",
-            By => "by",
-            CalcMode => "calcMode",
-            CapHeight => "cap-height",
-            Class => "class",
-            Clip => "clip",
-            ClipPathUnits => "clipPathUnits",
-            ClipPath => "clip-path",
-            ClipRule => "clip-rule",
-            Color => "color",
-            ColorInterpolation => "color-interpolation",
-            ColorInterpolationfilters => "color-interpolation-filters",
-            ColorProfile => "color-profile",
-            ColorRendering => "color-rendering",
-            ContentScriptType => "contentScriptType",
-            ContentStyleType => "contentStyleType",
-            Cursor => "cursor",
-            Cx => "cx",
-            Cy => "cy",
-            D => "d",
-            Decelerate => "decelerate",
-            Descent => "descent",
-            DiffuseConstant => "diffuseConstant",
-            Direction => "direction",
-            Display => "display",
-            Divisor => "divisor",
-            DominantBaseline => "dominant-baseline",
-            Dur => "dur",
-            Dx => "dx",
-            Dy => "dy",
-            EdgeMode => "edgeMode",
-            Elevation => "elevation",
-            EnableBackground => "enable-background",
-            End => "end",
-            Exponent => "exponent",
-            ExternalResourcesRequired => "externalResourcesRequired",
-            Fill => "fill",
-            FillOpacity => "fill-opacity",
-            FillRule => "fill-rule",
-            Filter => "filter",
-            FilterRes => "filterRes",
-            FilterUnits => "filterUnits",
-            FloodColor => "flood-color",
-            FloodOpacity => "flood-opacity",
-            FontFamily => "font-family",
-            FontSize => "font-size",
-            FontSizeadjust => "font-size-adjust",
-            FontStretch => "font-stretch",
-            FontStyle => "font-style",
-            FontVariant => "font-variant",
-            FontWeight => "font-weight",
-            Format => "format",
-            From => "from",
-            Fr => "fr",
-            Fx => "fx",
-            Fy => "fy",
-            G1 => "g1",
-            G2 => "g2",
-            GlyphName => "glyph-name",
-            GlyphOrientationhorizontal => "glyph-orientation-horizontal",
-            GlyphOrientationvertical => "glyph-orientation-vertical",
-            GlyphRef => "glyphRef",
-            GradientTransform => "gradientTransform",
-            GradientUnits => "gradientUnits",
-            Hanging => "hanging",
-            Height => "height",
-            Href => "href",
-            Hreflang => "hreflang",
-            HorizAdvx => "horiz-adv-x",
-            HorizOriginx => "horiz-origin-x",
-            Id => "id",
-            Ideographic => "ideographic",
-            ImageRendering => "image-rendering",
-            In => "in",
-            In2 => "in2",
-            Intercept => "intercept",
-            K => "k",
-            K1 => "k1",
-            K2 => "k2",
-            K3 => "k3",
-            K4 => "k4",
-            KernelMatrix => "kernelMatrix",
-            KernelUnitLength => "kernelUnitLength",
-            Kerning => "kerning",
-            KeyPoints => "keyPoints",
-            KeySplines => "keySplines",
-            KeyTimes => "keyTimes",
-            Lang => "lang",
-            LengthAdjust => "lengthAdjust",
-            LetterSpacing => "letter-spacing",
-            LightingColor => "lighting-color",
-            LimitingConeAngle => "limitingConeAngle",
-            Local => "local",
-            MarkerEnd => "marker-end",
-            MarkerMid => "marker-mid",
-            MarkerStart => "marker-start",
-            MarkerHeight => "markerHeight",
-            MarkerUnits => "markerUnits",
-            MarkerWidth => "markerWidth",
-            Mask => "mask",
-            MaskContentUnits => "maskContentUnits",
-            MaskUnits => "maskUnits",
-            Mathematical => "mathematical",
-            Max => "max",
-            Media => "media",
-            Method => "method",
-            Min => "min",
-            Mode => "mode",
-            Name => "name",
-            NumOctaves => "numOctaves",
-            Offset => "offset",
-            Opacity => "opacity",
-            Operator => "operator",
-            Order => "order",
-            Orient => "orient",
-            Orientation => "orientation",
-            Origin => "origin",
-            Overflow => "overflow",
-            OverlinePosition => "overline-position",
-            OverlineThickness => "overline-thickness",
-            Panose1 => "panose-1",
-            PaintOrder => "paint-order",
-            Path => "path",
-            PathLength => "pathLength",
-            PatternContentUnits => "patternContentUnits",
-            PatternTransform => "patternTransform",
-            PatternUnits => "patternUnits",
-            Ping => "ping",
-            PointerEvents => "pointer-events",
-            Points => "points",
-            PointsAtX => "pointsAtX",
-            PointsAtY => "pointsAtY",
-            PointsAtZ => "pointsAtZ",
-            PreserveAlpha => "preserveAlpha",
-            PreserveAspectRatio => "preserveAspectRatio",
-            PrimitiveUnits => "primitiveUnits",
-            R => "r",
-            Radius => "radius",
-            ReferrerPolicy => "referrerPolicy",
-            RefX => "refX",
-            RefY => "refY",
-            Rel => "rel",
-            RenderingIntent => "rendering-intent",
-            RepeatCount => "repeatCount",
-            RepeatDur => "repeatDur",
-            RequiredExtensions => "requiredExtensions",
-            RequiredFeatures => "requiredFeatures",
-            Restart => "restart",
-            Result => "result",
-            Rotate => "rotate",
-            Rx => "rx",
-            Ry => "ry",
-            Slope => "slope",
-            Spacing => "spacing",
-            SpecularConstant => "specularConstant",
-            SpecularExponent => "specularExponent",
-            Speed => "speed",
-            SpreadMethod => "spreadMethod",
-            StartOffset => "startOffset",
-            StdDeviation => "stdDeviation",
-            Stemh => "stemh",
-            Stemv => "stemv",
-            StitchTiles => "stitchTiles",
-            StopColor => "stop-color",
-            StopOpacity => "stop-opacity",
-            StrikethroughPosition => "strikethrough-position",
-            StrikethroughThickness => "strikethrough-thickness",
-            String => "string",
-            Stroke => "stroke",
-            StrokeDasharray => "stroke-dasharray",
-            StrokeDashoffset => "stroke-dashoffset",
-            StrokeLinecap => "stroke-linecap",
-            StrokeLinejoin => "stroke-linejoin",
-            StrokeMiterlimit => "stroke-miterlimit",
-            StrokeOpacity => "stroke-opacity",
-            StrokeWidth => "stroke-width",
-            Style => "style",
-            SurfaceScale => "surfaceScale",
-            SystemLanguage => "systemLanguage",
-            Tabindex => "tabindex",
-            TableValues => "tableValues",
-            Target => "target",
-            TargetX => "targetX",
-            TargetY => "targetY",
-            TextAnchor => "text-anchor",
-            TextDecoration => "text-decoration",
-            TextRendering => "text-rendering",
-            TextLength => "textLength",
-            To => "to",
-            Transform => "transform",
-            Type => "type",
-            U1 => "u1",
-            U2 => "u2",
-            UnderlinePosition => "underline-position",
-            UnderlineThickness => "underline-thickness",
-            Unicode => "unicode",
-            UnicodeBidi => "unicode-bidi",
-            UnicodeRange => "unicode-range",
-            UnitsPerem => "units-per-em",
-            VAlphabetic => "v-alphabetic",
-            VHanging => "v-hanging",
-            VIdeographic => "v-ideographic",
-            VMathematical => "v-mathematical",
-            Values => "values",
-            VectorEffect => "vector-effect",
-            Version => "version",
-            VertAdvy => "vert-adv-y",
-            VertOriginx => "vert-origin-x",
-            VertOriginy => "vert-origin-y",
-            ViewBox => "viewBox",
-            ViewTarget => "viewTarget",
-            Visibility => "visibility",
-            Width => "width",
-            Widths => "widths",
-            WordSpacing => "word-spacing",
-            WritingMode => "writing-mode",
-            X => "x",
-            XHeight => "x-height",
-            X1 => "x1",
-            X2 => "x2",
-            XChannelSelector => "xChannelSelector",
-            XlinkActuate => "xlink:actuate",
-            XlinkArcrole => "xlink:arcrole",
-            XlinkHref => "xlink:href",
-            XlinkRole => "xlink:role",
-            XlinkShow => "xlink:show",
-            XlinkTitle => "xlink:title",
-            XlinkType => "xlink:type",
-            XmlBase => "xml:base",
-            XmlLang => "xml:lang",
-            XmlSpace => "xml:space",
-            Y => "y",
-            Y1 => "y1",
-            Y2 => "y2",
-            YChannelSelector => "yChannelSelector",
-            Z => "z",
-            ZoomAndPan => "zoomAndPan",
-            UnmappedAttribute(attr) => &attr[..],
+#[cfg(feature = "serde")]
+impl serde::Serialize for Attribute {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Attribute {
+    /// Deserializes an attribute from its name, where unknown names become a Custom attribute
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(Attribute::custom(&name))
+    }
+}
+
+/// The value of an attribute of an [Element](../struct.Element.html)
+///
+/// # Note
+/// Values are text, except for the keywords of attributes with a closed vocabulary, like
+/// `fill-rule="evenodd"`, which are stored as a [KeywordValue](../keywords/enum.KeywordValue.html)
+/// discriminant. Either way a value dereferences to its text, and compares and hashes as that
+/// text.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::keywords::{FillRule, KeywordValue};
+///
+/// let path = SVGElem::new(Tag::Path)
+///     .set(Attr::FillRule, "evenodd")
+///     .set(Attr::Fill, "red");
+///
+/// let fill_rule = &path.get_attributes()[&Attr::FillRule];
+/// assert_eq!(fill_rule.keyword(), Some(KeywordValue::FillRule(FillRule::EvenOdd)));
+/// assert_eq!(fill_rule, "evenodd");
+/// assert_eq!(path.get_attributes()[&Attr::Fill].keyword(), None);
+/// ```
+#[derive(Clone)]
+pub struct AttributeValue(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Text(String),
+    Keyword(KeywordValue),
+}
+
+impl AttributeValue {
+    /// Creates the value of an attribute, storing it as a keyword if it is exactly one of the
+    /// keywords of that attribute
+    pub fn new(attribute: &Attribute, value: String) -> AttributeValue {
+        match KeywordValue::parse(attribute, &value) {
+            Some(keyword) => AttributeValue(Repr::Keyword(keyword)),
+            None => AttributeValue(Repr::Text(value)),
+        }
+    }
+
+    /// Returns the text of the value
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Text(text) => text,
+            Repr::Keyword(keyword) => keyword.name(),
+        }
+    }
+
+    /// Returns the keyword stored in the value, or None if the value is stored as text
+    pub fn keyword(&self) -> Option<KeywordValue> {
+        match self.0 {
+            Repr::Text(_) => None,
+            Repr::Keyword(keyword) => Some(keyword),
+        }
+    }
+}
+
+impl Deref for AttributeValue {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for AttributeValue {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for AttributeValue {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for AttributeValue {
+    fn eq(&self, other: &AttributeValue) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for AttributeValue {}
+
+impl PartialOrd for AttributeValue {
+    fn partial_cmp(&self, other: &AttributeValue) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AttributeValue {
+    fn cmp(&self, other: &AttributeValue) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for AttributeValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialEq<str> for AttributeValue {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for AttributeValue {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for AttributeValue {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<AttributeValue> for str {
+    fn eq(&self, other: &AttributeValue) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<AttributeValue> for &str {
+    fn eq(&self, other: &AttributeValue) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<AttributeValue> for String {
+    fn eq(&self, other: &AttributeValue) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl fmt::Debug for AttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for AttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<String> for AttributeValue {
+    /// Creates a value stored as text, use [new](#method.new) to store keywords as such
+    fn from(text: String) -> AttributeValue {
+        AttributeValue(Repr::Text(text))
+    }
+}
+
+impl From<&str> for AttributeValue {
+    fn from(text: &str) -> AttributeValue {
+        AttributeValue::from(String::from(text))
+    }
+}
+
+impl From<AttributeValue> for String {
+    fn from(value: AttributeValue) -> String {
+        match value.0 {
+            Repr::Text(text) => text,
+            Repr::Keyword(keyword) => String::from(keyword.name()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AttributeValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserializes the attributes of an element, storing the keywords of each attribute as such
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_attributes<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<Attribute, AttributeValue>, D::Error> {
+    use serde::Deserialize;
+
+    let attributes = HashMap::<Attribute, String>::deserialize(deserializer)?;
+    Ok(attributes
+        .into_iter()
+        .map(|(attribute, value)| {
+            let value = AttributeValue::new(&attribute, value);
+            (attribute, value)
         })
+        .collect())
+}
+
+/// Parses a number, e.g. `-1.5e3`, which may not be NaN or infinite
+fn number(value: &str) -> Option<f64> {
+    value.parse().ok().filter(|number: &f64| number.is_finite())
+}
+
+/// Parses a length or percentage, e.g. `10`, `1.5em` or `50%`, returning its number
+fn length(value: &str) -> Option<f64> {
+    value.parse::<Length>().ok().map(|length| length.value)
+}
+
+/// The keywords which every CSS property accepts
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["inherit", "initial", "unset", "revert", "revert-layer"];
+
+/// Checks whether a value is valid for an attribute of an element, returning the reason if it is
+/// not
+///
+/// Only attributes with simple value types are checked, other values are always valid. The
+/// presentation attributes also accept the CSS-wide keywords, like `inherit`.
+pub(crate) fn validate_value(
+    tag_name: &TagName,
+    attribute: &Attribute,
+    value: &str,
+) -> Result<(), &'static str> {
+    use Attribute::*;
+
+    let value = value.trim();
+    if attribute.is_presentation() && CSS_WIDE_KEYWORDS.contains(&value) {
+        return Ok(());
+    }
+
+    let numbers = |value: &str| {
+        value
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|part| !part.is_empty())
+            .map(number)
+            .collect::<Option<Vec<f64>>>()
+    };
+
+    match attribute {
+        // Text content elements position each character, so they take a list of lengths
+        X | Y | Dx | Dy if matches!(tag_name, TagName::Text | TagName::Tspan) => {
+            let lengths = value
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|part| !part.is_empty())
+                .map(length)
+                .collect::<Option<Vec<f64>>>();
+            match lengths {
+                Some(lengths) if !lengths.is_empty() => Ok(()),
+                _ => Err("expected a list of lengths"),
+            }
+        }
+        X | Y | Cx | Cy | X1 | X2 | Y1 | Y2 | Fx | Fy | Dx | Dy | RefX | RefY
+        | StrokeDashoffset => length(value).map(|_| ()).ok_or("expected a length"),
+        Width | Height | R | Rx | Ry | Fr | StrokeWidth | MarkerWidth | MarkerHeight => {
+            if value == "auto" && matches!(attribute, Width | Height | Rx | Ry) {
+                return Ok(());
+            }
+            match length(value) {
+                Some(length) if length < 0.0 => Err("expected a non-negative length"),
+                Some(_) => Ok(()),
+                None => Err("expected a length"),
+            }
+        }
+        Opacity | FillOpacity | StrokeOpacity | StopOpacity | FloodOpacity => value
+            .parse::<crate::opacity::Opacity>()
+            .map(|_| ())
+            .map_err(|_| "expected a number or percentage"),
+        StrokeMiterlimit => match number(value) {
+            Some(limit) if limit < 1.0 => Err("expected a number of at least 1"),
+            Some(_) => Ok(()),
+            None => Err("expected a number"),
+        },
+        PathLength | StdDeviation | NumOctaves | K1 | K2 | K3 | K4 | SurfaceScale => numbers(value)
+            .filter(|numbers| !numbers.is_empty())
+            .map(|_| ())
+            .ok_or("expected a number"),
+        ViewBox => match numbers(value).as_deref() {
+            Some([_, _, width, height]) if *width < 0.0 || *height < 0.0 => {
+                Err("expected a non-negative width and height")
+            }
+            Some([_, _, _, _]) => Ok(()),
+            _ => Err("expected four numbers"),
+        },
+        Points => match numbers(value) {
+            Some(numbers) if numbers.len() % 2 == 0 => Ok(()),
+            Some(_) => Err("expected an even amount of numbers"),
+            None => Err("expected a list of numbers"),
+        },
+        D => {
+            const PATH_CHARACTERS: &str = "MmLlHhVvCcSsQqTtAaZz0123456789.eE+-, \t\r\n";
+
+            if !value.is_empty() && !value.starts_with(['M', 'm']) {
+                Err("expected path data starting with a move")
+            } else if !value.chars().all(|c| PATH_CHARACTERS.contains(c)) {
+                Err("expected path data")
+            } else {
+                Ok(())
+            }
+        }
+        Id => {
+            if value.is_empty() || value.contains(char::is_whitespace) {
+                Err("expected a non-empty id without whitespace")
+            } else {
+                Ok(())
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_value, Attribute};
+    use crate::tag_name::TagName;
+
+    #[test]
+    fn test_all_names() {
+        assert_eq!(Attribute::ALL.len(), 255);
+        for attribute in Attribute::ALL {
+            assert_eq!(
+                Attribute::from_name(attribute.name()).as_ref(),
+                Some(attribute)
+            );
+        }
+
+        assert!(Attribute::iter().eq(Attribute::ALL.iter()));
+        assert!(Attribute::iter().any(|attribute| attribute.name() == "stroke-width"));
+
+        assert_eq!(TagName::ALL.len(), 73);
+        for tag_name in TagName::ALL {
+            assert_eq!(TagName::from_name(tag_name.name()).as_ref(), Some(tag_name));
+        }
+    }
+
+    #[test]
+    fn test_validate_value() {
+        assert!(validate_value(&TagName::Text, &Attribute::X, "10 20,30em").is_ok());
+        assert!(validate_value(&TagName::Tspan, &Attribute::Dy, "1.5em").is_ok());
+        assert!(validate_value(&TagName::Text, &Attribute::Dx, "1 ten").is_err());
+        assert!(validate_value(&TagName::Text, &Attribute::Y, " ").is_err());
+        assert!(validate_value(&TagName::Rect, &Attribute::X, "10 20").is_err());
+
+        assert!(validate_value(&TagName::Path, &Attribute::StrokeWidth, "inherit").is_ok());
+        assert!(validate_value(&TagName::Path, &Attribute::Opacity, " unset").is_ok());
+        assert!(validate_value(&TagName::Rect, &Attribute::Width, "inherit").is_err());
+        assert!(validate_value(&TagName::Path, &Attribute::StrokeWidth, "-1").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_map_keys() {
+        use std::collections::HashMap;
+
+        // Custom names are serialized as plain strings, so they can be keys of JSON objects
+        let mut attributes = HashMap::new();
+        attributes.insert(Attribute::StrokeWidth, 1);
+        attributes.insert(Attribute::custom("data-x"), 2);
+        let json = serde_json::to_string(&attributes).unwrap();
+        assert!(json.contains(r#""data-x":2"#));
+        assert_eq!(
+            serde_json::from_str::<HashMap<Attribute, i32>>(&json).unwrap(),
+            attributes
+        );
+
+        let mut tag_names = HashMap::new();
+        tag_names.insert(TagName::Rect, 1);
+        tag_names.insert(TagName::Custom(String::from("sodipodi:namedview")), 2);
+        let json = serde_json::to_string(&tag_names).unwrap();
+        assert!(json.contains(r#""sodipodi:namedview":2"#));
+        assert_eq!(
+            serde_json::from_str::<HashMap<TagName, i32>>(&json).unwrap(),
+            tag_names
+        );
     }
 }
@@ -0,0 +1,76 @@
+//! Encoding and decoding of the standard base64 alphabet, as used by `data:` URIs
+
+const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes data with the standard base64 alphabet, including padding
+pub(crate) fn encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let group = u32::from(bytes[0]) << 16 | u32::from(bytes[1]) << 8 | u32::from(bytes[2]);
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[(group >> (18 - 6 * index) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Decodes base64 data, ignoring whitespace and padding, or returns None if it contains other
+/// characters
+#[cfg(feature = "image")]
+pub(crate) fn decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(encoded.len() / 4 * 3);
+    let mut group = 0u32;
+    let mut bits = 0;
+    for byte in encoded.bytes() {
+        if byte.is_ascii_whitespace() || byte == b'=' {
+            continue;
+        }
+
+        let value = ALPHABET.iter().position(|&c| c == byte)? as u32;
+        group = group << 6 | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((group >> bits) as u8);
+        }
+    }
+
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_decode() {
+        use super::decode;
+
+        for data in [&b""[..], b"f", b"fo", b"foo", b"foobar"] {
+            assert_eq!(decode(&encode(data)).as_deref(), Some(data));
+        }
+        assert_eq!(decode("Zm9v\n YmFy").as_deref(), Some(&b"foobar"[..]));
+        assert_eq!(decode("Zm9v*"), None);
+    }
+}
@@ -0,0 +1,318 @@
+//! This module provides a [ChangeLog](struct.ChangeLog.html) which records the changes made to an
+//! element tree, so they can be undone and redone without keeping copies of the whole tree.
+//!
+//! # Examples
+//! ## Undoing a changed fill
+//! ```
+//! use svg_definitions::changes::ChangeLog;
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::tree::ElementPath;
+//!
+//! let mut svg = SVGElem::new(Tag::Svg).append(SVGElem::new(Tag::Rect).set(Attr::Fill, "red"));
+//! let mut log = ChangeLog::new();
+//!
+//! let rect = ElementPath::from(vec![0]);
+//! log.set_attribute(&mut svg, &rect, Attr::Fill, "blue");
+//! assert_eq!(svg.get_children()[0].get_attributes()[&Attr::Fill], "blue");
+//!
+//! assert!(log.undo(&mut svg));
+//! assert_eq!(svg.get_children()[0].get_attributes()[&Attr::Fill], "red");
+//!
+//! assert!(log.redo(&mut svg));
+//! assert_eq!(svg.get_children()[0].get_attributes()[&Attr::Fill], "blue");
+//! ```
+
+use crate::attributes::Attribute;
+use crate::tree::ElementPath;
+use crate::Element;
+
+/// A single change to an element tree, addressing the changed element by its path from the root
+#[derive(Debug, Clone)]
+pub enum Change {
+    /// Sets an attribute of an element
+    SetAttribute {
+        path: ElementPath,
+        attribute: Attribute,
+        value: String,
+    },
+
+    /// Removes an attribute of an element
+    RemoveAttribute {
+        path: ElementPath,
+        attribute: Attribute,
+    },
+
+    /// Inserts a child at a certain index of the children of an element
+    InsertChild {
+        path: ElementPath,
+        index: usize,
+        child: Element,
+    },
+
+    /// Removes the child at a certain index of the children of an element
+    RemoveChild { path: ElementPath, index: usize },
+}
+
+/// The kind of a change to an element, as reported to the observers of a
+/// [Document](../document/struct.Document.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// An attribute was set
+    AttributeSet(Attribute),
+
+    /// An attribute was removed
+    AttributeRemoved(Attribute),
+
+    /// A child was inserted at a certain index
+    ChildInserted(usize),
+
+    /// The child at a certain index was removed
+    ChildRemoved(usize),
+
+    /// The element and any of its descendants might have changed
+    Subtree,
+}
+
+impl Change {
+    /// Gets the kind of this change
+    pub fn kind(&self) -> ChangeKind {
+        match self {
+            Change::SetAttribute { attribute, .. } => ChangeKind::AttributeSet(attribute.clone()),
+            Change::RemoveAttribute { attribute, .. } => {
+                ChangeKind::AttributeRemoved(attribute.clone())
+            }
+            Change::InsertChild { index, .. } => ChangeKind::ChildInserted(*index),
+            Change::RemoveChild { index, .. } => ChangeKind::ChildRemoved(*index),
+        }
+    }
+
+    /// Gets the path of the element this change applies to
+    pub fn path(&self) -> &ElementPath {
+        match self {
+            Change::SetAttribute { path, .. }
+            | Change::RemoveAttribute { path, .. }
+            | Change::InsertChild { path, .. }
+            | Change::RemoveChild { path, .. } => path,
+        }
+    }
+
+    /// Applies this change to a tree, returning the change which reverts it
+    ///
+    /// # Note / Arguments
+    /// Returns None and leaves the tree untouched if the element or child does not exist. Also
+    /// returns None when removing an attribute which was not set, as there is nothing to revert.
+    pub fn apply(self, root: &mut Element) -> Option<Change> {
+        let element = root.get_path_mut(self.path())?;
+
+        match self {
+            Change::SetAttribute {
+                path,
+                attribute,
+                value,
+            } => Some(match element.set_attribute(attribute.clone(), value) {
+                Some(value) => Change::SetAttribute {
+                    path,
+                    attribute,
+                    value,
+                },
+                None => Change::RemoveAttribute { path, attribute },
+            }),
+            Change::RemoveAttribute { path, attribute } => {
+                let value = element.remove_attribute(&attribute)?;
+                Some(Change::SetAttribute {
+                    path,
+                    attribute,
+                    value,
+                })
+            }
+            Change::InsertChild { path, index, child } => {
+                if index > element.get_children().len() {
+                    return None;
+                }
+
+                element.insert_child(index, child);
+                Some(Change::RemoveChild { path, index })
+            }
+            Change::RemoveChild { path, index } => {
+                if index >= element.get_children().len() {
+                    return None;
+                }
+
+                let child = element.remove_child(index);
+                Some(Change::InsertChild { path, index, child })
+            }
+        }
+    }
+}
+
+/// A journal of applied changes which can be undone and redone
+#[derive(Debug, Clone, Default)]
+pub struct ChangeLog {
+    undo: Vec<Change>,
+    redo: Vec<Change>,
+}
+
+impl ChangeLog {
+    /// Creates a new empty change log
+    pub fn new() -> ChangeLog {
+        ChangeLog::default()
+    }
+
+    /// Applies a change to a tree and records it, returning whether it was applied
+    ///
+    /// # Note / Arguments
+    /// Applying a change discards all undone changes, so they can no longer be redone.
+    pub fn apply(&mut self, root: &mut Element, change: Change) -> bool {
+        match change.apply(root) {
+            Some(inverse) => {
+                self.undo.push(inverse);
+                self.redo.clear();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets an attribute of the element at a certain path and records it
+    pub fn set_attribute<T>(
+        &mut self,
+        root: &mut Element,
+        path: &ElementPath,
+        attribute: Attribute,
+        value: T,
+    ) -> bool
+    where
+        T: ToString,
+    {
+        self.apply(
+            root,
+            Change::SetAttribute {
+                path: path.clone(),
+                attribute,
+                value: value.to_string(),
+            },
+        )
+    }
+
+    /// Removes an attribute of the element at a certain path and records it
+    pub fn remove_attribute(
+        &mut self,
+        root: &mut Element,
+        path: &ElementPath,
+        attribute: Attribute,
+    ) -> bool {
+        self.apply(
+            root,
+            Change::RemoveAttribute {
+                path: path.clone(),
+                attribute,
+            },
+        )
+    }
+
+    /// Inserts a child into the element at a certain path and records it
+    pub fn insert_child(
+        &mut self,
+        root: &mut Element,
+        path: &ElementPath,
+        index: usize,
+        child: Element,
+    ) -> bool {
+        self.apply(
+            root,
+            Change::InsertChild {
+                path: path.clone(),
+                index,
+                child,
+            },
+        )
+    }
+
+    /// Removes a child of the element at a certain path and records it
+    pub fn remove_child(&mut self, root: &mut Element, path: &ElementPath, index: usize) -> bool {
+        self.apply(
+            root,
+            Change::RemoveChild {
+                path: path.clone(),
+                index,
+            },
+        )
+    }
+
+    /// Reverts the last applied change, returning false if there is nothing to undo
+    pub fn undo(&mut self, root: &mut Element) -> bool {
+        Self::step(root, &mut self.undo, &mut self.redo)
+    }
+
+    /// Reapplies the last undone change, returning false if there is nothing to redo
+    pub fn redo(&mut self, root: &mut Element) -> bool {
+        Self::step(root, &mut self.redo, &mut self.undo)
+    }
+
+    fn step(root: &mut Element, from: &mut Vec<Change>, to: &mut Vec<Change>) -> bool {
+        match from.pop() {
+            Some(change) => {
+                if let Some(inverse) = change.apply(root) {
+                    to.push(inverse);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns whether there are changes to undo
+    #[inline]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns whether there are changes to redo
+    #[inline]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets all recorded changes
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChangeLog;
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::tree::ElementPath;
+    use crate::Element;
+
+    #[test]
+    fn test_change_log() {
+        let mut root = Element::new(TagName::Svg).append(Element::new(TagName::Rect));
+        let original = root.to_string();
+        let mut log = ChangeLog::new();
+
+        let svg = ElementPath::new();
+        assert!(log.set_attribute(&mut root, &svg.child(0), Attribute::Fill, "red"));
+        assert!(log.insert_child(&mut root, &svg, 0, Element::new(TagName::Circle)));
+        assert!(log.remove_child(&mut root, &svg, 1));
+        assert!(!log.remove_attribute(&mut root, &svg, Attribute::Width));
+        assert!(!log.remove_child(&mut root, &svg.child(3), 0));
+
+        let changed = root.to_string();
+        assert_eq!(changed, "<svg><circle/></svg>");
+
+        while log.undo(&mut root) {}
+        assert_eq!(root.to_string(), original);
+        assert!(!log.can_undo());
+
+        while log.redo(&mut root) {}
+        assert_eq!(root.to_string(), changed);
+
+        log.undo(&mut root);
+        assert!(log.set_attribute(&mut root, &svg, Attribute::Width, 10));
+        assert!(!log.can_redo());
+    }
+}
@@ -0,0 +1,780 @@
+//! This module provides the [Color](struct.Color.html) type, which parses every color syntax of
+//! CSS, like the values of `fill`, `stroke` and `stop-color` in parsed files, and the
+//! [Hsl](struct.Hsl.html) representation to derive lighter, darker and mixed colors.
+//!
+//! # Examples
+//! ## Reading the fill of a parsed element
+//! ```
+//! use svg_definitions::color::Color;
+//! use svg_definitions::prelude::*;
+//!
+//! let rect = SVGElem::new(Tag::Rect).set(Attr::Fill, "hsl(120, 100%, 25%)");
+//! let fill = Color::parse(&rect.get_attributes()[&Attr::Fill]).unwrap();
+//!
+//! assert_eq!(fill, Color::rgb(0, 128, 0));
+//! assert_eq!(fill.to_string(), "#008000");
+//! ```
+//!
+//! ## Deriving the colors of a theme
+//! ```
+//! use svg_definitions::color::Color;
+//!
+//! let accent = Color::parse("#1f77b4").unwrap();
+//! let hover = accent.lighten(0.2);
+//! let shadow = accent.darken(0.3).with_alpha(0.5);
+//! let muted = accent.mix(&Color::rgb(128, 128, 128), 0.5);
+//! # assert!(hover.to_hsl().lightness > accent.to_hsl().lightness);
+//! # assert_eq!(shadow.alpha, 0.5);
+//! # assert_eq!(muted, Color::rgb(80, 124, 154));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::angle::Angle;
+
+/// The error returned when parsing a value which is not a color
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidColor(pub String);
+
+impl fmt::Display for InvalidColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid color {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidColor {}
+
+/// A color in the sRGB color space with an alpha channel
+///
+/// # Note / Arguments
+/// The alpha channel goes from 0 for transparent to 1 for opaque. Opaque colors are formatted as
+/// `#rrggbb`, other colors as `rgba(r, g, b, a)`, which all renderers support.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: f64,
+}
+
+impl Color {
+    /// Creates an opaque color from its channels
+    #[inline]
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Color {
+        Color::rgba(red, green, blue, 1.0)
+    }
+
+    /// Creates a color from its channels, where the alpha is clamped between 0 and 1
+    #[inline]
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f64) -> Color {
+        Color {
+            red,
+            green,
+            blue,
+            alpha: alpha.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Parses a CSS color
+    ///
+    /// # Note / Arguments
+    /// Supported are the named colors and `transparent`, hexadecimal colors with 3, 4, 6 or 8
+    /// digits and the `rgb()`, `rgba()`, `hsl()` and `hsla()` functions. The functions take
+    /// comma separated arguments or space separated arguments with the alpha after a `/`, and
+    /// channels and alpha can be percentages. Hues can have the units `deg`, `rad`, `grad` and
+    /// `turn`. Names and functions are case insensitive and values out of range are clamped.
+    /// Keywords like `currentColor` and `none` are not colors and give an error.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::color::Color;
+    ///
+    /// assert_eq!(Color::parse("#f80"), Ok(Color::rgb(255, 136, 0)));
+    /// assert_eq!(Color::parse("RebeccaPurple"), Ok(Color::rgb(102, 51, 153)));
+    /// assert_eq!(Color::parse("rgb(100% 0% 0% / 50%)"), Ok(Color::rgba(255, 0, 0, 0.5)));
+    /// assert_eq!(Color::parse("hsla(0.5turn, 100%, 50%, 0.25)"), Ok(Color::rgba(0, 255, 255, 0.25)));
+    /// assert!(Color::parse("currentColor").is_err());
+    /// ```
+    pub fn parse(value: &str) -> Result<Color, InvalidColor> {
+        let invalid = || InvalidColor(value.to_string());
+        let lowercase = value.trim().to_ascii_lowercase();
+
+        if let Some(hex) = lowercase.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(invalid);
+        }
+        if lowercase == "transparent" {
+            return Ok(Color::rgba(0, 0, 0, 0.0));
+        }
+        if let Ok(index) = NAMED_COLORS.binary_search_by_key(&&lowercase[..], |(name, _)| name) {
+            let [red, green, blue] = NAMED_COLORS[index].1;
+            return Ok(Color::rgb(red, green, blue));
+        }
+
+        let (name, arguments) = lowercase
+            .strip_suffix(')')
+            .and_then(|function| function.split_once('('))
+            .ok_or_else(invalid)?;
+        let arguments = split_arguments(arguments).ok_or_else(invalid)?;
+        match name.trim_end() {
+            "rgb" | "rgba" => parse_rgb(&arguments),
+            "hsl" | "hsla" => parse_hsl(&arguments),
+            _ => None,
+        }
+        .ok_or_else(invalid)
+    }
+
+    /// Returns whether the color is completely opaque
+    #[inline]
+    pub fn is_opaque(&self) -> bool {
+        self.alpha >= 1.0
+    }
+
+    /// Returns the color in its HSL representation
+    pub fn to_hsl(&self) -> Hsl {
+        let [red, green, blue] =
+            [self.red, self.green, self.blue].map(|channel| f64::from(channel) / 255.0);
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let chroma = max - min;
+        let lightness = (max + min) / 2.0;
+
+        let (hue, saturation) = if chroma == 0.0 {
+            (0.0, 0.0)
+        } else {
+            let hue = if max == red {
+                ((green - blue) / chroma).rem_euclid(6.0)
+            } else if max == green {
+                (blue - red) / chroma + 2.0
+            } else {
+                (red - green) / chroma + 4.0
+            };
+            (hue * 60.0, chroma / (1.0 - (2.0 * lightness - 1.0).abs()))
+        };
+
+        Hsl {
+            hue,
+            saturation,
+            lightness,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Creates a color from its HSL representation, rounding the channels
+    pub fn from_hsl(hsl: &Hsl) -> Color {
+        let [red, green, blue] = hsl_to_rgb(
+            hsl.hue,
+            hsl.saturation.clamp(0.0, 1.0),
+            hsl.lightness.clamp(0.0, 1.0),
+        );
+        Color::rgba(red, green, blue, hsl.alpha)
+    }
+
+    /// Returns the color with its lightness increased by an amount between 0 and 1, e.g. `0.2`
+    /// for 20 percentage points lighter
+    pub fn lighten(&self, amount: f64) -> Color {
+        let mut hsl = self.to_hsl();
+        hsl.lightness = (hsl.lightness + amount).clamp(0.0, 1.0);
+        Color::from_hsl(&hsl)
+    }
+
+    /// Returns the color with its lightness decreased by an amount between 0 and 1, see
+    /// [lighten](#method.lighten)
+    #[inline]
+    pub fn darken(&self, amount: f64) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Returns the color with another alpha, which is clamped between 0 and 1
+    #[inline]
+    pub fn with_alpha(&self, alpha: f64) -> Color {
+        Color::rgba(self.red, self.green, self.blue, alpha)
+    }
+
+    /// Returns the color a fraction `t` of the way to another color, interpolating the channels
+    /// and alpha linearly in sRGB like SVG animations do
+    ///
+    /// # Note / Arguments
+    /// A `t` of 0 returns this color and a `t` of 1 returns `other`, values outside are clamped.
+    pub fn mix(&self, other: &Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |from: u8, to: u8| {
+            (f64::from(from) + (f64::from(to) - f64::from(from)) * t).round() as u8
+        };
+        Color::rgba(
+            channel(self.red, other.red),
+            channel(self.green, other.green),
+            channel(self.blue, other.blue),
+            self.alpha + (other.alpha - self.alpha) * t,
+        )
+    }
+
+    /// Returns the relative luminance of the color as defined by WCAG, from 0 for black to 1 for
+    /// white, ignoring the alpha
+    pub fn luminance(&self) -> f64 {
+        let linear = |channel: u8| {
+            let value = f64::from(channel) / 255.0;
+            if value <= 0.04045 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.red) + 0.7152 * linear(self.green) + 0.0722 * linear(self.blue)
+    }
+
+    /// Returns the WCAG contrast ratio between this color and another color, from 1 for equal
+    /// luminances to 21 for black and white
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::color::Color;
+    ///
+    /// let ratio = Color::rgb(0x76, 0x76, 0x76).contrast(&Color::rgb(255, 255, 255));
+    /// assert!((ratio - 4.54).abs() < 0.01);
+    /// ```
+    pub fn contrast(&self, other: &Color) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+}
+
+/// The minimum contrast ratio between normal text and its background for WCAG level AA
+pub const MIN_CONTRAST: f64 = 4.5;
+
+/// Returns black or white, whichever contrasts most with a background, e.g. for labels on the
+/// bars of a chart
+///
+/// # Note / Arguments
+/// One of both always has a contrast ratio of at least 4.58 with an opaque background, which
+/// passes WCAG level AA. The alpha of the background is ignored.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::color::{self, Color};
+///
+/// assert_eq!(color::on_background(Color::parse("navy").unwrap()), Color::rgb(255, 255, 255));
+/// assert_eq!(color::on_background(Color::parse("gold").unwrap()), Color::rgb(0, 0, 0));
+/// ```
+pub fn on_background(background: Color) -> Color {
+    let (black, white) = (Color::rgb(0, 0, 0), Color::rgb(255, 255, 255));
+    if background.contrast(&black) >= background.contrast(&white) {
+        black
+    } else {
+        white
+    }
+}
+
+/// Returns the first color of a palette which has a contrast ratio of at least
+/// [MIN_CONTRAST](constant.MIN_CONTRAST.html) with a background, or None if none of them has
+///
+/// # Note / Arguments
+/// The palette is in order of preference, falling back to
+/// [on_background](fn.on_background.html) is always possible.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::color::{self, Color};
+///
+/// let (light, dark) = (Color::rgb(0xee, 0xee, 0xff), Color::rgb(0x11, 0x11, 0x33));
+/// let bar = Color::parse("#1f4e79").unwrap();
+/// assert_eq!(color::on_background_from(bar, &[dark, light]), Some(light));
+///
+/// // Neither contrasts enough with a middle gray
+/// let gray = Color::rgb(118, 118, 118);
+/// let label = color::on_background_from(gray, &[dark, light]).unwrap_or_else(|| color::on_background(gray));
+/// assert_eq!(label, Color::rgb(0, 0, 0));
+/// ```
+pub fn on_background_from(background: Color, palette: &[Color]) -> Option<Color> {
+    palette
+        .iter()
+        .find(|color| color.contrast(&background) >= MIN_CONTRAST)
+        .copied()
+}
+
+impl From<Hsl> for Color {
+    #[inline]
+    fn from(hsl: Hsl) -> Color {
+        Color::from_hsl(&hsl)
+    }
+}
+
+impl From<Color> for Hsl {
+    #[inline]
+    fn from(color: Color) -> Hsl {
+        color.to_hsl()
+    }
+}
+
+/// A color as hue, saturation and lightness
+///
+/// # Note / Arguments
+/// The hue is in degrees, the saturation, lightness and alpha go from 0 to 1. The representation
+/// is exact, only converting back to a [Color](struct.Color.html) rounds the channels.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::color::{Color, Hsl};
+///
+/// let mut hsl = Color::rgb(255, 0, 0).to_hsl();
+/// hsl.hue += 120.0;
+///
+/// assert_eq!(Color::from(hsl), Color::rgb(0, 255, 0));
+/// assert_eq!(hsl.to_string(), "hsl(120, 100%, 50%)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsl {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+    pub alpha: f64,
+}
+
+impl fmt::Display for Hsl {
+    /// Formats the color as `hsl()`, or as `hsla()` if it is not opaque, with the hue between 0
+    /// and 360
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let round = |value: f64| (value * 1000.0).round() / 1000.0;
+        let hue = round(self.hue.rem_euclid(360.0)) % 360.0;
+        let saturation = round(self.saturation * 100.0);
+        let lightness = round(self.lightness * 100.0);
+        if self.alpha >= 1.0 {
+            write!(f, "hsl({}, {}%, {}%)", hue, saturation, lightness)
+        } else {
+            write!(
+                f,
+                "hsla({}, {}%, {}%, {})",
+                hue,
+                saturation,
+                lightness,
+                round(self.alpha)
+            )
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = InvalidColor;
+
+    #[inline]
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Color::parse(value)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_opaque() {
+            write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+        } else {
+            write!(
+                f,
+                "rgba({}, {}, {}, {})",
+                self.red,
+                self.green,
+                self.blue,
+                (self.alpha * 1000.0).round() / 1000.0
+            )
+        }
+    }
+}
+
+/// Parses the digits of a hexadecimal color
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let length = match hex.len() {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return None,
+    };
+    let channel = |index: usize| {
+        let digits = hex.get(index * length..(index + 1) * length)?;
+        let channel = u8::from_str_radix(digits, 16).ok()?;
+        Some(if length == 1 { channel * 17 } else { channel })
+    };
+
+    let alpha = match channel(3) {
+        Some(alpha) => f64::from(alpha) / 255.0,
+        None => 1.0,
+    };
+    Some(Color::rgba(channel(0)?, channel(1)?, channel(2)?, alpha))
+}
+
+/// Splits the arguments of a color function into three channels and an optional alpha
+fn split_arguments(arguments: &str) -> Option<Vec<&str>> {
+    let arguments: Vec<&str> = if arguments.contains(',') {
+        arguments.split(',').map(str::trim).collect()
+    } else {
+        let (channels, alpha) = match arguments.split_once('/') {
+            Some((channels, alpha)) => (channels, Some(alpha.trim())),
+            None => (arguments, None),
+        };
+        let mut arguments: Vec<&str> = channels.split_whitespace().collect();
+        if arguments.len() != 3 {
+            return None;
+        }
+        arguments.extend(alpha);
+        arguments
+    };
+
+    match arguments.len() {
+        3 | 4 => Some(arguments),
+        _ => None,
+    }
+}
+
+/// Parses a number or a percentage, where a percentage is scaled to `full`
+fn number(argument: &str, full: f64) -> Option<f64> {
+    let value = match argument.strip_suffix('%') {
+        Some(percentage) => percentage.parse::<f64>().ok()? / 100.0 * full,
+        None => argument.parse().ok()?,
+    };
+    if value.is_finite() {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Parses the optional alpha argument of a color function
+fn alpha(arguments: &[&str]) -> Option<f64> {
+    match arguments.get(3) {
+        Some(alpha) => number(alpha, 1.0),
+        None => Some(1.0),
+    }
+}
+
+fn parse_rgb(arguments: &[&str]) -> Option<Color> {
+    let channel = |index: usize| {
+        number(arguments[index], 255.0).map(|channel| channel.clamp(0.0, 255.0).round() as u8)
+    };
+    Some(Color::rgba(
+        channel(0)?,
+        channel(1)?,
+        channel(2)?,
+        alpha(arguments)?,
+    ))
+}
+
+fn parse_hsl(arguments: &[&str]) -> Option<Color> {
+    let hue = arguments[0].parse::<Angle>().ok()?.to_degrees();
+
+    let saturation = number(arguments[1], 100.0)?.clamp(0.0, 100.0) / 100.0;
+    let lightness = number(arguments[2], 100.0)?.clamp(0.0, 100.0) / 100.0;
+    let [red, green, blue] = hsl_to_rgb(hue, saturation, lightness);
+    Some(Color::rgba(red, green, blue, alpha(arguments)?))
+}
+
+/// Converts a hue in degrees and a saturation and lightness between 0 and 1 to sRGB channels
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> [u8; 3] {
+    let hue = hue.rem_euclid(360.0);
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (red, green, blue) = match (hue / 60.0) as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    let offset = lightness - chroma / 2.0;
+    let channel = |value: f64| ((value + offset) * 255.0).clamp(0.0, 255.0).round() as u8;
+    [channel(red), channel(green), channel(blue)]
+}
+
+/// The named colors of CSS, sorted by name
+const NAMED_COLORS: &[(&str, [u8; 3])] = &[
+    ("aliceblue", [0xf0, 0xf8, 0xff]),
+    ("antiquewhite", [0xfa, 0xeb, 0xd7]),
+    ("aqua", [0x00, 0xff, 0xff]),
+    ("aquamarine", [0x7f, 0xff, 0xd4]),
+    ("azure", [0xf0, 0xff, 0xff]),
+    ("beige", [0xf5, 0xf5, 0xdc]),
+    ("bisque", [0xff, 0xe4, 0xc4]),
+    ("black", [0x00, 0x00, 0x00]),
+    ("blanchedalmond", [0xff, 0xeb, 0xcd]),
+    ("blue", [0x00, 0x00, 0xff]),
+    ("blueviolet", [0x8a, 0x2b, 0xe2]),
+    ("brown", [0xa5, 0x2a, 0x2a]),
+    ("burlywood", [0xde, 0xb8, 0x87]),
+    ("cadetblue", [0x5f, 0x9e, 0xa0]),
+    ("chartreuse", [0x7f, 0xff, 0x00]),
+    ("chocolate", [0xd2, 0x69, 0x1e]),
+    ("coral", [0xff, 0x7f, 0x50]),
+    ("cornflowerblue", [0x64, 0x95, 0xed]),
+    ("cornsilk", [0xff, 0xf8, 0xdc]),
+    ("crimson", [0xdc, 0x14, 0x3c]),
+    ("cyan", [0x00, 0xff, 0xff]),
+    ("darkblue", [0x00, 0x00, 0x8b]),
+    ("darkcyan", [0x00, 0x8b, 0x8b]),
+    ("darkgoldenrod", [0xb8, 0x86, 0x0b]),
+    ("darkgray", [0xa9, 0xa9, 0xa9]),
+    ("darkgreen", [0x00, 0x64, 0x00]),
+    ("darkgrey", [0xa9, 0xa9, 0xa9]),
+    ("darkkhaki", [0xbd, 0xb7, 0x6b]),
+    ("darkmagenta", [0x8b, 0x00, 0x8b]),
+    ("darkolivegreen", [0x55, 0x6b, 0x2f]),
+    ("darkorange", [0xff, 0x8c, 0x00]),
+    ("darkorchid", [0x99, 0x32, 0xcc]),
+    ("darkred", [0x8b, 0x00, 0x00]),
+    ("darksalmon", [0xe9, 0x96, 0x7a]),
+    ("darkseagreen", [0x8f, 0xbc, 0x8f]),
+    ("darkslateblue", [0x48, 0x3d, 0x8b]),
+    ("darkslategray", [0x2f, 0x4f, 0x4f]),
+    ("darkslategrey", [0x2f, 0x4f, 0x4f]),
+    ("darkturquoise", [0x00, 0xce, 0xd1]),
+    ("darkviolet", [0x94, 0x00, 0xd3]),
+    ("deeppink", [0xff, 0x14, 0x93]),
+    ("deepskyblue", [0x00, 0xbf, 0xff]),
+    ("dimgray", [0x69, 0x69, 0x69]),
+    ("dimgrey", [0x69, 0x69, 0x69]),
+    ("dodgerblue", [0x1e, 0x90, 0xff]),
+    ("firebrick", [0xb2, 0x22, 0x22]),
+    ("floralwhite", [0xff, 0xfa, 0xf0]),
+    ("forestgreen", [0x22, 0x8b, 0x22]),
+    ("fuchsia", [0xff, 0x00, 0xff]),
+    ("gainsboro", [0xdc, 0xdc, 0xdc]),
+    ("ghostwhite", [0xf8, 0xf8, 0xff]),
+    ("gold", [0xff, 0xd7, 0x00]),
+    ("goldenrod", [0xda, 0xa5, 0x20]),
+    ("gray", [0x80, 0x80, 0x80]),
+    ("green", [0x00, 0x80, 0x00]),
+    ("greenyellow", [0xad, 0xff, 0x2f]),
+    ("grey", [0x80, 0x80, 0x80]),
+    ("honeydew", [0xf0, 0xff, 0xf0]),
+    ("hotpink", [0xff, 0x69, 0xb4]),
+    ("indianred", [0xcd, 0x5c, 0x5c]),
+    ("indigo", [0x4b, 0x00, 0x82]),
+    ("ivory", [0xff, 0xff, 0xf0]),
+    ("khaki", [0xf0, 0xe6, 0x8c]),
+    ("lavender", [0xe6, 0xe6, 0xfa]),
+    ("lavenderblush", [0xff, 0xf0, 0xf5]),
+    ("lawngreen", [0x7c, 0xfc, 0x00]),
+    ("lemonchiffon", [0xff, 0xfa, 0xcd]),
+    ("lightblue", [0xad, 0xd8, 0xe6]),
+    ("lightcoral", [0xf0, 0x80, 0x80]),
+    ("lightcyan", [0xe0, 0xff, 0xff]),
+    ("lightgoldenrodyellow", [0xfa, 0xfa, 0xd2]),
+    ("lightgray", [0xd3, 0xd3, 0xd3]),
+    ("lightgreen", [0x90, 0xee, 0x90]),
+    ("lightgrey", [0xd3, 0xd3, 0xd3]),
+    ("lightpink", [0xff, 0xb6, 0xc1]),
+    ("lightsalmon", [0xff, 0xa0, 0x7a]),
+    ("lightseagreen", [0x20, 0xb2, 0xaa]),
+    ("lightskyblue", [0x87, 0xce, 0xfa]),
+    ("lightslategray", [0x77, 0x88, 0x99]),
+    ("lightslategrey", [0x77, 0x88, 0x99]),
+    ("lightsteelblue", [0xb0, 0xc4, 0xde]),
+    ("lightyellow", [0xff, 0xff, 0xe0]),
+    ("lime", [0x00, 0xff, 0x00]),
+    ("limegreen", [0x32, 0xcd, 0x32]),
+    ("linen", [0xfa, 0xf0, 0xe6]),
+    ("magenta", [0xff, 0x00, 0xff]),
+    ("maroon", [0x80, 0x00, 0x00]),
+    ("mediumaquamarine", [0x66, 0xcd, 0xaa]),
+    ("mediumblue", [0x00, 0x00, 0xcd]),
+    ("mediumorchid", [0xba, 0x55, 0xd3]),
+    ("mediumpurple", [0x93, 0x70, 0xdb]),
+    ("mediumseagreen", [0x3c, 0xb3, 0x71]),
+    ("mediumslateblue", [0x7b, 0x68, 0xee]),
+    ("mediumspringgreen", [0x00, 0xfa, 0x9a]),
+    ("mediumturquoise", [0x48, 0xd1, 0xcc]),
+    ("mediumvioletred", [0xc7, 0x15, 0x85]),
+    ("midnightblue", [0x19, 0x19, 0x70]),
+    ("mintcream", [0xf5, 0xff, 0xfa]),
+    ("mistyrose", [0xff, 0xe4, 0xe1]),
+    ("moccasin", [0xff, 0xe4, 0xb5]),
+    ("navajowhite", [0xff, 0xde, 0xad]),
+    ("navy", [0x00, 0x00, 0x80]),
+    ("oldlace", [0xfd, 0xf5, 0xe6]),
+    ("olive", [0x80, 0x80, 0x00]),
+    ("olivedrab", [0x6b, 0x8e, 0x23]),
+    ("orange", [0xff, 0xa5, 0x00]),
+    ("orangered", [0xff, 0x45, 0x00]),
+    ("orchid", [0xda, 0x70, 0xd6]),
+    ("palegoldenrod", [0xee, 0xe8, 0xaa]),
+    ("palegreen", [0x98, 0xfb, 0x98]),
+    ("paleturquoise", [0xaf, 0xee, 0xee]),
+    ("palevioletred", [0xdb, 0x70, 0x93]),
+    ("papayawhip", [0xff, 0xef, 0xd5]),
+    ("peachpuff", [0xff, 0xda, 0xb9]),
+    ("peru", [0xcd, 0x85, 0x3f]),
+    ("pink", [0xff, 0xc0, 0xcb]),
+    ("plum", [0xdd, 0xa0, 0xdd]),
+    ("powderblue", [0xb0, 0xe0, 0xe6]),
+    ("purple", [0x80, 0x00, 0x80]),
+    ("rebeccapurple", [0x66, 0x33, 0x99]),
+    ("red", [0xff, 0x00, 0x00]),
+    ("rosybrown", [0xbc, 0x8f, 0x8f]),
+    ("royalblue", [0x41, 0x69, 0xe1]),
+    ("saddlebrown", [0x8b, 0x45, 0x13]),
+    ("salmon", [0xfa, 0x80, 0x72]),
+    ("sandybrown", [0xf4, 0xa4, 0x60]),
+    ("seagreen", [0x2e, 0x8b, 0x57]),
+    ("seashell", [0xff, 0xf5, 0xee]),
+    ("sienna", [0xa0, 0x52, 0x2d]),
+    ("silver", [0xc0, 0xc0, 0xc0]),
+    ("skyblue", [0x87, 0xce, 0xeb]),
+    ("slateblue", [0x6a, 0x5a, 0xcd]),
+    ("slategray", [0x70, 0x80, 0x90]),
+    ("slategrey", [0x70, 0x80, 0x90]),
+    ("snow", [0xff, 0xfa, 0xfa]),
+    ("springgreen", [0x00, 0xff, 0x7f]),
+    ("steelblue", [0x46, 0x82, 0xb4]),
+    ("tan", [0xd2, 0xb4, 0x8c]),
+    ("teal", [0x00, 0x80, 0x80]),
+    ("thistle", [0xd8, 0xbf, 0xd8]),
+    ("tomato", [0xff, 0x63, 0x47]),
+    ("turquoise", [0x40, 0xe0, 0xd0]),
+    ("violet", [0xee, 0x82, 0xee]),
+    ("wheat", [0xf5, 0xde, 0xb3]),
+    ("white", [0xff, 0xff, 0xff]),
+    ("whitesmoke", [0xf5, 0xf5, 0xf5]),
+    ("yellow", [0xff, 0xff, 0x00]),
+    ("yellowgreen", [0x9a, 0xcd, 0x32]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{on_background, on_background_from, Color, Hsl, InvalidColor, NAMED_COLORS};
+
+    #[test]
+    fn test_parse() {
+        assert!(NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        let orange = Color::rgb(0xff, 0x88, 0x00);
+        for value in &[
+            "#f80",
+            "#FF8800",
+            "#ff8800ff",
+            " #f80f ",
+            "rgb(255, 136, 0)",
+            "RGBA(255,136,0,1)",
+            "rgb(100%, 53.333%, 0%)",
+            "rgb(255 136 0 / 100%)",
+            "hsl(32, 100%, 50%)",
+            "hsl(32deg 100% 50%)",
+        ] {
+            assert_eq!(Color::parse(value), Ok(orange), "{}", value);
+        }
+
+        assert_eq!("white".parse(), Ok(Color::rgb(255, 255, 255)));
+        assert_eq!(
+            Color::parse("#0008"),
+            Ok(Color::rgba(0, 0, 0, 136.0 / 255.0))
+        );
+        assert_eq!(Color::parse("transparent").unwrap().alpha, 0.0);
+        assert_eq!(Color::parse("rgb(300, -5, 0)"), Ok(Color::rgb(255, 0, 0)));
+        assert_eq!(
+            Color::parse("hsla(-120, 100%, 50%, 2)"),
+            Ok(Color::rgb(0, 0, 255))
+        );
+        assert_eq!(
+            Color::parse("hsl(3.14159rad, 100%, 50%)"),
+            Ok(Color::rgb(0, 255, 255))
+        );
+        assert_eq!(
+            Color::parse("hsl(0, 0%, 50%)"),
+            Ok(Color::rgb(128, 128, 128))
+        );
+
+        for value in &[
+            "",
+            "#ff88800",
+            "#ff880",
+            "#gg8800",
+            "none",
+            "currentColor",
+            "rgb(1, 2)",
+            "rgb(1 2 3 4)",
+            "rgb(1, 2, 3",
+            "cmyk(1, 2, 3)",
+            "rgb(nan, 2, 3)",
+            "url(#gradient)",
+        ] {
+            assert_eq!(
+                Color::parse(value),
+                Err(InvalidColor(value.to_string())),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Color::rgb(0, 128, 255).to_string(), "#0080ff");
+        assert_eq!(
+            Color::rgba(255, 0, 0, 1.0 / 3.0).to_string(),
+            "rgba(255, 0, 0, 0.333)"
+        );
+        assert_eq!(Color::rgba(0, 0, 0, 7.0).alpha, 1.0);
+    }
+
+    #[test]
+    fn test_conversions() {
+        // Every named color survives a round trip through HSL
+        for (name, [red, green, blue]) in NAMED_COLORS {
+            let color = Color::rgb(*red, *green, *blue);
+            assert_eq!(Color::from(color.to_hsl()), color, "{}", name);
+        }
+
+        let hsl = Color::parse("#1f77b4").unwrap().to_hsl();
+        assert!((hsl.hue - 204.6).abs() < 0.1);
+        assert!((hsl.saturation - 0.706).abs() < 0.001);
+        assert!((hsl.lightness - 0.414).abs() < 0.001);
+        assert_eq!(Color::rgb(50, 50, 50).to_hsl().saturation, 0.0);
+        assert_eq!(
+            Hsl::from(Color::rgba(255, 0, 255, 0.5)).to_string(),
+            "hsla(300, 100%, 50%, 0.5)"
+        );
+
+        let gray = Color::rgb(128, 128, 128);
+        assert_eq!(gray.lighten(0.2), Color::rgb(179, 179, 179));
+        assert_eq!(gray.darken(0.2), Color::rgb(77, 77, 77));
+        assert_eq!(gray.lighten(2.0), Color::rgb(255, 255, 255));
+        assert_eq!(gray.with_alpha(0.25).lighten(0.0).alpha, 0.25);
+
+        let red = Color::rgb(255, 0, 0);
+        let blue = Color::rgba(0, 0, 255, 0.0);
+        assert_eq!(red.mix(&blue, 0.0), red);
+        assert_eq!(red.mix(&blue, 1.5), blue);
+        assert_eq!(red.mix(&blue, 0.25), Color::rgba(191, 0, 64, 0.75));
+    }
+
+    #[test]
+    fn test_contrast() {
+        let (black, white) = (Color::rgb(0, 0, 0), Color::rgb(255, 255, 255));
+        assert_eq!(black.luminance(), 0.0);
+        assert!((white.luminance() - 1.0).abs() < 1e-9);
+        assert!((black.contrast(&white) - 21.0).abs() < 1e-9);
+        assert_eq!(white.contrast(&black), black.contrast(&white));
+        assert_eq!(black.contrast(&black), 1.0);
+
+        // Every background has a label color passing WCAG level AA
+        for value in 0..=255 {
+            for background in [
+                Color::rgb(value, 0, 0),
+                Color::rgb(value, value, 255 - value),
+            ] {
+                assert!(on_background(background).contrast(&background) >= 4.5);
+            }
+        }
+        assert_eq!(on_background(Color::rgba(0, 0, 0, 0.0)), white);
+
+        let palette = [Color::rgb(100, 100, 100), black];
+        assert_eq!(on_background_from(white, &palette), Some(palette[0]));
+        assert_eq!(
+            on_background_from(Color::rgb(200, 200, 200), &palette),
+            Some(black)
+        );
+        assert_eq!(on_background_from(white, &[]), None);
+    }
+}
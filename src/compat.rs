@@ -0,0 +1,298 @@
+//! This module rewrites documents for targets which only support a part of SVG, like static SVG
+//! 1.1 viewers or email clients, with [downlevel](fn.downlevel.html).
+//!
+//! # Examples
+//! ## Preparing a graphic for an older viewer
+//! ```
+//! use svg_definitions::compat::{self, TargetProfile};
+//! use svg_definitions::prelude::*;
+//!
+//! let document = SVGDoc::new(
+//!     SVGElem::new(Tag::Svg)
+//!         .append(
+//!             SVGElem::new(Tag::RadialGradient)
+//!                 .set(Attr::Id, "glow")
+//!                 .set(Attr::Fr, "10%"),
+//!         )
+//!         .append(SVGElem::new(Tag::Use).set(Attr::Href, "#shape")),
+//! );
+//!
+//! let downleveled = compat::downlevel(&document, TargetProfile::Svg11Static);
+//! let root = downleveled.get_root();
+//!
+//! assert!(!root.get_children()[0].get_attributes().contains_key(&Attr::Fr));
+//! assert_eq!(root.get_children()[1].get_attributes()[&Attr::XlinkHref], "#shape");
+//! ```
+
+use crate::attributes::Attribute;
+use crate::document::Document;
+use crate::namespace;
+use crate::style::CssProperty;
+use crate::tag_name::TagName;
+use crate::Element;
+
+/// The elements which animate their parent or a referenced element
+const ANIMATION_ELEMENTS: [TagName; 4] = [
+    TagName::Animate,
+    TagName::AnimateMotion,
+    TagName::AnimateTransform,
+    TagName::Set,
+];
+
+/// A kind of target with limited SVG support
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TargetProfile {
+    /// Viewers of static SVG 1.1, like older renderers, print workflows and image converters
+    ///
+    /// SVG 2 features are rewritten to SVG 1.1 and animations are removed.
+    Svg11Static,
+
+    /// Email clients, which block active content
+    ///
+    /// Like [Svg11Static](#variant.Svg11Static), where also scripts, `<foreignObject>` elements,
+    /// event handler attributes, like `onclick`, and `javascript:` links of `<a>` elements are
+    /// removed.
+    EmailClients,
+}
+
+impl TargetProfile {
+    /// Returns whether the target runs scripts and renders `<foreignObject>`
+    fn allows_active_content(&self) -> bool {
+        match self {
+            TargetProfile::Svg11Static => true,
+            TargetProfile::EmailClients => false,
+        }
+    }
+}
+
+/// Returns a copy of a document with the features which a target cannot handle rewritten or
+/// removed
+///
+/// # Note / Arguments
+/// For every profile:
+/// - `href` is written as `xlink:href`, declaring the `xlink` namespace on the root, where
+///   `href` wins if an element has both
+/// - The `fr` focal radius of radial gradients is removed, so the focal radius is 0
+/// - `<meshgradient>` elements are approximated by a `<linearGradient>` with the same id, with
+///   the colors of the stops of the mesh evenly spaced
+/// - Animation elements are removed
+///
+/// Other profiles remove more, see [TargetProfile](enum.TargetProfile.html). Processing
+/// instructions are copied, observers are not.
+pub fn downlevel(document: &Document, profile: TargetProfile) -> Document {
+    let mut root = document.get_root().clone();
+    let uses_xlink = downlevel_element(&mut root, profile);
+    if uses_xlink
+        && !root
+            .get_namespaces()
+            .iter()
+            .any(|(prefix, _)| *prefix == "xlink")
+    {
+        root = root.set_namespace("xlink", namespace::XLINK);
+    }
+
+    let mut downleveled = Document::new(root);
+    for instruction in document.get_processing_instructions() {
+        downleveled.add_processing_instruction(instruction.clone());
+    }
+    downleveled
+}
+
+/// Rewrites an element and its descendants for a profile, returning whether an `xlink:href`
+/// was written
+fn downlevel_element(element: &mut Element, profile: TargetProfile) -> bool {
+    if !profile.allows_active_content() {
+        element.attributes.retain(|attribute, _| match attribute {
+            Attribute::Custom(name) => !name.to_ascii_lowercase().starts_with("on"),
+            _ => true,
+        });
+    }
+
+    let mut uses_xlink = false;
+    if let Some(href) = element.attributes.remove(&Attribute::Href) {
+        // SVG 2 viewers prefer href over xlink:href, so it is the one that was rendered
+        element.attributes.insert(Attribute::XlinkHref, href);
+        uses_xlink = true;
+    }
+    if element.tag_name == TagName::A && !profile.allows_active_content() {
+        let is_script = element
+            .attributes
+            .get(&Attribute::XlinkHref)
+            .is_some_and(|href| is_javascript_url(href));
+        if is_script {
+            element.attributes.remove(&Attribute::XlinkHref);
+        }
+    }
+    if element.tag_name == TagName::RadialGradient {
+        element.attributes.remove(&Attribute::Fr);
+    }
+    if element.tag_name == TagName::Meshgradient {
+        *element = approximate_mesh(element);
+    }
+
+    element.children.retain(|child| {
+        let active = matches!(child.tag_name, TagName::Script | TagName::ForeignObject);
+        let unsupported = ANIMATION_ELEMENTS.contains(&child.tag_name)
+            || (active && !profile.allows_active_content());
+        !unsupported
+    });
+    for child in element.children.iter_mut() {
+        uses_xlink |= downlevel_element(child, profile);
+    }
+    uses_xlink
+}
+
+/// Returns whether a URL runs a script, ignoring case and the whitespace and control characters
+/// which browsers strip from URLs
+fn is_javascript_url(url: &str) -> bool {
+    let scheme: String = url
+        .chars()
+        .filter(|character| !character.is_whitespace() && !character.is_control())
+        .take("javascript:".len())
+        .collect();
+    scheme.eq_ignore_ascii_case("javascript:")
+}
+
+/// Returns a `<linearGradient>` with the id of a mesh gradient and the colors of its stops
+fn approximate_mesh(mesh: &Element) -> Element {
+    fn collect_colors(element: &Element, colors: &mut Vec<String>) {
+        if element.tag_name == TagName::Stop {
+            let color = match element.attributes.get(&Attribute::StopColor) {
+                Some(color) => Some(String::from(color.as_str())),
+                None => element
+                    .get_style()
+                    .get(&CssProperty::custom("stop-color"))
+                    .map(String::from),
+            };
+            if let Some(color) = color.filter(|color| colors.last() != Some(color)) {
+                colors.push(color);
+            }
+        }
+        for child in element.children.iter() {
+            collect_colors(child, colors);
+        }
+    }
+
+    let mut colors = Vec::new();
+    collect_colors(mesh, &mut colors);
+
+    let mut gradient = Element::new(TagName::LinearGradient);
+    if let Some(id) = mesh.attributes.get(&Attribute::Id) {
+        gradient = gradient.set(Attribute::Id, id);
+    }
+    let last = colors.len().saturating_sub(1).max(1) as f64;
+    colors
+        .into_iter()
+        .enumerate()
+        .fold(gradient, |gradient, (index, color)| {
+            gradient.append(
+                Element::new(TagName::Stop)
+                    .set(Attribute::Offset, index as f64 / last)
+                    .set(Attribute::StopColor, color),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{downlevel, TargetProfile};
+    use crate::attributes::Attribute;
+    use crate::document::Document;
+    use crate::namespace;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    fn stop(color: &str) -> Element {
+        Element::new(TagName::Stop).set(Attribute::StopColor, color)
+    }
+
+    #[test]
+    fn test_downlevel() {
+        let mesh = Element::new(TagName::Meshgradient)
+            .set(Attribute::Id, "mesh")
+            .set(Attribute::X, 10)
+            .append(
+                Element::new(TagName::Meshrow).append(
+                    Element::new(TagName::Meshpatch)
+                        .append(stop("red"))
+                        .append(stop("red"))
+                        .append(
+                            Element::new(TagName::Stop).set(Attribute::Style, "stop-color:blue"),
+                        )
+                        .append(stop("white")),
+                ),
+            );
+        let root = Element::new(TagName::Svg)
+            .append(mesh)
+            .append(
+                Element::new(TagName::Rect)
+                    .set(Attribute::custom("onClick"), "alert(1)")
+                    .set(Attribute::custom("data-id"), "a")
+                    .append(Element::new(TagName::Set).set(Attribute::To, "red")),
+            )
+            .append(Element::new(TagName::Script).set_inner("alert(1)"))
+            .append(Element::new(TagName::ForeignObject))
+            .append(
+                Element::new(TagName::Use)
+                    .set(Attribute::Href, "#mesh")
+                    .set(Attribute::XlinkHref, "#old"),
+            )
+            .append(Element::new(TagName::A).set(Attribute::Href, " Java\tScript:alert(1)"))
+            .append(Element::new(TagName::A).set(Attribute::XlinkHref, "https://example.com"));
+        let document = Document::new(root);
+
+        let svg11 = downlevel(&document, TargetProfile::Svg11Static);
+        let root = svg11.get_root();
+        assert_eq!(
+            root.get_children()[0].to_string(),
+            "<linearGradient id=\"mesh\">\
+               <stop offset=\"0\" stop-color=\"red\"/>\
+               <stop offset=\"0.5\" stop-color=\"blue\"/>\
+               <stop offset=\"1\" stop-color=\"white\"/>\
+             </linearGradient>"
+        );
+        assert_eq!(root.get_children()[1].get_children().len(), 0);
+        assert_eq!(root.get_children()[1].get_attributes().len(), 2);
+        assert_eq!(root.get_children().len(), 7);
+        assert_eq!(root.get_namespaces(), vec![("xlink", namespace::XLINK)]);
+        assert_eq!(
+            root.get_children()[4].to_string(),
+            "<use xlink:href=\"#mesh\"/>"
+        );
+
+        let email = downlevel(&document, TargetProfile::EmailClients);
+        let root = email.get_root();
+        let tags: Vec<&TagName> = root
+            .get_children()
+            .iter()
+            .map(|child| child.get_tag_name())
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                &TagName::LinearGradient,
+                &TagName::Rect,
+                &TagName::Use,
+                &TagName::A,
+                &TagName::A
+            ]
+        );
+        assert_eq!(root.get_children()[1].to_string(), "<rect data-id=\"a\"/>");
+        assert_eq!(root.get_children()[3].to_string(), "<a/>");
+        assert_eq!(
+            root.get_children()[4].to_string(),
+            "<a xlink:href=\"https://example.com\"/>"
+        );
+        assert_eq!(
+            svg11.get_root().get_children()[5].get_attributes()[&Attribute::XlinkHref],
+            " Java\tScript:alert(1)"
+        );
+
+        // Documents without references do not declare the namespace
+        let plain = Document::new(Element::new(TagName::Svg));
+        assert!(downlevel(&plain, TargetProfile::Svg11Static)
+            .get_root()
+            .get_namespaces()
+            .is_empty());
+    }
+}
@@ -0,0 +1,287 @@
+//! This module provides the [Context](struct.Context.html), which holds the configuration shared
+//! by builders and optimization passes: the precision of numbers, the way ids are generated and
+//! a theme for generated content.
+//!
+//! # Examples
+//! ## Generating consistent markers and text
+//! ```
+//! use svg_definitions::context::{Context, IdStrategy, Theme};
+//! use svg_definitions::markers::MarkerBuilder;
+//! use svg_definitions::text::TextBuilder;
+//!
+//! let context = Context::new()
+//!     .with_precision(2)
+//!     .with_id_strategy(IdStrategy::Prefixed(String::from("chart-")))
+//!     .with_theme(Theme {
+//!         foreground: String::from("#333"),
+//!         ..Theme::default()
+//!     });
+//!
+//! let marker = MarkerBuilder::arrow_head().context(&context).build();
+//! let label = TextBuilder::new((0.0, 0.0)).context(&context).line("Revenue").build();
+//! # assert_eq!(marker.get_attributes()[&svg_definitions::prelude::Attr::Id], "chart-arrow-head");
+//! ```
+
+use std::cell::Cell;
+
+/// The way a [Context](struct.Context.html) turns the names of generated elements into ids
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IdStrategy {
+    /// Uses the names as they are, e.g. `arrow-head`
+    Readable,
+
+    /// Puts a prefix before the names, e.g. `icons-arrow-head`, to avoid collisions with other
+    /// SVGs on the same page
+    Prefixed(String),
+
+    /// Numbers the ids in the order they are generated after a prefix, e.g. `i0`, which keeps
+    /// them short and unique
+    Sequential(String),
+}
+
+/// The color and font used by builders which draw content of their own
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// The color of text and markers
+    pub foreground: String,
+
+    /// The font family of text, or None to inherit it
+    pub font_family: Option<String>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            foreground: String::from("black"),
+            font_family: None,
+        }
+    }
+}
+
+/// Context holds the configuration which is consulted by
+/// [MarkerBuilder::context](../markers/struct.MarkerBuilder.html#method.context),
+/// [TextBuilder::context](../text/struct.TextBuilder.html#method.context),
+/// [FilterBuilder::context](../filters/struct.FilterBuilder.html#method.context),
+/// [PathDefinitionString::with_context](../path/struct.PathDefinitionString.html#method.with_context)
+/// and [round_numbers](../optimize/fn.round_numbers.html)
+///
+/// # Note / Arguments
+/// Other builders, like the animation builders and
+/// [SpriteSheet](../sprites/struct.SpriteSheet.html), do not take a context. By default
+/// numbers are written with at most 4 decimals, ids are readable and the theme is black text. A
+/// context with [IdStrategy::Sequential](enum.IdStrategy.html#variant.Sequential) counts the
+/// generated ids, so the same context should be used for a whole document. The count is not
+/// part of the configuration, so it is ignored when comparing contexts.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::context::{Context, IdStrategy};
+///
+/// let context = Context::new()
+///     .with_precision(1)
+///     .with_id_strategy(IdStrategy::Sequential(String::from("i")));
+///
+/// assert_eq!(context.number(2.25), "2.3");
+/// assert_eq!(context.id("shadow"), "i0");
+/// assert_eq!(context.id("shadow"), "i1");
+/// assert_eq!(context, context.clone().with_precision(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Context {
+    /// The maximum amount of decimals of generated numbers, or None for at most 4 decimals
+    pub precision: Option<usize>,
+
+    /// The way generated ids are named
+    pub id_strategy: IdStrategy,
+
+    /// The color and font of generated content
+    pub theme: Theme,
+
+    next_id: Cell<usize>,
+}
+
+impl Context {
+    /// Creates a new context with the default configuration
+    pub fn new() -> Context {
+        Context {
+            precision: None,
+            id_strategy: IdStrategy::Readable,
+            theme: Theme::default(),
+            next_id: Cell::new(0),
+        }
+    }
+
+    /// Sets the maximum amount of decimals of generated numbers
+    pub fn with_precision(mut self, decimals: usize) -> Self {
+        self.precision = Some(decimals);
+        self
+    }
+
+    /// Sets the way generated ids are named
+    pub fn with_id_strategy(mut self, id_strategy: IdStrategy) -> Self {
+        self.id_strategy = id_strategy;
+        self
+    }
+
+    /// Sets the colors and font of generated content
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Formats a number with the precision of the context, without trailing zeros
+    pub fn number(&self, value: f64) -> String {
+        let factor = 10f64.powi(self.precision.unwrap_or(4).min(15) as i32);
+        // Adding 0.0 turns a negative zero into a positive zero
+        ((value * factor).round() / factor + 0.0).to_string()
+    }
+
+    /// Returns the id of a generated element with a certain name, following the id strategy
+    pub fn id(&self, name: &str) -> String {
+        match &self.id_strategy {
+            IdStrategy::Readable => String::from(name),
+            IdStrategy::Prefixed(prefix) => format!("{}{}", prefix, name),
+            IdStrategy::Sequential(prefix) => {
+                let id = self.next_id.get();
+                self.next_id.set(id + 1);
+                format!("{}{}", prefix, id)
+            }
+        }
+    }
+
+    /// Rounds every number within a text, e.g. a `transform` or `points` attribute, to the
+    /// precision of the context
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::context::Context;
+    ///
+    /// let context = Context::new().with_precision(1);
+    /// assert_eq!(
+    ///     context.round_numbers("translate(10.04, -3.25e1) rotate(.66)"),
+    ///     "translate(10, -32.5) rotate(0.7)"
+    /// );
+    /// ```
+    pub fn round_numbers(&self, text: &str) -> String {
+        let bytes = text.as_bytes();
+        let mut output = String::with_capacity(text.len());
+        let mut index = 0;
+
+        while index < bytes.len() {
+            // References like `#id2` are left alone
+            if bytes[index] == b'#' {
+                let name_length = 1 + bytes[index + 1..]
+                    .iter()
+                    .take_while(|byte| {
+                        byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b':')
+                    })
+                    .count();
+                output.push_str(&text[index..index + name_length]);
+                index += name_length;
+                continue;
+            }
+
+            let number_length = number_length(&bytes[index..]);
+            if number_length == 0 {
+                let character = text[index..].chars().next().expect("index is within text");
+                output.push(character);
+                index += character.len_utf8();
+                continue;
+            }
+
+            let number = &text[index..index + number_length];
+            match number.parse::<f64>() {
+                Ok(value) => output.push_str(&self.number(value)),
+                Err(_) => output.push_str(number),
+            }
+            index += number_length;
+        }
+        output
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Context::new()
+    }
+}
+
+impl PartialEq for Context {
+    /// Compares the configuration of contexts, ignoring the amount of generated ids
+    fn eq(&self, other: &Context) -> bool {
+        self.precision == other.precision
+            && self.id_strategy == other.id_strategy
+            && self.theme == other.theme
+    }
+}
+
+/// Returns the length of the number at the start of some bytes, or 0 if they do not start with
+/// a number
+fn number_length(bytes: &[u8]) -> usize {
+    let digits = |start: usize| {
+        bytes[start.min(bytes.len())..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+    };
+
+    let mut length = match bytes.first() {
+        Some(b'+') | Some(b'-') => 1,
+        _ => 0,
+    };
+    let integer = digits(length);
+    length += integer;
+
+    let mut fraction = 0;
+    if bytes.get(length) == Some(&b'.') {
+        fraction = digits(length + 1);
+        if fraction > 0 || integer > 0 {
+            length += 1 + fraction;
+        }
+    }
+    if integer == 0 && fraction == 0 {
+        return 0;
+    }
+
+    if let Some(b'e') | Some(b'E') = bytes.get(length) {
+        let sign = match bytes.get(length + 1) {
+            Some(b'+') | Some(b'-') => 1,
+            _ => 0,
+        };
+        let exponent = digits(length + 1 + sign);
+        if exponent > 0 {
+            length += 1 + sign + exponent;
+        }
+    }
+    length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, IdStrategy};
+
+    #[test]
+    fn test_context() {
+        let context = Context::new();
+        assert_eq!(context.number(1.0 / 3.0), "0.3333");
+        assert_eq!(context.number(-0.00001), "0");
+        assert_eq!(context.id("a"), "a");
+
+        let context = Context::new()
+            .with_precision(0)
+            .with_id_strategy(IdStrategy::Prefixed(String::from("x-")));
+        assert_eq!(context.number(49.6), "50");
+        assert_eq!(context.id("a"), "x-a");
+        assert_eq!(
+            context.round_numbers("M1.5-2.5e0 url(#g1.5) 3.7px 1e"),
+            "M2-3 url(#g1.5) 4px 1e"
+        );
+
+        // Generating ids does not change the configuration
+        let sequential = Context::new().with_id_strategy(IdStrategy::Sequential(String::from("i")));
+        let used = sequential.clone();
+        assert_eq!(used.id("a"), "i0");
+        assert_eq!(used, sequential);
+        assert_ne!(used, Context::new());
+    }
+}
@@ -0,0 +1,143 @@
+//! Corpus module, enabled with "corpus" feature
+//!
+//! This module bundles a small set of SVGs with the structure of real-world exports, so parser
+//! and optimizer changes can be validated against realistic inputs. The samples cover the
+//! quirks of their generators, like the namespaced editor data of Inkscape, the class based
+//! styles and `xml:space` of Illustrator, the filter and clip path ids of Figma, the DOCTYPE and
+//! inline styles of plotting libraries and the terse path data of handwritten icons.
+//!
+//! # Note
+//! The samples are written for this crate after the exports of the named generators, they are
+//! not files taken from third parties.
+//!
+//! # Examples
+//! ## Checking that an optimization keeps every sample parsable
+//! *The feature "corpus" needs to be enabled for this*
+//! ```
+//! use svg_definitions::corpus;
+//!
+//! for sample in corpus::samples() {
+//!     let document = sample.parse().unwrap();
+//!     let reparsed = svg_definitions::prelude::SVGParseText(&document.to_string());
+//!     assert!(reparsed.is_ok(), "{} does not survive a round trip", sample.name);
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::document::Document;
+use crate::parser::{parse_document, ParseError};
+
+/// The application which produced a [Sample](struct.Sample.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Generator {
+    Inkscape,
+    Illustrator,
+    Figma,
+    Matplotlib,
+    Handwritten,
+}
+
+impl fmt::Display for Generator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Generator::Inkscape => "Inkscape",
+            Generator::Illustrator => "Adobe Illustrator",
+            Generator::Figma => "Figma",
+            Generator::Matplotlib => "Matplotlib",
+            Generator::Handwritten => "handwritten",
+        })
+    }
+}
+
+/// An SVG of the corpus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sample {
+    /// The file name of the sample, e.g. `inkscape-drawing.svg`
+    pub name: &'static str,
+
+    /// The application which produced the sample
+    pub generator: Generator,
+
+    /// The markup of the sample
+    pub source: &'static str,
+}
+
+impl Sample {
+    /// Parses the sample into a [Document](../document/struct.Document.html)
+    pub fn parse(&self) -> Result<Document, ParseError> {
+        parse_document(self.source)
+    }
+}
+
+const SAMPLES: &[Sample] = &[
+    Sample {
+        name: "inkscape-drawing.svg",
+        generator: Generator::Inkscape,
+        source: include_str!("../corpus/inkscape-drawing.svg"),
+    },
+    Sample {
+        name: "illustrator-logo.svg",
+        generator: Generator::Illustrator,
+        source: include_str!("../corpus/illustrator-logo.svg"),
+    },
+    Sample {
+        name: "figma-card.svg",
+        generator: Generator::Figma,
+        source: include_str!("../corpus/figma-card.svg"),
+    },
+    Sample {
+        name: "chart-export.svg",
+        generator: Generator::Matplotlib,
+        source: include_str!("../corpus/chart-export.svg"),
+    },
+    Sample {
+        name: "handwritten-icons.svg",
+        generator: Generator::Handwritten,
+        source: include_str!("../corpus/handwritten-icons.svg"),
+    },
+];
+
+/// Returns an iterator over all samples of the corpus
+pub fn samples() -> impl Iterator<Item = &'static Sample> {
+    SAMPLES.iter()
+}
+
+/// Returns the sample with a certain file name, or None if it is not part of the corpus
+pub fn get(name: &str) -> Option<&'static Sample> {
+    SAMPLES.iter().find(|sample| sample.name == name)
+}
+
+/// Returns an iterator over the samples produced by a certain application
+pub fn by_generator(generator: Generator) -> impl Iterator<Item = &'static Sample> {
+    SAMPLES
+        .iter()
+        .filter(move |sample| sample.generator == generator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{by_generator, get, samples, Generator};
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+
+    #[test]
+    fn test_corpus() {
+        for sample in samples() {
+            let document = sample
+                .parse()
+                .unwrap_or_else(|err| panic!("{} cannot be parsed: {:?}", sample.name, err));
+            assert_eq!(*document.get_root().get_tag_name(), TagName::Svg);
+        }
+
+        let figma = get("figma-card.svg").unwrap().parse().unwrap();
+        assert!(figma.reference_graph().dangling().is_empty());
+        assert_eq!(
+            figma.get_root().get_attributes()[&Attribute::ViewBox],
+            "0 0 320 200"
+        );
+
+        assert_eq!(by_generator(Generator::Inkscape).count(), 1);
+        assert!(get("missing.svg").is_none());
+    }
+}
@@ -0,0 +1,221 @@
+//! This module provides the [DashArray](struct.DashArray.html) type for the `stroke-dasharray`
+//! attribute, which describes the pattern of dashes and gaps of a stroke.
+//!
+//! # Examples
+//! ## Drawing dashed and dotted lines
+//! ```
+//! use svg_definitions::dash::DashArray;
+//! use svg_definitions::length::Length;
+//! use svg_definitions::prelude::*;
+//!
+//! let border = SVGElem::new(Tag::Rect)
+//!     .set(Attr::StrokeDasharray, DashArray::dashed(6.0, 3.0));
+//! let guide = SVGElem::new(Tag::Line)
+//!     .set(Attr::StrokeDasharray, DashArray::from(vec![Length::percent(5.0), Length::px(2.0)]));
+//!
+//! assert_eq!(border.get_attributes()[&Attr::StrokeDasharray], "6 3");
+//! assert_eq!(guide.get_attributes()[&Attr::StrokeDasharray], "5% 2px");
+//! assert_eq!(border.get_dash_array(&Attr::StrokeDasharray), Some(DashArray::dashed(6.0, 3.0)));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::attributes::Attribute;
+use crate::length::Length;
+use crate::Element;
+
+/// The error returned when parsing a value which is not a dash array
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDashArray(pub String);
+
+impl fmt::Display for InvalidDashArray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid dash array {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDashArray {}
+
+/// The alternating lengths of the dashes and gaps of a stroke, starting with a dash
+///
+/// # Note / Arguments
+/// An empty dash array is a solid stroke and is written as `none`. A list with an odd amount of
+/// lengths is repeated by renderers to get an even amount. Values converted from `f32` are
+/// written as short as possible, e.g. `0.1` instead of `0.10000000149011612`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DashArray(Vec<Length>);
+
+impl DashArray {
+    /// Creates a dash array from lengths, like `4`, `2px` or `5%`
+    #[inline]
+    pub fn new(lengths: Vec<Length>) -> DashArray {
+        DashArray(lengths)
+    }
+
+    /// A solid stroke without dashes, which is written as `none`
+    #[inline]
+    pub fn solid() -> DashArray {
+        DashArray(Vec::new())
+    }
+
+    /// Creates square dots with gaps of the same size, for a stroke with a certain width
+    ///
+    /// # Note / Arguments
+    /// The dots are only square with the default `butt` line caps. For round dots, use
+    /// `DashArray::from(vec![0.0, 2.0 * width])` with `stroke-linecap="round"`.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::dash::DashArray;
+    ///
+    /// assert_eq!(DashArray::dotted(1.5).to_string(), "1.5 1.5");
+    /// ```
+    #[inline]
+    pub fn dotted(width: f32) -> DashArray {
+        DashArray::from(vec![width, width])
+    }
+
+    /// Creates dashes of length `on` with gaps of length `off`
+    #[inline]
+    pub fn dashed(on: f32, off: f32) -> DashArray {
+        DashArray::from(vec![on, off])
+    }
+
+    /// Returns the lengths of the dashes and gaps
+    #[inline]
+    pub fn lengths(&self) -> &[Length] {
+        &self.0
+    }
+
+    /// Returns whether this dash array is a solid stroke
+    #[inline]
+    pub fn is_solid(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Converts an `f32` to the `f64` with the same shortest decimal representation
+fn shortest(value: f32) -> f64 {
+    value
+        .to_string()
+        .parse()
+        .unwrap_or_else(|_| f64::from(value))
+}
+
+impl From<Vec<Length>> for DashArray {
+    #[inline]
+    fn from(lengths: Vec<Length>) -> DashArray {
+        DashArray(lengths)
+    }
+}
+
+impl From<&[f32]> for DashArray {
+    fn from(values: &[f32]) -> DashArray {
+        DashArray(
+            values
+                .iter()
+                .map(|value| Length::number(shortest(*value)))
+                .collect(),
+        )
+    }
+}
+
+impl From<Vec<f32>> for DashArray {
+    #[inline]
+    fn from(values: Vec<f32>) -> DashArray {
+        DashArray::from(&values[..])
+    }
+}
+
+impl fmt::Display for DashArray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "none");
+        }
+
+        for (index, length) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", length)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DashArray {
+    type Err = InvalidDashArray;
+
+    /// Parses `none` or lengths separated by commas and/or whitespace, where negative lengths are
+    /// invalid
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidDashArray(value.to_string());
+
+        if value.trim() == "none" {
+            return Ok(DashArray::solid());
+        }
+
+        let lengths = value
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                part.parse::<Length>()
+                    .ok()
+                    .filter(|length| length.value >= 0.0)
+            })
+            .collect::<Option<Vec<Length>>>()
+            .ok_or_else(invalid)?;
+
+        if lengths.is_empty() {
+            Err(invalid())
+        } else {
+            Ok(DashArray(lengths))
+        }
+    }
+}
+
+impl Element {
+    /// Returns the value of an attribute as a [DashArray](dash/struct.DashArray.html), or None if
+    /// it is not set or not a dash array
+    pub fn get_dash_array(&self, attribute: &Attribute) -> Option<DashArray> {
+        self.attributes.get(attribute)?.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DashArray, InvalidDashArray};
+    use crate::length::Length;
+
+    #[test]
+    fn test_dash_array() {
+        assert_eq!(DashArray::from(vec![0.1f32, 2.0]).to_string(), "0.1 2");
+        assert_eq!(
+            DashArray::from(&[5.0f32, 1.0, 2.5][..]).to_string(),
+            "5 1 2.5"
+        );
+        assert_eq!(DashArray::solid().to_string(), "none");
+        assert!(DashArray::default().is_solid());
+        assert_eq!(
+            DashArray::dashed(4.0, 2.0).lengths(),
+            [Length::number(4.0), Length::number(2.0)]
+        );
+
+        assert_eq!(" none ".parse(), Ok(DashArray::solid()));
+        assert_eq!(
+            "4, 2mm,1%".parse(),
+            Ok(DashArray::new(vec![
+                Length::number(4.0),
+                Length::mm(2.0),
+                Length::percent(1.0)
+            ]))
+        );
+        for value in &["", " , ", "4 -2", "4 auto", "none 4"] {
+            assert_eq!(
+                value.parse::<DashArray>(),
+                Err(InvalidDashArray(value.to_string()))
+            );
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! This module provides decorations which are placed along paths, like the arrowheads of
+//! [arrows_along](fn.arrows_along.html) which show the direction of flows and currents.
+//!
+//! # Examples
+//! ## Showing the direction of a flow
+//! ```
+//! use svg_definitions::decor::{self, ArrowStyle};
+//! use svg_definitions::prelude::*;
+//!
+//! let flow = PathData::new()
+//!     .move_to((0.0, 50.0))
+//!     .curve_to((200.0, 50.0), (60.0, 0.0), (140.0, 100.0));
+//!
+//! let svg = SVGElem::new(Tag::Svg)
+//!     .append(
+//!         SVGElem::new(Tag::Path)
+//!             .set(Attr::D, flow.clone())
+//!             .set(Attr::Fill, "none")
+//!             .set(Attr::Stroke, "steelblue"),
+//!     )
+//!     .append(decor::arrows_along(&flow, 40.0, ArrowStyle::default()).set(Attr::Fill, "steelblue"));
+//! ```
+
+use crate::attributes::Attribute;
+use crate::path::PathDefinitionString;
+use crate::tag_name::TagName;
+use crate::Element;
+
+/// The shape of the arrowheads placed by [arrows_along](fn.arrows_along.html), where the
+/// length is measured along the path and the width across it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArrowStyle {
+    /// A closed triangle, which is drawn with the fill
+    Triangle { length: f64, width: f64 },
+
+    /// An open chevron of two lines, which is drawn with the stroke
+    Chevron { length: f64, width: f64 },
+}
+
+impl Default for ArrowStyle {
+    /// A triangle with a length of 8 and a width of 6
+    fn default() -> Self {
+        ArrowStyle::Triangle {
+            length: 8.0,
+            width: 6.0,
+        }
+    }
+}
+
+/// Returns a `<path>` with arrowheads placed along a path at regular distances, pointing in the
+/// direction the path is drawn
+///
+/// # Note / Arguments
+/// The arrowheads are `spacing` apart and centered on the drawn length of the path, with their
+/// tips on the path. A path shorter than the spacing gets one arrowhead halfway. Chevrons set
+/// `fill="none"`, the color of the arrowheads is set on the returned element or inherited.
+///
+/// # Panics
+/// If the `spacing` is not positive.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::decor::{self, ArrowStyle};
+/// use svg_definitions::prelude::*;
+///
+/// let wire = PathData::new().move_to((0.0, 0.0)).horizontal_line_to(100.0);
+/// let arrows = decor::arrows_along(&wire, 30.0, ArrowStyle::Chevron { length: 4.0, width: 4.0 });
+///
+/// // Arrowheads at 20, 50 and 80
+/// assert_eq!(
+///     arrows.to_string(),
+///     "<path d=\"M 16.00 -2.00 L 20.00 0.00 L 16.00 2.00 \
+///      M 46.00 -2.00 L 50.00 0.00 L 46.00 2.00 \
+///      M 76.00 -2.00 L 80.00 0.00 L 76.00 2.00\" fill=\"none\"/>"
+/// );
+/// ```
+pub fn arrows_along(path: &PathDefinitionString, spacing: f64, style: ArrowStyle) -> Element {
+    assert!(spacing > 0.0, "the spacing has to be positive");
+
+    let length = path.length();
+    let count = (length / spacing).floor().max(1.0) as usize;
+    let first = (length - (count - 1) as f64 * spacing) / 2.0;
+    let distances = (0..count).map(|index| first + index as f64 * spacing);
+
+    let (arrow_length, half_width, closed) = match style {
+        ArrowStyle::Triangle { length, width } => (length, width / 2.0, true),
+        ArrowStyle::Chevron { length, width } => (length, width / 2.0, false),
+    };
+
+    let mut arrows = PathDefinitionString::new();
+    for ((x, y), (dx, dy)) in path.points_at(distances) {
+        let norm = dx.hypot(dy);
+        if norm == 0.0 {
+            continue;
+        }
+        let (dx, dy) = (dx / norm, dy / norm);
+
+        // The corners lie behind the tip, on both sides of the path
+        let back = (x - dx * arrow_length, y - dy * arrow_length);
+        let corner = |side: f64| {
+            (
+                (back.0 - side * dy * half_width) as f32,
+                (back.1 + side * dx * half_width) as f32,
+            )
+        };
+
+        arrows = arrows
+            .move_to(corner(-1.0))
+            .line_to((x as f32, y as f32))
+            .line_to(corner(1.0));
+        if closed {
+            arrows = arrows.close_path();
+        }
+    }
+
+    let element = Element::new(TagName::Path).set(Attribute::D, arrows);
+    match style {
+        ArrowStyle::Triangle { .. } => element,
+        ArrowStyle::Chevron { .. } => element.set(Attribute::Fill, "none"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{arrows_along, ArrowStyle};
+    use crate::attributes::Attribute;
+    use crate::path::PathDefinitionString;
+
+    #[test]
+    fn test_arrows_along() {
+        let style = ArrowStyle::Triangle {
+            length: 2.0,
+            width: 2.0,
+        };
+
+        // A short path gets one arrowhead halfway, pointing down
+        let drop = PathDefinitionString::new()
+            .move_to((5.0, 0.0))
+            .vertical_line_to(10.0);
+        assert_eq!(
+            arrows_along(&drop, 50.0, style).to_string(),
+            "<path d=\"M 6.00 3.00 L 5.00 5.00 L 4.00 3.00 Z\"/>"
+        );
+
+        // Arrowheads follow the direction of the path around corners
+        let corner = PathDefinitionString::new()
+            .move_to((0.0, 0.0))
+            .horizontal_line_to(10.0)
+            .vertical_line_to(10.0);
+        let arrows = arrows_along(&corner, 10.0, style);
+        assert_eq!(
+            arrows.get_attributes()[&Attribute::D],
+            "M 3.00 -1.00 L 5.00 0.00 L 3.00 1.00 Z M 11.00 3.00 L 10.00 5.00 L 9.00 3.00 Z"
+        );
+
+        assert_eq!(
+            arrows_along(&PathDefinitionString::new(), 10.0, style).to_string(),
+            "<path d=\"\"/>"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_arrows_along_without_spacing() {
+        arrows_along(&PathDefinitionString::new(), 0.0, ArrowStyle::default());
+    }
+}
@@ -0,0 +1,137 @@
+//! This module provides human-readable differences between two
+//! [Elements](../struct.Element.html), e.g. for snapshot test failure messages.
+//!
+//! Every difference is put on its own line, starting with `+` for added, `-` for removed and
+//! `~` for changed nodes, attributes and texts. Nodes are addressed by a path of tag names
+//! with their child index, like `svg/g[1]/rect[0]`.
+//!
+//! # Examples
+//! ## Explaining a changed fill
+//! ```
+//! use svg_definitions::diff;
+//! use svg_definitions::prelude::*;
+//!
+//! let old = SVGElem::new(Tag::Svg)
+//!     .append(SVGElem::new(Tag::Rect).set(Attr::Fill, "red"));
+//! let new = SVGElem::new(Tag::Svg)
+//!     .append(SVGElem::new(Tag::Rect).set(Attr::Fill, "blue"))
+//!     .append(SVGElem::new(Tag::Circle));
+//!
+//! assert_eq!(
+//!     diff::explain(&old, &new),
+//!     "~ svg/rect[0] @fill: \"red\" -> \"blue\"\n+ svg/circle[1]\n"
+//! );
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::Element;
+
+/// Explains the differences between two elements, returning an empty string if they are equal
+pub fn explain(old: &Element, new: &Element) -> String {
+    let mut output = String::new();
+    explain_element(old, new, &old.get_tag_name().to_string(), &mut output);
+    output
+}
+
+fn explain_element(old: &Element, new: &Element, path: &str, output: &mut String) {
+    if old.get_tag_name() != new.get_tag_name() {
+        output.push_str(&format!(
+            "~ {}: tag {} -> {}\n",
+            path,
+            old.get_tag_name(),
+            new.get_tag_name()
+        ));
+    }
+
+    let old_attributes = sorted_attributes(old);
+    let new_attributes = sorted_attributes(new);
+
+    for (name, old_value) in old_attributes.iter() {
+        match new_attributes.get(name) {
+            None => output.push_str(&format!("- {} @{}: {:?}\n", path, name, old_value)),
+            Some(new_value) if new_value != old_value => output.push_str(&format!(
+                "~ {} @{}: {:?} -> {:?}\n",
+                path, name, old_value, new_value
+            )),
+            Some(_) => (),
+        }
+    }
+    for (name, new_value) in new_attributes.iter() {
+        if !old_attributes.contains_key(name) {
+            output.push_str(&format!("+ {} @{}: {:?}\n", path, name, new_value));
+        }
+    }
+
+    match (old.get_inner(), new.get_inner()) {
+        (Some(old_inner), None) => output.push_str(&format!("- {} text: {:?}\n", path, old_inner)),
+        (None, Some(new_inner)) => output.push_str(&format!("+ {} text: {:?}\n", path, new_inner)),
+        (Some(old_inner), Some(new_inner)) if old_inner != new_inner => output.push_str(&format!(
+            "~ {} text: {:?} -> {:?}\n",
+            path, old_inner, new_inner
+        )),
+        _ => (),
+    }
+
+    let old_children = old.get_children();
+    let new_children = new.get_children();
+    for index in 0..old_children.len().max(new_children.len()) {
+        match (old_children.get(index), new_children.get(index)) {
+            (Some(old_child), Some(new_child)) => {
+                let child_path = child_path(path, new_child, index);
+                explain_element(old_child, new_child, &child_path, output);
+            }
+            (Some(old_child), None) => {
+                output.push_str(&format!("- {}\n", child_path(path, old_child, index)))
+            }
+            (None, Some(new_child)) => {
+                output.push_str(&format!("+ {}\n", child_path(path, new_child, index)))
+            }
+            (None, None) => (),
+        }
+    }
+}
+
+fn child_path(path: &str, child: &Element, index: usize) -> String {
+    format!("{}/{}[{}]", path, child.get_tag_name(), index)
+}
+
+fn sorted_attributes(element: &Element) -> BTreeMap<String, &str> {
+    element
+        .get_attributes()
+        .iter()
+        .map(|(attribute, value)| (attribute.to_string(), value.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::explain;
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    #[test]
+    fn test_explain() {
+        let old = Element::new(TagName::G)
+            .set(Attribute::Id, "layer")
+            .append(Element::new(TagName::Text).set_inner("Hello"))
+            .append(Element::new(TagName::Rect));
+        let new = Element::new(TagName::G)
+            .set(Attribute::Class, "layer")
+            .append(Element::new(TagName::Text).set_inner("World"));
+
+        assert_eq!(explain(&old, &old.clone()), "");
+        assert_eq!(
+            explain(&old, &new),
+            "- g @id: \"layer\"\n\
+             + g @class: \"layer\"\n\
+             ~ g/text[0] text: \"Hello\" -> \"World\"\n\
+             - g/rect[1]\n"
+        );
+        assert_eq!(
+            explain(&Element::new(TagName::G), &Element::new(TagName::Svg)),
+            "~ g: tag g -> svg\n"
+        );
+    }
+}
@@ -0,0 +1,1376 @@
+//! This module provides a [Document](struct.Document.html), which owns the root
+//! [Element](../struct.Element.html) of an SVG and provides operations on the whole tree.
+//!
+//! # Note
+//! In the [crate::prelude](../prelude/index.html) the name for
+//! [Document](struct.Document.html) is [SVGDoc](../prelude/index.html)
+//!
+//! # Examples
+//! ## Extracting repeated styles into classes
+//! ```
+//! use svg_definitions::prelude::*;
+//!
+//! let mut document = SVGDoc::new(
+//!     SVGElem::new(Tag::Svg)
+//!         .append(SVGElem::new(Tag::Rect).set(Attr::Fill, "red"))
+//!         .append(SVGElem::new(Tag::Circle).set(Attr::Fill, "red")),
+//! );
+//!
+//! document.extract_classes();
+//! ```
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+use crate::attributes::Attribute;
+use crate::changes::{Change, ChangeKind};
+use crate::geometry::{self, BoundingBox};
+use crate::graph::ReferenceGraph;
+use crate::limits::{LimitError, Limits};
+use crate::references::{is_external, rewrite_references, url_targets};
+use crate::report::Report;
+use crate::tag_name::TagName;
+use crate::tree::ElementPath;
+use crate::{Element, Matrix, Rejected};
+
+/// A callback which is invoked with the path of the changed element and the kind of change
+type Observer = Box<dyn FnMut(&ElementPath, &ChangeKind)>;
+
+/// A list of sorted CSS declarations, e.g. `[("fill", "red"), ("stroke", "blue")]`
+type Declarations = Vec<(String, String)>;
+
+/// A reference to a resource outside of a [Document](struct.Document.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalRef {
+    /// The path of the element containing the reference
+    pub path: ElementPath,
+
+    /// The attribute containing the reference, or None for the stylesheet of a `<style>` element
+    pub attribute: Option<Attribute>,
+
+    /// The absolute URL which is referenced
+    pub url: String,
+}
+
+/// What frontends need to know about an element with an id, as listed by
+/// [metadata_map](struct.Document.html#method.metadata_map)
+///
+/// # Note
+/// With the feature "serde" enabled, this can be serialized, e.g. to send it along with the SVG.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementMetadata {
+    /// The tag name of the element
+    pub tag: TagName,
+
+    /// The classes in the `class` attribute of the element
+    pub classes: Vec<String>,
+
+    /// The bounding box of the element in the user space of the root, or None if it cannot be
+    /// determined
+    pub bbox: Option<BoundingBox>,
+}
+
+/// A processing instruction in the prolog of a [Document](struct.Document.html), e.g.
+/// `<?xml-stylesheet href="style.css"?>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessingInstruction {
+    /// The target, e.g. `xml-stylesheet`
+    pub target: String,
+
+    /// The content after the target, e.g. `href="style.css"`
+    pub value: Option<String>,
+}
+
+impl fmt::Display for ProcessingInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "<?{} {}?>", self.target, value),
+            None => write!(f, "<?{}?>", self.target),
+        }
+    }
+}
+
+/// Document provides a root [Element](../struct.Element.html) together with operations
+/// that work on the whole tree
+pub struct Document {
+    root: Element,
+    processing_instructions: Vec<ProcessingInstruction>,
+    observers: Vec<Observer>,
+    layers: Vec<Layer>,
+}
+
+/// A layer created by [Document::layer](struct.Document.html#method.layer)
+#[derive(Debug, Clone)]
+struct Layer {
+    name: String,
+    z: i32,
+
+    /// The index of the layer within the children of the root
+    index: usize,
+}
+
+impl Document {
+    /// Creates a new Document with a certain root element
+    #[inline]
+    pub fn new(root: Element) -> Document {
+        Document {
+            root,
+            processing_instructions: Vec::new(),
+            observers: Vec::new(),
+            layers: Vec::new(),
+        }
+    }
+
+    /// Gets an immutable reference to the root element of this Document
+    #[inline]
+    pub fn get_root(&self) -> &Element {
+        &self.root
+    }
+
+    /// Gets the processing instructions before the root element, e.g. the XML declaration or
+    /// `<?xml-stylesheet ...?>`, in the order of the markup
+    #[inline]
+    pub fn get_processing_instructions(&self) -> &[ProcessingInstruction] {
+        &self.processing_instructions
+    }
+
+    /// Adds a processing instruction after the existing ones, which is written before the root
+    /// element
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::document::ProcessingInstruction;
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let mut document = SVGDoc::new(SVGElem::new(Tag::Svg));
+    /// document.add_processing_instruction(ProcessingInstruction {
+    ///     target: String::from("xml-stylesheet"),
+    ///     value: Some(String::from("href=\"style.css\"")),
+    /// });
+    ///
+    /// assert_eq!(
+    ///     document.to_string(),
+    ///     "<?xml-stylesheet href=\"style.css\"?>\n<svg/>"
+    /// );
+    /// ```
+    pub fn add_processing_instruction(&mut self, instruction: ProcessingInstruction) {
+        self.processing_instructions.push(instruction);
+    }
+
+    /// Gets a mutable reference to the root element of this Document
+    ///
+    /// # Note
+    /// Changes made through this reference are not reported to the observers registered with
+    /// [on_change](#method.on_change).
+    #[inline]
+    pub fn get_root_mut(&mut self) -> &mut Element {
+        &mut self.root
+    }
+
+    /// Consumes the Document and returns its root element
+    #[inline]
+    pub fn into_root(self) -> Element {
+        self.root
+    }
+
+    /// Registers a callback which is invoked whenever the tree is changed through the methods of
+    /// this Document, with the path of the changed element and the kind of change
+    ///
+    /// # Note / Arguments
+    /// Operations on the whole tree, like [rename_id](#method.rename_id), report a
+    /// [Subtree](../changes/enum.ChangeKind.html) change of the root. Observers are not cloned
+    /// along with the Document.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// use svg_definitions::changes::ChangeKind;
+    /// use svg_definitions::prelude::*;
+    /// use svg_definitions::tree::ElementPath;
+    ///
+    /// let mut document = SVGDoc::new(SVGElem::new(Tag::Svg).append(SVGElem::new(Tag::Rect)));
+    ///
+    /// let touched = Rc::new(RefCell::new(Vec::new()));
+    /// let observed = touched.clone();
+    /// document.on_change(move |path, kind| observed.borrow_mut().push((path.clone(), kind.clone())));
+    ///
+    /// document.set_attribute(&ElementPath::from(vec![0]), Attr::Fill, "red");
+    ///
+    /// assert_eq!(
+    ///     touched.borrow()[0],
+    ///     (ElementPath::from(vec![0]), ChangeKind::AttributeSet(Attr::Fill))
+    /// );
+    /// ```
+    pub fn on_change<F>(&mut self, callback: F)
+    where
+        F: FnMut(&ElementPath, &ChangeKind) + 'static,
+    {
+        self.observers.push(Box::new(callback));
+    }
+
+    pub(crate) fn notify(&mut self, path: &ElementPath, kind: &ChangeKind) {
+        if path.is_root() {
+            self.track_layers(kind);
+        }
+        for observer in self.observers.iter_mut() {
+            observer(path, kind);
+        }
+    }
+
+    /// Applies a [Change](../changes/enum.Change.html) to the tree and notifies the observers,
+    /// returning the change which reverts it, or None if it could not be applied
+    pub fn apply(&mut self, change: Change) -> Option<Change> {
+        let path = change.path().clone();
+        let kind = change.kind();
+
+        let inverse = change.apply(&mut self.root)?;
+        self.notify(&path, &kind);
+        Some(inverse)
+    }
+
+    /// Sets an attribute of the element at a certain path, returning the previous value
+    ///
+    /// Returns None without changing anything if the element does not exist.
+    pub fn set_attribute<T>(
+        &mut self,
+        path: &ElementPath,
+        attribute: Attribute,
+        value: T,
+    ) -> Option<String>
+    where
+        T: ToString,
+    {
+        let previous = self
+            .root
+            .get_path_mut(path)?
+            .set_attribute(attribute.clone(), value);
+        self.notify(path, &ChangeKind::AttributeSet(attribute));
+        previous
+    }
+
+    /// Removes an attribute of the element at a certain path, returning the previous value
+    pub fn remove_attribute(
+        &mut self,
+        path: &ElementPath,
+        attribute: &Attribute,
+    ) -> Option<String> {
+        let previous = self.root.get_path_mut(path)?.remove_attribute(attribute)?;
+        self.notify(path, &ChangeKind::AttributeRemoved(attribute.clone()));
+        Some(previous)
+    }
+
+    /// Inserts a child at a certain index of the children of the element at a certain path,
+    /// returning whether it was inserted, which it is not if the element does not exist or
+    /// `index` is larger than the amount of children
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    /// use svg_definitions::tree::ElementPath;
+    ///
+    /// let mut document = SVGDoc::new(SVGElem::new(Tag::Svg));
+    /// let root = ElementPath::new();
+    ///
+    /// assert!(document.insert_child(&root, 0, SVGElem::new(Tag::Rect)));
+    /// assert!(!document.insert_child(&root, 2, SVGElem::new(Tag::Circle)));
+    /// assert!(!document.insert_child(&ElementPath::from(vec![3]), 0, SVGElem::new(Tag::Circle)));
+    /// # assert_eq!(document.get_root().to_string(), "<svg><rect/></svg>");
+    /// ```
+    pub fn insert_child(&mut self, path: &ElementPath, index: usize, child: Element) -> bool {
+        match self.root.get_path_mut(path) {
+            Some(element) if index <= element.children.len() => element.insert_child(index, child),
+            _ => return false,
+        }
+        self.notify(path, &ChangeKind::ChildInserted(index));
+        true
+    }
+
+    /// Removes and returns the child at a certain index of the children of the element at a
+    /// certain path, or None if it does not exist
+    pub fn remove_child(&mut self, path: &ElementPath, index: usize) -> Option<Element> {
+        let element = self.root.get_path_mut(path)?;
+        if index >= element.children.len() {
+            return None;
+        }
+
+        let child = element.remove_child(index);
+        self.notify(path, &ChangeKind::ChildRemoved(index));
+        Some(child)
+    }
+
+    /// Gets a mutable reference to the layer with a certain name, a `<g>` child of the root
+    /// which is kept before all layers with a higher `z` and after all layers with a lower `z`
+    ///
+    /// # Note / Arguments
+    /// The layer is created if it does not exist yet, and moved if it exists with a different
+    /// `z`. Layers with the same `z` stay in the order they were created. The name and `z` are
+    /// kept by this Document rather than in the output, so the layers are only known to the
+    /// Document which created them. Other children of the root, like `<defs>`, are not moved.
+    ///
+    /// Layers are followed when the children of the root are inserted or removed through the
+    /// methods of this Document. Moving the children of the root through
+    /// [get_root_mut](#method.get_root_mut) loses track of the layers, after which new ones are
+    /// created.
+    ///
+    /// Like [get_root_mut](#method.get_root_mut), changes made through this reference are not
+    /// reported to the observers, use [add_to_layer](#method.add_to_layer) for that.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let mut document = SVGDoc::new(SVGElem::new(Tag::Svg));
+    ///
+    /// // Independent modules add their content in any order
+    /// document.add_to_layer("annotations", 2, SVGElem::new(Tag::Text).set_inner("peak"));
+    /// document.add_to_layer("grid", 0, SVGElem::new(Tag::Line));
+    /// document.layer("data", 1).insert_child(0, SVGElem::new(Tag::Path));
+    ///
+    /// assert_eq!(
+    ///     document.to_string(),
+    ///     "<svg><g><line/></g><g><path/></g><g><text>peak</text></g></svg>"
+    /// );
+    /// ```
+    pub fn layer(&mut self, name: &str, z: i32) -> &mut Element {
+        let index = self.layer_index(name, z);
+        &mut self.root.children[index]
+    }
+
+    /// Appends an element to the layer with a certain name, see [layer](#method.layer), and
+    /// notifies the observers
+    pub fn add_to_layer(&mut self, name: &str, z: i32, element: Element) {
+        let index = self.layer_index(name, z);
+        let layer = &mut self.root.children[index];
+        let child_index = layer.children.len();
+        layer.children.push(element);
+        self.notify(
+            &ElementPath::new().child(index),
+            &ChangeKind::ChildInserted(child_index),
+        );
+    }
+
+    /// Returns the index of the layer with a certain name within the children of the root,
+    /// creating or moving it so it is ordered by `z`
+    fn layer_index(&mut self, name: &str, z: i32) -> usize {
+        // Layers which were moved away through get_root_mut are forgotten
+        let children = &self.root.children;
+        self.layers.retain(|layer| {
+            children
+                .get(layer.index)
+                .is_some_and(|child| child.tag_name == TagName::G)
+        });
+
+        let layer = match self.layers.iter().position(|layer| layer.name == name) {
+            Some(position) if self.layers[position].z == z => {
+                return self.layers[position].index;
+            }
+            Some(position) => {
+                let index = self.layers[position].index;
+                let layer = self.root.remove_child(index);
+                self.notify(&ElementPath::new(), &ChangeKind::ChildRemoved(index));
+                layer
+            }
+            None => Element::new(TagName::G),
+        };
+
+        let index = self
+            .layers
+            .iter()
+            .filter(|layer| layer.z > z)
+            .map(|layer| layer.index)
+            .min()
+            .unwrap_or(self.root.children.len());
+        self.root.insert_child(index, layer);
+        self.notify(&ElementPath::new(), &ChangeKind::ChildInserted(index));
+        self.layers.push(Layer {
+            name: String::from(name),
+            z,
+            index,
+        });
+        index
+    }
+
+    /// Keeps the indices of the layers in line with a change to the children of the root
+    fn track_layers(&mut self, kind: &ChangeKind) {
+        match *kind {
+            ChangeKind::ChildInserted(inserted) => {
+                for layer in self
+                    .layers
+                    .iter_mut()
+                    .filter(|layer| layer.index >= inserted)
+                {
+                    layer.index += 1;
+                }
+            }
+            ChangeKind::ChildRemoved(removed) => {
+                self.layers.retain(|layer| layer.index != removed);
+                for layer in self.layers.iter_mut().filter(|layer| layer.index > removed) {
+                    layer.index -= 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Creates a [Report](../report/struct.Report.html) with statistics about this Document,
+    /// like node counts per tag, attribute sizes and the estimated serialized size
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let document = SVGDoc::new(
+    ///     SVGElem::new(Tag::Svg)
+    ///         .append(SVGElem::new(Tag::Rect))
+    ///         .append(SVGElem::new(Tag::Rect)),
+    /// );
+    ///
+    /// let report = document.report();
+    /// assert_eq!(report.nodes, 3);
+    /// assert_eq!(report.tag_counts[&Tag::Rect], 2);
+    /// ```
+    #[inline]
+    pub fn report(&self) -> Report {
+        Report::new(&self.root)
+    }
+
+    /// Lists every reference to a resource outside of this Document, so applications can audit
+    /// or block the remote fetches it would cause
+    ///
+    /// # Note / Arguments
+    /// References are absolute URLs in `href` and `xlink:href` attributes, `url(...)` values of
+    /// other attributes (e.g. `style` and `filter`) and `url(...)` values and `@import` rules
+    /// in stylesheets. Both URLs with a scheme and protocol relative URLs (`//host/...`) are
+    /// listed, except for `data:` URLs.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let document = SVGDoc::new(
+    ///     SVGElem::new(Tag::Svg)
+    ///         .append(SVGElem::new(Tag::Image).set(Attr::Href, "https://example.com/cat.png"))
+    ///         .append(SVGElem::new(Tag::Rect).set(Attr::Fill, "url(#gradient)")),
+    /// );
+    ///
+    /// let references = document.external_references();
+    /// assert_eq!(references.len(), 1);
+    /// assert_eq!(references[0].attribute, Some(Attr::Href));
+    /// assert_eq!(references[0].url, "https://example.com/cat.png");
+    /// ```
+    pub fn external_references(&self) -> Vec<ExternalRef> {
+        let mut references = Vec::new();
+        collect_external_references(&self.root, &ElementPath::new(), &mut references);
+        references
+    }
+
+    /// Creates the [ReferenceGraph](../graph/struct.ReferenceGraph.html) of this Document, which
+    /// records which elements reference which ids
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let document = SVGDoc::new(
+    ///     SVGElem::new(Tag::Svg)
+    ///         .append(SVGElem::new(Tag::Filter).set(Attr::Id, "shadow"))
+    ///         .append(SVGElem::new(Tag::Rect).set(Attr::Filter, "url(#shadow)"))
+    ///         .append(SVGElem::new(Tag::Circle).set(Attr::Fill, "url(#gradient)")),
+    /// );
+    ///
+    /// let graph = document.reference_graph();
+    /// assert_eq!(graph.referencing("shadow").count(), 1);
+    /// assert_eq!(graph.dangling()[0].id, "gradient");
+    /// ```
+    #[inline]
+    pub fn reference_graph(&self) -> ReferenceGraph {
+        ReferenceGraph::new(&self.root)
+    }
+
+    /// Lists the tag, classes and bounding box of every element with an id, which frontends use
+    /// to build hover and interaction layers over the rendered SVG
+    ///
+    /// # Note / Arguments
+    /// Bounding boxes are computed like
+    /// [Element::bounding_box](../struct.Element.html#method.bounding_box) does, and are mapped
+    /// into the user space of the root through the transforms of the element and its ancestors.
+    /// They are None for elements without geometry, for elements which are not rendered in
+    /// place, like the content of `<defs>`, `<symbol>` and `<clipPath>`, and for nested `<svg>`
+    /// elements and their content, which are positioned by their own viewport. When several
+    /// elements have the same id, the first one is listed.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::geometry::BoundingBox;
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let document = SVGDoc::new(
+    ///     SVGElem::new(Tag::Svg)
+    ///         .append(
+    ///             SVGElem::new(Tag::Rect)
+    ///                 .set(Attr::Id, "bar-2024")
+    ///                 .set(Attr::Class, "bar highlighted")
+    ///                 .set(Attr::X, 10)
+    ///                 .set(Attr::Width, 20)
+    ///                 .set(Attr::Height, 50),
+    ///         )
+    ///         .append(SVGElem::new(Tag::Text).set(Attr::Id, "title").set_inner("Sales"))
+    ///         .append(
+    ///             SVGElem::new(Tag::G)
+    ///                 .set(Attr::Id, "trend")
+    ///                 .set(Attr::Transform, "translate(100 0) scale(2)")
+    ///                 .append(SVGElem::new(Tag::Path).set(Attr::Id, "line").set(Attr::D, "M 0 10 L 5 0")),
+    ///         )
+    ///         .append(
+    ///             SVGElem::new(Tag::Defs)
+    ///                 .append(SVGElem::new(Tag::Rect).set(Attr::Id, "hidden").set(Attr::Width, 5)),
+    ///         ),
+    /// );
+    ///
+    /// let metadata = document.metadata_map();
+    /// assert_eq!(metadata["bar-2024"].classes, ["bar", "highlighted"]);
+    /// assert_eq!(metadata["bar-2024"].bbox, Some(BoundingBox::new(10.0, 0.0, 20.0, 50.0)));
+    /// assert_eq!(metadata["title"].tag, Tag::Text);
+    /// assert_eq!(metadata["title"].bbox, None);
+    /// assert_eq!(metadata["line"].bbox, Some(BoundingBox::new(100.0, 0.0, 10.0, 20.0)));
+    /// assert_eq!(metadata["trend"].bbox, metadata["line"].bbox);
+    /// assert_eq!(metadata["hidden"].bbox, None);
+    /// ```
+    pub fn metadata_map(&self) -> BTreeMap<String, ElementMetadata> {
+        let mut metadata = BTreeMap::new();
+        collect_metadata(&self.root, Some(Matrix::IDENTITY), &mut metadata);
+        metadata
+    }
+
+    /// Renames the id `old` to `new`, updating every reference to it
+    ///
+    /// References are `url(#old)` values in attributes, inline styles and stylesheets, `#old`
+    /// values of `href` and `xlink:href` and `#old` selectors in stylesheets. Returns whether an
+    /// element with the id `old` was found.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let mut document = SVGDoc::new(
+    ///     SVGElem::new(Tag::Svg)
+    ///         .append(SVGElem::new(Tag::LinearGradient).set(Attr::Id, "gradient"))
+    ///         .append(SVGElem::new(Tag::Rect).set(Attr::Fill, "url(#gradient)")),
+    /// );
+    ///
+    /// assert!(document.rename_id("gradient", "icon-gradient"));
+    ///
+    /// let rect = &document.get_root().get_children()[1];
+    /// assert_eq!(rect.get_attributes()[&Attr::Fill], "url(#icon-gradient)");
+    /// ```
+    pub fn rename_id(&mut self, old: &str, new: &str) -> bool {
+        let rename = |id: &str| Some(new.to_string()).filter(|_| id == old);
+
+        let found = has_attribute_token(&self.root, &Attribute::Id, old);
+        rewrite_references(&mut self.root, &rename, &|_| None);
+        self.notify(&ElementPath::new(), &ChangeKind::Subtree);
+        found
+    }
+
+    /// Renames the class `old` to `new` in all `class` attributes and stylesheet selectors
+    ///
+    /// Returns whether an element with the class `old` was found.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let mut document = SVGDoc::new(
+    ///     SVGElem::new(Tag::Svg)
+    ///         .append(SVGElem::new(Tag::Style).set_inner(".shape { fill: red }"))
+    ///         .append(SVGElem::new(Tag::Rect).set(Attr::Class, "shape large")),
+    /// );
+    ///
+    /// assert!(document.rename_class("shape", "icon-shape"));
+    ///
+    /// let root = document.get_root();
+    /// assert_eq!(root.get_children()[0].get_inner().as_deref(), Some(".icon-shape { fill: red }"));
+    /// assert_eq!(root.get_children()[1].get_attributes()[&Attr::Class], "icon-shape large");
+    /// ```
+    pub fn rename_class(&mut self, old: &str, new: &str) -> bool {
+        let rename = |class: &str| Some(new.to_string()).filter(|_| class == old);
+
+        let found = has_attribute_token(&self.root, &Attribute::Class, old);
+        rewrite_references(&mut self.root, &|_| None, &rename);
+        self.notify(&ElementPath::new(), &ChangeKind::Subtree);
+        found
+    }
+
+    /// Prefixes every id and class, updating every reference to them
+    ///
+    /// This prevents collisions when multiple SVGs are inlined into the same HTML page. Only
+    /// references to ids defined within this Document are changed, but every class is prefixed
+    /// so stylesheets cannot affect the rest of the page.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let mut document = SVGDoc::new(
+    ///     SVGElem::new(Tag::Svg)
+    ///         .append(SVGElem::new(Tag::Style).set_inner(".shape { stroke: black }"))
+    ///         .append(SVGElem::new(Tag::LinearGradient).set(Attr::Id, "gradient"))
+    ///         .append(
+    ///             SVGElem::new(Tag::Rect)
+    ///                 .set(Attr::Class, "shape")
+    ///                 .set(Attr::Fill, "url(#gradient)"),
+    ///         ),
+    /// );
+    ///
+    /// document.scope_ids("icon-");
+    ///
+    /// let children = document.get_root().get_children();
+    /// assert_eq!(children[0].get_inner().as_deref(), Some(".icon-shape { stroke: black }"));
+    /// assert_eq!(children[1].get_attributes()[&Attr::Id], "icon-gradient");
+    /// assert_eq!(children[2].get_attributes()[&Attr::Class], "icon-shape");
+    /// assert_eq!(children[2].get_attributes()[&Attr::Fill], "url(#icon-gradient)");
+    /// ```
+    pub fn scope_ids(&mut self, prefix: &str) {
+        let mut ids = HashSet::new();
+        collect_ids(&self.root, &mut ids);
+
+        let rename_id = |id: &str| Some(format!("{}{}", prefix, id)).filter(|_| ids.contains(id));
+        let rename_class =
+            |class: &str| Some(format!("{}{}", prefix, class)).filter(|_| !class.is_empty());
+        rewrite_references(&mut self.root, &rename_id, &rename_class);
+        self.notify(&ElementPath::new(), &ChangeKind::Subtree);
+    }
+
+    /// Moves repeated combinations of presentation attributes and inline styles into CSS classes
+    ///
+    /// Every combination which occurs on at least two elements gets a class in a new `<style>`
+    /// element, which is inserted as the first child of the root. The attributes of those
+    /// elements are replaced by a reference to the class. Returns the amount of created classes.
+    ///
+    /// # Note
+    /// Class rules take precedence over presentation attributes, so elements which are also
+    /// targeted by other stylesheet rules might render differently afterwards. Lengths without
+    /// unit, like `font-size="12"`, are invalid in CSS, so they get the unit `px` in the rules.
+    /// Animation elements are left as they are, as their `fill` is a timing attribute.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let mut document = SVGDoc::new(
+    ///     SVGElem::new(Tag::Svg)
+    ///         .append(SVGElem::new(Tag::Rect).set(Attr::Fill, "red").set(Attr::Stroke, "blue"))
+    ///         .append(SVGElem::new(Tag::Circle).set(Attr::Style, "stroke: blue; fill: red"))
+    ///         .append(SVGElem::new(Tag::Text).set(Attr::FontSize, 12).set(Attr::StrokeDasharray, "4,0"))
+    ///         .append(SVGElem::new(Tag::Text).set(Attr::FontSize, "12").set(Attr::StrokeDasharray, "4 0")),
+    /// );
+    ///
+    /// assert_eq!(document.extract_classes(), 2);
+    ///
+    /// let style = &document.get_root().get_children()[0];
+    /// assert_eq!(
+    ///     style.get_inner().as_deref(),
+    ///     Some(".c0{fill:red;stroke:blue} .c1{font-size:12px;stroke-dasharray:4px 0}")
+    /// );
+    /// ```
+    pub fn extract_classes(&mut self) -> usize {
+        let mut occurrences: Vec<(Declarations, usize)> = Vec::new();
+        collect_declarations(&self.root, &mut occurrences);
+
+        let mut used_names = HashSet::new();
+        collect_class_names(&self.root, &mut used_names);
+
+        let mut classes = HashMap::new();
+        let mut rules = Vec::new();
+        let mut index = 0;
+        for (declarations, count) in occurrences {
+            if count < 2 {
+                continue;
+            }
+
+            let name = loop {
+                let name = format!("c{}", index);
+                index += 1;
+                if !used_names.contains(&name) {
+                    break name;
+                }
+            };
+
+            let body = declarations
+                .iter()
+                .map(|(property, value)| format!("{}:{}", property, value))
+                .collect::<Vec<_>>()
+                .join(";");
+            rules.push(format!(".{}{{{}}}", name, body));
+            classes.insert(declarations, name);
+        }
+
+        if classes.is_empty() {
+            return 0;
+        }
+
+        apply_classes(&mut self.root, &classes);
+
+        let mut style = Element::new(TagName::Style);
+        style.inner = Some(rules.join(" "));
+        self.root.children.insert(0, style);
+        self.track_layers(&ChangeKind::ChildInserted(0));
+
+        self.notify(&ElementPath::new(), &ChangeKind::Subtree);
+        classes.len()
+    }
+}
+
+impl Clone for Document {
+    /// Clones the tree, the processing instructions and the layers, without the observers
+    fn clone(&self) -> Self {
+        let mut document = Document::new(self.root.clone());
+        document.processing_instructions = self.processing_instructions.clone();
+        document.layers = self.layers.clone();
+        document
+    }
+}
+
+impl fmt::Debug for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Document")
+            .field("root", &self.root)
+            .field("processing_instructions", &self.processing_instructions)
+            .field("observers", &self.observers.len())
+            .field("layers", &self.layers)
+            .finish()
+    }
+}
+
+impl fmt::Display for Document {
+    /// Serializes the processing instructions, each on its own line, followed by the root
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for instruction in self.processing_instructions.iter() {
+            writeln!(f, "{}", instruction)?;
+        }
+        write!(f, "{}", self.root)
+    }
+}
+
+impl From<Element> for Document {
+    fn from(root: Element) -> Document {
+        Document::new(root)
+    }
+}
+
+/// A problem found when finalizing a [DocumentBuilder](struct.DocumentBuilder.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// Several elements have the same id
+    DuplicateId(String),
+
+    /// An id is referenced, but no element has it
+    MissingReference(String),
+
+    /// The built tree exceeds the [Limits](../limits/struct.Limits.html) of the builder
+    LimitExceeded(LimitError),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::DuplicateId(id) => write!(f, "the id {:?} is used more than once", id),
+            BuildError::MissingReference(id) => {
+                write!(f, "the id {:?} is referenced but does not exist", id)
+            }
+            BuildError::LimitExceeded(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// DocumentBuilder assembles a [Document](struct.Document.html) from separate sections, which
+/// can be filled in any order
+///
+/// # Note / Arguments
+/// The built root is an `<svg>` element with a `<style>` element containing all styles, then a
+/// `<defs>` element containing all definitions, and then the content. Empty sections are left
+/// out.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::document::DocumentBuilder;
+/// use svg_definitions::prelude::*;
+///
+/// let document = DocumentBuilder::new()
+///     .set(Attr::ViewBox, "0 0 10 10")
+///     .content(SVGElem::new(Tag::Rect).set(Attr::Fill, "url(#gradient)"))
+///     .defs(SVGElem::new(Tag::LinearGradient).set(Attr::Id, "gradient"))
+///     .styles("rect { stroke: black; }")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(
+///     document.get_root().to_string(),
+///     "<svg viewBox=\"0 0 10 10\"><style>rect { stroke: black; }</style>\
+///      <defs><linearGradient id=\"gradient\"/></defs><rect fill=\"url(#gradient)\"/></svg>"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct DocumentBuilder {
+    root: Element,
+    styles: Vec<String>,
+    defs: Vec<Element>,
+    content: Vec<Element>,
+    limits: Limits,
+    /// The amount of elements in the definitions and the content
+    nodes: usize,
+}
+
+impl DocumentBuilder {
+    /// Creates a new builder with an empty `<svg>` root
+    pub fn new() -> DocumentBuilder {
+        DocumentBuilder {
+            root: Element::new(TagName::Svg),
+            styles: Vec::new(),
+            defs: Vec::new(),
+            content: Vec::new(),
+            limits: Limits::default(),
+            nodes: 0,
+        }
+    }
+
+    /// Sets an attribute of the `<svg>` root
+    pub fn set<T>(mut self, attribute: Attribute, value: T) -> Self
+    where
+        T: ToString,
+    {
+        self.root = self.root.set(attribute, value);
+        self
+    }
+
+    /// Adds a definition, e.g. a gradient, to the `<defs>` section
+    pub fn defs(mut self, element: Element) -> Self {
+        self.nodes += count_nodes(&element);
+        self.defs.push(element);
+        self
+    }
+
+    /// Adds a definition like [defs](#method.defs), but gives back the builder in an error
+    /// if the tree built so far would exceed the [limits](#method.limits)
+    pub fn try_defs(self, element: Element) -> Result<Self, Rejected<Self, LimitError>> {
+        // The definition is in the <defs> section, which is a child of the root
+        match self.check_section(&element, 3, self.defs.len()) {
+            Ok(()) => Ok(self.defs(element)),
+            Err(error) => Err(Rejected::new(self, error)),
+        }
+    }
+
+    /// Adds a stylesheet to the `<style>` section
+    pub fn styles(mut self, css: &str) -> Self {
+        self.styles.push(css.trim().to_string());
+        self
+    }
+
+    /// Adds an element to the content which is drawn
+    pub fn content(mut self, element: Element) -> Self {
+        self.nodes += count_nodes(&element);
+        self.content.push(element);
+        self
+    }
+
+    /// Adds an element to the content like [content](#method.content), but gives back the builder
+    /// in an error if the tree built so far would exceed the [limits](#method.limits)
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::document::DocumentBuilder;
+    /// use svg_definitions::limits::{LimitError, Limits};
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let result = (0..1000).try_fold(
+    ///     DocumentBuilder::new().limits(Limits { max_nodes: Some(100), ..Limits::default() }),
+    ///     |builder, _| builder.try_content(SVGElem::new(Tag::Circle)),
+    /// );
+    ///
+    /// let (builder, error) = result.unwrap_err().into_parts();
+    /// assert_eq!(error, LimitError::TooManyNodes { limit: 100 });
+    /// assert_eq!(builder.build().unwrap().get_root().get_children().len(), 99);
+    /// ```
+    pub fn try_content(self, element: Element) -> Result<Self, Rejected<Self, LimitError>> {
+        let siblings = self.content.len()
+            + usize::from(!self.styles.is_empty())
+            + usize::from(!self.defs.is_empty());
+        match self.check_section(&element, 2, siblings) {
+            Ok(()) => Ok(self.content(element)),
+            Err(error) => Err(Rejected::new(self, error)),
+        }
+    }
+
+    /// Checks whether an element can be added to a section at a depth which already has an
+    /// amount of children
+    fn check_section(
+        &self,
+        element: &Element,
+        depth: usize,
+        siblings: usize,
+    ) -> Result<(), LimitError> {
+        if let Some(limit) = self.limits.max_children.filter(|limit| siblings >= *limit) {
+            return Err(LimitError::TooManyChildren { limit });
+        }
+
+        // The root and the <style> and <defs> sections, including the <defs> section which a
+        // first definition adds
+        let sections = 1
+            + usize::from(!self.styles.is_empty())
+            + usize::from(!self.defs.is_empty() || depth == 3);
+        self.limits
+            .check_subtree(element, depth, &mut (sections + self.nodes))
+    }
+
+    /// Sets the [Limits](../limits/struct.Limits.html) which the built tree has to stay within,
+    /// for documents generated from untrusted data
+    ///
+    /// # Note / Arguments
+    /// The limits are checked when building, and when adding elements with
+    /// [try_content](#method.try_content) and [try_defs](#method.try_defs), which stop before
+    /// the tree grows beyond them.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::document::{BuildError, DocumentBuilder};
+    /// use svg_definitions::limits::{LimitError, Limits};
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let errors = DocumentBuilder::new()
+    ///     .limits(Limits { max_nodes: Some(2), ..Limits::default() })
+    ///     .content(SVGElem::new(Tag::Rect))
+    ///     .content(SVGElem::new(Tag::Rect))
+    ///     .build()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(errors, vec![BuildError::LimitExceeded(LimitError::TooManyNodes { limit: 2 })]);
+    /// ```
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Assembles the Document, returning every problem if ids are duplicated, references do
+    /// not resolve or the tree exceeds the [limits](#method.limits)
+    ///
+    /// # Note / Arguments
+    /// References are `url(#id)` values and `#id` values of `href` and `xlink:href`.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::document::{BuildError, DocumentBuilder};
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let errors = DocumentBuilder::new()
+    ///     .defs(SVGElem::new(Tag::Circle).set(Attr::Id, "dot"))
+    ///     .content(SVGElem::new(Tag::Rect).set(Attr::Id, "dot").set(Attr::Mask, "url(#mask)"))
+    ///     .build()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     errors,
+    ///     vec![
+    ///         BuildError::DuplicateId(String::from("dot")),
+    ///         BuildError::MissingReference(String::from("mask")),
+    ///     ]
+    /// );
+    /// ```
+    pub fn build(self) -> Result<Document, Vec<BuildError>> {
+        let mut root = self.root;
+        if !self.styles.is_empty() {
+            let mut style = Element::new(TagName::Style);
+            style.inner = Some(self.styles.join("\n"));
+            root.children.push(style);
+        }
+        if !self.defs.is_empty() {
+            let mut defs = Element::new(TagName::Defs);
+            defs.children = self.defs;
+            root.children.push(defs);
+        }
+        root.children.extend(self.content);
+
+        let mut errors = Vec::new();
+        if let Err(error) = self.limits.check(&root) {
+            errors.push(BuildError::LimitExceeded(error));
+        }
+
+        let mut ids = HashSet::new();
+        let mut references = Vec::new();
+        collect_ids_and_references(&root, &mut ids, &mut references, &mut errors);
+
+        references.retain(|id| !ids.contains(id));
+        references.sort();
+        references.dedup();
+        errors.extend(references.into_iter().map(BuildError::MissingReference));
+
+        if errors.is_empty() {
+            Ok(Document::new(root))
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl Default for DocumentBuilder {
+    fn default() -> Self {
+        DocumentBuilder::new()
+    }
+}
+
+/// Returns the sorted style declarations of an element, or None if the element has none or
+/// its style attribute cannot be split into declarations
+fn declarations(element: &Element) -> Option<Declarations> {
+    // On animation elements `fill` is a timing attribute, like `fill="freeze"`
+    if let TagName::Animate
+    | TagName::AnimateMotion
+    | TagName::AnimateTransform
+    | TagName::Discard
+    | TagName::Set = element.tag_name
+    {
+        return None;
+    }
+
+    let mut declarations = BTreeMap::new();
+
+    for (attribute, value) in element.attributes.iter() {
+        if attribute.is_presentation() {
+            declarations.insert(attribute.to_string(), css_value(attribute, value.trim()));
+        }
+    }
+
+    // Inline styles take precedence over presentation attributes
+    for (property, value) in element.get_style().iter() {
+        declarations.insert(property.to_string(), value.to_string());
+    }
+
+    if declarations.is_empty() {
+        return None;
+    }
+
+    Some(declarations.into_iter().collect())
+}
+
+/// Returns the CSS value of a presentation attribute, where lengths without unit, which CSS does
+/// not accept, are given the unit `px`
+fn css_value(attribute: &Attribute, value: &str) -> String {
+    let is_length = matches!(
+        attribute,
+        Attribute::FontSize
+            | Attribute::StrokeWidth
+            | Attribute::StrokeDasharray
+            | Attribute::StrokeDashoffset
+            | Attribute::LetterSpacing
+            | Attribute::WordSpacing
+            | Attribute::Kerning
+            | Attribute::BaselineShift
+    );
+    if !is_length {
+        return value.to_string();
+    }
+
+    value
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|part| !part.is_empty())
+        .map(|part| match part.parse::<f64>() {
+            Ok(number) if number != 0.0 => format!("{}px", part),
+            _ => part.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn collect_declarations(element: &Element, occurrences: &mut Vec<(Declarations, usize)>) {
+    if let Some(declarations) = declarations(element) {
+        match occurrences
+            .iter_mut()
+            .find(|(decls, _)| *decls == declarations)
+        {
+            Some((_, count)) => *count += 1,
+            None => occurrences.push((declarations, 1)),
+        }
+    }
+
+    for child in element.children.iter() {
+        collect_declarations(child, occurrences);
+    }
+}
+
+fn collect_external_references(
+    element: &Element,
+    path: &ElementPath,
+    references: &mut Vec<ExternalRef>,
+) {
+    let mut attributes: Vec<_> = element.attributes.iter().collect();
+    attributes.sort_by_key(|(attribute, _)| attribute.to_string());
+
+    for (attribute, value) in attributes {
+        let urls = match attribute {
+            Attribute::Href | Attribute::XlinkHref => vec![value.trim()],
+            _ => url_targets(value),
+        };
+
+        references.extend(
+            urls.into_iter()
+                .filter(|url| is_external(url))
+                .map(|url| ExternalRef {
+                    path: path.clone(),
+                    attribute: Some(attribute.clone()),
+                    url: url.to_string(),
+                }),
+        );
+    }
+
+    if element.tag_name == TagName::Style {
+        if let Some(css) = &element.inner {
+            references.extend(
+                url_targets(css)
+                    .into_iter()
+                    .filter(|url| is_external(url))
+                    .map(|url| ExternalRef {
+                        path: path.clone(),
+                        attribute: None,
+                        url: url.to_string(),
+                    }),
+            );
+        }
+    }
+
+    for (index, child) in element.children.iter().enumerate() {
+        collect_external_references(child, &path.child(index), references);
+    }
+}
+
+/// Collects the ids and the local references of an element and its descendants, adding an error
+/// for every duplicated id
+fn collect_ids_and_references(
+    element: &Element,
+    ids: &mut HashSet<String>,
+    references: &mut Vec<String>,
+    errors: &mut Vec<BuildError>,
+) {
+    for (attribute, value) in element.attributes.iter() {
+        match attribute {
+            Attribute::Id => {
+                let id = value.trim().to_string();
+                if ids.contains(&id) {
+                    errors.push(BuildError::DuplicateId(id));
+                } else {
+                    ids.insert(id);
+                }
+            }
+            Attribute::Href | Attribute::XlinkHref => {
+                if let Some(id) = value.trim().strip_prefix('#') {
+                    references.push(id.to_string());
+                }
+            }
+            _ => references.extend(
+                url_targets(value)
+                    .into_iter()
+                    .filter_map(|url| url.strip_prefix('#'))
+                    .map(String::from),
+            ),
+        }
+    }
+
+    for child in element.children.iter() {
+        collect_ids_and_references(child, ids, references, errors);
+    }
+}
+
+/// Returns whether an element or one of its descendants has `token` in the value of `attribute`
+fn has_attribute_token(element: &Element, attribute: &Attribute, token: &str) -> bool {
+    let found = element
+        .attributes
+        .get(attribute)
+        .is_some_and(|value| value.split_whitespace().any(|t| t == token));
+
+    found
+        || element
+            .children
+            .iter()
+            .any(|child| has_attribute_token(child, attribute, token))
+}
+
+/// Adds the metadata of an element and its descendants, where `matrix` maps the user space of
+/// the element to that of the root, or is None if the element is not rendered there
+fn collect_metadata(
+    element: &Element,
+    matrix: Option<Matrix>,
+    metadata: &mut BTreeMap<String, ElementMetadata>,
+) {
+    // The content of these elements is only rendered where it is referenced
+    let matrix = matrix.filter(|_| {
+        !matches!(
+            element.tag_name,
+            TagName::Defs
+                | TagName::Symbol
+                | TagName::ClipPath
+                | TagName::Mask
+                | TagName::Pattern
+                | TagName::Marker
+        )
+    });
+
+    if let Some(id) = element.attributes.get(&Attribute::Id) {
+        metadata
+            .entry(id.trim().to_string())
+            .or_insert_with(|| ElementMetadata {
+                tag: element.tag_name.clone(),
+                classes: element
+                    .attributes
+                    .get(&Attribute::Class)
+                    .map(|class| class.split_whitespace().map(String::from).collect())
+                    .unwrap_or_default(),
+                bbox: matrix.and_then(|matrix| geometry::transformed_bounding_box(element, matrix)),
+            });
+    }
+
+    for child in element.children.iter() {
+        // The content of a nested <svg> is positioned and scaled by its own viewport
+        let matrix = matrix
+            .filter(|_| child.tag_name != TagName::Svg)
+            .map(|matrix| match child.get_transform() {
+                Some(transforms) => matrix * transforms.to_matrix(),
+                None => matrix,
+            });
+        collect_metadata(child, matrix, metadata);
+    }
+}
+
+pub(crate) fn count_nodes(element: &Element) -> usize {
+    1 + element.children.iter().map(count_nodes).sum::<usize>()
+}
+
+fn collect_ids(element: &Element, ids: &mut HashSet<String>) {
+    if let Some(id) = element.attributes.get(&Attribute::Id) {
+        ids.insert(id.trim().to_string());
+    }
+
+    for child in element.children.iter() {
+        collect_ids(child, ids);
+    }
+}
+
+fn collect_class_names(element: &Element, names: &mut HashSet<String>) {
+    if let Some(class) = element.attributes.get(&Attribute::Class) {
+        names.extend(class.split_whitespace().map(String::from));
+    }
+
+    for child in element.children.iter() {
+        collect_class_names(child, names);
+    }
+}
+
+fn apply_classes(element: &mut Element, classes: &HashMap<Declarations, String>) {
+    if let Some(name) = declarations(element).and_then(|decls| classes.get(&decls)) {
+        element
+            .attributes
+            .retain(|attribute, _| !attribute.is_presentation() && *attribute != Attribute::Style);
+
+        let class = match element.attributes.get(&Attribute::Class) {
+            Some(class) if !class.trim().is_empty() => format!("{} {}", class.trim(), name),
+            _ => name.clone(),
+        };
+        element.set_attribute(Attribute::Class, class);
+    }
+
+    for child in element.children.iter_mut() {
+        apply_classes(child, classes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Document;
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::tree::ElementPath;
+    use crate::Element;
+
+    #[test]
+    fn test_extract_classes_animations() {
+        let animated = || {
+            Element::new(TagName::Rect)
+                .set(Attribute::Fill, "red")
+                .append(
+                    Element::new(TagName::Animate)
+                        .set(Attribute::AttributeName, "x")
+                        .set(Attribute::To, 10)
+                        .set(Attribute::Dur, "1s")
+                        .set(Attribute::Fill, "freeze"),
+                )
+        };
+        let mut document = Document::new(
+            Element::new(TagName::Svg)
+                .append(animated())
+                .append(animated()),
+        );
+
+        // The animations keep their timing, only the rectangles get a class
+        assert_eq!(document.extract_classes(), 1);
+        let children = document.get_root().get_children();
+        assert_eq!(children[0].get_inner().as_deref(), Some(".c0{fill:red}"));
+        assert_eq!(
+            children[1].to_string(),
+            "<rect class=\"c0\"><animate attributeName=\"x\" dur=\"1s\" fill=\"freeze\" \
+             to=\"10\"/></rect>"
+        );
+    }
+
+    #[test]
+    fn test_layers() {
+        let mut document =
+            Document::new(Element::new(TagName::Svg).append(Element::new(TagName::Defs)));
+        document.add_to_layer("labels", 2, Element::new(TagName::Text));
+        document.add_to_layer("grid", 0, Element::new(TagName::Line));
+        document.add_to_layer("data", 1, Element::new(TagName::Path));
+        document.add_to_layer("overlay", 2, Element::new(TagName::Rect));
+
+        // The ordering is not written into the output
+        assert_eq!(
+            document.to_string(),
+            "<svg><defs/><g><line/></g><g><path/></g><g><text/></g><g><rect/></g></svg>"
+        );
+
+        // Layers are followed through changes to the children of the root
+        let root = ElementPath::new();
+        assert!(document.insert_child(&root, 0, Element::new(TagName::Style)));
+        document.remove_child(&root, 1);
+        document.add_to_layer("data", 1, Element::new(TagName::Circle));
+        assert_eq!(
+            document.to_string(),
+            "<svg><style/><g><line/></g><g><path/><circle/></g><g><text/></g><g><rect/></g></svg>"
+        );
+
+        // A layer which moves is placed after the layers with the same z
+        document.layer("grid", 2);
+        assert_eq!(
+            document.to_string(),
+            "<svg><style/><g><path/><circle/></g><g><text/></g><g><rect/></g><g><line/></g></svg>"
+        );
+
+        // A removed layer is created again
+        document.remove_child(&root, 1);
+        document.add_to_layer("data", 3, Element::new(TagName::Ellipse));
+        assert_eq!(
+            document.get_root().get_children()[4].to_string(),
+            "<g><ellipse/></g>"
+        );
+
+        // Layers which are moved away through get_root_mut are forgotten
+        document.get_root_mut().children.clear();
+        document
+            .layer("grid", 0)
+            .set_attribute(Attribute::Id, "grid");
+        assert_eq!(document.to_string(), "<svg><g id=\"grid\"/></svg>");
+
+        let mut clone = document.clone();
+        clone.add_to_layer("grid", 0, Element::new(TagName::Line));
+        assert_eq!(clone.get_root().get_children().len(), 1);
+    }
+}
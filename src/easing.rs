@@ -0,0 +1,452 @@
+//! This module provides easing functions, i.e. the pacing of an animation, which can be applied
+//! to SMIL animation elements and written as CSS timing functions.
+//!
+//! # Examples
+//! ## An overshooting pop in
+//! ```
+//! use svg_definitions::easing::Easing;
+//! use svg_definitions::prelude::*;
+//!
+//! let easing: Easing = "ease-out-back".parse().unwrap();
+//! let animation = easing.apply(
+//!     SVGElem::new(Tag::Animate)
+//!         .set(Attr::AttributeName, "r")
+//!         .set(Attr::From, 0)
+//!         .set(Attr::To, 10)
+//!         .set(Attr::Dur, "0.5s"),
+//! );
+//! # assert_eq!(animation.get_attributes()[&Attr::CalcMode], "linear");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::attributes::Attribute;
+use crate::Element;
+
+/// The number of points per segment an easing is sampled at, when it cannot be written as a
+/// keySpline
+const SAMPLES: usize = 30;
+
+/// The pacing of an animation, mapping the fraction of time passed to the fraction of the
+/// change made
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    /// A constant speed
+    Linear,
+
+    /// A cubic Bézier curve from (0, 0) to (1, 1) with two control points, as the CSS
+    /// `cubic-bezier(x1, y1, x2, y2)` function
+    CubicBezier(f64, f64, f64, f64),
+
+    /// A damped spring with a mass of 1, which settles at the end of the animation
+    Spring {
+        /// The stiffness of the spring, where a stiffer spring oscillates faster
+        stiffness: f64,
+
+        /// The damping of the spring, where a spring oscillates less with more damping
+        damping: f64,
+    },
+}
+
+impl Easing {
+    /// Starts slowly, speeds up quickly and ends slowly, as the CSS `ease` keyword
+    pub const EASE: Easing = Easing::CubicBezier(0.25, 0.1, 0.25, 1.0);
+
+    /// Starts slowly, as the CSS `ease-in` keyword
+    pub const EASE_IN: Easing = Easing::CubicBezier(0.42, 0.0, 1.0, 1.0);
+
+    /// Ends slowly, as the CSS `ease-out` keyword
+    pub const EASE_OUT: Easing = Easing::CubicBezier(0.0, 0.0, 0.58, 1.0);
+
+    /// Starts and ends slowly, as the CSS `ease-in-out` keyword
+    pub const EASE_IN_OUT: Easing = Easing::CubicBezier(0.42, 0.0, 0.58, 1.0);
+
+    /// Moves backwards a bit before starting
+    pub const EASE_IN_BACK: Easing = Easing::CubicBezier(0.36, 0.0, 0.66, -0.56);
+
+    /// Overshoots the end a bit before settling
+    pub const EASE_OUT_BACK: Easing = Easing::CubicBezier(0.34, 1.56, 0.64, 1.0);
+
+    /// Moves backwards a bit before starting and overshoots the end a bit before settling
+    pub const EASE_IN_OUT_BACK: Easing = Easing::CubicBezier(0.68, -0.6, 0.32, 1.6);
+
+    /// Returns a spring with a stiffness of 100 and a damping of 10, which overshoots a few times
+    pub fn spring() -> Easing {
+        Easing::Spring {
+            stiffness: 100.0,
+            damping: 10.0,
+        }
+    }
+
+    /// Returns the fraction of the change made at a fraction of the time from 0 to 1
+    ///
+    /// # Note / Arguments
+    /// The fraction of the change can lie outside of 0 to 1 for easings which overshoot.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::easing::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.at(0.25), 0.25);
+    /// assert!(Easing::EASE_OUT_BACK.at(0.75) > 1.0);
+    /// assert_eq!(Easing::spring().at(1.0), 1.0);
+    /// ```
+    pub fn at(&self, time: f64) -> f64 {
+        let time = time.clamp(0.0, 1.0);
+        match *self {
+            Easing::Linear => time,
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier((x1, y1), (x2, y2), time),
+            Easing::Spring { .. } if time >= 1.0 => 1.0,
+            Easing::Spring { stiffness, damping } => spring(stiffness, damping, time),
+        }
+    }
+
+    /// Returns whether the easing can be written as a keySpline, whose control points have to lie
+    /// within the unit square
+    fn is_key_spline(&self) -> bool {
+        match *self {
+            Easing::CubicBezier(x1, y1, x2, y2) => [x1, y1, x2, y2]
+                .iter()
+                .all(|number| (0.0..=1.0).contains(number)),
+            _ => false,
+        }
+    }
+
+    /// Applies the easing to every segment between the values of an `<animate>`,
+    /// `<animateTransform>` or `<animateMotion>` element
+    ///
+    /// # Note / Arguments
+    /// A `from` and `to` are turned into `values`, and `keyTimes` are kept or spaced evenly.
+    /// Bézier curves within the unit square set `calcMode="spline"` with a `keySplines` entry
+    /// per segment. Other easings set `calcMode="linear"`, where easings which overshoot are
+    /// sampled into extra `values` and `keyTimes`, since the control points of `keySplines`
+    /// have to lie within the unit square. Animations without `values` or `from` and `to` are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::easing::Easing;
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let animation = Easing::EASE_IN_OUT.apply(
+    ///     SVGElem::new(Tag::Animate)
+    ///         .set(Attr::AttributeName, "x")
+    ///         .set(Attr::Values, "0; 10; 0")
+    ///         .set(Attr::Dur, "2s"),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     animation.to_string(),
+    ///     "<animate attributeName=\"x\" calcMode=\"spline\" dur=\"2s\" keySplines=\"0.42 0 0.58 \
+    ///      1; 0.42 0 0.58 1\" keyTimes=\"0; 0.5; 1\" values=\"0; 10; 0\"/>"
+    /// );
+    /// ```
+    pub fn apply(&self, mut animation: Element) -> Element {
+        let get = |attribute: Attribute| {
+            animation
+                .attributes
+                .get(&attribute)
+                .map(|value| value.trim().to_string())
+        };
+
+        let values: Vec<String> = match (
+            get(Attribute::Values),
+            get(Attribute::From),
+            get(Attribute::To),
+        ) {
+            (Some(values), _, _) => values
+                .split(';')
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .collect(),
+            (None, Some(from), Some(to)) => vec![from, to],
+            _ => return animation,
+        };
+        if values.len() < 2 {
+            return animation;
+        }
+
+        let key_times: Vec<f64> = get(Attribute::KeyTimes)
+            .and_then(|key_times| {
+                key_times
+                    .split(';')
+                    .map(|time| time.trim().parse().ok())
+                    .collect::<Option<Vec<f64>>>()
+            })
+            .filter(|key_times| key_times.len() == values.len())
+            .unwrap_or_else(|| {
+                (0..values.len())
+                    .map(|index| index as f64 / (values.len() - 1) as f64)
+                    .collect()
+            });
+
+        for attribute in &[
+            Attribute::From,
+            Attribute::To,
+            Attribute::By,
+            Attribute::KeySplines,
+        ] {
+            animation.attributes.remove(attribute);
+        }
+
+        let (values, key_times) = match *self {
+            Easing::Linear => {
+                animation = animation.set(Attribute::CalcMode, "linear");
+                (values, key_times)
+            }
+            Easing::CubicBezier(x1, y1, x2, y2) if self.is_key_spline() => {
+                let spline = format!("{} {} {} {}", x1, y1, x2, y2);
+                animation = animation.set(Attribute::CalcMode, "spline").set(
+                    Attribute::KeySplines,
+                    vec![spline; values.len() - 1].join("; "),
+                );
+                (values, key_times)
+            }
+            _ => {
+                let mut sampled_values = vec![values[0].clone()];
+                let mut sampled_times = vec![key_times[0]];
+                for (segment, times) in key_times.windows(2).enumerate() {
+                    for sample in 1..=SAMPLES {
+                        let time = sample as f64 / SAMPLES as f64;
+                        sampled_values.push(crate::animation::interpolate(
+                            &values[segment],
+                            &values[segment + 1],
+                            self.at(time),
+                        ));
+                        sampled_times.push(times[0] + (times[1] - times[0]) * time);
+                    }
+                }
+                animation = animation.set(Attribute::CalcMode, "linear");
+                (sampled_values, sampled_times)
+            }
+        };
+
+        let key_times: Vec<String> = key_times
+            .iter()
+            .map(|time| ((time * 1e4).round() / 1e4).to_string())
+            .collect();
+        animation
+            .set(Attribute::Values, values.join("; "))
+            .set(Attribute::KeyTimes, key_times.join("; "))
+    }
+
+    /// Returns the easing as a CSS timing function
+    ///
+    /// # Note / Arguments
+    /// Springs are sampled into a `linear()` function.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::easing::Easing;
+    ///
+    /// assert_eq!(Easing::EASE_OUT_BACK.to_css(), "cubic-bezier(0.34, 1.56, 0.64, 1)");
+    /// assert!(Easing::spring().to_css().starts_with("linear(0, "));
+    /// ```
+    pub fn to_css(&self) -> String {
+        match *self {
+            Easing::Linear => String::from("linear"),
+            Easing::CubicBezier(x1, y1, x2, y2) => {
+                format!("cubic-bezier({}, {}, {}, {})", x1, y1, x2, y2)
+            }
+            Easing::Spring { .. } => {
+                let points: Vec<String> = (0..=SAMPLES)
+                    .map(|sample| {
+                        let value = self.at(sample as f64 / SAMPLES as f64);
+                        ((value * 1e4).round() / 1e4).to_string()
+                    })
+                    .collect();
+                format!("linear({})", points.join(", "))
+            }
+        }
+    }
+}
+
+/// The error returned when parsing an unknown easing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownEasing(pub String);
+
+impl fmt::Display for UnknownEasing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown easing {:?}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownEasing {}
+
+impl FromStr for Easing {
+    type Err = UnknownEasing;
+
+    /// Parses the name of a preset, e.g. `ease-out-back`, `cubic-bezier(x1, y1, x2, y2)`,
+    /// `spring` or `spring(stiffness, damping)`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || UnknownEasing(String::from(s));
+        let arguments = |name: &str, count: usize| -> Option<Vec<f64>> {
+            let arguments = s
+                .trim()
+                .strip_prefix(name)?
+                .trim_start()
+                .strip_prefix('(')?
+                .strip_suffix(')')?
+                .split(',')
+                .map(|number| number.trim().parse().ok())
+                .collect::<Option<Vec<f64>>>()?;
+            Some(arguments).filter(|arguments| arguments.len() == count)
+        };
+
+        Ok(match s.trim() {
+            "linear" => Easing::Linear,
+            "ease" => Easing::EASE,
+            "ease-in" => Easing::EASE_IN,
+            "ease-out" => Easing::EASE_OUT,
+            "ease-in-out" => Easing::EASE_IN_OUT,
+            "ease-in-back" => Easing::EASE_IN_BACK,
+            "ease-out-back" => Easing::EASE_OUT_BACK,
+            "ease-in-out-back" => Easing::EASE_IN_OUT_BACK,
+            "spring" => Easing::spring(),
+            _ => {
+                if let Some(arguments) = arguments("cubic-bezier", 4) {
+                    if !(0.0..=1.0).contains(&arguments[0]) || !(0.0..=1.0).contains(&arguments[2])
+                    {
+                        return Err(unknown());
+                    }
+                    Easing::CubicBezier(arguments[0], arguments[1], arguments[2], arguments[3])
+                } else if let Some(arguments) = arguments("spring", 2) {
+                    if arguments[0] <= 0.0 || arguments[1] <= 0.0 {
+                        return Err(unknown());
+                    }
+                    Easing::Spring {
+                        stiffness: arguments[0],
+                        damping: arguments[1],
+                    }
+                } else {
+                    return Err(unknown());
+                }
+            }
+        })
+    }
+}
+
+/// Returns the y coordinate of a cubic Bézier easing curve from (0, 0) to (1, 1) at an x
+/// coordinate
+pub(crate) fn cubic_bezier((x1, y1): (f64, f64), (x2, y2): (f64, f64), x: f64) -> f64 {
+    let bezier = |p1: f64, p2: f64, t: f64| {
+        3.0 * (1.0 - t) * (1.0 - t) * t * p1 + 3.0 * (1.0 - t) * t * t * p2 + t * t * t
+    };
+
+    // The x coordinate increases with t, so t can be found by bisection
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..50 {
+        let t = (low + high) / 2.0;
+        if bezier(x1, x2, t) < x {
+            low = t;
+        } else {
+            high = t;
+        }
+    }
+    bezier(y1, y2, (low + high) / 2.0)
+}
+
+/// Returns the position of a damped spring moving from 0 to 1 at a fraction of the time it
+/// takes to settle within 0.1% of 1
+fn spring(stiffness: f64, damping: f64, time: f64) -> f64 {
+    let frequency = stiffness.max(f64::EPSILON).sqrt();
+    let ratio = damping.max(0.0) / (2.0 * frequency);
+
+    if ratio < 1.0 {
+        let decay = ratio * frequency;
+        let damped_frequency = frequency * (1.0 - ratio * ratio).sqrt();
+        let time = time * 1000_f64.ln() / decay.max(f64::EPSILON);
+        1.0 - (-decay * time).exp()
+            * ((damped_frequency * time).cos()
+                + decay / damped_frequency * (damped_frequency * time).sin())
+    } else if ratio == 1.0 {
+        let time = time * 9.23 / frequency;
+        1.0 - (-frequency * time).exp() * (1.0 + frequency * time)
+    } else {
+        let root = (ratio * ratio - 1.0).sqrt();
+        let (slow, fast) = (-frequency * (ratio - root), -frequency * (ratio + root));
+        let time = time * 1000_f64.ln() / -slow;
+        1.0 - (fast * (slow * time).exp() - slow * (fast * time).exp()) / (fast - slow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Easing;
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    #[test]
+    fn test_easing() {
+        assert_eq!("ease-out".parse(), Ok(Easing::EASE_OUT));
+        assert_eq!(
+            "cubic-bezier(0.1, 0.7, 1.0, 0.1)".parse(),
+            Ok(Easing::CubicBezier(0.1, 0.7, 1.0, 0.1))
+        );
+        assert_eq!(
+            "spring(200, 5)".parse(),
+            Ok(Easing::Spring {
+                stiffness: 200.0,
+                damping: 5.0
+            })
+        );
+        assert!("cubic-bezier(2, 0, 0, 1)".parse::<Easing>().is_err());
+        assert!("bounce".parse::<Easing>().is_err());
+
+        for easing in &[
+            Easing::EASE,
+            Easing::EASE_IN_OUT_BACK,
+            Easing::spring(),
+            Easing::Spring {
+                stiffness: 100.0,
+                damping: 20.0,
+            },
+            Easing::Spring {
+                stiffness: 100.0,
+                damping: 50.0,
+            },
+        ] {
+            assert!(easing.at(0.0).abs() < 1e-6);
+            assert!((easing.at(1.0) - 1.0).abs() < 1e-3);
+        }
+        assert!(Easing::spring().at(0.2) > 1.0);
+        let overdamped = Easing::Spring {
+            stiffness: 100.0,
+            damping: 50.0,
+        };
+        assert!((0..10).all(|step| overdamped.at(step as f64 / 10.0) <= 1.0));
+    }
+
+    #[test]
+    fn test_apply() {
+        let animation = Element::new(TagName::Animate)
+            .set(Attribute::AttributeName, "x")
+            .set(Attribute::From, 0)
+            .set(Attribute::To, 100)
+            .set(Attribute::CalcMode, "discrete");
+
+        let linear = Easing::Linear.apply(animation.clone());
+        assert_eq!(
+            linear.to_string(),
+            "<animate attributeName=\"x\" calcMode=\"linear\" keyTimes=\"0; 1\" values=\"0; 100\"/>"
+        );
+
+        let sampled = Easing::EASE_OUT_BACK.apply(animation.clone());
+        let attributes = sampled.get_attributes();
+        let values: Vec<f64> = attributes[&Attribute::Values]
+            .split("; ")
+            .map(|value| value.parse().unwrap())
+            .collect();
+        assert_eq!(values.len(), 31);
+        assert_eq!(attributes[&Attribute::KeyTimes].split("; ").count(), 31);
+        assert!(values.iter().any(|value| *value > 100.0));
+        assert_eq!(values[30], 100.0);
+        assert!(!attributes.contains_key(&Attribute::From));
+        assert_eq!(attributes[&Attribute::CalcMode], "linear");
+
+        let unchanged = Easing::EASE.apply(Element::new(TagName::Set).set(Attribute::To, 1));
+        assert_eq!(unchanged.to_string(), "<set to=\"1\"/>");
+    }
+}
@@ -0,0 +1,78 @@
+//! Escaping of text and attribute values for serialization to XML
+
+use std::borrow::Cow;
+
+/// Returns whether a character can appear in an XML 1.0 document, even when escaped
+pub(crate) fn is_xml_char(character: char) -> bool {
+    !matches!(character, '\u{0}'..='\u{8}' | '\u{b}' | '\u{c}' | '\u{e}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}')
+}
+
+/// Escapes text content, replacing characters which cannot appear in XML with U+FFFD
+pub(crate) fn escape_text(text: &str) -> Cow<'_, str> {
+    escape(text, |character| match character {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        _ => None,
+    })
+}
+
+/// Escapes a double quoted attribute value, replacing characters which cannot appear in XML with
+/// U+FFFD
+///
+/// Whitespace other than spaces is escaped as well, since parsers normalize it to spaces.
+pub(crate) fn escape_attribute(value: &str) -> Cow<'_, str> {
+    escape(value, |character| match character {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        '"' => Some("&quot;"),
+        '\t' => Some("&#9;"),
+        '\n' => Some("&#10;"),
+        '\r' => Some("&#13;"),
+        _ => None,
+    })
+}
+
+fn escape<F>(text: &str, replacement: F) -> Cow<'_, str>
+where
+    F: Fn(char) -> Option<&'static str>,
+{
+    let needs_escaping =
+        |character: char| replacement(character).is_some() || !is_xml_char(character);
+    let start = match text.find(needs_escaping) {
+        Some(start) => start,
+        None => return Cow::Borrowed(text),
+    };
+
+    let mut escaped = String::with_capacity(text.len() + 8);
+    escaped.push_str(&text[..start]);
+    for character in text[start..].chars() {
+        match replacement(character) {
+            Some(replacement) => escaped.push_str(replacement),
+            None if !is_xml_char(character) => escaped.push('\u{fffd}'),
+            None => escaped.push(character),
+        }
+    }
+
+    Cow::Owned(escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_attribute, escape_text};
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape_text("plain ünïcode ✓"), "plain ünïcode ✓");
+        assert_eq!(
+            escape_text("a < b && \"c\" > 'd'"),
+            "a &lt; b &amp;&amp; \"c\" &gt; 'd'"
+        );
+        assert_eq!(escape_text("bell\u{7}"), "bell\u{fffd}");
+        assert_eq!(
+            escape_attribute("say \"hi\"\n&\tbye"),
+            "say &quot;hi&quot;&#10;&amp;&#9;bye"
+        );
+    }
+}
@@ -0,0 +1,293 @@
+//! This module provides rendering of definitions to PNG images, e.g. to ship an icon as a set of
+//! favicons next to the SVG version.
+//! *The feature "raster" needs to be enabled for this*
+//!
+//! # Examples
+//! ## Creating favicons
+//! ```
+//! use svg_definitions::export;
+//! use svg_definitions::prelude::*;
+//!
+//! let icon = SVGElem::new(Tag::Svg)
+//!     .set(Attr::ViewBox, "0 0 16 16")
+//!     .append(SVGElem::new(Tag::Circle).set(Attr::Cx, 8).set(Attr::Cy, 8).set(Attr::R, 7));
+//!
+//! let favicons = export::favicon_set(&icon, &[16, 32, 48, 512]).unwrap();
+//!
+//! // The PNG of 512 pixels is too large for favicon.ico, which holds the others
+//! assert_eq!(favicons.pngs.len(), 4);
+//! assert_eq!(&favicons.ico[4..6], &[3, 0]);
+//! ```
+
+use std::fmt;
+
+use resvg::{tiny_skia, usvg};
+
+use crate::attributes::Attribute;
+use crate::geometry;
+use crate::namespace;
+use crate::tag_name::TagName;
+use crate::view_box::ViewBoxProps;
+use crate::{Element, Matrix};
+
+/// The largest size of an image in an ICO container
+const MAX_ICO_SIZE: u32 = 256;
+
+/// An error which occurs while rendering a definition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportError {
+    /// The definition could not be parsed by the renderer
+    InvalidSvg(String),
+
+    /// An image cannot be created with this width and height
+    InvalidSize { width: u32, height: u32 },
+
+    /// An element which is not `<svg>` has no known bounding box to render
+    UnknownBounds,
+
+    /// None of the sizes of a favicon set fits in an ICO container
+    NoIcoSize,
+
+    /// The rendered image could not be encoded
+    EncodingError(String),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::InvalidSvg(error) => write!(f, "invalid svg: {}", error),
+            ExportError::InvalidSize { width, height } => {
+                write!(f, "invalid image size {}x{}", width, height)
+            }
+            ExportError::UnknownBounds => write!(f, "the bounds of the element are unknown"),
+            ExportError::NoIcoSize => write!(
+                f,
+                "no size is at most {} pixels, which an ico container needs",
+                MAX_ICO_SIZE
+            ),
+            ExportError::EncodingError(error) => write!(f, "failed to encode png: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+/// Renders an element to a PNG image of `width` by `height` pixels
+///
+/// # Note / Arguments
+/// The graphic is scaled to fit and centered, leaving the rest of the image transparent. An
+/// `<svg>` element is rendered with its view box or size. Other elements are rendered within
+/// their [bounding box](../struct.Element.html#method.bounding_box), including their own
+/// `transform`. Text is not rendered, as no fonts are loaded.
+///
+/// *The feature "raster" needs to be enabled for this*
+pub fn render_png(element: &Element, width: u32, height: u32) -> Result<Vec<u8>, ExportError> {
+    let mut pixmap =
+        tiny_skia::Pixmap::new(width, height).ok_or(ExportError::InvalidSize { width, height })?;
+
+    let tree = usvg::Tree::from_str(&to_document(element)?, &usvg::Options::default())
+        .map_err(|error| ExportError::InvalidSvg(error.to_string()))?;
+
+    let size = tree.size();
+    let scale = (width as f32 / size.width()).min(height as f32 / size.height());
+    let transform = tiny_skia::Transform::from_translate(
+        (width as f32 - size.width() * scale) / 2.0,
+        (height as f32 - size.height() * scale) / 2.0,
+    )
+    .pre_scale(scale, scale);
+
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    pixmap
+        .encode_png()
+        .map_err(|error| ExportError::EncodingError(error.to_string()))
+}
+
+/// Returns the markup of a standalone SVG document which shows an element
+fn to_document(element: &Element) -> Result<String, ExportError> {
+    let svg = if element.tag_name == TagName::Svg {
+        element.clone()
+    } else {
+        // The element keeps its own transform within the document
+        let matrix = element
+            .get_transform()
+            .map_or(Matrix::IDENTITY, |transforms| transforms.to_matrix());
+        let bounds = geometry::transformed_bounding_box(element, matrix)
+            .ok_or(ExportError::UnknownBounds)?;
+        Element::new(TagName::Svg)
+            .set(Attribute::ViewBox, ViewBoxProps::fit(&bounds))
+            .append(element.clone())
+    };
+
+    let svg = if svg
+        .get_namespaces()
+        .iter()
+        .any(|(prefix, _)| prefix.is_empty())
+    {
+        svg
+    } else {
+        svg.set_namespace("", namespace::SVG)
+    };
+    Ok(svg.to_string())
+}
+
+/// The images returned by [favicon_set](fn.favicon_set.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaviconSet {
+    /// A square PNG image per requested size, in the order of the sizes
+    pub pngs: Vec<(u32, Vec<u8>)>,
+
+    /// An ICO container with the PNG images of at most 256 pixels, for `favicon.ico`
+    pub ico: Vec<u8>,
+}
+
+/// Renders an icon to square PNG images of several sizes and bundles them in an ICO container
+///
+/// # Note / Arguments
+/// The ICO container only holds the images of at most 256 pixels, which is the largest size the
+/// format supports, so at least one size has to be at most 256. The icon is rendered like with
+/// [render_png](fn.render_png.html).
+///
+/// *The feature "raster" needs to be enabled for this*
+pub fn favicon_set(icon: &Element, sizes: &[u32]) -> Result<FaviconSet, ExportError> {
+    if !sizes.iter().any(|size| *size <= MAX_ICO_SIZE) {
+        return Err(ExportError::NoIcoSize);
+    }
+
+    let pngs = sizes
+        .iter()
+        .map(|size| render_png(icon, *size, *size).map(|png| (*size, png)))
+        .collect::<Result<Vec<(u32, Vec<u8>)>, ExportError>>()?;
+
+    let ico = ico_container(
+        &pngs
+            .iter()
+            .filter(|(size, _)| *size <= MAX_ICO_SIZE)
+            .collect::<Vec<_>>(),
+    );
+    Ok(FaviconSet { pngs, ico })
+}
+
+/// Returns an ICO container which holds square PNG images
+fn ico_container(pngs: &[&(u32, Vec<u8>)]) -> Vec<u8> {
+    const HEADER_SIZE: usize = 6;
+    const ENTRY_SIZE: usize = 16;
+
+    let mut ico = Vec::new();
+    ico.extend_from_slice(&0u16.to_le_bytes());
+    // The image type of icons
+    ico.extend_from_slice(&1u16.to_le_bytes());
+    ico.extend_from_slice(&(pngs.len() as u16).to_le_bytes());
+
+    let mut offset = HEADER_SIZE + ENTRY_SIZE * pngs.len();
+    for (size, png) in pngs.iter() {
+        // A size of 256 is stored as 0
+        let size = (*size % MAX_ICO_SIZE) as u8;
+        ico.extend_from_slice(&[size, size, 0, 0]);
+        // The color planes and bits per pixel
+        ico.extend_from_slice(&1u16.to_le_bytes());
+        ico.extend_from_slice(&32u16.to_le_bytes());
+        ico.extend_from_slice(&(png.len() as u32).to_le_bytes());
+        ico.extend_from_slice(&(offset as u32).to_le_bytes());
+        offset += png.len();
+    }
+
+    for (_, png) in pngs.iter() {
+        ico.extend_from_slice(png);
+    }
+    ico
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{favicon_set, render_png, ExportError};
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::Element;
+    use resvg::tiny_skia::Pixmap;
+
+    fn u32_at(bytes: &[u8], index: usize) -> u32 {
+        u32::from_le_bytes([
+            bytes[index],
+            bytes[index + 1],
+            bytes[index + 2],
+            bytes[index + 3],
+        ])
+    }
+
+    #[test]
+    fn test_render_png() {
+        let square = Element::new(TagName::Rect)
+            .set(Attribute::X, 10)
+            .set(Attribute::Y, 10)
+            .set(Attribute::Width, 10)
+            .set(Attribute::Height, 10)
+            .set(Attribute::Fill, "#ff0000");
+
+        // The square fills the whole image, but only the middle of a wide image
+        let png = render_png(&square, 8, 4).unwrap();
+        let pixmap = Pixmap::decode_png(&png).unwrap();
+        assert_eq!((pixmap.width(), pixmap.height()), (8, 4));
+        let pixel = |x, y| pixmap.pixel(x, y).unwrap();
+        assert_eq!((pixel(4, 2).red(), pixel(4, 2).alpha()), (255, 255));
+        assert_eq!(pixel(0, 2).alpha(), 0);
+
+        assert_eq!(
+            render_png(&square, 0, 4).unwrap_err(),
+            ExportError::InvalidSize {
+                width: 0,
+                height: 4
+            }
+        );
+        assert_eq!(
+            render_png(&Element::new(TagName::Text), 4, 4).unwrap_err(),
+            ExportError::UnknownBounds
+        );
+
+        // Paths and transformed elements are rendered within their bounds too
+        let diamond = Element::new(TagName::Path)
+            .set(Attribute::D, "M 10 0 L 20 10 L 10 20 L 0 10 Z")
+            .set(Attribute::Transform, "translate(100 100)")
+            .set(Attribute::Fill, "#ff0000");
+        let png = render_png(&diamond, 4, 4).unwrap();
+        let pixmap = Pixmap::decode_png(&png).unwrap();
+        assert_eq!(pixmap.pixel(2, 2).unwrap().alpha(), 255);
+        assert_eq!(pixmap.pixel(0, 0).unwrap().alpha(), 0);
+    }
+
+    #[test]
+    fn test_favicon_set() {
+        let icon = Element::new(TagName::Svg)
+            .set(Attribute::ViewBox, "0 0 1 1")
+            .append(
+                Element::new(TagName::Rect)
+                    .set(Attribute::Width, 1)
+                    .set(Attribute::Height, 1),
+            );
+
+        let favicons = favicon_set(&icon, &[16, 256, 512]).unwrap();
+        let sizes: Vec<u32> = favicons.pngs.iter().map(|(size, _)| *size).collect();
+        assert_eq!(sizes, vec![16, 256, 512]);
+
+        let ico = &favicons.ico;
+        assert_eq!(&ico[0..6], &[0, 0, 1, 0, 2, 0]);
+        assert_eq!(&ico[6..10], &[16, 16, 0, 0]);
+        assert_eq!(&ico[22..26], &[0, 0, 0, 0]);
+
+        // The entries point at the PNG images
+        for (index, (_, png)) in favicons.pngs.iter().take(2).enumerate() {
+            let entry = 6 + 16 * index;
+            let (size, offset) = (u32_at(ico, entry + 8), u32_at(ico, entry + 12));
+            assert_eq!(&ico[offset as usize..(offset + size) as usize], &png[..]);
+        }
+        assert_eq!(
+            ico.len(),
+            38 + favicons.pngs[0].1.len() + favicons.pngs[1].1.len()
+        );
+
+        assert_eq!(
+            favicon_set(&icon, &[512, 1024]).unwrap_err(),
+            ExportError::NoIcoSize
+        );
+        assert_eq!(favicon_set(&icon, &[]).unwrap_err(), ExportError::NoIcoSize);
+    }
+}
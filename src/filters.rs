@@ -0,0 +1,429 @@
+//! This module provides the [FilterBuilder](struct.FilterBuilder.html), which chains filter
+//! primitives into a `<filter>` element and keeps track of their `in` and `result` attributes.
+//!
+//! # Examples
+//! ## A glow around the source graphic
+//! ```
+//! use svg_definitions::filters::{FilterBuilder, Input};
+//! use svg_definitions::prelude::*;
+//!
+//! let glow = FilterBuilder::new("glow")
+//!     .gaussian_blur(4.0)
+//!     .merge(&[Input::Previous, Input::SourceGraphic])
+//!     .build();
+//!
+//! let text = SVGElem::new(Tag::Text).set(Attr::Filter, "url(#glow)");
+//! # assert_eq!(glow.get_children().len(), 2);
+//! ```
+
+use std::fmt;
+
+use crate::attributes::Attribute;
+use crate::context::Context;
+use crate::tag_name::TagName;
+use crate::Element;
+
+/// The input of a filter primitive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Input {
+    /// The element the filter is applied to
+    SourceGraphic,
+
+    /// The alpha channel of the element the filter is applied to
+    SourceAlpha,
+
+    /// The result of the primitive added last, or the source graphic if there is none
+    Previous,
+
+    /// The result of the primitive with a certain index, counting from 0 in the order they were
+    /// added
+    Stage(usize),
+}
+
+/// The operation of a `<feColorMatrix>`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorMatrix {
+    /// A 4x5 matrix in row-major order, mapping RGBA and a constant 1 to RGBA
+    Matrix([f64; 20]),
+
+    /// A saturation from 0 (grayscale) to 1 (unchanged)
+    Saturate(f64),
+
+    /// A rotation of the hue by an angle in degrees
+    HueRotate(f64),
+
+    /// Converts the luminance into the alpha channel
+    LuminanceToAlpha,
+}
+
+/// The operator of a `<feComposite>`, combining the input (A) with a second input (B)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompositeOperator {
+    /// A drawn over B
+    Over,
+
+    /// The part of A inside of B
+    In,
+
+    /// The part of A outside of B
+    Out,
+
+    /// The part of A inside of B, drawn over B
+    Atop,
+
+    /// The parts of A and B which do not overlap
+    Xor,
+
+    /// `k1 * A * B + k2 * A + k3 * B + k4` for every channel
+    Arithmetic(f64, f64, f64, f64),
+}
+
+impl fmt::Display for CompositeOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CompositeOperator::Over => "over",
+            CompositeOperator::In => "in",
+            CompositeOperator::Out => "out",
+            CompositeOperator::Atop => "atop",
+            CompositeOperator::Xor => "xor",
+            CompositeOperator::Arithmetic(..) => "arithmetic",
+        })
+    }
+}
+
+/// FilterBuilder assembles a `<filter>` element from primitives, where every primitive reads the
+/// result of the one before it unless another input is given
+///
+/// # Note / Arguments
+/// Every primitive gets an explicit `in` attribute, and the primitives whose result is used
+/// get a `result` attribute named after the filter id and their index, e.g. `shadow-0`.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::filters::{CompositeOperator, FilterBuilder, Input};
+///
+/// let filter = FilterBuilder::new("shadow")
+///     .input(Input::SourceAlpha)
+///     .gaussian_blur(2.0)
+///     .offset(3.0, 3.0)
+///     .flood("black", 0.5)
+///     .composite(Input::Stage(1), CompositeOperator::In)
+///     .merge(&[Input::Previous, Input::SourceGraphic])
+///     .build();
+///
+/// assert_eq!(
+///     filter.to_string(),
+///     "<filter id=\"shadow\">\
+///      <feGaussianBlur in=\"SourceAlpha\" result=\"shadow-0\" stdDeviation=\"2\"/>\
+///      <feOffset dx=\"3\" dy=\"3\" in=\"shadow-0\" result=\"shadow-1\"/>\
+///      <feFlood flood-color=\"black\" flood-opacity=\"0.5\" result=\"shadow-2\"/>\
+///      <feComposite in=\"shadow-2\" in2=\"shadow-1\" operator=\"in\" result=\"shadow-3\"/>\
+///      <feMerge><feMergeNode in=\"shadow-3\"/><feMergeNode in=\"SourceGraphic\"/></feMerge>\
+///      </filter>"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FilterBuilder {
+    filter: Element,
+    id: String,
+    primitives: Vec<Element>,
+    next_input: Input,
+}
+
+impl FilterBuilder {
+    /// Creates a new builder for a filter with a certain id
+    pub fn new(id: &str) -> FilterBuilder {
+        FilterBuilder {
+            filter: Element::new(TagName::Filter).set(Attribute::Id, id),
+            id: String::from(id),
+            primitives: Vec::new(),
+            next_input: Input::Previous,
+        }
+    }
+
+    /// Names the id after the id strategy of a [Context](../context/struct.Context.html)
+    pub fn context(mut self, context: &Context) -> Self {
+        self.id = context.id(&self.id);
+        self.filter = self.filter.set(Attribute::Id, &self.id);
+        self
+    }
+
+    /// Sets an attribute of the `<filter>` element, e.g. its region
+    pub fn set<T>(mut self, attribute: Attribute, value: T) -> Self
+    where
+        T: ToString,
+    {
+        self.filter = self.filter.set(attribute, value);
+        self
+    }
+
+    /// Sets the input of the next primitive, instead of the result of the previous one
+    pub fn input(mut self, input: Input) -> Self {
+        self.next_input = input;
+        self
+    }
+
+    /// Adds a `<feGaussianBlur>` with a certain standard deviation
+    pub fn gaussian_blur(self, std_deviation: f64) -> Self {
+        self.primitive(
+            Element::new(TagName::FeGaussianBlur).set(Attribute::StdDeviation, std_deviation),
+        )
+    }
+
+    /// Adds a `<feOffset>`, moving its input by (dx, dy)
+    pub fn offset(self, dx: f64, dy: f64) -> Self {
+        self.primitive(
+            Element::new(TagName::FeOffset)
+                .set(Attribute::Dx, dx)
+                .set(Attribute::Dy, dy),
+        )
+    }
+
+    /// Adds a `<feColorMatrix>`
+    pub fn color_matrix(self, matrix: ColorMatrix) -> Self {
+        let element = Element::new(TagName::FeColorMatrix);
+        self.primitive(match matrix {
+            ColorMatrix::Matrix(values) => element.set(Attribute::Type, "matrix").set(
+                Attribute::Values,
+                values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" "),
+            ),
+            ColorMatrix::Saturate(value) => element
+                .set(Attribute::Type, "saturate")
+                .set(Attribute::Values, value),
+            ColorMatrix::HueRotate(angle) => element
+                .set(Attribute::Type, "hueRotate")
+                .set(Attribute::Values, angle),
+            ColorMatrix::LuminanceToAlpha => element.set(Attribute::Type, "luminanceToAlpha"),
+        })
+    }
+
+    /// Adds a `<feFlood>`, filling the filter region with a color, which has no input
+    pub fn flood(mut self, color: &str, opacity: f64) -> Self {
+        let flood = Element::new(TagName::FeFlood)
+            .set(Attribute::FloodColor, color)
+            .set(Attribute::FloodOpacity, opacity);
+        self.next_input = Input::Previous;
+        self.primitives.push(flood);
+        self
+    }
+
+    /// Adds a `<feComposite>`, combining the input with a second input
+    ///
+    /// # Panics
+    /// If the second input is a stage which has not been added yet
+    pub fn composite(mut self, input2: Input, operator: CompositeOperator) -> Self {
+        let mut composite = Element::new(TagName::FeComposite)
+            .set(Attribute::In2, self.input_name(input2))
+            .set(Attribute::Operator, operator);
+        if let CompositeOperator::Arithmetic(k1, k2, k3, k4) = operator {
+            composite = composite
+                .set(Attribute::K1, k1)
+                .set(Attribute::K2, k2)
+                .set(Attribute::K3, k3)
+                .set(Attribute::K4, k4);
+        }
+        self.primitive(composite)
+    }
+
+    /// Adds a `<feMerge>`, drawing its inputs over each other with the first at the bottom
+    ///
+    /// # Panics
+    /// If one of the inputs is a stage which has not been added yet
+    pub fn merge(mut self, inputs: &[Input]) -> Self {
+        let mut merge = Element::new(TagName::FeMerge);
+        for input in inputs {
+            merge = merge.append(
+                Element::new(TagName::FeMergeNode).set(Attribute::In, self.input_name(*input)),
+            );
+        }
+        self.next_input = Input::Previous;
+        self.primitives.push(merge);
+        self
+    }
+
+    /// Assembles the `<filter>` element
+    pub fn build(self) -> Element {
+        let mut filter = self.filter;
+        filter.children.extend(self.primitives);
+        filter
+    }
+
+    /// Adds a primitive which reads the next input
+    fn primitive(mut self, element: Element) -> Self {
+        let input = std::mem::replace(&mut self.next_input, Input::Previous);
+        let element = element.set(Attribute::In, self.input_name(input));
+        self.primitives.push(element);
+        self
+    }
+
+    /// Returns the value of `in` for an input, giving the referenced primitive a result name
+    fn input_name(&mut self, input: Input) -> String {
+        let index = match input {
+            Input::SourceGraphic => return String::from("SourceGraphic"),
+            Input::SourceAlpha => return String::from("SourceAlpha"),
+            Input::Previous if self.primitives.is_empty() => return String::from("SourceGraphic"),
+            Input::Previous => self.primitives.len() - 1,
+            Input::Stage(index) => index,
+        };
+
+        let result = format!("{}-{}", self.id, index);
+        let primitive = self
+            .primitives
+            .get_mut(index)
+            .expect("the input stage has not been added yet");
+        primitive.set_attribute(Attribute::Result, result.clone());
+        result
+    }
+}
+
+/// Returns a drop shadow filter together with the value for the `filter` attribute, where the
+/// shadow is moved by (dx, dy) and blurred with a certain standard deviation
+///
+/// # Note / Arguments
+/// The id of the filter is derived from the arguments, so the same shadow can be added to the
+/// `<defs>` once and shared. The filter region is enlarged to keep the shadow from being cut
+/// off.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::filters;
+/// use svg_definitions::prelude::*;
+///
+/// let (shadow, filter) = filters::drop_shadow(2.0, 2.0, 3.0, "#000");
+///
+/// let svg = SVGElem::new(Tag::Svg)
+///     .append(SVGElem::new(Tag::Defs).append(shadow))
+///     .append(SVGElem::new(Tag::Rect).set(Attr::Filter, filter));
+/// # assert!(svg.to_string().contains("filter=\"url(#drop-shadow-2-2-3-_000)\""));
+/// ```
+pub fn drop_shadow(dx: f64, dy: f64, blur: f64, color: &str) -> (Element, String) {
+    let id = format!(
+        "drop-shadow-{}-{}-{}-{}",
+        id_number(dx),
+        id_number(dy),
+        id_number(blur),
+        color
+            .trim()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>()
+    );
+    let filter = enlarged(FilterBuilder::new(&id))
+        .input(Input::SourceAlpha)
+        .gaussian_blur(blur)
+        .offset(dx, dy)
+        .flood(color, 1.0)
+        .composite(Input::Stage(1), CompositeOperator::In)
+        .merge(&[Input::Previous, Input::SourceGraphic])
+        .build();
+
+    (filter, format!("url(#{})", id))
+}
+
+/// Returns a blur filter with a certain standard deviation together with the value for the
+/// `filter` attribute
+///
+/// # Note / Arguments
+/// The id of the filter is derived from the standard deviation, so the same blur can be added
+/// to the `<defs>` once and shared.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::filters;
+/// use svg_definitions::prelude::*;
+///
+/// let (blur, filter) = filters::blur(1.5);
+///
+/// assert_eq!(filter, "url(#blur-1p5)");
+/// let circle = SVGElem::new(Tag::Circle).set(Attr::Filter, filter);
+/// ```
+pub fn blur(std_deviation: f64) -> (Element, String) {
+    let id = format!("blur-{}", id_number(std_deviation));
+    let filter = enlarged(FilterBuilder::new(&id))
+        .gaussian_blur(std_deviation)
+        .build();
+
+    (filter, format!("url(#{})", id))
+}
+
+/// Enlarges the filter region from the default of 10% around the bounding box to 50%
+fn enlarged(builder: FilterBuilder) -> FilterBuilder {
+    builder
+        .set(Attribute::X, "-50%")
+        .set(Attribute::Y, "-50%")
+        .set(Attribute::Width, "200%")
+        .set(Attribute::Height, "200%")
+}
+
+/// Formats a number for use within an id, e.g. `m1p5` for -1.5
+fn id_number(value: f64) -> String {
+    value.to_string().replace('-', "m").replace('.', "p")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blur, drop_shadow, ColorMatrix, CompositeOperator, FilterBuilder, Input};
+    use crate::attributes::Attribute;
+
+    #[test]
+    fn test_filter_builder() {
+        let filter = FilterBuilder::new("f")
+            .set(Attribute::X, "-10%")
+            .color_matrix(ColorMatrix::Saturate(0.0))
+            .input(Input::SourceGraphic)
+            .composite(
+                Input::Stage(0),
+                CompositeOperator::Arithmetic(0.0, 0.5, 0.5, 0.0),
+            )
+            .color_matrix(ColorMatrix::LuminanceToAlpha)
+            .build();
+
+        assert_eq!(
+            filter.to_string(),
+            "<filter id=\"f\" x=\"-10%\">\
+             <feColorMatrix in=\"SourceGraphic\" result=\"f-0\" type=\"saturate\" values=\"0\"/>\
+             <feComposite in=\"SourceGraphic\" in2=\"f-0\" k1=\"0\" k2=\"0.5\" k3=\"0.5\" \
+             k4=\"0\" operator=\"arithmetic\" result=\"f-1\"/>\
+             <feColorMatrix in=\"f-1\" type=\"luminanceToAlpha\"/></filter>"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_missing_stage() {
+        FilterBuilder::new("f").merge(&[Input::Stage(0)]);
+    }
+
+    #[test]
+    fn test_one_liners() {
+        let (shadow, filter) = drop_shadow(-1.0, 0.5, 2.0, "rgba(0, 0, 0, .5)");
+        assert_eq!(filter, "url(#drop-shadow-m1-0p5-2-rgba_0__0__0___5_)");
+        assert_eq!(
+            shadow.get_attributes()[&Attribute::Id],
+            "drop-shadow-m1-0p5-2-rgba_0__0__0___5_"
+        );
+        assert_eq!(shadow.get_children().len(), 5);
+        assert_eq!(
+            shadow.get_children()[2].get_attributes()[&Attribute::FloodColor],
+            "rgba(0, 0, 0, .5)"
+        );
+        assert_eq!(
+            drop_shadow(-1.0, 0.5, 2.0, "rgba(0, 0, 0, .5)")
+                .0
+                .to_string(),
+            shadow.to_string()
+        );
+
+        let (blurred, filter) = blur(3.0);
+        assert_eq!(filter, "url(#blur-3)");
+        assert_eq!(
+            blurred.to_string(),
+            "<filter height=\"200%\" id=\"blur-3\" width=\"200%\" x=\"-50%\" y=\"-50%\">\
+             <feGaussianBlur in=\"SourceGraphic\" stdDeviation=\"3\"/></filter>"
+        );
+    }
+}
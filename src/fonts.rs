@@ -0,0 +1,647 @@
+//! This module provides embedding of fonts into a [Document](../document/struct.Document.html)
+//! and conversion of text into paths.
+//! *The feature "fonts" needs to be enabled for this*
+//!
+//! Embedded fonts are reduced to the glyphs which are used by the text of the document and are
+//! inlined as a `data:` URI in an `@font-face` rule, so the document renders the same everywhere
+//! without fetching any fonts. Converting text into the outlines of its glyphs removes the need
+//! for fonts altogether, e.g. for print and engraving.
+//!
+//! # Examples
+//! ## Embedding a font
+//! ```
+//! use svg_definitions::prelude::*;
+//!
+//! let mut document = SVGDoc::new(
+//!     SVGElem::new(Tag::Svg).append(
+//!         SVGElem::new(Tag::Text)
+//!             .set(Attr::FontFamily, "Inter, sans-serif")
+//!             .set_inner("Hello"),
+//!     ),
+//! );
+//!
+//! let result = document.embed_font("/path/to/Inter.ttf", &["Inter"]);
+//!
+//! // ...
+//! ```
+//!
+//! ## Converting text into paths
+//! ```
+//! use svg_definitions::prelude::*;
+//!
+//! let mut document = SVGDoc::new(
+//!     SVGElem::new(Tag::Svg).append(
+//!         SVGElem::new(Tag::Text)
+//!             .set(Attr::FontFamily, "Inter")
+//!             .set_inner("Hello"),
+//!     ),
+//! );
+//!
+//! document.convert_text_to_paths(|family| std::fs::read(format!("/path/to/{}.ttf", family)).ok());
+//!
+//! // ...
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use crate::attributes::Attribute;
+use crate::base64;
+use crate::changes::ChangeKind;
+use crate::document::Document;
+use crate::path::PathDefinitionString;
+use crate::tag_name::TagName;
+use crate::text::FontMetrics;
+use crate::transform::Transform;
+use crate::tree::ElementPath;
+use crate::Element;
+
+/// The font size of the root, as used by browsers
+const DEFAULT_FONT_SIZE: f64 = 16.0;
+
+/// Attributes which only affect the layout of text, which are dropped when converting text to
+/// paths
+const TEXT_ATTRIBUTES: &[Attribute] = &[
+    Attribute::X,
+    Attribute::Y,
+    Attribute::Dx,
+    Attribute::Dy,
+    Attribute::Rotate,
+    Attribute::TextLength,
+    Attribute::LengthAdjust,
+    Attribute::TextAnchor,
+    Attribute::DominantBaseline,
+    Attribute::AlignmentBaseline,
+    Attribute::BaselineShift,
+    Attribute::FontFamily,
+    Attribute::FontSize,
+    Attribute::FontSizeadjust,
+    Attribute::FontStretch,
+    Attribute::FontStyle,
+    Attribute::FontVariant,
+    Attribute::FontWeight,
+    Attribute::Kerning,
+    Attribute::LetterSpacing,
+    Attribute::WordSpacing,
+    Attribute::WritingMode,
+];
+
+/// An error which occurs while embedding a font
+#[derive(Debug)]
+pub enum FontError {
+    FileError(std::io::Error),
+    FaceError(ttf_parser::FaceParsingError),
+    WoffError(String),
+    SubsetError(subsetter::Error),
+    UnsupportedFormat,
+}
+
+impl Document {
+    /// Embeds a TTF, OTF or WOFF font for a list of font families, reduced to the glyphs used by
+    /// the text of this Document
+    ///
+    /// # Note / Arguments
+    /// Only glyphs of the inner text of elements which use one of `families_used` in their own
+    /// or inherited `font-family` are kept. A `<style>` element with an `@font-face` rule per
+    /// family is inserted as the first child of the root. WOFF2 fonts are not supported.
+    ///
+    /// *The feature "fonts" needs to be enabled for this*
+    pub fn embed_font<P>(&mut self, path: P, families_used: &[&str]) -> Result<(), FontError>
+    where
+        P: AsRef<Path>,
+    {
+        let data = std::fs::read(path).map_err(FontError::FileError)?;
+        self.embed_font_data(&data, families_used)
+    }
+
+    /// Embeds a font from its data for a list of font families, the same way as
+    /// [embed_font](#method.embed_font)
+    ///
+    /// *The feature "fonts" needs to be enabled for this*
+    pub fn embed_font_data(
+        &mut self,
+        data: &[u8],
+        families_used: &[&str],
+    ) -> Result<(), FontError> {
+        let data = match data.get(..4) {
+            Some(b"wOFF") => woff_to_sfnt(data)?,
+            Some(b"wOF2") => return Err(FontError::UnsupportedFormat),
+            _ => data.to_vec(),
+        };
+
+        let mut characters = BTreeSet::new();
+        collect_characters(self.get_root(), families_used, false, &mut characters);
+
+        let face = ttf_parser::Face::from_slice(&data, 0).map_err(FontError::FaceError)?;
+        // The first glyph is the fallback for missing characters and always needs to be kept
+        let mut glyphs: Vec<u16> = characters
+            .iter()
+            .filter_map(|&character| face.glyph_index(character))
+            .map(|glyph| glyph.0)
+            .collect();
+        glyphs.insert(0, 0);
+        glyphs.dedup();
+
+        let subset = subsetter::subset(&data, 0, subsetter::Profile::pdf(&glyphs))
+            .map_err(FontError::SubsetError)?;
+
+        let (mime, format) = if face.tables().cff.is_some() {
+            ("font/otf", "opentype")
+        } else {
+            ("font/ttf", "truetype")
+        };
+        let source = format!(
+            "url(data:{};base64,{}) format(\"{}\")",
+            mime,
+            base64::encode(&subset),
+            format
+        );
+
+        let rules: Vec<String> = families_used
+            .iter()
+            .map(|family| format!("@font-face{{font-family:\"{}\";src:{}}}", family, source))
+            .collect();
+
+        let mut style = Element::new(TagName::Style);
+        style.inner = Some(rules.join(" "));
+        self.get_root_mut().insert_child(0, style);
+
+        self.notify(&ElementPath::new(), &ChangeKind::ChildInserted(0));
+        Ok(())
+    }
+
+    /// Replaces every `<text>` element by a group of paths with the outlines of its glyphs,
+    /// returning the amount of converted text elements
+    ///
+    /// # Note / Arguments
+    /// The `font_resolver` returns the TTF or OTF data of a font family, or None if it is not
+    /// available. It is called at most once per family. The text of a `<text>` element is
+    /// followed by the text of its `<tspan>` children, which get their own paths. Supported are
+    /// the `x`, `y`, `dx`, `dy`, `font-family`, `font-size` and `text-anchor` attributes, with
+    /// their first values in user units. Text elements for which no font could be resolved
+    /// are left untouched.
+    ///
+    /// *The feature "fonts" needs to be enabled for this*
+    pub fn convert_text_to_paths<F>(&mut self, mut font_resolver: F) -> usize
+    where
+        F: FnMut(&str) -> Option<Vec<u8>>,
+    {
+        let mut fonts = HashMap::new();
+        let mut resolve = |family: &str| -> Option<Vec<u8>> {
+            fonts
+                .entry(family.to_lowercase())
+                .or_insert_with(|| font_resolver(family))
+                .clone()
+        };
+
+        let converted = convert_text(self.get_root_mut(), &TextStyle::default(), &mut resolve);
+        if converted > 0 {
+            self.notify(&ElementPath::new(), &ChangeKind::Subtree);
+        }
+        converted
+    }
+}
+
+/// The inherited properties used to lay out text
+#[derive(Debug, Clone)]
+struct TextStyle {
+    family: Option<String>,
+    size: f64,
+    anchor: String,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        TextStyle {
+            family: None,
+            size: DEFAULT_FONT_SIZE,
+            anchor: String::from("start"),
+        }
+    }
+}
+
+impl TextStyle {
+    /// Returns the style of an element inheriting from this style
+    fn inherit(&self, element: &Element) -> TextStyle {
+        let mut style = self.clone();
+        if let Some(family) = property(element, &Attribute::FontFamily, "font-family") {
+            style.family = Some(family.to_string());
+        }
+        if let Some(size) = property(element, &Attribute::FontSize, "font-size").and_then(length) {
+            style.size = size;
+        }
+        if let Some(anchor) = property(element, &Attribute::TextAnchor, "text-anchor") {
+            style.anchor = anchor.to_string();
+        }
+        style
+    }
+}
+
+/// Returns the first length of a value in user units, e.g. `12` for `12px 5`
+fn length(value: &str) -> Option<f64> {
+    let first = value
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .find(|part| !part.is_empty())?;
+    first.strip_suffix("px").unwrap_or(first).parse().ok()
+}
+
+/// Builds a path from glyph outlines, scaling and flipping them from font units to user units
+struct OutlinePath {
+    path: PathDefinitionString,
+    origin: (f64, f64),
+    scale: f64,
+}
+
+impl OutlinePath {
+    fn point(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            (self.origin.0 + f64::from(x) * self.scale) as f32,
+            (self.origin.1 - f64::from(y) * self.scale) as f32,
+        )
+    }
+}
+
+impl ttf_parser::OutlineBuilder for OutlinePath {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let to = self.point(x, y);
+        self.path = std::mem::take(&mut self.path).move_to(to);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let to = self.point(x, y);
+        self.path = std::mem::take(&mut self.path).line_to(to);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (control, to) = (self.point(x1, y1), self.point(x, y));
+        self.path = std::mem::take(&mut self.path).quad_curve_to(to, control);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (control_1, control_2, to) = (self.point(x1, y1), self.point(x2, y2), self.point(x, y));
+        self.path = std::mem::take(&mut self.path).curve_to(to, control_1, control_2);
+    }
+
+    fn close(&mut self) {
+        self.path = std::mem::take(&mut self.path).close_path();
+    }
+}
+
+/// Returns a copy of an element without its children, inner text and text layout attributes
+fn without_text_attributes(element: &Element) -> Element {
+    let tag_name = match element.get_tag_name() {
+        TagName::Text => TagName::G,
+        _ => TagName::Path,
+    };
+
+    let mut copy = Element::new(tag_name);
+    for (attribute, value) in element.get_attributes().iter() {
+        if !TEXT_ATTRIBUTES.contains(attribute) {
+            copy.set_attribute(attribute.clone(), value);
+        }
+    }
+    copy
+}
+
+/// Replaces all text elements within an element by paths, returning the amount of replaced
+/// text elements
+fn convert_text(
+    element: &mut Element,
+    style: &TextStyle,
+    resolve: &mut dyn FnMut(&str) -> Option<Vec<u8>>,
+) -> usize {
+    let style = style.inherit(element);
+    let mut converted = 0;
+
+    for child in element.children.iter_mut() {
+        if *child.get_tag_name() == TagName::Text {
+            if let Some(group) = text_to_paths(child, &style, resolve) {
+                *child = group;
+                converted += 1;
+            }
+        } else {
+            converted += convert_text(child, &style, resolve);
+        }
+    }
+
+    converted
+}
+
+/// Returns the collapsed text of an element, where all whitespace is turned into single spaces
+fn collapsed_text(element: &Element) -> String {
+    let text = element.get_inner().as_deref().unwrap_or("");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Converts a text element into a group of paths, or None if no font could be resolved for it
+fn text_to_paths(
+    text: &Element,
+    style: &TextStyle,
+    resolve: &mut dyn FnMut(&str) -> Option<Vec<u8>>,
+) -> Option<Element> {
+    let style = style.inherit(text);
+    let position = |element: &Element, attribute: Attribute| {
+        element
+            .get_attributes()
+            .get(&attribute)
+            .and_then(|value| length(value))
+    };
+
+    let mut pen = (
+        position(text, Attribute::X).unwrap_or(0.0) + position(text, Attribute::Dx).unwrap_or(0.0),
+        position(text, Attribute::Y).unwrap_or(0.0) + position(text, Attribute::Dy).unwrap_or(0.0),
+    );
+    let start = pen.0;
+
+    let mut runs = vec![(text, style.clone())];
+    runs.extend(
+        text.get_children()
+            .iter()
+            .filter(|child| *child.get_tag_name() == TagName::Tspan)
+            .map(|tspan| (tspan, style.inherit(tspan))),
+    );
+
+    let mut paths = Vec::with_capacity(runs.len());
+    for (index, (run, run_style)) in runs.into_iter().enumerate() {
+        if index > 0 {
+            pen.0 = position(run, Attribute::X).unwrap_or(pen.0);
+            pen.1 = position(run, Attribute::Y).unwrap_or(pen.1);
+            pen.0 += position(run, Attribute::Dx).unwrap_or(0.0);
+            pen.1 += position(run, Attribute::Dy).unwrap_or(0.0);
+        }
+
+        let data = run_style
+            .family
+            .as_deref()
+            .into_iter()
+            .flat_map(font_families)
+            .find_map(&mut *resolve)?;
+        let face = ttf_parser::Face::from_slice(&data, 0).ok()?;
+
+        let mut outline = OutlinePath {
+            path: PathDefinitionString::new().with_precision(2),
+            origin: pen,
+            scale: run_style.size / f64::from(face.units_per_em()),
+        };
+        for character in collapsed_text(run).chars() {
+            let glyph = face
+                .glyph_index(character)
+                .unwrap_or(ttf_parser::GlyphId(0));
+            face.outline_glyph(glyph, &mut outline);
+
+            let advance = f64::from(face.glyph_hor_advance(glyph).unwrap_or(0)) * outline.scale;
+            outline.origin.0 += advance;
+        }
+        pen = outline.origin;
+
+        if !outline.path.get_commands().is_empty() {
+            // The attributes of the text element itself are kept on the group
+            let path = match index {
+                0 => Element::new(TagName::Path),
+                _ => without_text_attributes(run),
+            };
+            paths.push((path, outline.path));
+        }
+    }
+
+    let shift = match style.anchor.as_str() {
+        "middle" => (start - pen.0) / 2.0,
+        "end" => start - pen.0,
+        _ => 0.0,
+    };
+    let mut group = without_text_attributes(text);
+    for (path, mut outline) in paths {
+        if shift != 0.0 {
+            outline = outline.transformed(&Transform::Translate(shift, 0.0));
+        }
+        group = group.append(path.set(Attribute::D, outline));
+    }
+
+    Some(group)
+}
+
+/// Returns the families of a `font-family` value, without quotes
+fn font_families(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(',')
+        .map(|family| family.trim().trim_matches(|c| c == '"' || c == '\'').trim())
+}
+
+/// Returns the value of a property of an element, from its inline style or its attribute
+fn property<'a>(element: &'a Element, attribute: &Attribute, name: &str) -> Option<&'a str> {
+    let from_style = element
+        .get_attributes()
+        .get(&Attribute::Style)
+        .and_then(|style| {
+            style.split(';').find_map(|declaration| {
+                let (property, value) = declaration.split_once(':')?;
+                Some(value.trim()).filter(|_| property.trim() == name)
+            })
+        });
+
+    from_style.or_else(|| {
+        element
+            .get_attributes()
+            .get(attribute)
+            .map(|value| value.as_str())
+    })
+}
+
+/// Collects the characters of the inner text of elements using one of the families
+fn collect_characters(
+    element: &Element,
+    families: &[&str],
+    inherited: bool,
+    characters: &mut BTreeSet<char>,
+) {
+    let uses_family = match property(element, &Attribute::FontFamily, "font-family") {
+        Some(value) => font_families(value).any(|family| {
+            families
+                .iter()
+                .any(|used| used.eq_ignore_ascii_case(family))
+        }),
+        None => inherited,
+    };
+
+    if uses_family && *element.get_tag_name() != TagName::Style {
+        if let Some(inner) = element.get_inner() {
+            characters.extend(inner.chars().filter(|character| !character.is_control()));
+        }
+    }
+
+    for child in element.get_children() {
+        collect_characters(child, families, uses_family, characters);
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes([
+        *data.get(offset)?,
+        *data.get(offset + 1)?,
+    ]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Converts a WOFF font into the OpenType font it wraps, decompressing its tables
+fn woff_to_sfnt(data: &[u8]) -> Result<Vec<u8>, FontError> {
+    let invalid = || FontError::WoffError(String::from("Invalid WOFF header"));
+
+    let flavor = read_u32(data, 4).ok_or_else(invalid)?;
+    let table_count = read_u16(data, 12).ok_or_else(invalid)? as usize;
+
+    let mut tables = Vec::with_capacity(table_count);
+    for index in 0..table_count {
+        let entry = 44 + index * 20;
+        let tag = data.get(entry..entry + 4).ok_or_else(invalid)?;
+        let offset = read_u32(data, entry + 4).ok_or_else(invalid)? as usize;
+        let compressed_length = read_u32(data, entry + 8).ok_or_else(invalid)? as usize;
+        let length = read_u32(data, entry + 12).ok_or_else(invalid)? as usize;
+        let checksum = read_u32(data, entry + 16).ok_or_else(invalid)?;
+
+        let stored = data
+            .get(offset..offset + compressed_length)
+            .ok_or_else(invalid)?;
+        let table = if compressed_length < length {
+            miniz_oxide::inflate::decompress_to_vec_zlib(stored)
+                .map_err(|error| FontError::WoffError(format!("{:?}", error)))?
+        } else {
+            stored.to_vec()
+        };
+
+        tables.push((tag, checksum, table));
+    }
+
+    // The offset table of an OpenType font
+    let mut sfnt = Vec::new();
+    let entry_selector = (table_count.max(1) as f64).log2().floor() as u16;
+    let search_range = 2u16.pow(u32::from(entry_selector)) * 16;
+    sfnt.extend_from_slice(&flavor.to_be_bytes());
+    sfnt.extend_from_slice(&(table_count as u16).to_be_bytes());
+    sfnt.extend_from_slice(&search_range.to_be_bytes());
+    sfnt.extend_from_slice(&entry_selector.to_be_bytes());
+    sfnt.extend_from_slice(
+        &((table_count as u16) * 16)
+            .saturating_sub(search_range)
+            .to_be_bytes(),
+    );
+
+    let mut offset = 12 + 16 * table_count;
+    for (tag, checksum, table) in tables.iter() {
+        sfnt.extend_from_slice(tag);
+        sfnt.extend_from_slice(&checksum.to_be_bytes());
+        sfnt.extend_from_slice(&(offset as u32).to_be_bytes());
+        sfnt.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        offset += table.len().div_ceil(4) * 4;
+    }
+
+    for (_, _, table) in tables.iter() {
+        sfnt.extend_from_slice(table);
+        sfnt.resize(sfnt.len().div_ceil(4) * 4, 0);
+    }
+
+    Ok(sfnt)
+}
+
+impl FontMetrics for ttf_parser::Face<'_> {
+    /// Returns the sum of the advances of the glyphs, without kerning
+    fn text_width(&self, text: &str) -> f64 {
+        let advances: u32 = text
+            .chars()
+            .map(|character| {
+                let glyph = self
+                    .glyph_index(character)
+                    .unwrap_or(ttf_parser::GlyphId(0));
+                u32::from(self.glyph_hor_advance(glyph).unwrap_or(0))
+            })
+            .sum();
+        f64::from(advances) / f64::from(self.units_per_em())
+    }
+
+    fn ascent(&self) -> f64 {
+        f64::from(self.ascender()) / f64::from(self.units_per_em())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect_characters, length, FontError};
+    use crate::attributes::Attribute;
+    use crate::document::Document;
+    use crate::tag_name::TagName;
+    use crate::Element;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_collect_characters() {
+        let root = Element::new(TagName::Svg)
+            .append(
+                Element::new(TagName::G)
+                    .set(Attribute::FontFamily, "'Inter', sans-serif")
+                    .append(Element::new(TagName::Text).set_inner("ab"))
+                    .append(
+                        Element::new(TagName::Text)
+                            .set(Attribute::Style, "font-family: serif")
+                            .set_inner("xy"),
+                    ),
+            )
+            .append(
+                Element::new(TagName::Text)
+                    .set(Attribute::Style, "fill: red; font-family: inter")
+                    .set_inner("cb"),
+            );
+
+        let mut characters = BTreeSet::new();
+        collect_characters(&root, &["Inter"], false, &mut characters);
+        assert_eq!(characters.into_iter().collect::<String>(), "abc");
+    }
+
+    #[test]
+    fn test_unresolved_text() {
+        assert_eq!(length("12px 4"), Some(12.0));
+        assert_eq!(length(" 1.5,2"), Some(1.5));
+        assert_eq!(length("2em"), None);
+
+        let root = Element::new(TagName::Svg)
+            .append(Element::new(TagName::Text).set_inner("a"))
+            .append(
+                Element::new(TagName::G)
+                    .set(Attribute::FontFamily, "Inter, Roboto")
+                    .append(Element::new(TagName::Text).set_inner("b"))
+                    .append(Element::new(TagName::Text).set_inner("c")),
+            );
+        let mut document = Document::new(root.clone());
+
+        let mut requested = Vec::new();
+        let converted = document.convert_text_to_paths(|family| {
+            requested.push(family.to_string());
+            None
+        });
+
+        assert_eq!(converted, 0);
+        assert_eq!(requested, vec!["Inter", "Roboto"]);
+        assert_eq!(document.get_root().to_string(), root.to_string());
+    }
+
+    #[test]
+    fn test_unsupported_fonts() {
+        let mut document = Document::new(Element::new(TagName::Svg));
+
+        assert!(matches!(
+            document.embed_font_data(b"wOF2....", &["Inter"]),
+            Err(FontError::UnsupportedFormat)
+        ));
+        assert!(matches!(
+            document.embed_font_data(b"wOFF", &["Inter"]),
+            Err(FontError::WoffError(_))
+        ));
+        assert!(matches!(
+            document.embed_font_data(b"not a font", &["Inter"]),
+            Err(FontError::FaceError(_))
+        ));
+        assert!(document.get_root().get_children().is_empty());
+    }
+}
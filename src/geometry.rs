@@ -0,0 +1,960 @@
+//! This module provides geometric helpers for [Elements](../struct.Element.html), like the
+//! [BoundingBox](struct.BoundingBox.html) of basic shapes, and the conversion between the
+//! endpoint and [center](struct.CenterArc.html) parameterizations of elliptical arcs.
+//!
+//! # Examples
+//! ## Getting the bounds of a circle
+//! ```
+//! use svg_definitions::geometry::{self, BoundingBox};
+//! use svg_definitions::prelude::*;
+//!
+//! let circle = SVGElem::new(Tag::Circle).set(Attr::Cx, 10).set(Attr::Cy, 5).set(Attr::R, 5);
+//!
+//! assert_eq!(
+//!     geometry::bounding_box(&circle),
+//!     Some(BoundingBox::new(5.0, 0.0, 10.0, 10.0))
+//! );
+//! ```
+
+use crate::attributes::Attribute;
+use crate::length::Length;
+use crate::path::PathDefinitionString;
+use crate::syntax;
+use crate::tag_name::TagName;
+use crate::transform::Transform;
+use crate::{Element, Matrix};
+
+/// An axis-aligned rectangle enclosing some geometry
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingBox {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl BoundingBox {
+    /// Creates a new bounding box from its top-left corner and size
+    #[inline]
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> BoundingBox {
+        BoundingBox {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Creates the smallest bounding box enclosing a list of points, or None for no points
+    pub fn from_points(points: &[(f64, f64)]) -> Option<BoundingBox> {
+        let (first, rest) = points.split_first()?;
+        let (mut min, mut max) = (*first, *first);
+        for &(x, y) in rest {
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        }
+
+        Some(BoundingBox::new(min.0, min.1, max.0 - min.0, max.1 - min.1))
+    }
+
+    /// Returns the x coordinate of the right edge
+    #[inline]
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    /// Returns the y coordinate of the bottom edge
+    #[inline]
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    /// Returns whether `other` lies completely within this bounding box
+    pub fn contains(&self, other: &BoundingBox) -> bool {
+        self.x <= other.x
+            && self.y <= other.y
+            && other.right() <= self.right()
+            && other.bottom() <= self.bottom()
+    }
+
+    /// Returns whether this bounding box and `other` overlap or touch
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.x <= other.right()
+            && other.x <= self.right()
+            && self.y <= other.bottom()
+            && other.y <= self.bottom()
+    }
+
+    /// Returns the smallest bounding box enclosing both this bounding box and `other`
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        BoundingBox::new(
+            x,
+            y,
+            self.right().max(other.right()) - x,
+            self.bottom().max(other.bottom()) - y,
+        )
+    }
+
+    /// Returns this bounding box grown by a margin on every side
+    pub fn inflate(&self, margin: f64) -> BoundingBox {
+        BoundingBox::new(
+            self.x - margin,
+            self.y - margin,
+            self.width + 2.0 * margin,
+            self.height + 2.0 * margin,
+        )
+    }
+}
+
+/// Parses a length in user units, e.g. `10`, `10px` or `1in`, or None if it depends on the font
+/// or viewport
+pub(crate) fn user_units(value: &str) -> Option<f64> {
+    value.parse::<Length>().ok()?.to_user_units()
+}
+
+/// Parses the `viewBox` attribute of an element into the area it shows, or None if it is not set,
+/// malformed or empty
+pub(crate) fn view_box(element: &Element) -> Option<BoundingBox> {
+    element
+        .get_view_box()
+        .filter(|view_box| view_box.width > 0.0 && view_box.height > 0.0)
+        .map(|view_box| view_box.to_bounds())
+}
+
+/// Returns the value of a length attribute in user units, using a default if it is not set and
+/// None if it is not in user units
+fn length(element: &Element, attribute: Attribute, default: f64) -> Option<f64> {
+    match element.attributes.get(&attribute) {
+        Some(value) => user_units(value),
+        None => Some(default),
+    }
+}
+
+/// Parses the coordinate pairs of a `points` attribute
+fn points(value: &str) -> Option<Vec<(f64, f64)>> {
+    let numbers = syntax::numbers(value).ok()?;
+
+    Some(
+        numbers
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect(),
+    )
+}
+
+/// Returns the bounding box of the fill geometry of an element in its own user space
+///
+/// # Note / Arguments
+/// Supported are `<rect>`, `<image>`, `<circle>`, `<ellipse>`, `<line>`, `<polyline>`,
+/// `<polygon>`, and groups (`<g>`, `<a>` and `<switch>`) of which all children are supported.
+/// Returns None for other elements, elements with lengths which are not in user units, and
+/// groups with transformed children. Strokes and the own `transform` of the element are not
+/// taken into account. Use [Element::bounding_box](../struct.Element.html#method.bounding_box)
+/// for paths and trees with transforms.
+pub fn bounding_box(element: &Element) -> Option<BoundingBox> {
+    match element.tag_name {
+        TagName::Rect | TagName::Image => Some(BoundingBox::new(
+            length(element, Attribute::X, 0.0)?,
+            length(element, Attribute::Y, 0.0)?,
+            length(element, Attribute::Width, 0.0)?,
+            length(element, Attribute::Height, 0.0)?,
+        )),
+        TagName::Circle => {
+            let r = length(element, Attribute::R, 0.0)?;
+            Some(BoundingBox::new(
+                length(element, Attribute::Cx, 0.0)? - r,
+                length(element, Attribute::Cy, 0.0)? - r,
+                2.0 * r,
+                2.0 * r,
+            ))
+        }
+        TagName::Ellipse => {
+            let rx = length(element, Attribute::Rx, 0.0)?;
+            let ry = length(element, Attribute::Ry, 0.0)?;
+            Some(BoundingBox::new(
+                length(element, Attribute::Cx, 0.0)? - rx,
+                length(element, Attribute::Cy, 0.0)? - ry,
+                2.0 * rx,
+                2.0 * ry,
+            ))
+        }
+        TagName::Line => BoundingBox::from_points(&[
+            (
+                length(element, Attribute::X1, 0.0)?,
+                length(element, Attribute::Y1, 0.0)?,
+            ),
+            (
+                length(element, Attribute::X2, 0.0)?,
+                length(element, Attribute::Y2, 0.0)?,
+            ),
+        ]),
+        TagName::Polyline | TagName::Polygon => {
+            BoundingBox::from_points(&points(element.attributes.get(&Attribute::Points)?)?)
+        }
+        TagName::G | TagName::A | TagName::Switch => {
+            element
+                .children
+                .iter()
+                .try_fold(None, |bounds: Option<BoundingBox>, child| {
+                    if child.attributes.contains_key(&Attribute::Transform) {
+                        return None;
+                    }
+
+                    let child_bounds = bounding_box(child)?;
+                    Some(Some(match bounds {
+                        Some(bounds) => bounds.union(&child_bounds),
+                        None => child_bounds,
+                    }))
+                })?
+        }
+        _ => None,
+    }
+}
+
+/// Returns the radii of the rounded corners of a `<rect>` with a positive size, or None if they
+/// are not in user units
+fn corner_radii(element: &Element, width: f64, height: f64) -> Option<(f64, f64)> {
+    // A missing radius is the same as the other one
+    let (rx, ry) = match (
+        element.attributes.get(&Attribute::Rx),
+        element.attributes.get(&Attribute::Ry),
+    ) {
+        (None, None) => (0.0, 0.0),
+        (Some(rx), None) => (user_units(rx)?, user_units(rx)?),
+        (None, Some(ry)) => (user_units(ry)?, user_units(ry)?),
+        (Some(rx), Some(ry)) => (user_units(rx)?, user_units(ry)?),
+    };
+    Some((rx.max(0.0).min(width / 2.0), ry.max(0.0).min(height / 2.0)))
+}
+
+/// Returns the perimeter of an ellipse, using Ramanujan's approximation
+fn ellipse_perimeter(rx: f64, ry: f64) -> f64 {
+    if rx + ry == 0.0 {
+        return 0.0;
+    }
+    let h = ((rx - ry) / (rx + ry)).powi(2);
+    std::f64::consts::PI * (rx + ry) * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()))
+}
+
+/// Returns the length of the outline of an element, as drawn by a stroke, like `getTotalLength()`
+/// in the browser
+///
+/// # Note / Arguments
+/// Supported are `<path>`, `<rect>` (also with rounded corners), `<circle>`, `<ellipse>`,
+/// `<line>`, `<polyline>` and `<polygon>`. Returns None for other elements, invalid path data
+/// and lengths which are not in user units. The `pathLength` attribute and the `transform` of
+/// the element are not taken into account.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::geometry;
+/// use svg_definitions::prelude::*;
+///
+/// let polyline = SVGElem::new(Tag::Polyline).set(Attr::Points, "0,0 3,4 3,10");
+/// assert_eq!(geometry::total_length(&polyline), Some(11.0));
+///
+/// let path = SVGElem::new(Tag::Path).set(Attr::D, "M 0 0 H 10 V 10");
+/// assert_eq!(geometry::total_length(&path), Some(20.0));
+/// ```
+pub fn total_length(element: &Element) -> Option<f64> {
+    let polyline_length = |points: &[(f64, f64)]| -> f64 {
+        points
+            .windows(2)
+            .map(|pair| (pair[1].0 - pair[0].0).hypot(pair[1].1 - pair[0].1))
+            .sum()
+    };
+
+    match element.tag_name {
+        TagName::Path => {
+            let d = element.attributes.get(&Attribute::D)?;
+            d.parse::<PathDefinitionString>()
+                .ok()
+                .map(|path| path.length())
+        }
+        TagName::Rect => {
+            let width = length(element, Attribute::Width, 0.0)?;
+            let height = length(element, Attribute::Height, 0.0)?;
+            if width <= 0.0 || height <= 0.0 {
+                return Some(0.0);
+            }
+
+            let (rx, ry) = corner_radii(element, width, height)?;
+            if rx == 0.0 || ry == 0.0 {
+                return Some(2.0 * (width + height));
+            }
+            Some(2.0 * (width - 2.0 * rx) + 2.0 * (height - 2.0 * ry) + ellipse_perimeter(rx, ry))
+        }
+        TagName::Circle => {
+            Some(2.0 * std::f64::consts::PI * length(element, Attribute::R, 0.0)?.max(0.0))
+        }
+        TagName::Ellipse => Some(ellipse_perimeter(
+            length(element, Attribute::Rx, 0.0)?.max(0.0),
+            length(element, Attribute::Ry, 0.0)?.max(0.0),
+        )),
+        TagName::Line => Some(polyline_length(&[
+            (
+                length(element, Attribute::X1, 0.0)?,
+                length(element, Attribute::Y1, 0.0)?,
+            ),
+            (
+                length(element, Attribute::X2, 0.0)?,
+                length(element, Attribute::Y2, 0.0)?,
+            ),
+        ])),
+        TagName::Polyline => Some(polyline_length(&points(
+            element.attributes.get(&Attribute::Points)?,
+        )?)),
+        TagName::Polygon => {
+            let mut points = points(element.attributes.get(&Attribute::Points)?)?;
+            if let Some(first) = points.first().cloned() {
+                points.push(first);
+            }
+            Some(polyline_length(&points))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the outline of a shape as path data in its own user space, or None for other elements,
+/// shapes which are not rendered and lengths which are not in user units
+fn outline(element: &Element) -> Option<PathDefinitionString> {
+    let path = PathDefinitionString::new();
+    match element.tag_name {
+        TagName::Path => element.attributes.get(&Attribute::D)?.parse().ok(),
+        TagName::Rect | TagName::Image => {
+            let (x, y) = (
+                length(element, Attribute::X, 0.0)?,
+                length(element, Attribute::Y, 0.0)?,
+            );
+            let width = length(element, Attribute::Width, 0.0)?;
+            let height = length(element, Attribute::Height, 0.0)?;
+            if width <= 0.0 || height <= 0.0 {
+                return None;
+            }
+
+            let (rx, ry) = match element.tag_name {
+                TagName::Rect => corner_radii(element, width, height)?,
+                _ => (0.0, 0.0),
+            };
+            if rx == 0.0 || ry == 0.0 {
+                return Some(
+                    path.move_to((x, y))
+                        .horizontal_line_to(x + width)
+                        .vertical_line_to(y + height)
+                        .horizontal_line_to(x)
+                        .close_path(),
+                );
+            }
+            let (right, bottom) = (x + width, y + height);
+            Some(
+                path.move_to((x + rx, y))
+                    .horizontal_line_to(right - rx)
+                    .arc_to((right, y + ry), (rx, ry), 0.0, false, true)
+                    .vertical_line_to(bottom - ry)
+                    .arc_to((right - rx, bottom), (rx, ry), 0.0, false, true)
+                    .horizontal_line_to(x + rx)
+                    .arc_to((x, bottom - ry), (rx, ry), 0.0, false, true)
+                    .vertical_line_to(y + ry)
+                    .arc_to((x + rx, y), (rx, ry), 0.0, false, true)
+                    .close_path(),
+            )
+        }
+        TagName::Circle | TagName::Ellipse => {
+            let (cx, cy) = (
+                length(element, Attribute::Cx, 0.0)?,
+                length(element, Attribute::Cy, 0.0)?,
+            );
+            let (rx, ry) = match element.tag_name {
+                TagName::Circle => {
+                    let r = length(element, Attribute::R, 0.0)?;
+                    (r, r)
+                }
+                _ => (
+                    length(element, Attribute::Rx, 0.0)?,
+                    length(element, Attribute::Ry, 0.0)?,
+                ),
+            };
+            if rx <= 0.0 || ry <= 0.0 {
+                return None;
+            }
+            Some(
+                path.move_to((cx + rx, cy))
+                    .arc_to((cx - rx, cy), (rx, ry), 0.0, false, true)
+                    .arc_to((cx + rx, cy), (rx, ry), 0.0, false, true)
+                    .close_path(),
+            )
+        }
+        TagName::Line => Some(
+            path.move_to((
+                length(element, Attribute::X1, 0.0)?,
+                length(element, Attribute::Y1, 0.0)?,
+            ))
+            .line_to((
+                length(element, Attribute::X2, 0.0)?,
+                length(element, Attribute::Y2, 0.0)?,
+            )),
+        ),
+        TagName::Polyline | TagName::Polygon => {
+            let points = points(element.attributes.get(&Attribute::Points)?)?;
+            let (first, rest) = points.split_first()?;
+            let path = rest
+                .iter()
+                .fold(path.move_to(*first), |path, point| path.line_to(*point));
+            match element.tag_name {
+                TagName::Polygon => Some(path.close_path()),
+                _ => Some(path),
+            }
+        }
+        _ => None,
+    }
+}
+
+impl Element {
+    /// Returns the bounding box of the fill geometry of this element and all its descendants, in
+    /// the user space of this element, like `getBBox()` in the browser
+    ///
+    /// # Note / Arguments
+    /// The geometry of `<path>`, `<rect>`, `<image>`, `<circle>`, `<ellipse>`, `<line>`,
+    /// `<polyline>` and `<polygon>` elements is combined, where the `transform` attributes of the
+    /// descendants are applied. Groups (`<g>`, `<a>` and `<switch>`) are entered, while the
+    /// content of other elements, like `<defs>`, `<clipPath>`, nested `<svg>` and text, is left
+    /// out. Shapes with lengths which are not in user units are left out as well. Returns None
+    /// if there is no geometry. Strokes and the own `transform` of this element are not taken
+    /// into account.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    /// use svg_definitions::view_box::ViewBoxProps;
+    ///
+    /// let svg = SVGElem::new(Tag::Svg)
+    ///     .append(SVGElem::new(Tag::Circle).set(Attr::R, 10))
+    ///     .append(
+    ///         SVGElem::new(Tag::G).set(Attr::Transform, "translate(50 0) rotate(45)").append(
+    ///             SVGElem::new(Tag::Rect).set(Attr::Width, 20).set(Attr::Height, 20),
+    ///         ),
+    ///     );
+    ///
+    /// // Size the view box to the content
+    /// let bounds = svg.bounding_box().unwrap();
+    /// let svg = svg.set(Attr::ViewBox, ViewBoxProps::fit(&bounds));
+    /// # assert_eq!((bounds.x, bounds.y), (-10.0, -10.0));
+    /// # assert!((bounds.right() - (50.0 + 10.0 * 2f64.sqrt())).abs() < 1e-9);
+    /// # assert!((bounds.bottom() - 20.0 * 2f64.sqrt()).abs() < 1e-9);
+    /// ```
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let own = outline(self).and_then(|path| path.bounding_box());
+        union_children(self, Matrix::IDENTITY, own)
+    }
+
+    /// Converts a basic shape into an equivalent `<path>`, e.g. before morphing or clipping
+    /// shapes, returning None for other elements
+    ///
+    /// # Note / Arguments
+    /// Supported are `<rect>` (also with rounded corners), `<circle>`, `<ellipse>`, `<line>`,
+    /// `<polyline>` and `<polygon>`, while a `<path>` is returned as it is. The attributes which
+    /// describe the geometry are replaced by `d`, and all other attributes, like `transform`
+    /// and `fill`, are kept. Numbers are written with up to 6 decimals. Also returns None for
+    /// shapes which are not rendered, like a `<rect>` without a size, and for lengths which are
+    /// not in user units.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let rect = SVGElem::new(Tag::Rect)
+    ///     .set(Attr::Width, 20)
+    ///     .set(Attr::Height, 10)
+    ///     .set(Attr::Rx, 2)
+    ///     .set(Attr::Fill, "teal");
+    /// let path = rect.to_path().unwrap();
+    ///
+    /// assert_eq!(
+    ///     path.to_string(),
+    ///     "<path d=\"M 2 0 H 18 A 2 2 0 0 1 20 2 V 8 A 2 2 0 0 1 18 10 H 2 A 2 2 0 0 1 0 8 \
+    ///      V 2 A 2 2 0 0 1 2 0 Z\" fill=\"teal\"/>"
+    /// );
+    /// assert!(SVGElem::new(Tag::Text).to_path().is_none());
+    /// ```
+    pub fn to_path(&self) -> Option<Element> {
+        let geometry: &[Attribute] = match self.tag_name {
+            TagName::Path => return Some(self.clone()),
+            TagName::Rect => &[
+                Attribute::X,
+                Attribute::Y,
+                Attribute::Width,
+                Attribute::Height,
+                Attribute::Rx,
+                Attribute::Ry,
+            ],
+            TagName::Circle => &[Attribute::Cx, Attribute::Cy, Attribute::R],
+            TagName::Ellipse => &[Attribute::Cx, Attribute::Cy, Attribute::Rx, Attribute::Ry],
+            TagName::Line => &[Attribute::X1, Attribute::Y1, Attribute::X2, Attribute::Y2],
+            TagName::Polyline | TagName::Polygon => &[Attribute::Points],
+            _ => return None,
+        };
+        let d = outline(self)?.with_precision(6);
+
+        let mut path = self.clone();
+        path.tag_name = TagName::Path;
+        for attribute in geometry {
+            path.attributes.remove(attribute);
+        }
+        Some(path.set(Attribute::D, d))
+    }
+}
+
+/// Returns the bounding box of an element and its descendants like
+/// [Element::bounding_box](../struct.Element.html#method.bounding_box), where `matrix` maps the
+/// user space of the element to the space of the bounding box
+pub(crate) fn transformed_bounding_box(element: &Element, matrix: Matrix) -> Option<BoundingBox> {
+    let own =
+        outline(element).and_then(|path| path.transformed(&Transform::from(matrix)).bounding_box());
+    union_children(element, matrix, own)
+}
+
+/// Returns the union of two optional bounding boxes
+fn union(bounds: Option<BoundingBox>, other: Option<BoundingBox>) -> Option<BoundingBox> {
+    match (bounds, other) {
+        (Some(bounds), Some(other)) => Some(bounds.union(&other)),
+        (bounds, other) => bounds.or(other),
+    }
+}
+
+/// Adds the bounds of the descendants of an element to `bounds`, where `matrix` maps the user
+/// space of the element to the space of the bounds
+fn union_children(
+    element: &Element,
+    matrix: Matrix,
+    bounds: Option<BoundingBox>,
+) -> Option<BoundingBox> {
+    element.children.iter().fold(bounds, |bounds, child| {
+        let matrix = match child.get_transform() {
+            Some(transforms) => matrix * transforms.to_matrix(),
+            None => matrix,
+        };
+        let own = outline(child)
+            .and_then(|path| path.transformed(&Transform::from(matrix)).bounding_box());
+        match child.tag_name {
+            TagName::G | TagName::A | TagName::Switch => {
+                union_children(child, matrix, union(bounds, own))
+            }
+            _ => union(bounds, own),
+        }
+    })
+}
+
+/// The center parameterization of an elliptical arc, as used by most rendering APIs
+///
+/// # Note / Arguments
+/// The `x_axis_rotation` is in degrees like in path data, while the angles are in radians. The
+/// angles are measured on the unrotated ellipse, so `point(start_angle)` is the start of the arc.
+/// A positive `sweep_angle` goes in the direction of positive angles, which is clockwise on
+/// screen since the y axis points down.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CenterArc {
+    pub center: (f64, f64),
+    pub radii: (f64, f64),
+    pub x_axis_rotation: f64,
+    pub start_angle: f64,
+    pub sweep_angle: f64,
+}
+
+impl CenterArc {
+    /// Returns the point on the ellipse at an angle in radians
+    pub fn point(&self, angle: f64) -> (f64, f64) {
+        let (sin, cos) = self.x_axis_rotation.to_radians().sin_cos();
+        let (x, y) = (self.radii.0 * angle.cos(), self.radii.1 * angle.sin());
+        (
+            self.center.0 + cos * x - sin * y,
+            self.center.1 + sin * x + cos * y,
+        )
+    }
+
+    /// Returns the derivative of the point on the ellipse at an angle in radians, i.e. the
+    /// tangent in the direction of positive angles
+    pub fn derivative(&self, angle: f64) -> (f64, f64) {
+        let (sin, cos) = self.x_axis_rotation.to_radians().sin_cos();
+        let (x, y) = (-self.radii.0 * angle.sin(), self.radii.1 * angle.cos());
+        (cos * x - sin * y, sin * x + cos * y)
+    }
+
+    /// Returns the end angle in radians
+    #[inline]
+    pub fn end_angle(&self) -> f64 {
+        self.start_angle + self.sweep_angle
+    }
+}
+
+/// The endpoint parameterization of an elliptical arc, as used by the `A` command of path data
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EndpointArc {
+    pub from: (f64, f64),
+    pub radii: (f64, f64),
+    pub x_axis_rotation: f64,
+    pub large_arc_flag: bool,
+    pub sweep_flag: bool,
+    pub to: (f64, f64),
+}
+
+/// Converts an arc from its endpoint parameterization to its center parameterization, following
+/// the SVG implementation notes
+///
+/// # Note / Arguments
+/// The arguments are those of an absolute `A` command starting at `from`. Negative radii are made
+/// positive and radii which are too small to reach `to` are scaled up, like renderers do. Returns
+/// None when the arc is drawn as a straight line, because a radius is zero, or omitted, because
+/// `from` and `to` are equal.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::geometry::arc_endpoint_to_center;
+///
+/// // The upper half of a circle around (5, 0)
+/// let arc = arc_endpoint_to_center((0.0, 0.0), (5.0, 5.0), 0.0, false, true, (10.0, 0.0)).unwrap();
+///
+/// # assert!((arc.center.0 - 5.0).abs() < 1e-9 && arc.center.1.abs() < 1e-9);
+/// # assert!((arc.sweep_angle - std::f64::consts::PI).abs() < 1e-9);
+/// let (x, y) = arc.point(arc.start_angle);
+/// assert!(x.abs() < 1e-9 && y.abs() < 1e-9);
+/// ```
+pub fn arc_endpoint_to_center(
+    from: (f64, f64),
+    (rx, ry): (f64, f64),
+    x_axis_rotation: f64,
+    large_arc_flag: bool,
+    sweep_flag: bool,
+    to: (f64, f64),
+) -> Option<CenterArc> {
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if from == to || rx == 0.0 || ry == 0.0 {
+        return None;
+    }
+
+    let (sin, cos) = x_axis_rotation.to_radians().sin_cos();
+    let (dx, dy) = ((from.0 - to.0) / 2.0, (from.1 - to.1) / 2.0);
+    let x1 = cos * dx + sin * dy;
+    let y1 = -sin * dx + cos * dy;
+
+    // Scale up radii which are too small to reach the endpoint
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let sign = if large_arc_flag == sweep_flag {
+        -1.0
+    } else {
+        1.0
+    };
+    let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+
+    let cx1 = coefficient * rx * y1 / ry;
+    let cy1 = -coefficient * ry * x1 / rx;
+    let center = (
+        cos * cx1 - sin * cy1 + (from.0 + to.0) / 2.0,
+        sin * cx1 + cos * cy1 + (from.1 + to.1) / 2.0,
+    );
+
+    let angle =
+        |(ux, uy): (f64, f64), (vx, vy): (f64, f64)| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+    let start_vector = ((x1 - cx1) / rx, (y1 - cy1) / ry);
+    let end_vector = ((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+
+    let start_angle = angle((1.0, 0.0), start_vector);
+    let mut sweep_angle = angle(start_vector, end_vector);
+    if !sweep_flag && sweep_angle > 0.0 {
+        sweep_angle -= 2.0 * std::f64::consts::PI;
+    } else if sweep_flag && sweep_angle < 0.0 {
+        sweep_angle += 2.0 * std::f64::consts::PI;
+    }
+
+    Some(CenterArc {
+        center,
+        radii: (rx, ry),
+        x_axis_rotation,
+        start_angle,
+        sweep_angle,
+    })
+}
+
+/// Converts an arc from its center parameterization to its endpoint parameterization, the inverse
+/// of [arc_endpoint_to_center](fn.arc_endpoint_to_center.html)
+///
+/// # Note / Arguments
+/// A `sweep_angle` of a full turn or more cannot be expressed with a single `A` command, since
+/// its endpoints are equal and the arc would be omitted. Such arcs have to be split first.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::geometry::{arc_center_to_endpoint, CenterArc};
+///
+/// let arc = arc_center_to_endpoint(&CenterArc {
+///     center: (5.0, 0.0),
+///     radii: (5.0, 5.0),
+///     x_axis_rotation: 0.0,
+///     start_angle: std::f64::consts::PI,
+///     sweep_angle: std::f64::consts::PI,
+/// });
+///
+/// assert!(!arc.large_arc_flag && arc.sweep_flag);
+/// # assert!((arc.from.0 - 0.0).abs() < 1e-9 && (arc.to.0 - 10.0).abs() < 1e-9);
+/// ```
+pub fn arc_center_to_endpoint(arc: &CenterArc) -> EndpointArc {
+    EndpointArc {
+        from: arc.point(arc.start_angle),
+        radii: (arc.radii.0.abs(), arc.radii.1.abs()),
+        x_axis_rotation: arc.x_axis_rotation,
+        large_arc_flag: arc.sweep_angle.abs() > std::f64::consts::PI,
+        sweep_flag: arc.sweep_angle > 0.0,
+        to: arc.point(arc.end_angle()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        arc_center_to_endpoint, arc_endpoint_to_center, bounding_box, total_length, view_box,
+        BoundingBox,
+    };
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    #[test]
+    fn test_bounding_box() {
+        let group = Element::new(TagName::G)
+            .append(
+                Element::new(TagName::Rect)
+                    .set(Attribute::X, "1px")
+                    .set(Attribute::Width, 4)
+                    .set(Attribute::Height, 2),
+            )
+            .append(Element::new(TagName::Polygon).set(Attribute::Points, "0,5 3,-1 2 2"))
+            .append(
+                Element::new(TagName::Line)
+                    .set(Attribute::X1, 6)
+                    .set(Attribute::Y1, 1),
+            );
+        assert_eq!(
+            bounding_box(&group),
+            Some(BoundingBox::new(0.0, -1.0, 6.0, 6.0))
+        );
+
+        let transformed = group
+            .clone()
+            .append(Element::new(TagName::Rect).set(Attribute::Transform, "scale(2)"));
+        assert_eq!(bounding_box(&transformed), None);
+        assert_eq!(
+            bounding_box(&Element::new(TagName::Rect).set(Attribute::Width, "50%")),
+            None
+        );
+        assert_eq!(bounding_box(&Element::new(TagName::G)), None);
+        assert_eq!(bounding_box(&Element::new(TagName::Path)), None);
+
+        assert_eq!(
+            view_box(&Element::new(TagName::Svg).set(Attribute::ViewBox, "-5,0 20 10")),
+            Some(BoundingBox::new(-5.0, 0.0, 20.0, 10.0))
+        );
+        assert_eq!(
+            view_box(&Element::new(TagName::Svg).set(Attribute::ViewBox, "0 0 0 10")),
+            None
+        );
+
+        let outer = BoundingBox::new(0.0, 0.0, 10.0, 10.0);
+        assert!(outer.contains(&BoundingBox::new(2.0, 2.0, 8.0, 8.0)));
+        assert!(!outer.contains(&BoundingBox::new(2.0, 2.0, 9.0, 8.0)));
+        assert!(outer.intersects(&BoundingBox::new(10.0, 5.0, 1.0, 1.0)));
+        assert!(!outer.intersects(&BoundingBox::new(11.0, 5.0, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_total_length() {
+        let rect = Element::new(TagName::Rect)
+            .set(Attribute::Width, 10)
+            .set(Attribute::Height, 4);
+        assert_eq!(total_length(&rect), Some(28.0));
+
+        // Radii are clamped to half of the size, so this is a circle
+        let rounded = rect
+            .clone()
+            .set(Attribute::Height, 10)
+            .set(Attribute::Rx, 20);
+        let length = total_length(&rounded).unwrap();
+        assert!((length - 10.0 * std::f64::consts::PI).abs() < 1e-9);
+
+        let ellipse = Element::new(TagName::Ellipse)
+            .set(Attribute::Rx, 10)
+            .set(Attribute::Ry, 5);
+        assert!((total_length(&ellipse).unwrap() - 48.4422).abs() < 1e-3);
+
+        let polygon = Element::new(TagName::Polygon).set(Attribute::Points, "0,0 3,0 3,4");
+        assert_eq!(total_length(&polygon), Some(12.0));
+
+        let path = Element::new(TagName::Path).set(Attribute::D, "M 0 0 L 1 1 X");
+        assert_eq!(total_length(&path), None);
+        assert_eq!(total_length(&Element::new(TagName::G)), None);
+    }
+
+    #[test]
+    fn test_arc_parameterizations() {
+        let close =
+            |a: (f64, f64), b: (f64, f64)| (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9;
+
+        // Every combination of flags on a rotated ellipse survives a round trip
+        for &(large_arc_flag, sweep_flag) in
+            &[(false, false), (false, true), (true, false), (true, true)]
+        {
+            let arc = arc_endpoint_to_center(
+                (1.0, 2.0),
+                (6.0, 3.0),
+                30.0,
+                large_arc_flag,
+                sweep_flag,
+                (7.0, 5.0),
+            )
+            .unwrap();
+            assert!(close(arc.point(arc.start_angle), (1.0, 2.0)));
+            assert!(close(arc.point(arc.end_angle()), (7.0, 5.0)));
+
+            let endpoint = arc_center_to_endpoint(&arc);
+            assert_eq!(endpoint.large_arc_flag, large_arc_flag);
+            assert_eq!(endpoint.sweep_flag, sweep_flag);
+            assert_eq!(endpoint.radii, (6.0, 3.0));
+            assert!(close(endpoint.from, (1.0, 2.0)));
+            assert!(close(endpoint.to, (7.0, 5.0)));
+        }
+
+        // Radii which are too small are scaled up to a half ellipse
+        let arc =
+            arc_endpoint_to_center((0.0, 0.0), (-1.0, 1.0), 0.0, true, false, (10.0, 0.0)).unwrap();
+        assert!(close(arc.center, (5.0, 0.0)));
+        assert!(close(arc.radii, (5.0, 5.0)));
+        assert!((arc.sweep_angle + std::f64::consts::PI).abs() < 1e-9);
+
+        assert_eq!(
+            arc_endpoint_to_center((0.0, 0.0), (0.0, 1.0), 0.0, false, false, (1.0, 0.0)),
+            None
+        );
+        assert_eq!(
+            arc_endpoint_to_center((1.0, 0.0), (1.0, 1.0), 0.0, false, false, (1.0, 0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_element_bounding_box() {
+        let close = |bounds: BoundingBox, expected: BoundingBox| {
+            let (left, right) = (
+                [bounds.x, bounds.y, bounds.width, bounds.height],
+                [expected.x, expected.y, expected.width, expected.height],
+            );
+            for (left, right) in left.iter().zip(right.iter()) {
+                assert!(
+                    (left - right).abs() < 1e-6,
+                    "{:?} != {:?}",
+                    bounds,
+                    expected
+                );
+            }
+        };
+
+        let rounded = Element::new(TagName::Rect)
+            .set(Attribute::Width, 10)
+            .set(Attribute::Height, 4)
+            .set(Attribute::Rx, 2)
+            .set(Attribute::Transform, "rotate(90)");
+        let svg = Element::new(TagName::Svg)
+            .append(
+                Element::new(TagName::G)
+                    .set(Attribute::Transform, "translate(100 0) scale(2)")
+                    .append(rounded),
+            )
+            .append(
+                Element::new(TagName::Defs)
+                    .append(Element::new(TagName::Circle).set(Attribute::R, 500)),
+            )
+            .append(Element::new(TagName::Rect).set(Attribute::Width, "50%"))
+            .append(
+                Element::new(TagName::Path)
+                    .set(Attribute::D, "M 0 50 Q 10 70 20 50")
+                    .set(Attribute::Transform, "skewX(0)"),
+            );
+        close(
+            svg.bounding_box().unwrap(),
+            BoundingBox::new(0.0, 0.0, 100.0, 60.0),
+        );
+
+        let ellipse = Element::new(TagName::Ellipse)
+            .set(Attribute::Cx, 5)
+            .set(Attribute::Rx, 5)
+            .set(Attribute::Ry, 2);
+        close(
+            ellipse.bounding_box().unwrap(),
+            BoundingBox::new(0.0, -2.0, 10.0, 4.0),
+        );
+
+        assert_eq!(Element::new(TagName::Svg).bounding_box(), None);
+        assert_eq!(Element::new(TagName::Circle).bounding_box(), None);
+    }
+
+    #[test]
+    fn test_to_path() {
+        let d =
+            |element: Element| element.to_path().unwrap().get_attributes()[&Attribute::D].clone();
+
+        let circle = Element::new(TagName::Circle)
+            .set(Attribute::Cx, 5)
+            .set(Attribute::Cy, 5)
+            .set(Attribute::R, 2.5)
+            .set(Attribute::Id, "dot");
+        let path = circle.to_path().unwrap();
+        assert_eq!(*path.get_tag_name(), TagName::Path);
+        assert_eq!(path.get_attributes().len(), 2);
+        assert_eq!(
+            path.get_attributes()[&Attribute::D],
+            "M 7.5 5 A 2.5 2.5 0 0 1 2.5 5 A 2.5 2.5 0 0 1 7.5 5 Z"
+        );
+
+        let polygon = Element::new(TagName::Polygon).set(Attribute::Points, "0,0 4,0 4,3");
+        assert_eq!(d(polygon), "M 0 0 L 4 0 L 4 3 Z");
+        let polyline = Element::new(TagName::Polyline).set(Attribute::Points, "0,0 4,0 4,3");
+        assert_eq!(d(polyline), "M 0 0 L 4 0 L 4 3");
+        let line = Element::new(TagName::Line)
+            .set(Attribute::X2, 1)
+            .set(Attribute::Y2, 0.125);
+        assert_eq!(d(line), "M 0 0 L 1 0.125");
+        let ellipse = Element::new(TagName::Ellipse)
+            .set(Attribute::Rx, 2)
+            .set(Attribute::Ry, 1);
+        assert_eq!(d(ellipse), "M 2 0 A 2 1 0 0 1 -2 0 A 2 1 0 0 1 2 0 Z");
+
+        // A missing radius is the same as the other one, before both are clamped
+        let rect = Element::new(TagName::Rect)
+            .set(Attribute::Width, 4)
+            .set(Attribute::Height, 2)
+            .set(Attribute::Ry, 5)
+            .set(Attribute::Transform, "scale(2)");
+        let path = rect.to_path().unwrap();
+        assert_eq!(
+            path.get_attributes()[&Attribute::D],
+            "M 2 0 H 2 A 2 1 0 0 1 4 1 V 1 A 2 1 0 0 1 2 2 H 2 A 2 1 0 0 1 0 1 V 1 A 2 1 0 0 1 2 0 Z"
+        );
+        assert_eq!(path.get_attributes()[&Attribute::Transform], "scale(2)");
+
+        let path = Element::new(TagName::Path).set(Attribute::D, "M0,0");
+        assert_eq!(d(path), "M0,0");
+        assert!(Element::new(TagName::Rect).to_path().is_none());
+        assert!(Element::new(TagName::Circle)
+            .set(Attribute::R, "1em")
+            .to_path()
+            .is_none());
+        assert!(Element::new(TagName::G).to_path().is_none());
+    }
+}
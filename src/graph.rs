@@ -0,0 +1,299 @@
+//! This module provides the [ReferenceGraph](struct.ReferenceGraph.html) of a
+//! [Document](../document/struct.Document.html), which records which elements reference which
+//! ids, e.g. shapes using gradients, filters and markers or `<use>` elements using symbols.
+//!
+//! # Examples
+//! ## Finding what a symbol depends on
+//! ```
+//! use svg_definitions::prelude::*;
+//!
+//! let document = SVGDoc::new(
+//!     SVGElem::new(Tag::Svg)
+//!         .append(
+//!             SVGElem::new(Tag::Defs)
+//!                 .append(SVGElem::new(Tag::LinearGradient).set(Attr::Id, "base"))
+//!                 .append(
+//!                     SVGElem::new(Tag::LinearGradient)
+//!                         .set(Attr::Id, "shade")
+//!                         .set(Attr::Href, "#base"),
+//!                 )
+//!                 .append(
+//!                     SVGElem::new(Tag::Symbol)
+//!                         .set(Attr::Id, "icon")
+//!                         .append(SVGElem::new(Tag::Rect).set(Attr::Fill, "url(#shade)")),
+//!                 ),
+//!         )
+//!         .append(SVGElem::new(Tag::Use).set(Attr::Href, "#icon")),
+//! );
+//!
+//! let graph = document.reference_graph();
+//! assert_eq!(graph.dependencies("icon"), vec!["base", "shade"]);
+//! assert_eq!(graph.referencing("icon").count(), 1);
+//! println!("{}", graph.to_dot());
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::attributes::Attribute;
+use crate::references::url_targets;
+use crate::tag_name::TagName;
+use crate::tree::ElementPath;
+use crate::Element;
+
+/// A reference from an element to an id
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Reference {
+    /// The path of the element containing the reference
+    pub path: ElementPath,
+
+    /// The attribute containing the reference, or None for the stylesheet of a `<style>` element
+    pub attribute: Option<Attribute>,
+
+    /// The referenced id
+    pub id: String,
+}
+
+/// A directed graph from elements to the ids they reference
+///
+/// # Note / Arguments
+/// References are `#id` values of `href` and `xlink:href` attributes and `url(#id)` values of
+/// other attributes, inline styles and stylesheets. References to ids which do not exist in the
+/// document are kept and listed by [dangling](#method.dangling).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceGraph {
+    /// The path of the element with every id, where the first element wins for duplicated ids
+    pub ids: BTreeMap<String, ElementPath>,
+
+    /// The references in document order
+    pub references: Vec<Reference>,
+}
+
+impl ReferenceGraph {
+    /// Creates the reference graph of an element and all its descendants
+    pub fn new(root: &Element) -> ReferenceGraph {
+        let mut graph = ReferenceGraph {
+            ids: BTreeMap::new(),
+            references: Vec::new(),
+        };
+        graph.add_element(root, &ElementPath::new());
+        graph
+    }
+
+    fn add_element(&mut self, element: &Element, path: &ElementPath) {
+        if let Some(id) = element.attributes.get(&Attribute::Id) {
+            self.ids
+                .entry(id.trim().to_string())
+                .or_insert_with(|| path.clone());
+        }
+
+        let mut attributes: Vec<_> = element.attributes.iter().collect();
+        attributes.sort_by_key(|(attribute, _)| attribute.to_string());
+        for (attribute, value) in attributes {
+            let ids = match attribute {
+                Attribute::Href | Attribute::XlinkHref => {
+                    value.trim().strip_prefix('#').into_iter().collect()
+                }
+                _ => local_ids(value),
+            };
+            self.references.extend(ids.into_iter().map(|id| Reference {
+                path: path.clone(),
+                attribute: Some(attribute.clone()),
+                id: id.to_string(),
+            }));
+        }
+
+        if element.tag_name == TagName::Style {
+            if let Some(css) = &element.inner {
+                self.references
+                    .extend(local_ids(css).into_iter().map(|id| Reference {
+                        path: path.clone(),
+                        attribute: None,
+                        id: id.to_string(),
+                    }));
+            }
+        }
+
+        for (index, child) in element.children.iter().enumerate() {
+            self.add_element(child, &path.child(index));
+        }
+    }
+
+    /// Returns the references to an id
+    pub fn referencing<'a>(&'a self, id: &'a str) -> impl Iterator<Item = &'a Reference> {
+        self.references
+            .iter()
+            .filter(move |reference| reference.id == id)
+    }
+
+    /// Returns the references made by the element at a path, without those of its descendants
+    pub fn references_from<'a>(
+        &'a self,
+        path: &'a ElementPath,
+    ) -> impl Iterator<Item = &'a Reference> {
+        self.references
+            .iter()
+            .filter(move |reference| reference.path == *path)
+    }
+
+    /// Returns the references to ids which do not exist in the document
+    pub fn dangling(&self) -> Vec<&Reference> {
+        self.references
+            .iter()
+            .filter(|reference| !self.ids.contains_key(&reference.id))
+            .collect()
+    }
+
+    /// Returns the ids which are not referenced from anywhere in the document
+    pub fn unreferenced(&self) -> Vec<&str> {
+        let referenced: BTreeSet<&str> = self
+            .references
+            .iter()
+            .map(|reference| &reference.id[..])
+            .collect();
+        self.ids
+            .keys()
+            .map(|id| &id[..])
+            .filter(|id| !referenced.contains(id))
+            .collect()
+    }
+
+    /// Returns the sorted ids which the element with an id needs to render, directly or through
+    /// other references, including references made by its descendants
+    ///
+    /// # Note / Arguments
+    /// This is the set of definitions which has to be copied along when the element is extracted
+    /// into another document. Ids which do not exist in the document are left out, and the id
+    /// itself is only included if it is part of a reference cycle.
+    pub fn dependencies(&self, id: &str) -> Vec<&str> {
+        let mut found: BTreeSet<&str> = BTreeSet::new();
+        let mut pending = vec![id];
+
+        while let Some(current) = pending.pop() {
+            let path = match self.ids.get(current) {
+                Some(path) => path,
+                None => continue,
+            };
+            for reference in self.references.iter() {
+                if reference.path.starts_with(path)
+                    && self.ids.contains_key(&reference.id)
+                    && found.insert(&reference.id)
+                {
+                    pending.push(&reference.id);
+                }
+            }
+        }
+
+        found.into_iter().collect()
+    }
+
+    /// Formats the graph in the DOT language of Graphviz, with an edge from every referencing
+    /// element to the element with the referenced id
+    ///
+    /// # Note / Arguments
+    /// Elements are named by their id, or by their [ElementPath](../tree/struct.ElementPath.html)
+    /// if they have none. Dangling references point to a dashed node.
+    pub fn to_dot(&self) -> String {
+        let paths: BTreeMap<&ElementPath, &str> =
+            self.ids.iter().map(|(id, path)| (path, &id[..])).collect();
+        let name = |path: &ElementPath| match paths.get(path) {
+            Some(id) => format!("{:?}", format!("#{}", id)),
+            None => format!("{:?}", path.to_string()),
+        };
+
+        let mut dot = String::from("digraph references {\n");
+        let dangling: BTreeSet<&str> = self
+            .dangling()
+            .into_iter()
+            .map(|reference| &reference.id[..])
+            .collect();
+        for id in dangling {
+            dot.push_str(&format!("    {:?} [style=dashed];\n", format!("#{}", id)));
+        }
+        for reference in self.references.iter() {
+            let label = match &reference.attribute {
+                Some(attribute) => attribute.to_string(),
+                None => String::from("style"),
+            };
+            let target = match self.ids.get(&reference.id) {
+                Some(path) => name(path),
+                None => format!("{:?}", format!("#{}", reference.id)),
+            };
+            dot.push_str(&format!(
+                "    {} -> {} [label={:?}];\n",
+                name(&reference.path),
+                target,
+                label
+            ));
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+/// Returns the ids of all `url(#id)` references in a value
+fn local_ids(value: &str) -> Vec<&str> {
+    url_targets(value)
+        .into_iter()
+        .filter_map(|url| url.strip_prefix('#'))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReferenceGraph;
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::tree::ElementPath;
+    use crate::Element;
+
+    #[test]
+    fn test_reference_graph() {
+        let root = Element::new(TagName::Svg)
+            .append(Element::new(TagName::Style).set_inner("rect { filter: url(#blur) }"))
+            .append(
+                Element::new(TagName::Defs)
+                    .append(
+                        Element::new(TagName::Filter)
+                            .set(Attribute::Id, "blur")
+                            .set(Attribute::Href, "#loop"),
+                    )
+                    .append(
+                        Element::new(TagName::Pattern)
+                            .set(Attribute::Id, "loop")
+                            .append(
+                                Element::new(TagName::Rect).set(Attribute::Filter, "url(#blur)"),
+                            ),
+                    )
+                    .append(Element::new(TagName::Marker).set(Attribute::Id, "arrow")),
+            )
+            .append(
+                Element::new(TagName::Path)
+                    .set(Attribute::MarkerEnd, "url(#arrow)")
+                    .set(Attribute::Fill, "url(#missing) red"),
+            );
+        let graph = ReferenceGraph::new(&root);
+
+        assert_eq!(graph.ids["loop"], ElementPath::from(vec![1, 1]));
+        assert_eq!(graph.referencing("blur").count(), 2);
+        assert_eq!(graph.referencing("blur").next().unwrap().attribute, None);
+        assert_eq!(
+            graph
+                .references_from(&ElementPath::from(vec![2]))
+                .map(|reference| &reference.id[..])
+                .collect::<Vec<_>>(),
+            vec!["missing", "arrow"]
+        );
+        assert_eq!(graph.dangling().len(), 1);
+        assert!(graph.unreferenced().is_empty());
+
+        // The cycle between the filter and the pattern includes the id itself
+        assert_eq!(graph.dependencies("blur"), vec!["blur", "loop"]);
+        assert!(graph.dependencies("arrow").is_empty());
+        assert!(graph.dependencies("unknown").is_empty());
+
+        let dot = graph.to_dot();
+        assert!(dot.contains("    \"#missing\" [style=dashed];\n"));
+        assert!(dot.contains("    \"/2\" -> \"#arrow\" [label=\"marker-end\"];\n"));
+        assert!(dot.contains("    \"/0\" -> \"#blur\" [label=\"style\"];\n"));
+    }
+}
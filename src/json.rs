@@ -0,0 +1,478 @@
+//! This module provides a JSON representation of [Elements](../struct.Element.html), as used by
+//! virtual DOM tools, without depending on serde.
+//!
+//! # Schema
+//! Every element is an object with the following keys:
+//! - `"tag"`: the tag name as a string, e.g. `"linearGradient"`
+//! - `"attrs"`: an object mapping attribute names to their values as strings
+//! - `"children"`: an array of child elements
+//! - `"inner"`: the inner text as a string, or `null`
+//! - `"raw"`: whether the inner text is XML which is written without escaping, as set by
+//!   [set_inner_raw](../struct.Element.html#method.set_inner_raw)
+//!
+//! Attributes are written sorted by name and all keys are always written. When reading, the
+//! `"attrs"`, `"children"`, `"inner"` and `"raw"` keys may be left out and unknown keys are
+//! ignored.
+//!
+//! # Examples
+//! ## Exporting a circle
+//! ```
+//! use svg_definitions::prelude::*;
+//!
+//! let circle = SVGElem::new(Tag::Circle).set(Attr::R, 5);
+//!
+//! assert_eq!(
+//!     circle.to_json(),
+//!     r#"{"tag":"circle","attrs":{"r":"5"},"children":[],"inner":null,"raw":false}"#
+//! );
+//! ```
+
+use std::fmt;
+
+use crate::attributes::{Attribute, AttributeValue};
+use crate::tag_name::string_to_tag_or_custom;
+use crate::Element;
+
+/// The error enum used when reading JSON
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonError {
+    /// The input is not valid JSON, at a certain byte offset
+    Syntax(usize),
+
+    /// An element has a tag name which is not a valid XML name
+    UnknownTag(String),
+
+    /// The JSON does not follow the schema, with a description of what is wrong
+    Schema(String),
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonError::Syntax(offset) => write!(f, "invalid JSON at byte {}", offset),
+            JsonError::UnknownTag(tag) => write!(f, "unknown tag name {:?}", tag),
+            JsonError::Schema(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+impl Element {
+    /// Converts the element and its descendants into JSON, following the schema of the
+    /// [json module](json/index.html)
+    pub fn to_json(&self) -> String {
+        let mut output = String::new();
+        write_element(self, &mut output);
+        output
+    }
+
+    /// Reads an element and its descendants from JSON, following the schema of the
+    /// [json module](json/index.html)
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let group = SVGElem::from_json(
+    ///     r#"{"tag":"g","children":[{"tag":"text","attrs":{"x":"1"},"inner":"Hello"}]}"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(group.to_string(), "<g><text x=\"1\">Hello</text></g>");
+    /// ```
+    pub fn from_json(json: &str) -> Result<Element, JsonError> {
+        let mut reader = Reader { json, offset: 0 };
+        let value = reader.value()?;
+        reader.whitespace();
+        if reader.offset != json.len() {
+            return Err(JsonError::Syntax(reader.offset));
+        }
+
+        element_from_value(value)
+    }
+}
+
+/// A parsed JSON value
+enum Value {
+    Null,
+    Bool(bool),
+    // The values of numbers are not needed by the schema
+    Number,
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+fn element_from_value(value: Value) -> Result<Element, JsonError> {
+    let fields = match value {
+        Value::Object(fields) => fields,
+        _ => {
+            return Err(JsonError::Schema(String::from(
+                "an element must be an object",
+            )))
+        }
+    };
+
+    let mut tag = None;
+    let mut attributes = Vec::new();
+    let mut children = Vec::new();
+    let mut inner = None;
+    let mut raw = false;
+
+    for (key, value) in fields {
+        match (key.as_str(), value) {
+            ("tag", Value::String(name)) => {
+                tag = Some(string_to_tag_or_custom(&name).ok_or(JsonError::UnknownTag(name))?)
+            }
+            ("attrs", Value::Object(values)) => {
+                for (name, value) in values {
+                    match value {
+                        Value::String(value) => attributes.push((Attribute::custom(&name), value)),
+                        _ => {
+                            return Err(JsonError::Schema(format!(
+                                "the value of attribute {:?} must be a string",
+                                name
+                            )))
+                        }
+                    }
+                }
+            }
+            ("children", Value::Array(values)) => {
+                for value in values {
+                    children.push(element_from_value(value)?);
+                }
+            }
+            ("inner", Value::String(text)) => inner = Some(text),
+            ("inner", Value::Null) => inner = None,
+            ("raw", Value::Bool(value)) => raw = value,
+            ("tag", _) | ("attrs", _) | ("children", _) | ("inner", _) | ("raw", _) => {
+                return Err(JsonError::Schema(format!(
+                    "the {:?} key has the wrong type",
+                    key
+                )))
+            }
+            _ => (),
+        }
+    }
+
+    let mut element = Element::new(
+        tag.ok_or_else(|| JsonError::Schema(String::from("an element must have a tag")))?,
+    );
+    element.attributes = attributes
+        .into_iter()
+        .map(|(attribute, value)| {
+            let value = AttributeValue::new(&attribute, value);
+            (attribute, value)
+        })
+        .collect();
+    element.children = children;
+    element.raw_inner = raw && inner.is_some();
+    element.inner = inner;
+    Ok(element)
+}
+
+fn write_element(element: &Element, output: &mut String) {
+    output.push_str("{\"tag\":");
+    write_string(&element.tag_name.to_string(), output);
+
+    output.push_str(",\"attrs\":{");
+    let mut attributes: Vec<(String, &str)> = element
+        .attributes
+        .iter()
+        .map(|(attribute, value)| (attribute.to_string(), value.as_str()))
+        .collect();
+    attributes.sort();
+    for (index, (name, value)) in attributes.into_iter().enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+        write_string(&name, output);
+        output.push(':');
+        write_string(value, output);
+    }
+
+    output.push_str("},\"children\":[");
+    for (index, child) in element.children.iter().enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+        write_element(child, output);
+    }
+
+    output.push_str("],\"inner\":");
+    match &element.inner {
+        Some(inner) => write_string(inner, output),
+        None => output.push_str("null"),
+    }
+    output.push_str(",\"raw\":");
+    output.push_str(if element.raw_inner { "true" } else { "false" });
+    output.push('}');
+}
+
+fn write_string(string: &str, output: &mut String) {
+    output.push('"');
+    for character in string.chars() {
+        match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            character if (character as u32) < 0x20 => {
+                output.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => output.push(character),
+        }
+    }
+    output.push('"');
+}
+
+/// A reader of JSON values, at a certain byte offset of the input
+struct Reader<'a> {
+    json: &'a str,
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn rest(&self) -> &'a str {
+        &self.json[self.offset..]
+    }
+
+    fn error<T>(&self) -> Result<T, JsonError> {
+        Err(JsonError::Syntax(self.offset))
+    }
+
+    fn whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), JsonError> {
+        self.whitespace();
+        if !self.rest().starts_with(token) {
+            return self.error();
+        }
+        self.offset += token.len();
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<Value, JsonError> {
+        self.whitespace();
+
+        match self.rest().chars().next() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => self.string().map(Value::String),
+            Some('n') => self.expect("null").map(|_| Value::Null),
+            Some('t') => self.expect("true").map(|_| Value::Bool(true)),
+            Some('f') => self.expect("false").map(|_| Value::Bool(false)),
+            Some(character) if character == '-' || character.is_ascii_digit() => {
+                let rest = self.rest();
+                let length = rest
+                    .find(|c: char| !(c.is_ascii_digit() || "+-.eE".contains(c)))
+                    .unwrap_or(rest.len());
+                if rest[..length].parse::<f64>().is_err() {
+                    return self.error();
+                }
+
+                self.offset += length;
+                Ok(Value::Number)
+            }
+            _ => self.error(),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, JsonError> {
+        self.expect("{")?;
+        let mut fields = Vec::new();
+
+        self.whitespace();
+        if self.rest().starts_with('}') {
+            self.offset += 1;
+            return Ok(Value::Object(fields));
+        }
+
+        loop {
+            self.whitespace();
+            let key = self.string()?;
+            self.expect(":")?;
+            fields.push((key, self.value()?));
+
+            self.whitespace();
+            match self.rest().chars().next() {
+                Some(',') => self.offset += 1,
+                Some('}') => {
+                    self.offset += 1;
+                    return Ok(Value::Object(fields));
+                }
+                _ => return self.error(),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, JsonError> {
+        self.expect("[")?;
+        let mut values = Vec::new();
+
+        self.whitespace();
+        if self.rest().starts_with(']') {
+            self.offset += 1;
+            return Ok(Value::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+
+            self.whitespace();
+            match self.rest().chars().next() {
+                Some(',') => self.offset += 1,
+                Some(']') => {
+                    self.offset += 1;
+                    return Ok(Value::Array(values));
+                }
+                _ => return self.error(),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        if !self.rest().starts_with('"') {
+            return self.error();
+        }
+        self.offset += 1;
+
+        let mut string = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((index, character)) = chars.next() {
+            match character {
+                '"' => {
+                    self.offset += index + 1;
+                    return Ok(string);
+                }
+                '\\' => {
+                    let escaped = match chars.next() {
+                        Some((_, '"')) => '"',
+                        Some((_, '\\')) => '\\',
+                        Some((_, '/')) => '/',
+                        Some((_, 'b')) => '\u{8}',
+                        Some((_, 'f')) => '\u{c}',
+                        Some((_, 'n')) => '\n',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 't')) => '\t',
+                        Some((start, 'u')) => {
+                            let code = self.code_unit(start + 1)?;
+                            chars.nth(3);
+
+                            // Characters outside of the basic plane are written as surrogate pairs
+                            let code = if (0xd800..0xdc00).contains(&code)
+                                && self.rest()[start + 5..].starts_with("\\u")
+                            {
+                                let low = self.code_unit(start + 7)?;
+                                chars.nth(5);
+                                0x10000
+                                    + ((code - 0xd800) << 10)
+                                    + (low.wrapping_sub(0xdc00) & 0x3ff)
+                            } else {
+                                code
+                            };
+
+                            match char::from_u32(code) {
+                                Some(character) => character,
+                                None => return Err(JsonError::Syntax(self.offset + start)),
+                            }
+                        }
+                        _ => return Err(JsonError::Syntax(self.offset + index)),
+                    };
+                    string.push(escaped);
+                }
+                character if (character as u32) < 0x20 => {
+                    return Err(JsonError::Syntax(self.offset + index))
+                }
+                character => string.push(character),
+            }
+        }
+
+        Err(JsonError::Syntax(self.json.len()))
+    }
+
+    /// Reads the four hexadecimal digits of a `\u` escape, starting at an offset of the rest
+    fn code_unit(&self, start: usize) -> Result<u32, JsonError> {
+        self.rest()
+            .get(start..start + 4)
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or(JsonError::Syntax(self.offset + start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonError;
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    #[test]
+    fn test_round_trip() {
+        let mut text = Element::new(TagName::Text);
+        text.inner = Some(String::from("Quote \" and \\ and\nnewline \u{1F600}"));
+
+        let root = Element::new(TagName::Svg)
+            .set(Attribute::ViewBox, "0 0 10 10")
+            .set(Attribute::Custom(String::from("data-id")), "1")
+            .append(Element::new(TagName::LinearGradient))
+            .append(text);
+
+        let json = root.to_json();
+        assert_eq!(Element::from_json(&json).unwrap().to_json(), json);
+
+        // Raw inner XML stays unescaped
+        let raw = Element::new(TagName::ForeignObject).set_inner_raw("<p>hi</p>");
+        let json = raw.to_json();
+        assert!(json.ends_with(r#""inner":"<p>hi</p>","raw":true}"#));
+        let read = Element::from_json(&json).unwrap();
+        assert_eq!(read.to_string(), "<foreignObject><p>hi</p></foreignObject>");
+        assert_eq!(read.to_json(), json);
+        assert!(matches!(
+            Element::from_json(r#"{"tag":"g","raw":"yes"}"#),
+            Err(JsonError::Schema(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_json() {
+        let element = Element::from_json(
+            " { \"tag\" : \"rect\", \"extra\": [1, -2.5e3, true, false, null, {}],\
+             \"inner\": \"\\u00e9\\ud83d\\ude00\" } ",
+        )
+        .unwrap();
+        assert_eq!(element.get_inner().as_deref(), Some("\u{e9}\u{1F600}"));
+
+        assert_eq!(
+            Element::from_json("{\"tag\":\"blink\"}")
+                .unwrap()
+                .get_tag_name(),
+            &TagName::Custom(String::from("blink"))
+        );
+        assert_eq!(
+            Element::from_json("{\"tag\":\"<blink>\"}").unwrap_err(),
+            JsonError::UnknownTag(String::from("<blink>"))
+        );
+        assert!(matches!(
+            Element::from_json("{\"attrs\":{}}"),
+            Err(JsonError::Schema(_))
+        ));
+        assert!(matches!(
+            Element::from_json("{\"tag\":\"g\",\"attrs\":{\"x\":1}}"),
+            Err(JsonError::Schema(_))
+        ));
+        assert_eq!(
+            Element::from_json("{\"tag\":\"g\"} x").unwrap_err(),
+            JsonError::Syntax(12)
+        );
+        assert_eq!(
+            Element::from_json("{\"tag\":\"g\"").unwrap_err(),
+            JsonError::Syntax(10)
+        );
+    }
+}
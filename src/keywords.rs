@@ -0,0 +1,268 @@
+//! This module provides typed keywords for attributes with a small closed vocabulary, like
+//! `fill-rule` and `visibility`, so their values can be matched exhaustively.
+//!
+//! # Examples
+//! ## Reading and writing a keyword
+//! ```
+//! use svg_definitions::keywords::{FillRule, Visibility};
+//! use svg_definitions::prelude::*;
+//!
+//! let path = SVGElem::new(Tag::Path)
+//!     .set(Attr::FillRule, FillRule::EvenOdd)
+//!     .set(Attr::Visibility, " hidden ");
+//!
+//! assert_eq!(path.get_keyword(&Attr::FillRule), Some(FillRule::EvenOdd));
+//! match path.get_keyword(&Attr::Visibility) {
+//!     Some(Visibility::Visible) | None => println!("drawn"),
+//!     Some(Visibility::Hidden) | Some(Visibility::Collapse) => println!("not drawn"),
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::attributes::Attribute;
+
+/// A keyword of an attribute with a closed vocabulary
+pub trait Keyword: Sized + Into<KeywordValue> {
+    /// The attributes which have this vocabulary
+    const ATTRIBUTES: &'static [Attribute];
+
+    /// Returns the keyword as it is written in SVG
+    fn name(&self) -> &'static str;
+
+    /// Returns the keyword with a certain name, or None if it is not part of the vocabulary
+    fn from_name(name: &str) -> Option<Self>;
+
+    /// Returns the keyword stored in a KeywordValue, or None if it is of another vocabulary
+    fn from_value(value: KeywordValue) -> Option<Self>;
+}
+
+/// A keyword of any of the vocabularies, as it is stored in an
+/// [AttributeValue](../attributes/struct.AttributeValue.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeywordValue {
+    FillRule(FillRule),
+    StrokeLinecap(StrokeLinecap),
+    StrokeLinejoin(StrokeLinejoin),
+    Display(Display),
+    Visibility(Visibility),
+}
+
+impl KeywordValue {
+    /// Returns the keyword as it is written in SVG
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeywordValue::FillRule(keyword) => keyword.name(),
+            KeywordValue::StrokeLinecap(keyword) => keyword.name(),
+            KeywordValue::StrokeLinejoin(keyword) => keyword.name(),
+            KeywordValue::Display(keyword) => keyword.name(),
+            KeywordValue::Visibility(keyword) => keyword.name(),
+        }
+    }
+
+    /// Returns the keyword of an attribute with a certain name, or None if the attribute has no
+    /// vocabulary or the name is not part of it
+    ///
+    /// # Note / Arguments
+    /// The name has to match exactly, so e.g. `" evenodd"` is not a keyword.
+    pub fn parse(attribute: &Attribute, name: &str) -> Option<KeywordValue> {
+        fn parse_as<K: Keyword>(attribute: &Attribute, name: &str) -> Option<KeywordValue> {
+            if K::ATTRIBUTES.contains(attribute) {
+                K::from_name(name).map(Into::into)
+            } else {
+                None
+            }
+        }
+
+        parse_as::<FillRule>(attribute, name)
+            .or_else(|| parse_as::<StrokeLinecap>(attribute, name))
+            .or_else(|| parse_as::<StrokeLinejoin>(attribute, name))
+            .or_else(|| parse_as::<Display>(attribute, name))
+            .or_else(|| parse_as::<Visibility>(attribute, name))
+    }
+}
+
+impl fmt::Display for KeywordValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The error returned when parsing a value which is not a keyword of the vocabulary
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKeyword(pub String);
+
+impl fmt::Display for UnknownKeyword {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown keyword {:?}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownKeyword {}
+
+keyword_enum! {
+    /// The rule deciding which parts of a shape are inside, i.e. the `fill-rule` and `clip-rule`
+    /// attributes
+    pub enum FillRule for [FillRule, ClipRule] {
+        /// A point is inside if the outline winds around it a non-zero amount of times
+        NonZero = "nonzero",
+
+        /// A point is inside if a ray from it crosses the outline an odd amount of times
+        EvenOdd = "evenodd",
+    }
+}
+
+keyword_enum! {
+    /// The shape at the ends of open strokes, i.e. the `stroke-linecap` attribute
+    pub enum StrokeLinecap for [StrokeLinecap] {
+        /// The stroke ends at the end of the path
+        Butt = "butt",
+
+        /// The stroke ends with a half circle
+        Round = "round",
+
+        /// The stroke ends with a half square
+        Square = "square",
+    }
+}
+
+keyword_enum! {
+    /// The shape at the corners of strokes, i.e. the `stroke-linejoin` attribute
+    pub enum StrokeLinejoin for [StrokeLinejoin] {
+        /// A sharp corner, which is beveled when it exceeds the miter limit
+        Miter = "miter",
+
+        /// A sharp corner, which is clipped when it exceeds the miter limit
+        MiterClip = "miter-clip",
+
+        /// A rounded corner
+        Round = "round",
+
+        /// A cut off corner
+        Bevel = "bevel",
+
+        /// A corner of extended arcs
+        Arcs = "arcs",
+    }
+}
+
+keyword_enum! {
+    /// Whether and how an element is rendered, i.e. the `display` attribute
+    ///
+    /// # Note
+    /// For SVG content only `none` makes a difference, it removes the element and its children
+    /// from rendering.
+    pub enum Display for [Display] {
+        Inline = "inline",
+        Block = "block",
+        InlineBlock = "inline-block",
+        ListItem = "list-item",
+        RunIn = "run-in",
+        Compact = "compact",
+        Marker = "marker",
+        Table = "table",
+        InlineTable = "inline-table",
+        TableRowGroup = "table-row-group",
+        TableHeaderGroup = "table-header-group",
+        TableFooterGroup = "table-footer-group",
+        TableRow = "table-row",
+        TableColumnGroup = "table-column-group",
+        TableColumn = "table-column",
+        TableCell = "table-cell",
+        TableCaption = "table-caption",
+        Flex = "flex",
+        Grid = "grid",
+        Contents = "contents",
+        None = "none",
+    }
+}
+
+keyword_enum! {
+    /// Whether an element is drawn, i.e. the `visibility` attribute
+    ///
+    /// # Note
+    /// Unlike `display="none"`, hidden elements still take up space and children can be made
+    /// visible again.
+    pub enum Visibility for [Visibility] {
+        Visible = "visible",
+        Hidden = "hidden",
+        Collapse = "collapse",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Display, FillRule, KeywordValue, StrokeLinejoin, UnknownKeyword, Visibility};
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    #[test]
+    fn test_keywords() {
+        for keyword in StrokeLinejoin::ALL {
+            assert_eq!(StrokeLinejoin::from_name(keyword.name()), Some(*keyword));
+        }
+        assert_eq!(" evenodd".parse(), Ok(FillRule::EvenOdd));
+        assert_eq!(
+            "EvenOdd".parse::<FillRule>(),
+            Err(UnknownKeyword(String::from("EvenOdd")))
+        );
+
+        let element = Element::new(TagName::G)
+            .set(Attribute::Display, Display::None)
+            .set(Attribute::ClipRule, "inherit");
+        assert_eq!(
+            element.to_string(),
+            "<g clip-rule=\"inherit\" display=\"none\"/>"
+        );
+        assert_eq!(
+            element.get_keyword(&Attribute::Display),
+            Some(Display::None)
+        );
+        assert_eq!(element.get_keyword::<FillRule>(&Attribute::ClipRule), None);
+        assert_eq!(element.get_keyword::<FillRule>(&Attribute::FillRule), None);
+    }
+
+    #[test]
+    fn test_keyword_values() {
+        let mut element = Element::new(TagName::Path)
+            .set(Attribute::ClipRule, FillRule::EvenOdd)
+            .set(Attribute::Visibility, " hidden ")
+            .set(Attribute::Custom(String::from("data-rule")), "evenodd");
+        let attributes = element.get_attributes();
+        assert_eq!(
+            attributes[&Attribute::ClipRule].keyword(),
+            Some(KeywordValue::FillRule(FillRule::EvenOdd))
+        );
+        assert_eq!(attributes[&Attribute::Visibility].keyword(), None);
+        assert_eq!(attributes[&Attribute::Visibility], " hidden ");
+        assert_eq!(
+            attributes[&Attribute::Custom(String::from("data-rule"))].keyword(),
+            None
+        );
+        assert_eq!(
+            element.get_keyword(&Attribute::Visibility),
+            Some(Visibility::Hidden)
+        );
+
+        // Keywords only belong to the attributes they are linked to
+        assert_eq!(
+            element.get_keyword::<FillRule>(&Attribute::Visibility),
+            None
+        );
+        assert_eq!(
+            element.get_keyword::<Visibility>(&Attribute::ClipRule),
+            None
+        );
+
+        element.set_attribute(Attribute::Visibility, "collapse");
+        assert_eq!(
+            element.get_attributes()[&Attribute::Visibility].keyword(),
+            Some(KeywordValue::Visibility(Visibility::Collapse))
+        );
+        assert_eq!(
+            element.to_string(),
+            "<path clip-rule=\"evenodd\" data-rule=\"evenodd\" visibility=\"collapse\"/>"
+        );
+    }
+}
@@ -0,0 +1,325 @@
+//! This module helps aligning composed graphics to a design grid, with a [Grid](struct.Grid.html)
+//! to snap points to and column [guides](fn.guides.html). Both can also be drawn as guide
+//! elements while working on a layout.
+//!
+//! # Examples
+//! ## Placing cards in columns
+//! ```
+//! use svg_definitions::geometry::BoundingBox;
+//! use svg_definitions::layout::{self, Grid};
+//! use svg_definitions::prelude::*;
+//!
+//! let page = BoundingBox::new(0.0, 0.0, 600.0, 400.0);
+//! let columns = layout::guides(&page, 3, 20.0);
+//! let grid = Grid::new(8.0);
+//!
+//! let mut svg = SVGElem::new(Tag::Svg);
+//! for column in columns.columns.iter() {
+//!     let (x, y) = grid.snap((column.x, 30.0));
+//!     svg = svg.append(
+//!         SVGElem::new(Tag::Rect)
+//!             .set(Attr::X, x)
+//!             .set(Attr::Y, y)
+//!             .set(Attr::Width, column.width)
+//!             .set(Attr::Height, 100),
+//!     );
+//! }
+//!
+//! // The guides are only shown while designing
+//! let draft = svg.clone().append(columns.to_element()).append(grid.to_element(&page));
+//! # assert_eq!(svg.get_children()[1].get_attributes()[&Attr::X], "208");
+//! # assert_eq!(draft.get_children().len(), 5);
+//! ```
+
+use crate::attributes::Attribute;
+use crate::geometry::BoundingBox;
+use crate::path::PathDefinitionString;
+use crate::tag_name::TagName;
+use crate::Element;
+
+/// The color in which guides are drawn
+const GUIDE_COLOR: &str = "#00bfff";
+
+/// A regular grid of points to snap to, starting at an origin
+///
+/// # Note / Arguments
+/// The spacing is always positive, as it can only be set by the constructors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Grid {
+    origin: (f64, f64),
+    spacing: (f64, f64),
+}
+
+impl Grid {
+    /// Creates a square grid through `(0, 0)`
+    ///
+    /// # Panics
+    /// If the `spacing` is not positive.
+    pub fn new(spacing: f64) -> Grid {
+        Grid::rectangular(spacing, spacing)
+    }
+
+    /// Creates a grid through `(0, 0)` with a different horizontal and vertical spacing
+    ///
+    /// # Panics
+    /// If one of the spacings is not positive.
+    pub fn rectangular(horizontal: f64, vertical: f64) -> Grid {
+        assert!(
+            horizontal > 0.0 && vertical > 0.0,
+            "the spacing of a grid has to be positive"
+        );
+        Grid {
+            origin: (0.0, 0.0),
+            spacing: (horizontal, vertical),
+        }
+    }
+
+    /// Gets a point on the grid
+    #[inline]
+    pub fn origin(&self) -> (f64, f64) {
+        self.origin
+    }
+
+    /// Gets the horizontal and vertical distance between points
+    #[inline]
+    pub fn spacing(&self) -> (f64, f64) {
+        self.spacing
+    }
+
+    /// Moves the grid, so it runs through a point
+    pub fn with_origin(mut self, origin: (f64, f64)) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Returns the point on the grid which is closest to a point
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::layout::Grid;
+    ///
+    /// let grid = Grid::new(10.0).with_origin((5.0, 0.0));
+    /// assert_eq!(grid.snap((13.0, 26.0)), (15.0, 30.0));
+    /// assert_eq!(grid.snap((-1.0, -4.0)), (-5.0, 0.0));
+    /// ```
+    pub fn snap(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (
+            snap_value(x, self.origin.0, self.spacing.0),
+            snap_value(y, self.origin.1, self.spacing.1),
+        )
+    }
+
+    /// Returns a bounding box with its corners snapped to the grid, which can be empty for boxes
+    /// which are smaller than the spacing
+    pub fn snap_box(&self, bounds: &BoundingBox) -> BoundingBox {
+        let (x, y) = self.snap((bounds.x, bounds.y));
+        let (right, bottom) = self.snap((bounds.right(), bounds.bottom()));
+        BoundingBox::new(x, y, right - x, bottom - y)
+    }
+
+    /// Returns a `<path>` with the lines of the grid within some bounds, to show the grid while
+    /// designing
+    ///
+    /// # Note / Arguments
+    /// The lines are drawn light blue, which can be changed by setting the `stroke` of the
+    /// returned element. They do not catch pointer events.
+    pub fn to_element(&self, bounds: &BoundingBox) -> Element {
+        let mut lines = PathDefinitionString::new();
+        for x in grid_lines(bounds.x, bounds.right(), self.origin.0, self.spacing.0) {
+            lines = lines
+                .move_to((x, bounds.y))
+                .vertical_line_to(bounds.bottom());
+        }
+        for y in grid_lines(bounds.y, bounds.bottom(), self.origin.1, self.spacing.1) {
+            lines = lines
+                .move_to((bounds.x, y))
+                .horizontal_line_to(bounds.right());
+        }
+
+        Element::new(TagName::Path)
+            .set(Attribute::D, lines)
+            .set(Attribute::Fill, "none")
+            .set(Attribute::Stroke, GUIDE_COLOR)
+            .set(Attribute::StrokeWidth, 0.5)
+            .set(Attribute::PointerEvents, "none")
+    }
+}
+
+/// Returns the multiple of `spacing` from `origin` which is closest to a value
+fn snap_value(value: f64, origin: f64, spacing: f64) -> f64 {
+    origin + ((value - origin) / spacing).round() * spacing
+}
+
+/// Returns the positions of grid lines from `start` up to and including `end`
+fn grid_lines(start: f64, end: f64, origin: f64, spacing: f64) -> impl Iterator<Item = f64> {
+    let first = ((start - origin) / spacing).ceil();
+    (0..)
+        .map(move |index| origin + (first + index as f64) * spacing)
+        .take_while(move |position| *position <= end)
+}
+
+/// The columns of a layout, as returned by [guides](fn.guides.html)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Guides {
+    /// The bounds which are divided into columns
+    pub bounds: BoundingBox,
+
+    /// The columns from left to right
+    pub columns: Vec<BoundingBox>,
+
+    /// The space between two columns
+    pub gutter: f64,
+}
+
+impl Guides {
+    /// Returns the column edge which is closest to a horizontal position
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::geometry::BoundingBox;
+    /// use svg_definitions::layout;
+    ///
+    /// // Columns from 0 to 90 and from 110 to 200
+    /// let guides = layout::guides(&BoundingBox::new(0.0, 0.0, 200.0, 100.0), 2, 20.0);
+    /// assert_eq!(guides.snap_x(97.0), 90.0);
+    /// assert_eq!(guides.snap_x(104.0), 110.0);
+    /// ```
+    pub fn snap_x(&self, x: f64) -> f64 {
+        self.columns
+            .iter()
+            .flat_map(|column| vec![column.x, column.right()])
+            .min_by(|a, b| (a - x).abs().total_cmp(&(b - x).abs()))
+            .unwrap_or(x)
+    }
+
+    /// Returns the box spanning `count` columns, starting at the column with index `first`,
+    /// including the gutters between them
+    ///
+    /// # Panics
+    /// If `count` is 0 or the columns do not exist.
+    pub fn span(&self, first: usize, count: usize) -> BoundingBox {
+        assert!(count > 0, "a span has at least one column");
+        let start = &self.columns[first];
+        let end = &self.columns[first + count - 1];
+        BoundingBox::new(start.x, start.y, end.right() - start.x, start.height)
+    }
+
+    /// Returns a `<g>` with a translucent `<rect>` for every column, to show the columns while
+    /// designing
+    ///
+    /// # Note / Arguments
+    /// The columns are drawn light blue, which can be changed by setting the `fill` of the
+    /// returned element. They do not catch pointer events.
+    pub fn to_element(&self) -> Element {
+        self.columns.iter().fold(
+            Element::new(TagName::G)
+                .set(Attribute::Fill, GUIDE_COLOR)
+                .set(Attribute::FillOpacity, 0.2)
+                .set(Attribute::PointerEvents, "none"),
+            |group, column| {
+                group.append(
+                    Element::new(TagName::Rect)
+                        .set(Attribute::X, column.x)
+                        .set(Attribute::Y, column.y)
+                        .set(Attribute::Width, column.width)
+                        .set(Attribute::Height, column.height),
+                )
+            },
+        )
+    }
+}
+
+/// Divides bounds into columns of equal width, with a gutter between them
+///
+/// # Note / Arguments
+/// The columns have the full height of the bounds. The first column starts at the left edge and
+/// the last ends at the right edge.
+///
+/// # Panics
+/// If there are no columns or the gutters are wider than the bounds.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::geometry::BoundingBox;
+/// use svg_definitions::layout;
+///
+/// let guides = layout::guides(&BoundingBox::new(10.0, 0.0, 320.0, 240.0), 4, 20.0);
+///
+/// assert_eq!(guides.columns[1], BoundingBox::new(95.0, 0.0, 65.0, 240.0));
+/// assert_eq!(guides.span(1, 2), BoundingBox::new(95.0, 0.0, 150.0, 240.0));
+/// ```
+pub fn guides(bounds: &BoundingBox, columns: usize, gutter: f64) -> Guides {
+    assert!(columns > 0, "a layout has at least one column");
+    let gutters = gutter * (columns - 1) as f64;
+    assert!(
+        gutters <= bounds.width,
+        "the gutters are wider than the bounds"
+    );
+
+    let width = (bounds.width - gutters) / columns as f64;
+    Guides {
+        bounds: *bounds,
+        columns: (0..columns)
+            .map(|index| {
+                BoundingBox::new(
+                    bounds.x + index as f64 * (width + gutter),
+                    bounds.y,
+                    width,
+                    bounds.height,
+                )
+            })
+            .collect(),
+        gutter,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{guides, Grid};
+    use crate::attributes::Attribute;
+    use crate::geometry::BoundingBox;
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::rectangular(4.0, 3.0).with_origin((1.0, 1.0));
+        assert_eq!((grid.origin(), grid.spacing()), ((1.0, 1.0), (4.0, 3.0)));
+        assert_eq!(grid.snap((2.9, 2.4)), (1.0, 1.0));
+        assert_eq!(grid.snap((3.1, 2.6)), (5.0, 4.0));
+        assert_eq!(
+            grid.snap_box(&BoundingBox::new(0.0, 0.0, 10.0, 1.0)),
+            BoundingBox::new(1.0, 1.0, 8.0, 0.0)
+        );
+
+        let lines = Grid::new(5.0).to_element(&BoundingBox::new(1.0, 0.0, 9.0, 5.0));
+        assert_eq!(
+            lines.get_attributes()[&Attribute::D],
+            "M 5.00 0.00 V 5.00 M 10.00 0.00 V 5.00 M 1.00 0.00 H 10.00 M 1.00 5.00 H 10.00"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grid_without_spacing() {
+        Grid::rectangular(4.0, 0.0);
+    }
+
+    #[test]
+    fn test_guides() {
+        let bounds = BoundingBox::new(0.0, 10.0, 100.0, 50.0);
+        let single = guides(&bounds, 1, 20.0);
+        assert_eq!(single.columns, vec![bounds]);
+        assert_eq!(single.span(0, 1), bounds);
+
+        let columns = guides(&bounds, 3, 5.0);
+        assert_eq!(columns.columns[2], BoundingBox::new(70.0, 10.0, 30.0, 50.0));
+        assert_eq!(columns.snap_x(-10.0), 0.0);
+        assert_eq!(columns.snap_x(66.0), 65.0);
+        assert_eq!(columns.to_element().get_children().len(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_guides_too_wide() {
+        guides(&BoundingBox::new(0.0, 0.0, 10.0, 10.0), 3, 6.0);
+    }
+}
@@ -0,0 +1,291 @@
+//! This module provides the [Length](struct.Length.html) type for the values of geometric
+//! attributes, like `x`, `width`, `r` and `stroke-width`, together with their
+//! [units](enum.LengthUnit.html).
+//!
+//! # Examples
+//! ## Setting and reading lengths
+//! ```
+//! use svg_definitions::length::{Length, LengthUnit};
+//! use svg_definitions::prelude::*;
+//!
+//! let rect = SVGElem::new(Tag::Rect)
+//!     .set(Attr::Width, Length::percent(50.0))
+//!     .set(Attr::Height, Length::mm(20.0))
+//!     .set(Attr::Rx, "0.5em");
+//!
+//! assert_eq!(rect.get_attributes()[&Attr::Width], "50%");
+//! assert_eq!(rect.get_length(&Attr::Rx), Some(Length::em(0.5)));
+//! assert_eq!(rect.get_length(&Attr::Height).unwrap().unit, LengthUnit::Mm);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::attributes::Attribute;
+use crate::Element;
+
+closed_enum! {
+    /// The unit of a [Length](struct.Length.html)
+    pub enum LengthUnit {
+        /// A number without unit, in user units
+        Number = "",
+
+        /// Pixels, which are equal to user units
+        Px = "px",
+
+        /// A percentage of the viewport, or of the bounding box for some attributes
+        Percent = "%",
+
+        /// Multiples of the font size
+        Em = "em",
+
+        /// Multiples of the x-height of the font
+        Ex = "ex",
+
+        /// Multiples of the font size of the root element
+        Rem = "rem",
+
+        /// Percentages of the viewport width
+        Vw = "vw",
+
+        /// Percentages of the viewport height
+        Vh = "vh",
+
+        /// Percentages of the smaller viewport dimension
+        Vmin = "vmin",
+
+        /// Percentages of the larger viewport dimension
+        Vmax = "vmax",
+
+        /// Quarter millimeters
+        Q = "Q",
+
+        /// Millimeters
+        Mm = "mm",
+
+        /// Centimeters
+        Cm = "cm",
+
+        /// Inches
+        In = "in",
+
+        /// Points, which are 1/72 of an inch
+        Pt = "pt",
+
+        /// Picas, which are 12 points
+        Pc = "pc",
+    }
+}
+
+impl LengthUnit {
+    /// Returns the amount of user units in one of this unit, or None if it depends on the font or
+    /// viewport
+    pub fn user_units(&self) -> Option<f64> {
+        use LengthUnit::*;
+
+        Some(match self {
+            Number | Px => 1.0,
+            In => 96.0,
+            Cm => 96.0 / 2.54,
+            Mm => 96.0 / 25.4,
+            Q => 96.0 / 101.6,
+            Pt => 96.0 / 72.0,
+            Pc => 16.0,
+            Percent | Em | Ex | Rem | Vw | Vh | Vmin | Vmax => return None,
+        })
+    }
+}
+
+/// The error returned when parsing a value which is not a length
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidLength(pub String);
+
+impl fmt::Display for InvalidLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid length {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidLength {}
+
+/// A number with a unit, e.g. `10`, `1.5em` or `50%`
+///
+/// # Note / Arguments
+/// Lengths are formatted as they are written in SVG, so they can be passed to
+/// [set](../struct.Element.html#method.set) directly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Length {
+    pub value: f64,
+    pub unit: LengthUnit,
+}
+
+impl Length {
+    /// Creates a new length
+    #[inline]
+    pub fn new(value: f64, unit: LengthUnit) -> Length {
+        Length { value, unit }
+    }
+
+    /// Creates a length in user units, which is written without unit
+    #[inline]
+    pub fn number(value: f64) -> Length {
+        Length::new(value, LengthUnit::Number)
+    }
+
+    /// Creates a length in pixels
+    #[inline]
+    pub fn px(value: f64) -> Length {
+        Length::new(value, LengthUnit::Px)
+    }
+
+    /// Creates a percentage
+    #[inline]
+    pub fn percent(value: f64) -> Length {
+        Length::new(value, LengthUnit::Percent)
+    }
+
+    /// Creates a length in multiples of the font size
+    #[inline]
+    pub fn em(value: f64) -> Length {
+        Length::new(value, LengthUnit::Em)
+    }
+
+    /// Creates a length in multiples of the font size of the root element
+    #[inline]
+    pub fn rem(value: f64) -> Length {
+        Length::new(value, LengthUnit::Rem)
+    }
+
+    /// Creates a length in percentages of the viewport width
+    #[inline]
+    pub fn vw(value: f64) -> Length {
+        Length::new(value, LengthUnit::Vw)
+    }
+
+    /// Creates a length in percentages of the viewport height
+    #[inline]
+    pub fn vh(value: f64) -> Length {
+        Length::new(value, LengthUnit::Vh)
+    }
+
+    /// Creates a length in millimeters
+    #[inline]
+    pub fn mm(value: f64) -> Length {
+        Length::new(value, LengthUnit::Mm)
+    }
+
+    /// Creates a length in quarter millimeters
+    #[inline]
+    pub fn q(value: f64) -> Length {
+        Length::new(value, LengthUnit::Q)
+    }
+
+    /// Returns the length in user units, or None if its unit depends on the font or viewport
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::length::Length;
+    ///
+    /// assert_eq!("1in".parse::<Length>().unwrap().to_user_units(), Some(96.0));
+    /// assert_eq!(Length::percent(50.0).to_user_units(), None);
+    /// ```
+    #[inline]
+    pub fn to_user_units(&self) -> Option<f64> {
+        Some(self.value * self.unit.user_units()?)
+    }
+}
+
+impl From<f64> for Length {
+    #[inline]
+    fn from(value: f64) -> Length {
+        Length::number(value)
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+impl FromStr for Length {
+    type Err = InvalidLength;
+
+    /// Parses a length, where units are case insensitive and surrounding whitespace is ignored
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+
+        // Longer units go first, so `rem` is not read as `em` and `vmin` not as `in`
+        let mut units: Vec<LengthUnit> = LengthUnit::ALL.to_vec();
+        units.sort_by_key(|unit| std::cmp::Reverse(unit.name().len()));
+
+        units
+            .into_iter()
+            .find_map(|unit| {
+                let split = trimmed.len().checked_sub(unit.name().len())?;
+                let suffix = trimmed.get(split..)?;
+                if !suffix.eq_ignore_ascii_case(unit.name()) {
+                    return None;
+                }
+                let number: f64 = trimmed[..split].parse().ok()?;
+                if number.is_finite() {
+                    Some(Length::new(number, unit))
+                } else {
+                    None
+                }
+            })
+            .ok_or_else(|| InvalidLength(value.to_string()))
+    }
+}
+
+impl Element {
+    /// Returns the value of an attribute as a [Length](length/struct.Length.html), or None if it
+    /// is not set or not a length, e.g. `auto`
+    pub fn get_length(&self, attribute: &Attribute) -> Option<Length> {
+        self.attributes.get(attribute)?.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidLength, Length, LengthUnit};
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    #[test]
+    fn test_length() {
+        for unit in LengthUnit::ALL {
+            let length = Length::new(-1.5, *unit);
+            assert_eq!(length.to_string().parse(), Ok(length));
+        }
+
+        assert_eq!(" 10 ".parse(), Ok(Length::number(10.0)));
+        assert_eq!("2REM".parse(), Ok(Length::rem(2.0)));
+        assert_eq!("1e2vmin".parse(), Ok(Length::new(100.0, LengthUnit::Vmin)));
+        assert_eq!("4q".parse(), Ok(Length::q(4.0)));
+        assert_eq!(
+            ".5in".parse::<Length>().unwrap().to_user_units(),
+            Some(48.0)
+        );
+        assert_eq!(
+            Length::q(4.0).to_user_units(),
+            Length::mm(1.0).to_user_units()
+        );
+        assert_eq!(Length::from(2.0).to_string(), "2");
+
+        for value in &["", "px", "10 px", "10pxx", "1e", "auto", "nan", "inf%"] {
+            assert_eq!(
+                value.parse::<Length>(),
+                Err(InvalidLength(value.to_string()))
+            );
+        }
+
+        let circle = Element::new(TagName::Circle)
+            .set(Attribute::R, Length::vw(10.0))
+            .set(Attribute::Cx, "auto");
+        assert_eq!(circle.get_length(&Attribute::R), Some(Length::vw(10.0)));
+        assert_eq!(circle.get_length(&Attribute::Cx), None);
+        assert_eq!(circle.get_length(&Attribute::Cy), None);
+    }
+}
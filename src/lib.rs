@@ -29,57 +29,182 @@
 //! ## Getting a svg from a file
 //! *The feature "parsing" needs to be enabled for this*
 //! ```
+//! # #[cfg(feature = "parsing")]
+//! # {
 //! use svg_definitions::prelude::*;
 //!
 //! let shape = SVGParseFile("/path/to/file.svg");
 //!
 //! // ...
+//! # }
 //! ```
 //!
 //! ## Getting a svg from text
 //! *The feature "parsing" needs to be enabled for this*
 //! ```
+//! # #[cfg(feature = "parsing")]
+//! # {
 //! use svg_definitions::prelude::*;
 //!
 //! let rect = SVGParseText("<rect width=\"50px\" height=\"50\" fill=\"black\" />");
 //!
 //! // ...
+//! # }
 //! ```
 
+#[macro_use]
+mod macros;
+
 pub mod prelude;
 
+pub mod angle;
+pub mod animation;
 pub mod attributes;
+pub mod changes;
+pub mod color;
+pub mod compat;
+pub mod context;
+pub mod dash;
+pub mod decor;
+pub mod diff;
+pub mod document;
+pub mod easing;
+mod escape;
+pub mod filters;
+pub mod geometry;
+pub mod graph;
+pub mod json;
+pub mod keywords;
+pub mod layout;
+pub mod length;
+pub mod limits;
+pub mod locale;
+pub mod markers;
+pub mod matrix;
+pub mod namespace;
+pub mod opacity;
+pub mod optimize;
+pub mod palette;
 pub mod path;
+pub mod random;
+mod references;
+pub mod report;
+pub mod sprites;
+pub mod style;
+pub mod syntax;
 pub mod tag_name;
+pub mod text;
+pub mod transform;
+pub mod tree;
+pub mod validate;
+pub mod view_box;
 
+#[cfg(any(feature = "fonts", feature = "image"))]
+mod base64;
+#[cfg(feature = "corpus")]
+pub mod corpus;
+#[cfg(feature = "raster")]
+pub mod export;
+#[cfg(feature = "fonts")]
+pub mod fonts;
 #[cfg(feature = "parsing")]
 pub mod parser;
+#[cfg(feature = "parsing")]
+pub mod testing;
 
 pub type Point2D = (f32, f32);
 
+pub use matrix::Matrix;
+
+/// A point on a pixel grid, for geometry which should be written without decimals
+pub type IntPoint2D = (i32, i32);
+
+/// Converts the coordinates of a point into the coordinates which are stored in a
+/// [PathDefinitionString](path/struct.PathDefinitionString.html)
+///
+/// # Note / Arguments
+/// This is implemented for [Point2D](type.Point2D.html), [IntPoint2D](type.IntPoint2D.html) and
+/// `(f64, f64)`, so path builders accept all of them.
+pub trait IntoPoint {
+    fn into_point(self) -> (f64, f64);
+}
+
+impl IntoPoint for (f32, f32) {
+    #[inline]
+    fn into_point(self) -> (f64, f64) {
+        (f64::from(self.0), f64::from(self.1))
+    }
+}
+
+impl IntoPoint for (f64, f64) {
+    #[inline]
+    fn into_point(self) -> (f64, f64) {
+        self
+    }
+}
+
+impl IntoPoint for (i32, i32) {
+    #[inline]
+    fn into_point(self) -> (f64, f64) {
+        (f64::from(self.0), f64::from(self.1))
+    }
+}
+
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 
-use attributes::Attribute;
+use attributes::{Attribute, AttributeValue};
+use escape::{escape_attribute, escape_text, is_xml_char};
 use tag_name::TagName;
+use tree::ElementPath;
 
-type Attributes = HashMap<Attribute, String>;
+type Attributes = HashMap<Attribute, AttributeValue>;
 type Children = Vec<Element>;
 
 /// Element provides a way to simulate DOM SVG elements
+///
+/// # Note
+/// With the feature "serde" enabled, Elements implement `Serialize` and `Deserialize`.
+///
+/// # Examples
+/// ## Sending an element as JSON
+/// *The feature "serde" needs to be enabled for this*
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use svg_definitions::prelude::*;
+///
+/// let rect = SVGElem::new(Tag::Rect)
+///     .set(Attr::Width, 10)
+///     .set(Attr::D, PathData::new().move_to((0.0, 0.0)).line_to((1.0, 1.0)));
+///
+/// let json = serde_json::to_string(&rect).unwrap();
+/// let received: SVGElem = serde_json::from_str(&json).unwrap();
+/// # assert_eq!(received.to_string(), rect.to_string());
+/// # assert!(json.contains(r#""width":"10""#));
+/// # let custom = SVGElem::new(Tag::G).set(Attr::Custom(String::from("data-x")), 1);
+/// # let custom: SVGElem = serde_json::from_str(&serde_json::to_string(&custom).unwrap()).unwrap();
+/// # assert_eq!(custom.to_string(), "<g data-x=\"1\"/>");
+/// # let path = PathData::new().with_precision(1).line_to((1.0, 1.0));
+/// # let path: PathData = serde_json::from_str(&serde_json::to_string(&path).unwrap()).unwrap();
+/// # assert!(path.is_str("L 1 1"));
+/// # }
+/// ```
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Element {
     tag_name: TagName,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "attributes::deserialize_attributes")
+    )]
     attributes: Attributes,
     children: Children,
     inner: Option<String>,
-}
-
-fn is_allowed_inner(character: char) -> bool {
-    const NON_ALPHANUMERIC_ALLOWED_CHARACTERS: &'static str = r#"' \-_/.!?:;(){}[]`~&,""#;
-
-    return character.is_ascii_alphanumeric()
-        || NON_ALPHANUMERIC_ALLOWED_CHARACTERS.contains(character);
+    #[cfg_attr(feature = "serde", serde(default))]
+    raw_inner: bool,
 }
 
 // Implementation of Element
@@ -91,6 +216,7 @@ impl Element {
             attributes: HashMap::new(),
             children: Vec::new(),
             inner: None,
+            raw_inner: false,
         }
     }
 
@@ -102,28 +228,234 @@ impl Element {
         self
     }
 
-    /// Sets the inner text to a plain string
-    /// Allowed characters are *a-zA-Z0-9'" -_/\.!?:;(){}[]`~&,*
+    /// Sets the inner text to a plain string, which is trimmed
+    ///
+    /// # Note / Arguments
+    /// The text is stored as is and escaped when the element is serialized, so it can contain
+    /// any Unicode characters. Characters which cannot appear in XML at all, like most control
+    /// characters, are serialized as U+FFFD, use [try_set_inner](#method.try_set_inner) to reject
+    /// them instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let text = SVGElem::new(Tag::Text).set_inner("1 < 2 & \"ünïcode\"");
+    ///
+    /// assert_eq!(text.get_inner().as_deref(), Some("1 < 2 & \"ünïcode\""));
+    /// assert_eq!(text.to_string(), "<text>1 &lt; 2 &amp; \"ünïcode\"</text>");
+    /// ```
     #[inline]
     pub fn set_inner(mut self, text: &str) -> Self {
-        if !text.chars().all(is_allowed_inner) {
-            return self;
+        self.inner = Some(String::from(text.trim()));
+        self.raw_inner = false;
+        self
+    }
+
+    /// Sets the inner text to a plain string like [set_inner](#method.set_inner), but returns an
+    /// error if the text contains a character which cannot appear in XML, which gives back the
+    /// element unchanged
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    /// use svg_definitions::SetError;
+    ///
+    /// let label = SVGElem::new(Tag::Text).try_set_inner("Grüße, 世界 👋").unwrap();
+    /// assert_eq!(label.to_string(), "<text>Grüße, 世界 👋</text>");
+    ///
+    /// let rejected = label.try_set_inner("bell\u{7}").unwrap_err();
+    /// assert_eq!(
+    ///     *rejected.error(),
+    ///     SetError::InvalidCharacter { character: '\u{7}', index: 4 }
+    /// );
+    /// assert_eq!(rejected.into_value().to_string(), "<text>Grüße, 世界 👋</text>");
+    /// ```
+    pub fn try_set_inner(self, text: &str) -> Result<Self, Rejected<Self, SetError>> {
+        match text
+            .char_indices()
+            .find(|&(_, character)| !is_xml_char(character))
+        {
+            Some((index, character)) => Err(Rejected::new(
+                self,
+                SetError::InvalidCharacter { character, index },
+            )),
+            None => Ok(self.set_inner(text)),
         }
+    }
 
-        self.inner = Some(String::from(String::from(text).trim()));
+    /// Sets the inner content to already serialized XML, which is written out as is
+    ///
+    /// # Note / Arguments
+    /// The content is neither trimmed nor escaped, so it has to be well-formed XML on its own,
+    /// e.g. an XHTML blob for a `<foreignObject>`. Text from untrusted sources should never be
+    /// passed here, since it can inject arbitrary markup, use [set_inner](#method.set_inner)
+    /// for it instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let object = SVGElem::new(Tag::ForeignObject)
+    ///     .set_inner_raw(r#"<p xmlns="http://www.w3.org/1999/xhtml">Hello &amp; welcome</p>"#);
+    ///
+    /// assert!(object.is_inner_raw());
+    /// assert_eq!(
+    ///     object.to_string(),
+    ///     r#"<foreignObject><p xmlns="http://www.w3.org/1999/xhtml">Hello &amp; welcome</p></foreignObject>"#
+    /// );
+    /// ```
+    #[inline]
+    pub fn set_inner_raw(mut self, xml: &str) -> Self {
+        self.inner = Some(String::from(xml));
+        self.raw_inner = true;
         self
     }
 
+    /// Sets the inner content to a text wrapped in a CDATA section, which is mostly useful for
+    /// the scripts and stylesheets of `<script>` and `<style>` elements
+    ///
+    /// # Note / Arguments
+    /// The text is stored raw, see [set_inner_raw](#method.set_inner_raw), and any `]]>` within
+    /// it is split over two CDATA sections, so the text can contain anything but characters
+    /// which cannot appear in XML.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let script = SVGElem::new(Tag::Script).set_inner_cdata("if (a < b && c) { run(); }");
+    ///
+    /// assert_eq!(
+    ///     script.to_string(),
+    ///     "<script><![CDATA[if (a < b && c) { run(); }]]></script>"
+    /// );
+    /// # let style = SVGElem::new(Tag::Style).set_inner_cdata("a]]>b");
+    /// # assert_eq!(style.to_string(), "<style><![CDATA[a]]]]><![CDATA[>b]]></style>");
+    /// ```
+    pub fn set_inner_cdata(self, text: &str) -> Self {
+        self.set_inner_raw(&format!(
+            "<![CDATA[{}]]>",
+            text.replace("]]>", "]]]]><![CDATA[>")
+        ))
+    }
+
     /// Sets an attribute of the self element to a certain value
     #[inline]
     pub fn set<T>(mut self, attribute: Attribute, value: T) -> Self
     where
         T: ToString,
     {
-        self.attributes.insert(attribute, value.to_string());
+        let value = AttributeValue::new(&attribute, value.to_string());
+        self.attributes.insert(attribute, value);
         self
     }
 
+    /// Declares an XML namespace on the self element, where an empty prefix declares the default
+    /// namespace
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::namespace;
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let svg = SVGElem::new(Tag::Svg)
+    ///     .set_namespace("", namespace::SVG)
+    ///     .set_namespace("xlink", namespace::XLINK);
+    ///
+    /// assert_eq!(
+    ///     svg.to_string(),
+    ///     "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\"/>"
+    /// );
+    /// ```
+    #[inline]
+    pub fn set_namespace(self, prefix: &str, uri: &str) -> Self {
+        let declaration = if prefix.is_empty() {
+            Attribute::custom("xmlns")
+        } else {
+            Attribute::namespaced("xmlns", prefix)
+        };
+        self.set(declaration, uri)
+    }
+
+    /// Gets the XML namespaces declared on this Element as `(prefix, uri)` pairs sorted by
+    /// prefix, where the default namespace has an empty prefix
+    pub fn get_namespaces(&self) -> Vec<(&str, &str)> {
+        let mut namespaces: Vec<(&str, &str)> = self
+            .attributes
+            .iter()
+            .filter(|(attribute, _)| attribute.is_namespace_declaration())
+            .map(|(attribute, uri)| match attribute.prefix() {
+                Some(_) => (attribute.local_name(), &uri[..]),
+                None => ("", &uri[..]),
+            })
+            .collect();
+        namespaces.sort();
+        namespaces
+    }
+
+    /// Sets an attribute like [set](#method.set), but returns an error if the value contains a
+    /// character which cannot appear in XML or is not valid for the attribute
+    ///
+    /// # Note / Arguments
+    /// Values are checked for the attributes with simple value types, like lengths, numbers,
+    /// `viewBox`, `points` and ids, where `x`, `y`, `dx` and `dy` of `<text>` and `<tspan>` are
+    /// lists of lengths and presentation attributes also accept the CSS-wide keywords, like
+    /// `inherit`. The values of other attributes are only checked for invalid characters. The
+    /// element is given back with the error.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    /// use svg_definitions::SetError;
+    ///
+    /// let rect = SVGElem::new(Tag::Rect).try_set(Attr::Width, "10px").unwrap();
+    /// # assert_eq!(rect.get_attributes()[&Attr::Width], "10px");
+    ///
+    /// let rejected = rect.try_set(Attr::Height, -10).unwrap_err();
+    /// assert!(matches!(
+    ///     rejected.error(),
+    ///     SetError::InvalidValue { attribute: Attr::Height, .. }
+    /// ));
+    /// let rect = rejected.into_value();
+    /// # assert_eq!(rect.to_string(), "<rect width=\"10px\"/>");
+    ///
+    /// assert!(rect.try_set(Attr::StrokeWidth, "inherit").is_ok());
+    /// assert!(SVGElem::new(Tag::Rect).try_set(Attr::X, "ten").is_err());
+    /// assert!(SVGElem::new(Tag::Text).try_set(Attr::X, "0 10 20").is_ok());
+    /// ```
+    pub fn try_set<T>(
+        self,
+        attribute: Attribute,
+        value: T,
+    ) -> Result<Self, Rejected<Self, SetError>>
+    where
+        T: ToString,
+    {
+        let value = value.to_string();
+        if let Some((index, character)) = value
+            .char_indices()
+            .find(|&(_, character)| !is_xml_char(character))
+        {
+            return Err(Rejected::new(
+                self,
+                SetError::InvalidCharacter { character, index },
+            ));
+        }
+
+        match attributes::validate_value(&self.tag_name, &attribute, &value) {
+            Ok(()) => Ok(self.set(attribute, value)),
+            Err(reason) => Err(Rejected::new(
+                self,
+                SetError::InvalidValue {
+                    attribute,
+                    value,
+                    reason,
+                },
+            )),
+        }
+    }
+
     /// Gets an immutable reference to the tag_name of this Element
     #[inline]
     pub fn get_tag_name(&self) -> &TagName {
@@ -136,6 +468,36 @@ impl Element {
         &self.attributes
     }
 
+    /// Returns the value of an attribute with a closed vocabulary as a typed keyword, or None if
+    /// it is not set or not part of the vocabulary, e.g. `inherit`
+    ///
+    /// # Note / Arguments
+    /// Every [Keyword](keywords/trait.Keyword.html) belongs to certain attributes, for other
+    /// attributes this always returns None, e.g. `get_keyword::<FillRule>(&Attr::Visibility)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::keywords::StrokeLinecap;
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let line = SVGElem::new(Tag::Line).set(Attr::StrokeLinecap, "round");
+    /// assert_eq!(line.get_keyword(&Attr::StrokeLinecap), Some(StrokeLinecap::Round));
+    /// ```
+    pub fn get_keyword<K>(&self, attribute: &Attribute) -> Option<K>
+    where
+        K: keywords::Keyword,
+    {
+        if !K::ATTRIBUTES.contains(attribute) {
+            return None;
+        }
+
+        let value = self.attributes.get(attribute)?;
+        match value.keyword() {
+            Some(keyword) => K::from_value(keyword),
+            None => K::from_name(value.trim()),
+        }
+    }
+
     /// Gets an immutable reference to the children of this Element
     #[inline]
     pub fn get_children(&self) -> &Children {
@@ -147,11 +509,291 @@ impl Element {
     pub fn get_inner(&self) -> &Option<String> {
         &self.inner
     }
+
+    /// Returns whether the inner content is already serialized XML, which is set by
+    /// [set_inner_raw](#method.set_inner_raw) and [set_inner_cdata](#method.set_inner_cdata)
+    #[inline]
+    pub fn is_inner_raw(&self) -> bool {
+        self.raw_inner
+    }
+
+    /// Sets an attribute of the self element in place, returning the previous value
+    #[inline]
+    pub fn set_attribute<T>(&mut self, attribute: Attribute, value: T) -> Option<String>
+    where
+        T: ToString,
+    {
+        let value = AttributeValue::new(&attribute, value.to_string());
+        self.attributes.insert(attribute, value).map(String::from)
+    }
+
+    /// Removes an attribute of the self element in place, returning the previous value
+    #[inline]
+    pub fn remove_attribute(&mut self, attribute: &Attribute) -> Option<String> {
+        self.attributes.remove(attribute).map(String::from)
+    }
+
+    /// Removes the attributes for which a predicate returns true from the self element and all
+    /// its descendants, returning the amount of removed attributes
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let mut svg = SVGElem::new(Tag::Svg)
+    ///     .set(Attr::custom("data-author"), "me")
+    ///     .append(
+    ///         SVGElem::new(Tag::Rect)
+    ///             .set(Attr::Width, 10)
+    ///             .set(Attr::Fill, "red")
+    ///             .set(Attr::custom("data-layer"), "background"),
+    ///     );
+    ///
+    /// // Drops all data attributes before publishing
+    /// let removed = svg.strip_attributes(|attribute, _| attribute.name().starts_with("data-"));
+    /// assert_eq!(removed, 2);
+    /// assert_eq!(svg.to_string(), "<svg><rect fill=\"red\" width=\"10\"/></svg>");
+    ///
+    /// // Drops everything which is not presentational
+    /// svg.strip_attributes(|attribute, _| !attribute.is_presentation());
+    /// assert_eq!(svg.to_string(), "<svg><rect fill=\"red\"/></svg>");
+    /// ```
+    pub fn strip_attributes<F>(&mut self, mut predicate: F) -> usize
+    where
+        F: FnMut(&Attribute, &str) -> bool,
+    {
+        fn strip(
+            element: &mut Element,
+            predicate: &mut dyn FnMut(&Attribute, &str) -> bool,
+        ) -> usize {
+            let before = element.attributes.len();
+            element
+                .attributes
+                .retain(|attribute, value| !predicate(attribute, value));
+            let removed = before - element.attributes.len();
+
+            removed
+                + element
+                    .children
+                    .iter_mut()
+                    .map(|child| strip(child, predicate))
+                    .sum::<usize>()
+        }
+
+        strip(self, &mut predicate)
+    }
+
+    /// Replaces every occurrence of a literal pattern in the attribute values and inner text of
+    /// the self element and all its descendants, returning the amount of replaced occurrences
+    ///
+    /// # Note / Arguments
+    /// An empty pattern replaces nothing. Use
+    /// [replace_values_regex](#method.replace_values_regex) to replace by a regular expression.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let mut svg = SVGElem::new(Tag::Svg)
+    ///     .append(SVGElem::new(Tag::Image).set(Attr::Href, "http://cdn.local/a.png"))
+    ///     .append(SVGElem::new(Tag::Text).set(Attr::FontFamily, "Arial").set_inner("Arial text"));
+    ///
+    /// assert_eq!(svg.replace_values("http://cdn.local/", "https://cdn.example.com/"), 1);
+    /// assert_eq!(svg.replace_values("Arial", "Inter"), 2);
+    /// assert_eq!(
+    ///     svg.to_string(),
+    ///     "<svg><image href=\"https://cdn.example.com/a.png\"/>\
+    ///      <text font-family=\"Inter\">Inter text</text></svg>"
+    /// );
+    /// ```
+    pub fn replace_values(&mut self, pattern: &str, replacement: &str) -> usize {
+        if pattern.is_empty() {
+            return 0;
+        }
+
+        self.replace_text(&mut |text| match text.matches(pattern).count() {
+            0 => None,
+            count => Some((text.replace(pattern, replacement), count)),
+        })
+    }
+
+    /// Replaces every match of a regular expression in the attribute values and inner text of
+    /// the self element and all its descendants, returning the amount of replaced matches
+    ///
+    /// # Note / Arguments
+    /// *The feature "regex" needs to be enabled for this*
+    ///
+    /// The replacement can refer to capture groups, e.g. `$1` or `${name}`, see
+    /// [Regex::replace_all](https://docs.rs/regex/1/regex/struct.Regex.html#method.replace_all).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "regex")]
+    /// # {
+    /// use regex::Regex;
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let mut rect = SVGElem::new(Tag::Rect)
+    ///     .set(Attr::Fill, "url(#old-gradient)")
+    ///     .set(Attr::Stroke, "url(#old-outline)");
+    ///
+    /// let pattern = Regex::new(r"#old-(\w+)").unwrap();
+    /// assert_eq!(rect.replace_values_regex(&pattern, "#new-$1"), 2);
+    /// assert_eq!(rect.get_attributes()[&Attr::Fill], "url(#new-gradient)");
+    /// # }
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn replace_values_regex(&mut self, pattern: &regex::Regex, replacement: &str) -> usize {
+        self.replace_text(&mut |text| match pattern.find_iter(text).count() {
+            0 => None,
+            count => Some((pattern.replace_all(text, replacement).into_owned(), count)),
+        })
+    }
+
+    /// Rewrites the attribute values and inner text of the self element and its descendants with
+    /// a function returning the new text and the amount of replacements, or None to keep it
+    fn replace_text(&mut self, replace: &mut dyn FnMut(&str) -> Option<(String, usize)>) -> usize {
+        let mut replaced = 0;
+        for (attribute, value) in self.attributes.iter_mut() {
+            if let Some((text, count)) = replace(value) {
+                *value = AttributeValue::new(attribute, text);
+                replaced += count;
+            }
+        }
+        for value in self.inner.iter_mut() {
+            if let Some((text, count)) = replace(value) {
+                *value = text;
+                replaced += count;
+            }
+        }
+
+        replaced
+            + self
+                .children
+                .iter_mut()
+                .map(|child| child.replace_text(replace))
+                .sum::<usize>()
+    }
+
+    /// Inserts a child at a certain index of the children of the self element
+    ///
+    /// # Panics
+    /// Panics if `index` is larger than the amount of children.
+    #[inline]
+    pub fn insert_child(&mut self, index: usize, child: Element) {
+        self.children.insert(index, child);
+    }
+
+    /// Removes and returns the child at a certain index of the children of the self element
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove_child(&mut self, index: usize) -> Element {
+        self.children.remove(index)
+    }
+
+    /// Moves the child at a certain index to the end of the children, so it is painted on top of
+    /// its siblings
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let mut group = SVGElem::new(Tag::G)
+    ///     .append(SVGElem::new(Tag::Rect))
+    ///     .append(SVGElem::new(Tag::Circle))
+    ///     .append(SVGElem::new(Tag::Line));
+    ///
+    /// group.bring_to_front(0);
+    /// assert_eq!(group.to_string(), "<g><circle/><line/><rect/></g>");
+    ///
+    /// group.send_to_back(1);
+    /// assert_eq!(group.to_string(), "<g><line/><circle/><rect/></g>");
+    /// ```
+    pub fn bring_to_front(&mut self, index: usize) {
+        let child = self.children.remove(index);
+        self.children.push(child);
+    }
+
+    /// Moves the child at a certain index to the start of the children, so it is painted below
+    /// its siblings
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn send_to_back(&mut self, index: usize) {
+        let child = self.children.remove(index);
+        self.children.insert(0, child);
+    }
+
+    /// Sorts the children with a comparison function, where children which compare as less are
+    /// painted below the others
+    ///
+    /// # Note / Arguments
+    /// The sort is stable, so children which compare as equal keep their paint order.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let z_index = |element: &SVGElem| {
+    ///     element.get_attributes().get(&Attr::custom("data-z")).and_then(|z| z.parse().ok()).unwrap_or(0)
+    /// };
+    ///
+    /// let mut group = SVGElem::new(Tag::G)
+    ///     .append(SVGElem::new(Tag::Rect).set(Attr::custom("data-z"), 2))
+    ///     .append(SVGElem::new(Tag::Circle))
+    ///     .append(SVGElem::new(Tag::Line).set(Attr::custom("data-z"), -1));
+    ///
+    /// group.reorder_children(|a, b| z_index(a).cmp(&z_index(b)));
+    /// # assert_eq!(
+    /// #     group.get_children().iter().map(|child| child.get_tag_name().to_string()).collect::<Vec<_>>(),
+    /// #     vec!["line", "circle", "rect"]
+    /// # );
+    /// ```
+    pub fn reorder_children<F>(&mut self, compare: F)
+    where
+        F: FnMut(&Element, &Element) -> Ordering,
+    {
+        self.children.sort_by(compare);
+    }
+
+    /// Gets an immutable reference to the descendant at a certain [ElementPath](tree/struct.ElementPath.html),
+    /// or None if it does not exist
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    /// use svg_definitions::tree::ElementPath;
+    ///
+    /// let svg = SVGElem::new(Tag::Svg)
+    ///     .append(SVGElem::new(Tag::G).append(SVGElem::new(Tag::Rect)));
+    ///
+    /// let rect = svg.get_path(&ElementPath::from(vec![0, 0])).unwrap();
+    /// assert_eq!(*rect.get_tag_name(), Tag::Rect);
+    /// assert!(svg.get_path(&ElementPath::from(vec![1])).is_none());
+    /// ```
+    pub fn get_path(&self, path: &ElementPath) -> Option<&Element> {
+        path.indices()
+            .iter()
+            .try_fold(self, |element, &index| element.children.get(index))
+    }
+
+    /// Gets a mutable reference to the descendant at a certain [ElementPath](tree/struct.ElementPath.html),
+    /// or None if it does not exist
+    pub fn get_path_mut(&mut self, path: &ElementPath) -> Option<&mut Element> {
+        path.indices()
+            .iter()
+            .try_fold(self, |element, &index| element.children.get_mut(index))
+    }
 }
 
 impl Clone for Element {
     fn clone(&self) -> Self {
-        let mut elem = Element::new(self.tag_name);
+        let mut elem = Element::new(self.tag_name.clone());
         for (key, value) in self.attributes.iter() {
             elem.attributes.insert(key.clone(), value.clone());
         }
@@ -161,6 +803,7 @@ impl Clone for Element {
         if let Some(inr) = &self.inner {
             elem.inner = Some(inr.to_owned());
         }
+        elem.raw_inner = self.raw_inner;
         elem
     }
 }
@@ -176,8 +819,147 @@ impl Hash for Element {
     }
 }
 
-impl Into<Element> for TagName {
-    fn into(self) -> Element {
-        Element::new(self)
+/// The error returned by the fallible setters of an [Element](struct.Element.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetError {
+    /// A character which cannot appear in XML, e.g. most control characters, at a byte index
+    InvalidCharacter { character: char, index: usize },
+
+    /// A value which is not valid for an attribute, with the reason why
+    InvalidValue {
+        attribute: Attribute,
+        value: String,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for SetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetError::InvalidCharacter { character, index } => write!(
+                f,
+                "the character {:?} at byte {} cannot appear in XML",
+                character, index
+            ),
+            SetError::InvalidValue {
+                attribute,
+                value,
+                reason,
+            } => write!(
+                f,
+                "invalid value {:?} for attribute {}: {}",
+                value, attribute, reason
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SetError {}
+
+/// An error which gives back the value it occurred on, e.g. the element which an append was
+/// rejected for, so it is not lost
+///
+/// # Examples
+/// ```
+/// use svg_definitions::limits::{LimitError, Limits};
+/// use svg_definitions::prelude::*;
+///
+/// let limits = Limits { max_children: Some(1), ..Limits::default() };
+/// let rejected = SVGElem::new(Tag::G)
+///     .append(SVGElem::new(Tag::Rect))
+///     .try_append(SVGElem::new(Tag::Circle), &limits)
+///     .unwrap_err();
+///
+/// assert_eq!(*rejected.error(), LimitError::TooManyChildren { limit: 1 });
+/// assert_eq!(rejected.into_value().get_children().len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rejected<T, E> {
+    // Boxed, so results stay small
+    value: Box<T>,
+    error: E,
+}
+
+impl<T, E> Rejected<T, E> {
+    pub(crate) fn new(value: T, error: E) -> Rejected<T, E> {
+        Rejected {
+            value: Box::new(value),
+            error,
+        }
+    }
+
+    /// Gets the error
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Gets the value the error occurred on
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the value the error occurred on
+    #[inline]
+    pub fn into_value(self) -> T {
+        *self.value
+    }
+
+    /// Returns the value the error occurred on and the error
+    #[inline]
+    pub fn into_parts(self) -> (T, E) {
+        (*self.value, self.error)
+    }
+}
+
+impl<T, E: fmt::Display> fmt::Display for Rejected<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl<T: fmt::Debug, E: std::error::Error + 'static> std::error::Error for Rejected<T, E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl fmt::Display for Element {
+    /// Serializes the Element and its children to SVG markup, with the attributes sorted by name
+    /// and the inner text and attribute values escaped
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}", self.tag_name)?;
+
+        let mut attributes: Vec<(String, &str)> = self
+            .attributes
+            .iter()
+            .map(|(attribute, value)| (attribute.to_string(), value.as_str()))
+            .collect();
+        attributes.sort();
+        for (name, value) in attributes {
+            write!(f, " {}=\"{}\"", name, escape_attribute(value))?;
+        }
+
+        if self.inner.is_none() && self.children.is_empty() {
+            return f.write_str("/>");
+        }
+
+        f.write_str(">")?;
+        match &self.inner {
+            Some(inner) if self.raw_inner => f.write_str(inner)?,
+            Some(inner) => f.write_str(&escape_text(inner))?,
+            None => (),
+        }
+        for child in self.children.iter() {
+            write!(f, "{}", child)?;
+        }
+        write!(f, "</{}>", self.tag_name)
+    }
+}
+
+impl From<TagName> for Element {
+    fn from(tag_name: TagName) -> Element {
+        Element::new(tag_name)
     }
 }
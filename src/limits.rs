@@ -0,0 +1,424 @@
+//! This module provides opt-in [Limits](struct.Limits.html) on the size of element trees, so
+//! applications which generate SVG from untrusted data cannot accidentally build unbounded
+//! trees.
+//!
+//! Limits are enforced while building by a [GuardedElement](struct.GuardedElement.html), which
+//! keeps track of the size of its whole tree, by
+//! [Element::try_append](../struct.Element.html#method.try_append) for a single append, by a
+//! [DocumentBuilder](../document/struct.DocumentBuilder.html#method.limits) with limits and by
+//! [check](struct.Limits.html#method.check) on any tree.
+//!
+//! # Examples
+//! ## Rendering user supplied data points
+//! ```
+//! use svg_definitions::limits::{LimitError, Limits};
+//! use svg_definitions::prelude::*;
+//!
+//! let limits = Limits {
+//!     max_nodes: Some(1000),
+//!     ..Limits::default()
+//! };
+//!
+//! let points = vec![(1.0, 2.0); 5000];
+//! let chart = points.iter().try_fold(limits.guard(SVGElem::new(Tag::G)).unwrap(), |chart, (x, y)| {
+//!     chart.try_append(SVGElem::new(Tag::Circle).set(Attr::Cx, x).set(Attr::Cy, y))
+//! });
+//!
+//! // The chart which was built so far is kept
+//! let (chart, error) = chart.unwrap_err().into_parts();
+//! assert_eq!(error, LimitError::TooManyNodes { limit: 1000 });
+//! assert_eq!(chart.nodes(), 1000);
+//! ```
+
+use std::fmt;
+
+use crate::{Element, Rejected};
+
+/// The error returned when an element tree exceeds [Limits](struct.Limits.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitError {
+    /// An element has more children than the limit
+    TooManyChildren { limit: usize },
+
+    /// The tree has more levels than the limit
+    TooDeep { limit: usize },
+
+    /// The tree has more elements than the limit
+    TooManyNodes { limit: usize },
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LimitError::TooManyChildren { limit } => {
+                write!(f, "an element has more than {} children", limit)
+            }
+            LimitError::TooDeep { limit } => {
+                write!(f, "the tree is deeper than {} levels", limit)
+            }
+            LimitError::TooManyNodes { limit } => {
+                write!(f, "the tree has more than {} elements", limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LimitError {}
+
+/// Upper bounds on the size of an element tree, where None means unlimited
+///
+/// # Note / Arguments
+/// The depth counts levels, so a single element has a depth of 1 and its children a depth of 2.
+/// The default has no limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Limits {
+    /// The maximum amount of children of every element
+    pub max_children: Option<usize>,
+
+    /// The maximum amount of levels of the tree
+    pub max_depth: Option<usize>,
+
+    /// The maximum amount of elements in the tree, including the root
+    pub max_nodes: Option<usize>,
+}
+
+impl Limits {
+    /// Checks an element and all its descendants against the limits
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::limits::{LimitError, Limits};
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let nested = SVGElem::new(Tag::Svg).append(SVGElem::new(Tag::G).append(SVGElem::new(Tag::Rect)));
+    /// let limits = Limits { max_depth: Some(2), ..Limits::default() };
+    ///
+    /// assert_eq!(limits.check(&nested), Err(LimitError::TooDeep { limit: 2 }));
+    /// assert_eq!(Limits::default().check(&nested), Ok(()));
+    /// ```
+    pub fn check(&self, root: &Element) -> Result<(), LimitError> {
+        self.check_subtree(root, 1, &mut 0)
+    }
+
+    /// Checks an element at a certain depth and its descendants, adding them to `nodes`
+    pub(crate) fn check_subtree(
+        &self,
+        element: &Element,
+        depth: usize,
+        nodes: &mut usize,
+    ) -> Result<(), LimitError> {
+        *nodes += 1;
+        if let Some(limit) = self.max_nodes.filter(|limit| *nodes > *limit) {
+            return Err(LimitError::TooManyNodes { limit });
+        }
+        if let Some(limit) = self.max_depth.filter(|limit| depth > *limit) {
+            return Err(LimitError::TooDeep { limit });
+        }
+        if let Some(limit) = self
+            .max_children
+            .filter(|limit| element.children.len() > *limit)
+        {
+            return Err(LimitError::TooManyChildren { limit });
+        }
+
+        for child in element.children.iter() {
+            self.check_subtree(child, depth + 1, nodes)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the amount of levels of a tree
+fn depth(element: &Element) -> usize {
+    1 + element.children.iter().map(depth).max().unwrap_or(0)
+}
+
+impl Limits {
+    /// Starts building a tree within the limits from an element, or gives back the element in an
+    /// error if it already exceeds them
+    pub fn guard(&self, element: Element) -> Result<GuardedElement, Rejected<Element, LimitError>> {
+        let mut nodes = 0;
+        if let Err(error) = self.check_subtree(&element, 1, &mut nodes) {
+            return Err(Rejected::new(element, error));
+        }
+
+        Ok(GuardedElement {
+            depth: depth(&element),
+            element,
+            limits: *self,
+            nodes,
+        })
+    }
+}
+
+/// An element which keeps track of the size of its tree, so every append is checked against
+/// [Limits](struct.Limits.html) for the whole tree, as returned by
+/// [Limits::guard](struct.Limits.html#method.guard)
+///
+/// # Note / Arguments
+/// Appending an element takes time linear in the size of the appended subtree, and appending
+/// a guarded element takes constant time, so trees can be built from guarded subtrees. When an
+/// append would exceed the limits, the guarded element is given back unchanged in the error.
+#[derive(Debug, Clone)]
+pub struct GuardedElement {
+    element: Element,
+    limits: Limits,
+    nodes: usize,
+    depth: usize,
+}
+
+impl GuardedElement {
+    /// Gets the element
+    #[inline]
+    pub fn get(&self) -> &Element {
+        &self.element
+    }
+
+    /// Returns the element, ending the guard
+    #[inline]
+    pub fn into_inner(self) -> Element {
+        self.element
+    }
+
+    /// Returns the amount of elements in the tree, including the root
+    #[inline]
+    pub fn nodes(&self) -> usize {
+        self.nodes
+    }
+
+    /// Returns the amount of levels of the tree
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Appends an element to the children, or gives back this element in an error if the tree
+    /// would exceed the limits
+    pub fn try_append(self, child: Element) -> Result<Self, Rejected<Self, LimitError>> {
+        if let Err(error) = self.check_children() {
+            return Err(Rejected::new(self, error));
+        }
+        let mut nodes = self.nodes;
+        if let Err(error) = self.limits.check_subtree(&child, 2, &mut nodes) {
+            return Err(Rejected::new(self, error));
+        }
+
+        let depth = self.depth.max(depth(&child) + 1);
+        Ok(self.push(child, nodes, depth))
+    }
+
+    /// Appends a guarded element to the children, or gives back this element in an error if the
+    /// tree would exceed the limits of this element
+    ///
+    /// # Note / Arguments
+    /// The appended tree is assumed to be within the limits of this element except for its size
+    /// and depth, as when it was guarded by the same limits.
+    pub fn try_append_guarded(
+        self,
+        child: GuardedElement,
+    ) -> Result<Self, Rejected<Self, LimitError>> {
+        let (nodes, depth) = (self.nodes + child.nodes, self.depth.max(child.depth + 1));
+        let error =
+            self.check_children()
+                .and(match (self.limits.max_nodes, self.limits.max_depth) {
+                    (Some(limit), _) if nodes > limit => Err(LimitError::TooManyNodes { limit }),
+                    (_, Some(limit)) if depth > limit => Err(LimitError::TooDeep { limit }),
+                    _ => Ok(()),
+                });
+        match error {
+            Ok(()) => Ok(self.push(child.element, nodes, depth)),
+            Err(error) => Err(Rejected::new(self, error)),
+        }
+    }
+
+    /// Checks whether one more child can be appended
+    fn check_children(&self) -> Result<(), LimitError> {
+        match self.limits.max_children {
+            Some(limit) if self.element.children.len() >= limit => {
+                Err(LimitError::TooManyChildren { limit })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn push(mut self, child: Element, nodes: usize, depth: usize) -> Self {
+        self.element.children.push(child);
+        self.nodes = nodes;
+        self.depth = depth;
+        self
+    }
+}
+
+impl Element {
+    /// Appends an element to the children like [append](#method.append), but gives back this
+    /// element in an error if this exceeds some [Limits](limits/struct.Limits.html)
+    ///
+    /// # Note / Arguments
+    /// Only what is added is checked: the amount of children of this element, and the appended
+    /// subtree, which counts as starting one level deep. The size of the rest of the tree is not
+    /// known to a single element, so use a
+    /// [GuardedElement](limits/struct.GuardedElement.html) to enforce the limits while building
+    /// a whole tree.
+    pub fn try_append(
+        self,
+        child: Element,
+        limits: &Limits,
+    ) -> Result<Self, Rejected<Self, LimitError>> {
+        if let Some(limit) = limits
+            .max_children
+            .filter(|limit| self.children.len() >= *limit)
+        {
+            return Err(Rejected::new(self, LimitError::TooManyChildren { limit }));
+        }
+
+        // The appended subtree and this element
+        if let Err(error) = limits.check_subtree(&child, 2, &mut 1) {
+            return Err(Rejected::new(self, error));
+        }
+
+        Ok(self.append(child))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LimitError, Limits};
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    fn chain(length: usize) -> Element {
+        (1..length).fold(Element::new(TagName::G), |child, _| {
+            Element::new(TagName::G).append(child)
+        })
+    }
+
+    #[test]
+    fn test_check() {
+        let limits = Limits {
+            max_children: Some(2),
+            max_depth: Some(3),
+            max_nodes: Some(4),
+        };
+        assert_eq!(limits.check(&chain(3)), Ok(()));
+        assert_eq!(
+            limits.check(&chain(4)),
+            Err(LimitError::TooDeep { limit: 3 })
+        );
+
+        let wide = Element::new(TagName::G)
+            .append(Element::new(TagName::Rect))
+            .append(Element::new(TagName::Rect))
+            .append(Element::new(TagName::Rect));
+        assert_eq!(
+            limits.check(&wide),
+            Err(LimitError::TooManyChildren { limit: 2 })
+        );
+
+        let bushy = Element::new(TagName::G).append(wide.clone()).append(wide);
+        let limits = Limits {
+            max_nodes: Some(8),
+            ..Limits::default()
+        };
+        assert_eq!(
+            limits.check(&bushy),
+            Err(LimitError::TooManyNodes { limit: 8 })
+        );
+    }
+
+    #[test]
+    fn test_try_append() {
+        let limits = Limits {
+            max_children: Some(1),
+            max_depth: Some(3),
+            max_nodes: Some(4),
+        };
+        let root = Element::new(TagName::Svg)
+            .try_append(chain(2), &limits)
+            .unwrap();
+        assert_eq!(
+            root.try_append(Element::new(TagName::Rect), &limits)
+                .unwrap_err()
+                .into_parts()
+                .1,
+            LimitError::TooManyChildren { limit: 1 }
+        );
+        assert_eq!(
+            Element::new(TagName::Svg)
+                .try_append(chain(3), &limits)
+                .unwrap_err()
+                .into_parts()
+                .1,
+            LimitError::TooDeep { limit: 3 }
+        );
+
+        let pair = Element::new(TagName::G)
+            .append(Element::new(TagName::Rect))
+            .append(Element::new(TagName::Rect));
+        let limits = Limits {
+            max_nodes: Some(3),
+            ..Limits::default()
+        };
+        let (svg, error) = Element::new(TagName::Svg)
+            .set(Attribute::Id, "kept")
+            .try_append(pair, &limits)
+            .unwrap_err()
+            .into_parts();
+        assert_eq!(error, LimitError::TooManyNodes { limit: 3 });
+        assert_eq!(svg.get_attributes()[&Attribute::Id], "kept");
+    }
+
+    #[test]
+    fn test_guard() {
+        let limits = Limits {
+            max_children: Some(2),
+            max_depth: Some(3),
+            max_nodes: Some(6),
+        };
+        assert_eq!(
+            limits.guard(chain(4)).unwrap_err().into_parts().1,
+            LimitError::TooDeep { limit: 3 }
+        );
+
+        // The whole tree is counted across appends
+        let guarded = limits
+            .guard(Element::new(TagName::Svg))
+            .unwrap()
+            .try_append(chain(2))
+            .unwrap();
+        assert_eq!((guarded.nodes(), guarded.depth()), (3, 3));
+        let (guarded, error) = guarded.try_append(chain(3)).unwrap_err().into_parts();
+        assert_eq!(error, LimitError::TooDeep { limit: 3 });
+        let guarded = guarded.try_append(Element::new(TagName::Rect)).unwrap();
+        let (guarded, error) = guarded
+            .try_append(Element::new(TagName::Rect))
+            .unwrap_err()
+            .into_parts();
+        assert_eq!(error, LimitError::TooManyChildren { limit: 2 });
+        assert_eq!(guarded.get().get_children().len(), 2);
+
+        let group = limits
+            .guard(Element::new(TagName::G))
+            .unwrap()
+            .try_append(Element::new(TagName::Rect))
+            .unwrap();
+        let root = limits.guard(Element::new(TagName::Svg)).unwrap();
+        let root = root.try_append_guarded(group.clone()).unwrap();
+        assert_eq!((root.nodes(), root.depth()), (3, 3));
+        let root = root.try_append_guarded(group.clone()).unwrap();
+        assert_eq!(root.nodes(), 5);
+
+        let (root, error) = limits
+            .guard(Element::new(TagName::Svg))
+            .unwrap()
+            .try_append_guarded(group.clone())
+            .unwrap()
+            .try_append(Element::new(TagName::Rect))
+            .unwrap()
+            .try_append_guarded(group)
+            .unwrap_err()
+            .into_parts();
+        assert_eq!(error, LimitError::TooManyChildren { limit: 2 });
+        assert_eq!(root.into_inner().get_children().len(), 2);
+    }
+}
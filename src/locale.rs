@@ -0,0 +1,188 @@
+//! This module provides the [Localizer](trait.Localizer.html) trait, which builders consult for
+//! the messages, numbers and dates in the text they generate, so the same code produces assets
+//! in every language.
+//!
+//! # Examples
+//! ## A label in German
+//! ```
+//! use svg_definitions::locale::{Catalog, Localizer};
+//! use svg_definitions::text::TextBuilder;
+//!
+//! let german = Catalog::new()
+//!     .message("legend.total", "Gesamt")
+//!     .separators(',', ".")
+//!     .date_pattern("%d.%m.%Y");
+//!
+//! let label = TextBuilder::new((0.0, 0.0))
+//!     .localized_line(&german, "legend.total")
+//!     .line(&german.format_number(12345.678, 2))
+//!     .line(&german.format_date(2024, 3, 1))
+//!     .build();
+//! # let lines: Vec<_> = label.get_children().iter().map(|tspan| tspan.get_inner().clone().unwrap()).collect();
+//! # assert_eq!(lines, ["Gesamt", "12.345,68", "01.03.2024"]);
+//! ```
+
+use std::collections::HashMap;
+
+/// Translates messages and formats numbers and dates for a language
+///
+/// # Note / Arguments
+/// Only [t](#tymethod.t) has to be implemented. Numbers are formatted with a `.` as decimal
+/// separator and a `,` between groups of thousands and dates as `YYYY-MM-DD` by default.
+pub trait Localizer {
+    /// Returns the translation of a message key, e.g. `legend.total`
+    fn t(&self, key: &str) -> String;
+
+    /// Formats a number with a fixed amount of decimals
+    fn format_number(&self, value: f64, decimals: usize) -> String {
+        group_number(value, decimals, '.', ",")
+    }
+
+    /// Formats a date, where the month and day start at 1
+    fn format_date(&self, year: i32, month: u32, day: u32) -> String {
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+/// Translates keys with a map, keeping the keys which are missing from it
+impl Localizer for HashMap<String, String> {
+    fn t(&self, key: &str) -> String {
+        self.get(key).cloned().unwrap_or_else(|| String::from(key))
+    }
+}
+
+/// A [Localizer](trait.Localizer.html) with a table of messages and the number and date formats
+/// of a language
+///
+/// # Note / Arguments
+/// Keys which are missing from the table are returned as they are, which makes missing
+/// translations visible in the generated assets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+    decimal_separator: char,
+    group_separator: String,
+    date_pattern: String,
+}
+
+impl Catalog {
+    /// Creates an empty catalog with the default formats of [Localizer](trait.Localizer.html)
+    pub fn new() -> Catalog {
+        Catalog {
+            messages: HashMap::new(),
+            decimal_separator: '.',
+            group_separator: String::from(","),
+            date_pattern: String::from("%Y-%m-%d"),
+        }
+    }
+
+    /// Adds the translation of a message key
+    pub fn message(mut self, key: &str, translation: &str) -> Self {
+        self.messages
+            .insert(String::from(key), String::from(translation));
+        self
+    }
+
+    /// Sets the separator before the decimals and the separator between groups of thousands,
+    /// which can be empty
+    pub fn separators(mut self, decimal: char, group: &str) -> Self {
+        self.decimal_separator = decimal;
+        self.group_separator = String::from(group);
+        self
+    }
+
+    /// Sets the format of dates, where `%Y` is replaced by the year, `%m` by the month and `%d`
+    /// by the day, the latter two with two digits
+    pub fn date_pattern(mut self, pattern: &str) -> Self {
+        self.date_pattern = String::from(pattern);
+        self
+    }
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        Catalog::new()
+    }
+}
+
+impl Localizer for Catalog {
+    fn t(&self, key: &str) -> String {
+        self.messages.t(key)
+    }
+
+    fn format_number(&self, value: f64, decimals: usize) -> String {
+        group_number(
+            value,
+            decimals,
+            self.decimal_separator,
+            &self.group_separator,
+        )
+    }
+
+    fn format_date(&self, year: i32, month: u32, day: u32) -> String {
+        self.date_pattern
+            .replace("%Y", &format!("{:04}", year))
+            .replace("%m", &format!("{:02}", month))
+            .replace("%d", &format!("{:02}", day))
+    }
+}
+
+/// Formats a number with a fixed amount of decimals and separators between groups of thousands
+fn group_number(
+    value: f64,
+    decimals: usize,
+    decimal_separator: char,
+    group_separator: &str,
+) -> String {
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (&formatted[..], None),
+    };
+
+    // Values like -0.001 would otherwise be written as -0.00
+    let mut output = String::new();
+    if value < 0.0 && formatted.bytes().any(|byte| matches!(byte, b'1'..=b'9')) {
+        output.push('-');
+    }
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            output.push_str(group_separator);
+        }
+        output.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        output.push(decimal_separator);
+        output.push_str(fraction);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Catalog, Localizer};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_localizers() {
+        let mut messages = HashMap::new();
+        messages.insert(String::from("axis.time"), String::from("Time"));
+        assert_eq!(messages.t("axis.time"), "Time");
+        assert_eq!(messages.t("axis.value"), "axis.value");
+        assert_eq!(messages.format_number(-1234567.891, 1), "-1,234,567.9");
+        assert_eq!(messages.format_number(-0.001, 2), "0.00");
+        assert_eq!(messages.format_number(999.0, 0), "999");
+        assert_eq!(messages.format_date(987, 12, 31), "0987-12-31");
+
+        let french = Catalog::new()
+            .message("axis.time", "Temps")
+            .separators(',', "\u{202f}")
+            .date_pattern("%d/%m/%Y");
+        assert_eq!(french.t("axis.time"), "Temps");
+        assert_eq!(french.format_number(1000.5, 2), "1\u{202f}000,50");
+        assert_eq!(french.format_date(2024, 7, 14), "14/07/2024");
+
+        let plain = Catalog::new().separators('.', "");
+        assert_eq!(plain.format_number(1234.4, 0), "1234");
+    }
+}
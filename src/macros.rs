@@ -0,0 +1,178 @@
+/// Defines an enum of SVG names from one table of `Variant = "name"` entries
+///
+/// # Note / Arguments
+/// The `Custom(String)` variant, which holds any name outside of the table, is declared first
+/// and emitted last. Besides the enum, this generates:
+///
+/// * `ALL`, a slice of every variant in the table
+/// * `iter`, iterating over `ALL`
+/// * `name`, returning the name of a variant
+/// * `from_name`, returning the variant of a name in the table
+macro_rules! named_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $enum:ident {
+            $(#[$custom_meta:meta])*
+            Custom(String);
+
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $name:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        pub enum $enum {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+
+            $(#[$custom_meta])*
+            Custom(String),
+        }
+
+        impl $enum {
+            /// Every variant except `Custom`, in the order of the table
+            pub const ALL: &'static [$enum] = &[$($enum::$variant,)*];
+
+            /// Returns an iterator over every variant except `Custom`, in the order of the table
+            pub fn iter() -> std::slice::Iter<'static, $enum> {
+                Self::ALL.iter()
+            }
+
+            /// Returns the name as it is written in SVG, e.g. `stroke-width` or `linearGradient`
+            pub fn name(&self) -> &str {
+                match self {
+                    $($enum::$variant => $name,)*
+                    $enum::Custom(name) => &name[..],
+                }
+            }
+
+            /// Returns the variant with a certain name, or None if the name is not in the table
+            pub fn from_name(name: &str) -> Option<$enum> {
+                match name {
+                    $($name => Some($enum::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+/// Defines an enum of names without a `Custom` variant from one table of `Variant = "name"`
+/// entries, e.g. the units of lengths
+///
+/// # Note / Arguments
+/// Besides the enum, this generates `ALL`, `name`, `from_name`, `Display` and `FromStr`.
+macro_rules! closed_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $enum:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $name:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $enum {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+        }
+
+        impl $enum {
+            /// Every keyword, in the order of the table
+            pub const ALL: &'static [$enum] = &[$($enum::$variant,)*];
+
+            /// Returns the keyword as it is written in SVG
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($enum::$variant => $name,)*
+                }
+            }
+
+            /// Returns the variant of a keyword, or None if it is not in the table
+            pub fn from_name(name: &str) -> Option<$enum> {
+                match name {
+                    $($name => Some($enum::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl std::fmt::Display for $enum {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        impl std::str::FromStr for $enum {
+            type Err = crate::keywords::UnknownKeyword;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $enum::from_name(s.trim())
+                    .ok_or_else(|| crate::keywords::UnknownKeyword(String::from(s)))
+            }
+        }
+    };
+}
+
+/// Defines an enum of the keywords of attributes with a closed vocabulary from one table of
+/// `Variant = "keyword"` entries
+///
+/// # Note / Arguments
+/// The enum is declared with `for [Attribute, ...]`, the attributes which have it as their
+/// vocabulary. Besides everything [closed_enum](macro.closed_enum.html) generates, this
+/// implements [Keyword](keywords/trait.Keyword.html), which needs a variant of the same name in
+/// [KeywordValue](keywords/enum.KeywordValue.html).
+macro_rules! keyword_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $enum:ident for [$($attribute:ident),+ $(,)?] {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $name:literal,
+            )*
+        }
+    ) => {
+        closed_enum! {
+            $(#[$meta])*
+            pub enum $enum {
+                $(
+                    $(#[$variant_meta])*
+                    $variant = $name,
+                )*
+            }
+        }
+
+        impl crate::keywords::Keyword for $enum {
+            const ATTRIBUTES: &'static [crate::attributes::Attribute] =
+                &[$(crate::attributes::Attribute::$attribute,)+];
+
+            fn name(&self) -> &'static str {
+                $enum::name(self)
+            }
+
+            fn from_name(name: &str) -> Option<Self> {
+                $enum::from_name(name)
+            }
+
+            fn from_value(value: crate::keywords::KeywordValue) -> Option<Self> {
+                match value {
+                    crate::keywords::KeywordValue::$enum(keyword) => Some(keyword),
+                    _ => None,
+                }
+            }
+        }
+
+        impl From<$enum> for crate::keywords::KeywordValue {
+            fn from(keyword: $enum) -> crate::keywords::KeywordValue {
+                crate::keywords::KeywordValue::$enum(keyword)
+            }
+        }
+    };
+}
@@ -0,0 +1,215 @@
+//! This module provides the [MarkerBuilder](struct.MarkerBuilder.html), which sets up the
+//! attributes of a `<marker>` together, and prefab arrowheads, dots and squares.
+//!
+//! # Examples
+//! ## An arrow
+//! ```
+//! use svg_definitions::markers::MarkerBuilder;
+//! use svg_definitions::prelude::*;
+//!
+//! let svg = SVGElem::new(Tag::Svg)
+//!     .append(SVGElem::new(Tag::Defs).append(MarkerBuilder::arrow_head().build()))
+//!     .append(
+//!         SVGElem::new(Tag::Line)
+//!             .set(Attr::X2, 50)
+//!             .set(Attr::Stroke, "black")
+//!             .set(Attr::MarkerEnd, "url(#arrow-head)"),
+//!     );
+//! ```
+
+use std::fmt;
+
+use crate::attributes::Attribute;
+use crate::context::Context;
+use crate::tag_name::TagName;
+use crate::Element;
+
+/// The rotation of a marker, i.e. the `orient` attribute
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Orient {
+    /// Follows the direction of the path
+    Auto,
+
+    /// Follows the direction of the path, but points backwards at the start of the path, so the
+    /// same arrowhead can be used for both ends
+    AutoStartReverse,
+
+    /// A fixed angle in degrees
+    Angle(f64),
+}
+
+impl fmt::Display for Orient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Orient::Auto => f.write_str("auto"),
+            Orient::AutoStartReverse => f.write_str("auto-start-reverse"),
+            Orient::Angle(angle) => write!(f, "{}", angle),
+        }
+    }
+}
+
+/// MarkerBuilder assembles a `<marker>` element, which draws its content at the vertices of
+/// paths, lines, polylines and polygons referencing it
+///
+/// # Note / Arguments
+/// The content is drawn in the coordinates of the view box, which is `0 0 10 10` unless set
+/// otherwise. It is scaled to the marker size, in multiples of the stroke width of the
+/// referencing element, and the reference point is placed on the vertex. The content inherits
+/// the fill of the marker, which can be set with [set](#method.set).
+///
+/// # Examples
+/// ```
+/// use svg_definitions::markers::{MarkerBuilder, Orient};
+/// use svg_definitions::prelude::*;
+///
+/// let marker = MarkerBuilder::new("tick")
+///     .size(4.0, 4.0)
+///     .reference(5.0, 10.0)
+///     .orient(Orient::Auto)
+///     .set(Attr::Stroke, "black")
+///     .append(SVGElem::new(Tag::Line).set(Attr::X1, 5).set(Attr::X2, 5).set(Attr::Y2, 10))
+///     .build();
+///
+/// assert_eq!(
+///     marker.to_string(),
+///     "<marker id=\"tick\" markerHeight=\"4\" markerWidth=\"4\" orient=\"auto\" refX=\"5\" \
+///      refY=\"10\" stroke=\"black\" viewBox=\"0 0 10 10\"><line x1=\"5\" x2=\"5\" y2=\"10\"/></marker>"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct MarkerBuilder {
+    marker: Element,
+}
+
+impl MarkerBuilder {
+    /// Creates a new builder for a marker with a certain id, a view box of `0 0 10 10` and the
+    /// reference point in the center
+    pub fn new(id: &str) -> MarkerBuilder {
+        MarkerBuilder {
+            marker: Element::new(TagName::Marker)
+                .set(Attribute::Id, id)
+                .set(Attribute::ViewBox, "0 0 10 10")
+                .set(Attribute::RefX, 5)
+                .set(Attribute::RefY, 5),
+        }
+    }
+
+    /// Returns a triangular arrowhead with the id `arrow-head`, whose tip is on the vertex and
+    /// which points outwards at both ends of a path
+    pub fn arrow_head() -> MarkerBuilder {
+        MarkerBuilder::new("arrow-head")
+            .size(6.0, 6.0)
+            .reference(10.0, 5.0)
+            .orient(Orient::AutoStartReverse)
+            .append(Element::new(TagName::Path).set(Attribute::D, "M 0 0 L 10 5 L 0 10 Z"))
+    }
+
+    /// Returns a dot with the id `dot`, centered on the vertex
+    pub fn dot() -> MarkerBuilder {
+        MarkerBuilder::new("dot").size(4.0, 4.0).append(
+            Element::new(TagName::Circle)
+                .set(Attribute::Cx, 5)
+                .set(Attribute::Cy, 5)
+                .set(Attribute::R, 5),
+        )
+    }
+
+    /// Returns a square with the id `square`, centered on the vertex and rotated along the path
+    pub fn square() -> MarkerBuilder {
+        MarkerBuilder::new("square")
+            .size(4.0, 4.0)
+            .orient(Orient::Auto)
+            .append(
+                Element::new(TagName::Rect)
+                    .set(Attribute::Width, 10)
+                    .set(Attribute::Height, 10),
+            )
+    }
+
+    /// Sets the id of the marker, which is referenced as `url(#id)`
+    pub fn id(self, id: &str) -> Self {
+        self.set(Attribute::Id, id)
+    }
+
+    /// Names the id after the id strategy of a [Context](../context/struct.Context.html) and
+    /// fills the marker with the foreground color of its theme
+    pub fn context(self, context: &Context) -> Self {
+        let name = self.marker.attributes[&Attribute::Id].clone();
+        self.id(&context.id(&name))
+            .set(Attribute::Fill, &context.theme.foreground)
+    }
+
+    /// Sets the size the view box is scaled to, in multiples of the stroke width
+    pub fn size(self, width: f64, height: f64) -> Self {
+        self.set(Attribute::MarkerWidth, width)
+            .set(Attribute::MarkerHeight, height)
+    }
+
+    /// Sets the point of the view box which is placed on the vertex
+    pub fn reference(self, x: f64, y: f64) -> Self {
+        self.set(Attribute::RefX, x).set(Attribute::RefY, y)
+    }
+
+    /// Sets the rotation of the marker
+    pub fn orient(self, orient: Orient) -> Self {
+        self.set(Attribute::Orient, orient)
+    }
+
+    /// Sets the view box the content is drawn in
+    pub fn view_box(self, min_x: f64, min_y: f64, width: f64, height: f64) -> Self {
+        self.set(
+            Attribute::ViewBox,
+            format!("{} {} {} {}", min_x, min_y, width, height),
+        )
+    }
+
+    /// Sets an attribute of the `<marker>` element, e.g. the fill inherited by its content
+    pub fn set<T>(mut self, attribute: Attribute, value: T) -> Self
+    where
+        T: ToString,
+    {
+        self.marker = self.marker.set(attribute, value);
+        self
+    }
+
+    /// Appends an element to the content of the marker
+    pub fn append(mut self, element: Element) -> Self {
+        self.marker = self.marker.append(element);
+        self
+    }
+
+    /// Assembles the `<marker>` element
+    pub fn build(self) -> Element {
+        self.marker
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarkerBuilder;
+    use crate::attributes::Attribute;
+
+    #[test]
+    fn test_prefabs() {
+        let arrow = MarkerBuilder::arrow_head()
+            .id("red-arrow")
+            .set(Attribute::Fill, "red")
+            .build();
+        assert_eq!(
+            arrow.to_string(),
+            "<marker fill=\"red\" id=\"red-arrow\" markerHeight=\"6\" markerWidth=\"6\" \
+             orient=\"auto-start-reverse\" refX=\"10\" refY=\"5\" viewBox=\"0 0 10 10\">\
+             <path d=\"M 0 0 L 10 5 L 0 10 Z\"/></marker>"
+        );
+
+        let dot = MarkerBuilder::dot().build();
+        assert_eq!(dot.get_attributes()[&Attribute::RefX], "5");
+        assert!(!dot.get_attributes().contains_key(&Attribute::Orient));
+
+        let square = MarkerBuilder::square()
+            .view_box(-1.0, -1.0, 12.0, 12.0)
+            .build();
+        assert_eq!(square.get_attributes()[&Attribute::ViewBox], "-1 -1 12 12");
+        assert_eq!(square.get_attributes()[&Attribute::Orient], "auto");
+    }
+}
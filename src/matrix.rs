@@ -0,0 +1,307 @@
+//! This module provides the [Matrix](struct.Matrix.html) of 2D affine transforms, which
+//! composes, inverts and decomposes transforms, e.g. to flatten nested transforms or to map a
+//! pointer position back into the user space of an element.
+//!
+//! # Examples
+//! ## Hit-testing a rotated rectangle
+//! ```
+//! use svg_definitions::geometry::BoundingBox;
+//! use svg_definitions::Matrix;
+//!
+//! let rect = BoundingBox::new(0.0, 0.0, 40.0, 10.0);
+//! let transform = Matrix::translate(100.0, 100.0) * Matrix::rotate(90.0);
+//!
+//! // Map the pointer into the user space of the rectangle
+//! let (x, y) = transform.inverse().unwrap().apply((95.0, 120.0));
+//! assert!(x >= rect.x && x <= rect.right() && y >= rect.y && y <= rect.bottom());
+//! ```
+
+use std::fmt;
+use std::ops::Mul;
+
+use crate::transform::Transform;
+
+/// A 2D affine transform, mapping (x, y) to (a * x + c * y + e, b * x + d * y + f)
+///
+/// # Note / Arguments
+/// The coefficients are those of the SVG `matrix(a b c d e f)` transform, which is how a matrix
+/// is formatted. Multiplying `first * second` gives the matrix which applies `second` first,
+/// like the transform list `first second` does.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+impl Matrix {
+    /// The transform which maps every point to itself
+    pub const IDENTITY: Matrix = Matrix::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
+    /// Creates a matrix from its coefficients
+    #[inline]
+    pub const fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Matrix {
+        Matrix { a, b, c, d, e, f }
+    }
+
+    /// Creates a translation
+    #[inline]
+    pub fn translate(x: f64, y: f64) -> Matrix {
+        Matrix::new(1.0, 0.0, 0.0, 1.0, x, y)
+    }
+
+    /// Creates a scaling around the origin
+    #[inline]
+    pub fn scale(x: f64, y: f64) -> Matrix {
+        Matrix::new(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// Creates a rotation around the origin by an angle in degrees, which is clockwise on screen
+    #[inline]
+    pub fn rotate(angle: f64) -> Matrix {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        Matrix::new(cos, sin, -sin, cos, 0.0, 0.0)
+    }
+
+    /// Creates a skew along the x axis by an angle in degrees
+    #[inline]
+    pub fn skew_x(angle: f64) -> Matrix {
+        Matrix::new(1.0, 0.0, angle.to_radians().tan(), 1.0, 0.0, 0.0)
+    }
+
+    /// Creates a skew along the y axis by an angle in degrees
+    #[inline]
+    pub fn skew_y(angle: f64) -> Matrix {
+        Matrix::new(1.0, angle.to_radians().tan(), 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Returns the matrix which applies `other` first and then this matrix
+    pub fn multiply(&self, other: &Matrix) -> Matrix {
+        Matrix::new(
+            self.a * other.a + self.c * other.b,
+            self.b * other.a + self.d * other.b,
+            self.a * other.c + self.c * other.d,
+            self.b * other.c + self.d * other.d,
+            self.a * other.e + self.c * other.f + self.e,
+            self.b * other.e + self.d * other.f + self.f,
+        )
+    }
+
+    /// Returns the determinant of the linear part, which is the factor by which areas are
+    /// scaled and negative for mirroring transforms
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// Returns the matrix which undoes this matrix, or None if it collapses the plane onto a
+    /// line or point
+    pub fn inverse(&self) -> Option<Matrix> {
+        let determinant = self.determinant();
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        Some(Matrix::new(
+            self.d / determinant,
+            -self.b / determinant,
+            -self.c / determinant,
+            self.a / determinant,
+            (self.c * self.f - self.d * self.e) / determinant,
+            (self.b * self.e - self.a * self.f) / determinant,
+        ))
+    }
+
+    /// Applies this matrix to a point
+    #[inline]
+    pub fn apply(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (
+            self.a * x + self.c * y + self.e,
+            self.b * x + self.d * y + self.f,
+        )
+    }
+
+    /// Applies only the linear part of this matrix to a vector, ignoring the translation
+    #[inline]
+    pub fn apply_vector(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (self.a * x + self.c * y, self.b * x + self.d * y)
+    }
+
+    /// Splits this matrix into a translation, rotation, skew and scaling, or None if it is not
+    /// invertible
+    ///
+    /// # Note / Arguments
+    /// Mirroring is expressed as a negative vertical scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::Matrix;
+    ///
+    /// let matrix = Matrix::translate(5.0, 0.0) * Matrix::rotate(30.0) * Matrix::scale(2.0, 3.0);
+    /// let parts = matrix.decompose().unwrap();
+    ///
+    /// assert!((parts.rotate - 30.0).abs() < 1e-9);
+    /// assert!((parts.scale.1 - 3.0).abs() < 1e-9 && parts.skew_x.abs() < 1e-9);
+    /// assert_eq!(parts.translate, (5.0, 0.0));
+    /// ```
+    pub fn decompose(&self) -> Option<Decomposition> {
+        let determinant = self.determinant();
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let scale_x = self.a.hypot(self.b);
+        let shear = (self.a * self.c + self.b * self.d) / determinant;
+        Some(Decomposition {
+            translate: (self.e, self.f),
+            rotate: self.b.atan2(self.a).to_degrees(),
+            skew_x: shear.atan().to_degrees(),
+            scale: (scale_x, determinant / scale_x),
+        })
+    }
+}
+
+impl Default for Matrix {
+    #[inline]
+    fn default() -> Self {
+        Matrix::IDENTITY
+    }
+}
+
+impl Mul for Matrix {
+    type Output = Matrix;
+
+    #[inline]
+    fn mul(self, other: Matrix) -> Matrix {
+        self.multiply(&other)
+    }
+}
+
+impl From<Transform> for Matrix {
+    #[inline]
+    fn from(transform: Transform) -> Matrix {
+        let [a, b, c, d, e, f] = transform.coefficients();
+        Matrix::new(a, b, c, d, e, f)
+    }
+}
+
+impl From<Matrix> for Transform {
+    #[inline]
+    fn from(matrix: Matrix) -> Transform {
+        Transform::Matrix(matrix.a, matrix.b, matrix.c, matrix.d, matrix.e, matrix.f)
+    }
+}
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Transform::from(*self).fmt(f)
+    }
+}
+
+/// The parts of a [Matrix](struct.Matrix.html), as returned by
+/// [decompose](struct.Matrix.html#method.decompose)
+///
+/// # Note / Arguments
+/// The matrix is `translate(x y) rotate(angle) skewX(angle) scale(x y)`, so the scaling is
+/// applied first. Angles are in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decomposition {
+    pub translate: (f64, f64),
+    pub rotate: f64,
+    pub skew_x: f64,
+    pub scale: (f64, f64),
+}
+
+impl Decomposition {
+    /// Composes the parts back into a matrix
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::translate(self.translate.0, self.translate.1)
+            * Matrix::rotate(self.rotate)
+            * Matrix::skew_x(self.skew_x)
+            * Matrix::scale(self.scale.0, self.scale.1)
+    }
+
+    /// Returns the parts as transform operations, e.g. to animate them separately
+    pub fn to_transforms(&self) -> Vec<Transform> {
+        vec![
+            Transform::Translate(self.translate.0, self.translate.1),
+            Transform::Rotate(self.rotate, 0.0, 0.0),
+            Transform::SkewX(self.skew_x),
+            Transform::Scale(self.scale.0, self.scale.1),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix;
+    use crate::transform::Transform;
+
+    fn assert_close(left: Matrix, right: Matrix) {
+        let (left, right) = (
+            [left.a, left.b, left.c, left.d, left.e, left.f],
+            [right.a, right.b, right.c, right.d, right.e, right.f],
+        );
+        for (left, right) in left.iter().zip(right.iter()) {
+            assert!((left - right).abs() < 1e-9, "{:?} != {:?}", left, right);
+        }
+    }
+
+    #[test]
+    fn test_multiply_and_invert() {
+        let matrix = Matrix::translate(3.0, -2.0) * Matrix::scale(2.0, 4.0);
+        assert_eq!(matrix.apply((1.0, 1.0)), (5.0, 2.0));
+        assert_eq!(matrix.apply_vector((1.0, 1.0)), (2.0, 4.0));
+        assert_eq!(matrix.determinant(), 8.0);
+        assert_eq!(Matrix::IDENTITY * matrix, matrix);
+
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(inverse.apply((5.0, 2.0)), (1.0, 1.0));
+        assert_close(inverse * matrix, Matrix::IDENTITY);
+        assert_eq!(Matrix::scale(0.0, 1.0).inverse(), None);
+
+        for transform in [
+            Transform::Rotate(30.0, 4.0, 5.0),
+            Transform::SkewY(10.0),
+            Transform::Matrix(1.0, 2.0, 3.0, 4.0, 5.0, 6.0),
+        ] {
+            let (x, y) = Matrix::from(transform).apply((2.0, 7.0));
+            let (expected_x, expected_y) = transform.apply((2.0, 7.0));
+            assert!((x - expected_x).abs() < 1e-9 && (y - expected_y).abs() < 1e-9);
+        }
+        assert_eq!(
+            Matrix::translate(1.0, 2.0).to_string(),
+            "matrix(1 0 0 1 1 2)"
+        );
+    }
+
+    #[test]
+    fn test_decompose() {
+        let matrices = [
+            Matrix::IDENTITY,
+            Matrix::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0),
+            Matrix::rotate(-120.0) * Matrix::skew_x(20.0) * Matrix::scale(0.5, -2.0),
+            Matrix::skew_y(40.0) * Matrix::scale(-1.0, 1.0),
+        ];
+        for matrix in matrices.iter() {
+            let parts = matrix.decompose().unwrap();
+            assert_close(parts.to_matrix(), *matrix);
+
+            let transforms = parts.to_transforms();
+            let composed = transforms
+                .into_iter()
+                .fold(Matrix::IDENTITY, |matrix, transform| {
+                    matrix * Matrix::from(transform)
+                });
+            assert_close(composed, *matrix);
+        }
+
+        let parts = Matrix::scale(2.0, -3.0).decompose().unwrap();
+        assert_eq!((parts.rotate, parts.scale), (0.0, (2.0, -3.0)));
+        assert_eq!(Matrix::new(1.0, 2.0, 2.0, 4.0, 0.0, 0.0).decompose(), None);
+    }
+}
@@ -0,0 +1,38 @@
+//! This module provides the URIs of the XML namespaces which are commonly used in SVG files, to
+//! be declared with [set_namespace](../struct.Element.html#method.set_namespace).
+//!
+//! # Examples
+//! ## Adding Inkscape layer metadata
+//! ```
+//! use svg_definitions::namespace;
+//! use svg_definitions::prelude::*;
+//!
+//! let svg = SVGElem::new(Tag::Svg)
+//!     .set_namespace("", namespace::SVG)
+//!     .set_namespace("inkscape", namespace::INKSCAPE)
+//!     .append(
+//!         SVGElem::new(Tag::G)
+//!             .set(Attr::namespaced("inkscape", "groupmode"), "layer")
+//!             .set(Attr::namespaced("inkscape", "label"), "Background"),
+//!     );
+//!
+//! assert_eq!(
+//!     svg.get_namespaces(),
+//!     vec![("", namespace::SVG), ("inkscape", namespace::INKSCAPE)]
+//! );
+//! ```
+
+/// The namespace of SVG elements
+pub const SVG: &str = "http://www.w3.org/2000/svg";
+
+/// The namespace of `xlink:*` attributes, like `xlink:href`
+pub const XLINK: &str = "http://www.w3.org/1999/xlink";
+
+/// The namespace of `xml:*` attributes, like `xml:space`, which is always declared
+pub const XML: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// The namespace of Inkscape metadata
+pub const INKSCAPE: &str = "http://www.inkscape.org/namespaces/inkscape";
+
+/// The namespace of Sodipodi metadata, which is written by Inkscape
+pub const SODIPODI: &str = "http://sodipodi.sourceforge.net/DTD/sodipodi-0.dtd";
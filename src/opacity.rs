@@ -0,0 +1,176 @@
+//! This module provides the [Opacity](struct.Opacity.html) type for the `opacity`,
+//! `fill-opacity`, `stroke-opacity`, `stop-opacity` and `flood-opacity` attributes, which keeps
+//! its value between 0 and 1.
+//!
+//! # Examples
+//! ## Setting opacities
+//! ```
+//! use svg_definitions::opacity::Opacity;
+//! use svg_definitions::prelude::*;
+//!
+//! let rect = SVGElem::new(Tag::Rect)
+//!     .set(Attr::FillOpacity, Opacity::new(0.1f32))
+//!     .set(Attr::StrokeOpacity, Opacity::from_percent(150.0));
+//!
+//! assert_eq!(rect.get_attributes()[&Attr::FillOpacity], "0.1");
+//! assert_eq!(rect.get_attributes()[&Attr::StrokeOpacity], "1");
+//! assert_eq!(rect.get_opacity(&Attr::FillOpacity), Some(Opacity::new(0.1)));
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::attributes::Attribute;
+use crate::Element;
+
+/// The error returned when an opacity is out of range or not a number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidOpacity(pub String);
+
+impl fmt::Display for InvalidOpacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid opacity {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidOpacity {}
+
+/// An opacity from 0 for transparent to 1 for opaque
+///
+/// # Note / Arguments
+/// Opacities are written with at most 4 decimals, so values converted from `f32` are written
+/// without noise like `0.10000000149011612`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Opacity(f64);
+
+impl Opacity {
+    /// Fully transparent
+    pub const TRANSPARENT: Opacity = Opacity(0.0);
+
+    /// Fully opaque
+    pub const OPAQUE: Opacity = Opacity(1.0);
+
+    /// Creates an opacity, clamping the value between 0 and 1, where NaN becomes opaque like an
+    /// invalid attribute value does
+    pub fn new<T: Into<f64>>(value: T) -> Opacity {
+        let value = value.into();
+        if value.is_nan() {
+            Opacity::OPAQUE
+        } else {
+            // Adding 0.0 turns a negative zero into a positive zero
+            Opacity(value.clamp(0.0, 1.0) + 0.0)
+        }
+    }
+
+    /// Creates an opacity from a percentage, clamping it between 0 and 100
+    #[inline]
+    pub fn from_percent<T: Into<f64>>(percentage: T) -> Opacity {
+        Opacity::new(percentage.into() / 100.0)
+    }
+
+    /// Returns the opacity as a number between 0 and 1
+    #[inline]
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl Default for Opacity {
+    #[inline]
+    fn default() -> Self {
+        Opacity::OPAQUE
+    }
+}
+
+impl TryFrom<f64> for Opacity {
+    type Error = InvalidOpacity;
+
+    /// Creates an opacity, failing for values outside of 0 to 1 instead of clamping them
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if (0.0..=1.0).contains(&value) {
+            Ok(Opacity::new(value))
+        } else {
+            Err(InvalidOpacity(value.to_string()))
+        }
+    }
+}
+
+impl TryFrom<f32> for Opacity {
+    type Error = InvalidOpacity;
+
+    #[inline]
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Opacity::try_from(f64::from(value))
+    }
+}
+
+impl From<Opacity> for f64 {
+    #[inline]
+    fn from(opacity: Opacity) -> f64 {
+        opacity.0
+    }
+}
+
+impl fmt::Display for Opacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", (self.0 * 1e4).round() / 1e4)
+    }
+}
+
+impl FromStr for Opacity {
+    type Err = InvalidOpacity;
+
+    /// Parses a number or percentage, clamping it like renderers do
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        let parsed = match trimmed.strip_suffix('%') {
+            Some(percentage) => percentage.parse::<f64>().map(|number| number / 100.0),
+            None => trimmed.parse::<f64>(),
+        };
+        match parsed {
+            Ok(number) if number.is_finite() => Ok(Opacity::new(number)),
+            _ => Err(InvalidOpacity(value.to_string())),
+        }
+    }
+}
+
+impl Element {
+    /// Returns the value of an opacity attribute as an [Opacity](opacity/struct.Opacity.html), or
+    /// None if it is not set or not a number or percentage, e.g. `inherit`
+    pub fn get_opacity(&self, attribute: &Attribute) -> Option<Opacity> {
+        self.attributes.get(attribute)?.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidOpacity, Opacity};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_opacity() {
+        assert_eq!(Opacity::new(-0.5), Opacity::TRANSPARENT);
+        assert_eq!(Opacity::new(f64::NAN), Opacity::OPAQUE);
+        assert_eq!(Opacity::new(-0.0).to_string(), "0");
+        assert_eq!(Opacity::new(0.7f32).to_string(), "0.7");
+        assert_eq!(Opacity::from_percent(12.5).value(), 0.125);
+        assert_eq!(f64::from(Opacity::default()), 1.0);
+
+        assert_eq!(Opacity::try_from(0.25), Ok(Opacity::new(0.25)));
+        assert_eq!(
+            Opacity::try_from(1.5f32),
+            Err(InvalidOpacity(String::from("1.5")))
+        );
+        assert!(Opacity::try_from(f64::NAN).is_err());
+
+        assert_eq!(" 40% ".parse(), Ok(Opacity::new(0.4)));
+        assert_eq!("2".parse(), Ok(Opacity::OPAQUE));
+        for value in &["", "%", "half", "inf", "0.5 0.5"] {
+            assert_eq!(
+                value.parse::<Opacity>(),
+                Err(InvalidOpacity(value.to_string()))
+            );
+        }
+    }
+}
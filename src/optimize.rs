@@ -0,0 +1,897 @@
+//! This module provides optimization passes which rewrite an [Element](../struct.Element.html)
+//! tree in place, to make its rendering cleaner or its serialization smaller.
+//!
+//! Passes which reason about geometry only understand the shapes supported by
+//! [geometry::bounding_box](../geometry/fn.bounding_box.html) and leave everything else alone.
+//!
+//! # Examples
+//! ## Snapping a rectangle to the pixel grid
+//! ```
+//! use svg_definitions::optimize::{self, SnapStrategy};
+//! use svg_definitions::prelude::*;
+//!
+//! let mut svg = SVGElem::new(Tag::Svg).append(
+//!     SVGElem::new(Tag::Rect)
+//!         .set(Attr::X, 10.3)
+//!         .set(Attr::Y, 4.8)
+//!         .set(Attr::Width, 20)
+//!         .set(Attr::Height, 10)
+//!         .set(Attr::Stroke, "black"),
+//! );
+//!
+//! optimize::pixel_snap(&mut svg, SnapStrategy::Crisp);
+//!
+//! let rect = &svg.get_children()[0];
+//! assert_eq!(rect.get_attributes()[&Attr::X], "10.5");
+//! assert_eq!(rect.get_attributes()[&Attr::Y], "4.5");
+//! ```
+
+use crate::attributes::Attribute;
+use crate::color::Color;
+use crate::context::Context;
+use crate::geometry::{bounding_box, user_units, view_box, BoundingBox};
+use crate::keywords::Visibility;
+use crate::path::PathDefinitionString;
+use crate::style::CssProperty;
+use crate::tag_name::TagName;
+use crate::Element;
+
+#[cfg(feature = "image")]
+mod images;
+#[cfg(feature = "image")]
+pub use images::{downscale_images, RasterFormat, RasterOptions};
+
+/// The way [pixel_snap](fn.pixel_snap.html) moves coordinates onto the pixel grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapStrategy {
+    /// Rounds coordinates to whole pixels, but puts the edges of axis-aligned lines and
+    /// rectangles with an odd stroke width in the middle of pixels, so their strokes exactly
+    /// cover whole pixels
+    Crisp,
+
+    /// Rounds all coordinates to whole pixels
+    Round,
+}
+
+/// The stroke properties which are inherited by descendants
+#[derive(Debug, Clone, Copy)]
+struct Stroke {
+    visible: bool,
+    width: f64,
+}
+
+impl Default for Stroke {
+    fn default() -> Self {
+        Stroke {
+            visible: false,
+            width: 1.0,
+        }
+    }
+}
+
+impl Stroke {
+    /// Returns the stroke of an element inheriting from this stroke
+    fn inherit(self, element: &Element) -> Stroke {
+        let mut stroke = self;
+        if let Some(value) = element.attributes.get(&Attribute::Stroke) {
+            stroke.visible = value.trim() != "none";
+        }
+        if let Some(width) = element
+            .attributes
+            .get(&Attribute::StrokeWidth)
+            .and_then(|value| user_units(value))
+        {
+            stroke.width = width;
+        }
+        stroke
+    }
+}
+
+/// Moves the coordinates of rectangles and lines onto the pixel grid, eliminating the blurry
+/// edges of anti-aliased strokes and fills
+///
+/// # Note / Arguments
+/// Only `<rect>` and `<line>` elements are snapped, using the `stroke` and `stroke-width`
+/// attributes they have or inherit. Elements with a `transform`, and all their descendants,
+/// are left untouched, as are coordinates which are not in user units.
+pub fn pixel_snap(root: &mut Element, strategy: SnapStrategy) {
+    snap_element(root, strategy, Stroke::default());
+}
+
+fn snap_element(element: &mut Element, strategy: SnapStrategy, inherited: Stroke) {
+    if element.attributes.contains_key(&Attribute::Transform) {
+        return;
+    }
+
+    let stroke = inherited.inherit(element);
+
+    // Whether the edges of the element should be in the middle of pixels
+    let odd_stroke = strategy == SnapStrategy::Crisp
+        && stroke.visible
+        && stroke.width.fract() == 0.0
+        && stroke.width % 2.0 == 1.0;
+    let edge = |value: f64| {
+        if odd_stroke {
+            (value - 0.5).round() + 0.5
+        } else {
+            value.round()
+        }
+    };
+
+    match element.tag_name {
+        TagName::Rect => {
+            snap_attribute(element, Attribute::X, edge);
+            snap_attribute(element, Attribute::Y, edge);
+            snap_attribute(element, Attribute::Width, f64::round);
+            snap_attribute(element, Attribute::Height, f64::round);
+        }
+        TagName::Line => {
+            let coordinate = |attribute| {
+                element
+                    .attributes
+                    .get(&attribute)
+                    .map_or(Some(0.0), |value| user_units(value))
+            };
+            let horizontal = coordinate(Attribute::Y1) == coordinate(Attribute::Y2);
+            let vertical = coordinate(Attribute::X1) == coordinate(Attribute::X2);
+
+            // Only the coordinates across an axis-aligned line determine its sharpness
+            for (attribute, across) in [
+                (Attribute::X1, vertical),
+                (Attribute::X2, vertical),
+                (Attribute::Y1, horizontal),
+                (Attribute::Y2, horizontal),
+            ] {
+                if across {
+                    snap_attribute(element, attribute, edge);
+                } else {
+                    snap_attribute(element, attribute, f64::round);
+                }
+            }
+        }
+        _ => (),
+    }
+
+    for child in element.children.iter_mut() {
+        snap_element(child, strategy, stroke);
+    }
+}
+
+fn snap_attribute<F>(element: &mut Element, attribute: Attribute, snap: F)
+where
+    F: Fn(f64) -> f64,
+{
+    if let Some(value) = element.attributes.get_mut(&attribute) {
+        if let Some(number) = user_units(value) {
+            // Adding 0.0 turns a negative zero into a positive zero
+            *value = (snap(number) + 0.0).to_string().into();
+        }
+    }
+}
+
+/// The paint properties which are inherited by descendants
+#[derive(Debug, Clone)]
+struct Paint {
+    fill: Option<String>,
+    fill_opacity: f64,
+    visible: bool,
+    stroke: Stroke,
+}
+
+impl Default for Paint {
+    fn default() -> Self {
+        Paint {
+            fill: None,
+            fill_opacity: 1.0,
+            visible: true,
+            stroke: Stroke::default(),
+        }
+    }
+}
+
+impl Paint {
+    /// Returns the paint of an element inheriting from this paint
+    fn inherit(&self, element: &Element) -> Paint {
+        let mut paint = self.clone();
+        if let Some(fill) = element.attributes.get(&Attribute::Fill) {
+            paint.fill = Some(fill.trim().to_string());
+        }
+        if let Some(opacity) = element.attributes.get(&Attribute::FillOpacity) {
+            paint.fill_opacity = opacity.trim().parse().unwrap_or(0.0);
+        }
+        if let Some(visibility) = element.get_keyword(&Attribute::Visibility) {
+            paint.visible = match visibility {
+                Visibility::Visible => true,
+                Visibility::Hidden | Visibility::Collapse => false,
+            };
+        }
+        paint.stroke = paint.stroke.inherit(element);
+        paint
+    }
+}
+
+/// Returns whether a fill is a color without transparency
+fn is_opaque_color(fill: Option<&str>) -> bool {
+    let fill = match fill {
+        // The default fill is black
+        None => return true,
+        Some(fill) => fill.to_lowercase(),
+    };
+
+    if fill.starts_with('#') {
+        return fill.len() == 4 || fill.len() == 7;
+    }
+
+    !(fill.is_empty()
+        || fill == "none"
+        || fill == "transparent"
+        || fill.contains('(') && !fill.starts_with("rgb(") && !fill.starts_with("hsl(")
+        || fill.contains('/'))
+}
+
+/// Returns the extent of everything an element draws, or None if it is not known or the element
+/// should be kept anyway
+fn visible_extent(element: &Element, stroke: Stroke) -> Option<BoundingBox> {
+    const UNSAFE_ATTRIBUTES: &[Attribute] = &[
+        // Referenced elements and styled elements could be needed or drawn elsewhere
+        Attribute::Id,
+        Attribute::Class,
+        Attribute::Style,
+        Attribute::Transform,
+        Attribute::Filter,
+        Attribute::MarkerStart,
+        Attribute::MarkerMid,
+        Attribute::MarkerEnd,
+    ];
+
+    if UNSAFE_ATTRIBUTES
+        .iter()
+        .any(|attribute| element.attributes.contains_key(attribute))
+    {
+        return None;
+    }
+
+    let stroke = stroke.inherit(element);
+    match element.tag_name {
+        TagName::G | TagName::A | TagName::Switch => {
+            element
+                .children
+                .iter()
+                .try_fold(None, |extent: Option<BoundingBox>, child| {
+                    let child_extent = visible_extent(child, stroke)?;
+                    Some(Some(match extent {
+                        Some(extent) => extent.union(&child_extent),
+                        None => child_extent,
+                    }))
+                })?
+        }
+        _ => {
+            let bounds = bounding_box(element)?;
+            if stroke.visible {
+                // Miter joins can reach further than half of the stroke width
+                Some(bounds.inflate(2.0 * stroke.width))
+            } else {
+                Some(bounds)
+            }
+        }
+    }
+}
+
+/// Returns the area an element covers completely with an opaque fill, if it is an axis-aligned
+/// rectangle without rounded corners
+fn opaque_cover(element: &Element, paint: &Paint) -> Option<BoundingBox> {
+    const UNSAFE_ATTRIBUTES: &[Attribute] = &[
+        Attribute::Class,
+        Attribute::Style,
+        Attribute::Transform,
+        Attribute::Opacity,
+        Attribute::Filter,
+        Attribute::Mask,
+        Attribute::ClipPath,
+        Attribute::Display,
+    ];
+
+    if element.tag_name != TagName::Rect
+        || UNSAFE_ATTRIBUTES
+            .iter()
+            .any(|attribute| element.attributes.contains_key(attribute))
+    {
+        return None;
+    }
+
+    let rounded = [Attribute::Rx, Attribute::Ry].iter().any(|attribute| {
+        element
+            .attributes
+            .get(attribute)
+            .is_some_and(|value| user_units(value) != Some(0.0))
+    });
+    let paint = paint.inherit(element);
+    if rounded
+        || !paint.visible
+        || paint.fill_opacity < 1.0
+        || !is_opaque_color(paint.fill.as_deref())
+    {
+        return None;
+    }
+
+    bounding_box(element)
+}
+
+/// Removes elements which are completely hidden behind later opaque siblings, returning the
+/// amount of removed elements
+///
+/// # Note / Arguments
+/// Only axis-aligned rectangles with an opaque color fill are considered to hide anything, and
+/// only elements with a known extent, including their strokes, can be hidden. Elements with an
+/// id, class, style or transform are always kept, as are animated elements, since
+/// `<animate>` and `<set>` can move or reveal them, and the children of a `<switch>`, of which
+/// only one is rendered. Since rules of stylesheets are not taken into account, nothing is
+/// removed from trees with a `<style>` element.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::optimize;
+/// use svg_definitions::prelude::*;
+///
+/// let mut svg = SVGElem::new(Tag::Svg)
+///     .append(SVGElem::new(Tag::Circle).set(Attr::Cx, 5).set(Attr::Cy, 5).set(Attr::R, 2))
+///     .append(SVGElem::new(Tag::Rect).set(Attr::Width, 10).set(Attr::Height, 10).set(Attr::Fill, "white"));
+///
+/// assert_eq!(optimize::remove_covered(&mut svg), 1);
+/// assert_eq!(*svg.get_children()[0].get_tag_name(), Tag::Rect);
+/// ```
+pub fn remove_covered(root: &mut Element) -> usize {
+    if contains_tag(root, &TagName::Style) {
+        return 0;
+    }
+
+    remove_covered_children(root, &Paint::default())
+}
+
+fn contains_tag(element: &Element, tag_name: &TagName) -> bool {
+    element.tag_name == *tag_name
+        || element
+            .children
+            .iter()
+            .any(|child| contains_tag(child, tag_name))
+}
+
+/// Returns whether an element or one of its descendants is animated by an animation element
+fn is_animated(element: &Element) -> bool {
+    element.children.iter().any(|child| {
+        matches!(
+            child.tag_name,
+            TagName::Animate | TagName::Set | TagName::AnimateTransform | TagName::AnimateMotion
+        ) || is_animated(child)
+    })
+}
+
+fn remove_covered_children(element: &mut Element, inherited: &Paint) -> usize {
+    // Only one child of a switch is rendered, so they do not cover each other
+    if element.tag_name == TagName::Switch {
+        return 0;
+    }
+
+    let paint = inherited.inherit(element);
+
+    // Walk backwards, collecting the areas covered by later siblings
+    let mut covers: Vec<BoundingBox> = Vec::new();
+    let mut removed = 0;
+    for index in (0..element.children.len()).rev() {
+        let child = &element.children[index];
+
+        let animated = is_animated(child);
+        let hidden = !animated
+            && visible_extent(child, paint.stroke)
+                .is_some_and(|extent| covers.iter().any(|cover| cover.contains(&extent)));
+        if hidden {
+            element.children.remove(index);
+            removed += 1;
+            continue;
+        }
+
+        if let Some(cover) = opaque_cover(child, &paint).filter(|_| !animated) {
+            covers.push(cover);
+        }
+        removed += remove_covered_children(&mut element.children[index], &paint);
+    }
+
+    removed
+}
+
+/// Removes elements which lie completely outside of the `viewBox` of the root, returning the
+/// amount of removed elements
+///
+/// # Note / Arguments
+/// Elements are kept if their extent, including their strokes, reaches within `margin` user
+/// units of the `viewBox`. Only elements with a known extent are removed, and elements with an
+/// id, class, style or transform and animated elements are always kept. Nothing is removed if the root has no valid
+/// `viewBox` or the tree has a `<style>` element.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::optimize;
+/// use svg_definitions::prelude::*;
+///
+/// let mut svg = SVGElem::new(Tag::Svg)
+///     .set(Attr::ViewBox, "0 0 100 100")
+///     .append(SVGElem::new(Tag::Circle).set(Attr::Cx, 50).set(Attr::Cy, 50).set(Attr::R, 10))
+///     .append(SVGElem::new(Tag::Circle).set(Attr::Cx, 5000).set(Attr::Cy, 50).set(Attr::R, 10));
+///
+/// assert_eq!(optimize::cull_outside_viewbox(&mut svg, 0.0), 1);
+/// assert_eq!(svg.get_children().len(), 1);
+/// ```
+pub fn cull_outside_viewbox(root: &mut Element, margin: f64) -> usize {
+    let area = match view_box(root) {
+        Some(area) => area.inflate(margin),
+        None => return 0,
+    };
+    if contains_tag(root, &TagName::Style) {
+        return 0;
+    }
+
+    cull_children(root, &area, Stroke::default())
+}
+
+fn cull_children(element: &mut Element, area: &BoundingBox, inherited: Stroke) -> usize {
+    let stroke = inherited.inherit(element);
+
+    let mut removed = 0;
+    let mut index = 0;
+    while index < element.children.len() {
+        let child = &mut element.children[index];
+        // Animations can move elements into the view box
+        let outside = !is_animated(child)
+            && visible_extent(child, stroke).is_some_and(|extent| !area.intersects(&extent));
+        if outside {
+            element.children.remove(index);
+            removed += 1;
+            continue;
+        }
+
+        // Only groups share the coordinate system of their parent
+        let same_space = !child.attributes.contains_key(&Attribute::Transform)
+            && matches!(child.tag_name, TagName::G | TagName::A | TagName::Switch);
+        if same_space {
+            removed += cull_children(child, area, stroke);
+        }
+        index += 1;
+    }
+
+    removed
+}
+
+/// Attributes which only contain numbers and lengths, whose numbers can be rounded
+const NUMERIC_ATTRIBUTES: &[Attribute] = &[
+    Attribute::X,
+    Attribute::Y,
+    Attribute::X1,
+    Attribute::Y1,
+    Attribute::X2,
+    Attribute::Y2,
+    Attribute::Cx,
+    Attribute::Cy,
+    Attribute::R,
+    Attribute::Rx,
+    Attribute::Ry,
+    Attribute::Fx,
+    Attribute::Fy,
+    Attribute::Dx,
+    Attribute::Dy,
+    Attribute::Width,
+    Attribute::Height,
+    Attribute::StrokeWidth,
+    Attribute::Points,
+    Attribute::ViewBox,
+    Attribute::Transform,
+];
+
+/// Rounds the numbers of geometric attributes and path data to the precision of a
+/// [Context](../context/struct.Context.html), returning the amount of changed attributes
+///
+/// # Note / Arguments
+/// Path data is rewritten with the formatting of [PathData](../prelude/index.html), and is
+/// kept as it is if it cannot be parsed. Without a precision, numbers are rounded to 4
+/// decimals.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::context::Context;
+/// use svg_definitions::optimize;
+/// use svg_definitions::prelude::*;
+///
+/// let mut svg = SVGElem::new(Tag::Svg)
+///     .append(SVGElem::new(Tag::Circle).set(Attr::Cx, 10.00049).set(Attr::R, "4.25px"))
+///     .append(SVGElem::new(Tag::Path).set(Attr::D, "M0,0 L 3.14159,2.71828"));
+///
+/// assert_eq!(optimize::round_numbers(&mut svg, &Context::new().with_precision(1)), 3);
+/// assert_eq!(
+///     svg.to_string(),
+///     "<svg><circle cx=\"10\" r=\"4.3px\"/><path d=\"M 0 0 L 3.1 2.7\"/></svg>"
+/// );
+/// ```
+pub fn round_numbers(root: &mut Element, context: &Context) -> usize {
+    let mut changed = 0;
+
+    for (attribute, value) in root.attributes.iter_mut() {
+        let rounded = if *attribute == Attribute::D {
+            match value.parse::<PathDefinitionString>() {
+                Ok(path) => path
+                    .with_precision(context.precision.unwrap_or(4))
+                    .to_string(),
+                Err(_) => continue,
+            }
+        } else if NUMERIC_ATTRIBUTES.contains(attribute) {
+            context.round_numbers(value)
+        } else {
+            continue;
+        };
+
+        if rounded != *value {
+            *value = rounded.into();
+            changed += 1;
+        }
+    }
+
+    for child in root.children.iter_mut() {
+        changed += round_numbers(child, context);
+    }
+    changed
+}
+
+/// Attributes and style properties whose value is a color or a paint
+const COLOR_ATTRIBUTES: &[Attribute] = &[
+    Attribute::Fill,
+    Attribute::Stroke,
+    Attribute::StopColor,
+    Attribute::FloodColor,
+    Attribute::LightingColor,
+    Attribute::Color,
+];
+
+/// Replaces every color by the nearest color of a palette, returning the amount of changed
+/// values, to enforce e.g. the palette of a brand on imported artwork
+///
+/// # Note / Arguments
+/// Colors are replaced in color attributes and in `style` attributes when they are at most
+/// `tolerance` away from the nearest color of the palette, so colors which are far from all
+/// of them can be kept. The distance is measured between the red, green and blue channels from
+/// 0 to 255, where every color is within 442 of any other. The alpha of a color is kept.
+/// Keywords like `none` and `currentColor`, references like `url(#gradient)` and stylesheets
+/// are left alone.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::color::Color;
+/// use svg_definitions::optimize;
+/// use svg_definitions::prelude::*;
+///
+/// let brand = [Color::rgb(0, 82, 155), Color::rgb(255, 255, 255)];
+/// let mut svg = SVGElem::new(Tag::Svg)
+///     .append(SVGElem::new(Tag::Rect).set(Attr::Fill, "#03559a").set(Attr::Stroke, "none"))
+///     .append(SVGElem::new(Tag::Circle).set(Attr::Style, "fill: rgba(250, 250, 250, 0.5)"))
+///     .append(SVGElem::new(Tag::Circle).set(Attr::Fill, "red"));
+///
+/// assert_eq!(optimize::quantize_colors(&mut svg, &brand, 20.0), 2);
+/// assert_eq!(
+///     svg.to_string(),
+///     "<svg><rect fill=\"#00529b\" stroke=\"none\"/>\
+///      <circle style=\"fill: rgba(255, 255, 255, 0.5)\"/><circle fill=\"red\"/></svg>"
+/// );
+/// ```
+pub fn quantize_colors(root: &mut Element, palette: &[Color], tolerance: f64) -> usize {
+    let mut changed = 0;
+
+    for (attribute, value) in root.attributes.iter_mut() {
+        if !COLOR_ATTRIBUTES.contains(attribute) {
+            continue;
+        }
+        if let Some(quantized) = quantize_color(value, palette, tolerance) {
+            *value = quantized.into();
+            changed += 1;
+        }
+    }
+
+    if root.attributes.contains_key(&Attribute::Style) {
+        let mut style = root.get_style();
+        let mut style_changed = false;
+        for attribute in COLOR_ATTRIBUTES {
+            let property = CssProperty::from(attribute);
+            let quantized = style
+                .get(&property)
+                .and_then(|value| quantize_color(value, palette, tolerance));
+            if let Some(quantized) = quantized {
+                style.set(property, quantized);
+                style_changed = true;
+                changed += 1;
+            }
+        }
+        if style_changed {
+            root.set_attribute(Attribute::Style, style.to_string());
+        }
+    }
+
+    for child in root.children.iter_mut() {
+        changed += quantize_colors(child, palette, tolerance);
+    }
+    changed
+}
+
+/// Returns the nearest color of a palette to a color value, or None if the value is not a
+/// color, no palette color is close enough or the value would not change
+fn quantize_color(value: &str, palette: &[Color], tolerance: f64) -> Option<String> {
+    let color = Color::parse(value).ok()?;
+    let distance = |other: &Color| {
+        let channel = |a: u8, b: u8| f64::from(a) - f64::from(b);
+        channel(color.red, other.red)
+            .hypot(channel(color.green, other.green))
+            .hypot(channel(color.blue, other.blue))
+    };
+
+    let nearest = palette
+        .iter()
+        .map(|entry| (entry, distance(entry)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .filter(|(_, distance)| *distance <= tolerance)?
+        .0;
+
+    Some(nearest.with_alpha(color.alpha).to_string()).filter(|quantized| quantized != value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cull_outside_viewbox, pixel_snap, quantize_colors, remove_covered, SnapStrategy};
+    use crate::attributes::Attribute;
+    use crate::color::Color;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    fn attribute(element: &Element, attribute: Attribute) -> &str {
+        &element.get_attributes()[&attribute]
+    }
+
+    #[test]
+    fn test_pixel_snap() {
+        let mut root = Element::new(TagName::G)
+            .set(Attribute::Stroke, "black")
+            .set(Attribute::StrokeWidth, 3)
+            .append(
+                Element::new(TagName::Line)
+                    .set(Attribute::X1, 0.2)
+                    .set(Attribute::Y1, "4.7px")
+                    .set(Attribute::X2, 10.6)
+                    .set(Attribute::Y2, "4.7px"),
+            )
+            .append(
+                Element::new(TagName::Rect)
+                    .set(Attribute::StrokeWidth, 2)
+                    .set(Attribute::X, 0.7)
+                    .set(Attribute::Width, "50%"),
+            )
+            .append(
+                Element::new(TagName::G)
+                    .set(Attribute::Transform, "scale(2)")
+                    .append(Element::new(TagName::Rect).set(Attribute::X, 0.7)),
+            );
+        let original = root.clone();
+
+        pixel_snap(&mut root, SnapStrategy::Crisp);
+        let children = root.get_children();
+        assert_eq!(attribute(&children[0], Attribute::X1), "0");
+        assert_eq!(attribute(&children[0], Attribute::X2), "11");
+        assert_eq!(attribute(&children[0], Attribute::Y1), "4.5");
+        assert_eq!(attribute(&children[0], Attribute::Y2), "4.5");
+        assert_eq!(attribute(&children[1], Attribute::X), "1");
+        assert_eq!(attribute(&children[1], Attribute::Width), "50%");
+        assert_eq!(
+            attribute(&children[2].get_children()[0], Attribute::X),
+            "0.7"
+        );
+
+        let mut root = original;
+        pixel_snap(&mut root, SnapStrategy::Round);
+        assert_eq!(attribute(&root.get_children()[0], Attribute::Y1), "5");
+    }
+
+    #[test]
+    fn test_remove_covered() {
+        let cover = |fill: &str| {
+            Element::new(TagName::Rect)
+                .set(Attribute::Width, 10)
+                .set(Attribute::Height, 10)
+                .set(Attribute::Fill, fill)
+        };
+        let dot = |x: f64| {
+            Element::new(TagName::Circle)
+                .set(Attribute::Cx, x)
+                .set(Attribute::Cy, 5)
+                .set(Attribute::R, 1)
+        };
+
+        let mut root = Element::new(TagName::Svg)
+            .append(dot(5.0))
+            .append(dot(5.0).set(Attribute::Id, "referenced"))
+            .append(
+                dot(5.0)
+                    .set(Attribute::Stroke, "red")
+                    .set(Attribute::StrokeWidth, 3),
+            )
+            .append(dot(12.0))
+            .append(Element::new(TagName::G).append(dot(4.0)).append(dot(6.0)))
+            .append(cover("rgb(0, 0, 0)"))
+            .append(dot(5.0))
+            .append(cover("rgba(0, 0, 0, 0.5)"))
+            .append(cover("#fff8"))
+            .append(cover("url(#gradient)"))
+            .append(
+                Element::new(TagName::G)
+                    .set(Attribute::FillOpacity, 0.5)
+                    .append(cover("red"))
+                    .append(dot(5.0)),
+            );
+
+        assert_eq!(remove_covered(&mut root), 2);
+        let tags: Vec<String> = root
+            .get_children()
+            .iter()
+            .map(|child| child.get_tag_name().to_string())
+            .collect();
+        assert_eq!(
+            tags,
+            vec!["circle", "circle", "circle", "rect", "circle", "rect", "rect", "rect", "g"]
+        );
+        assert_eq!(root.get_children()[8].get_children().len(), 2);
+
+        let mut styled = Element::new(TagName::Svg)
+            .append(Element::new(TagName::Style))
+            .append(dot(5.0))
+            .append(cover("red"));
+        assert_eq!(remove_covered(&mut styled), 0);
+
+        let set = |attribute: Attribute, to: &str| {
+            Element::new(TagName::Set)
+                .set(Attribute::AttributeName, attribute)
+                .set(Attribute::To, to)
+        };
+        let mut animated = Element::new(TagName::Svg)
+            .append(dot(5.0).append(set(Attribute::Cx, "20")))
+            .append(
+                Element::new(TagName::G)
+                    .append(dot(5.0).append(set(Attribute::Visibility, "visible"))),
+            )
+            .append(dot(5.0))
+            .append(cover("red").append(set(Attribute::Visibility, "hidden")))
+            .append(
+                Element::new(TagName::Switch)
+                    .append(dot(5.0))
+                    .append(cover("red")),
+            );
+        assert_eq!(remove_covered(&mut animated), 0);
+        assert_eq!(animated.get_children().len(), 5);
+    }
+
+    #[test]
+    fn test_cull_outside_viewbox() {
+        let dot = |x: f64| {
+            Element::new(TagName::Circle)
+                .set(Attribute::Cx, x)
+                .set(Attribute::Cy, 5)
+                .set(Attribute::R, 1)
+        };
+
+        let mut root = Element::new(TagName::Svg)
+            .set(Attribute::ViewBox, "0 0 10 10")
+            .append(dot(5.0))
+            .append(dot(12.0))
+            .append(dot(30.0))
+            .append(dot(30.0).set(Attribute::Id, "referenced"))
+            .append(
+                dot(13.0)
+                    .set(Attribute::Stroke, "black")
+                    .set(Attribute::StrokeWidth, 2),
+            )
+            .append(
+                Element::new(TagName::G)
+                    .append(dot(5.0))
+                    .append(dot(40.0))
+                    .append(Element::new(TagName::Path)),
+            )
+            .append(
+                Element::new(TagName::G)
+                    .set(Attribute::Transform, "translate(-30)")
+                    .append(dot(30.0)),
+            );
+
+        let mut with_margin = root.clone();
+        assert_eq!(cull_outside_viewbox(&mut with_margin, 5.0), 2);
+
+        assert_eq!(cull_outside_viewbox(&mut root, 0.0), 3);
+        let children = root.get_children();
+        assert_eq!(children.len(), 5);
+        assert_eq!(attribute(&children[1], Attribute::Id), "referenced");
+        assert_eq!(children[3].get_children().len(), 2);
+        assert_eq!(children[4].get_children().len(), 1);
+
+        let mut unbounded = Element::new(TagName::Svg).append(dot(30.0));
+        assert_eq!(cull_outside_viewbox(&mut unbounded, 0.0), 0);
+
+        // Elements which are animated into the view box are kept
+        let mut animated = Element::new(TagName::Svg)
+            .set(Attribute::ViewBox, "0 0 10 10")
+            .append(
+                dot(30.0).append(
+                    Element::new(TagName::Animate)
+                        .set(Attribute::AttributeName, "cx")
+                        .set(Attribute::To, 5)
+                        .set(Attribute::Dur, "1s"),
+                ),
+            )
+            .append(Element::new(TagName::G).append(dot(30.0).append(
+                Element::new(TagName::AnimateMotion).set(Attribute::Path, "M 0 0 L -25 0"),
+            )));
+        assert_eq!(cull_outside_viewbox(&mut animated, 0.0), 0);
+        assert_eq!(animated.get_children().len(), 2);
+    }
+
+    #[test]
+    fn test_round_numbers() {
+        use super::round_numbers;
+        use crate::context::Context;
+
+        let mut svg = Element::new(TagName::Svg)
+            .set(Attribute::ViewBox, "0 0 100.123 50")
+            .set(Attribute::Id, "a1.2345")
+            .append(
+                Element::new(TagName::Path)
+                    .set(Attribute::D, "M 0 0 L 1.23456 1 X")
+                    .set(Attribute::Transform, "rotate(33.33333 5 5)")
+                    .set(Attribute::Fill, "url(#g1.23456)"),
+            );
+
+        assert_eq!(
+            round_numbers(&mut svg, &Context::new().with_precision(2)),
+            2
+        );
+        assert_eq!(
+            svg.to_string(),
+            "<svg id=\"a1.2345\" viewBox=\"0 0 100.12 50\"><path d=\"M 0 0 L 1.23456 1 X\" \
+             fill=\"url(#g1.23456)\" transform=\"rotate(33.33 5 5)\"/></svg>"
+        );
+        assert_eq!(
+            round_numbers(&mut svg, &Context::new().with_precision(2)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_quantize_colors() {
+        let palette = [Color::rgb(0, 0, 0), Color::rgb(200, 0, 0)];
+        let mut root = Element::new(TagName::G)
+            .set(Attribute::Fill, "#000")
+            .set(
+                Attribute::Style,
+                "stroke:url(#gradient); stop-color: #c80a0a;opacity:0.5",
+            )
+            .append(
+                Element::new(TagName::Stop)
+                    .set(Attribute::StopColor, "hsl(0, 100%, 40%)")
+                    .set(Attribute::Color, "currentColor"),
+            )
+            .append(Element::new(TagName::Rect).set(Attribute::Fill, "blue"));
+
+        assert_eq!(quantize_colors(&mut root, &palette, 30.0), 3);
+        assert_eq!(attribute(&root, Attribute::Fill), "#000000");
+        assert_eq!(
+            attribute(&root, Attribute::Style),
+            "stroke: url(#gradient); stop-color: #c80000; opacity: 0.5"
+        );
+        let stop = &root.get_children()[0];
+        assert_eq!(attribute(stop, Attribute::StopColor), "#c80000");
+        assert_eq!(attribute(stop, Attribute::Color), "currentColor");
+        assert_eq!(attribute(&root.get_children()[1], Attribute::Fill), "blue");
+
+        // Values which already are palette colors are not changed again
+        assert_eq!(quantize_colors(&mut root, &palette, 30.0), 0);
+        assert_eq!(quantize_colors(&mut root, &[], 1000.0), 0);
+    }
+}
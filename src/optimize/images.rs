@@ -0,0 +1,295 @@
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat};
+
+use crate::attributes::Attribute;
+use crate::base64;
+use crate::geometry::user_units;
+use crate::tag_name::TagName;
+use crate::Element;
+
+/// The encoding of a raster image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RasterFormat {
+    /// Lossless PNG
+    Png,
+
+    /// Lossy JPEG with a quality from 1 to 100, which drops any transparency
+    Jpeg(u8),
+
+    /// Lossless WebP
+    WebP,
+}
+
+impl RasterFormat {
+    fn mime_type(&self) -> &'static str {
+        match self {
+            RasterFormat::Png => "image/png",
+            RasterFormat::Jpeg(_) => "image/jpeg",
+            RasterFormat::WebP => "image/webp",
+        }
+    }
+
+    fn encode(&self, image: &DynamicImage) -> Option<Vec<u8>> {
+        let mut encoded = Cursor::new(Vec::new());
+        match *self {
+            RasterFormat::Png => image.write_to(&mut encoded, ImageFormat::Png).ok()?,
+            RasterFormat::Jpeg(quality) => JpegEncoder::new_with_quality(&mut encoded, quality)
+                .encode_image(&image.to_rgb8())
+                .ok()?,
+            RasterFormat::WebP => {
+                let image = if image.color().has_alpha() {
+                    DynamicImage::ImageRgba8(image.to_rgba8())
+                } else {
+                    DynamicImage::ImageRgb8(image.to_rgb8())
+                };
+                image.write_to(&mut encoded, ImageFormat::WebP).ok()?
+            }
+        }
+
+        Some(encoded.into_inner())
+    }
+}
+
+/// The options of [downscale_images](fn.downscale_images.html)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RasterOptions {
+    /// The amount of pixels per user unit to keep, e.g. 2 for high density displays
+    pub scale: f64,
+
+    /// The encoding of downscaled images, or None to keep the encoding of every image
+    pub format: Option<RasterFormat>,
+}
+
+impl Default for RasterOptions {
+    fn default() -> Self {
+        RasterOptions {
+            scale: 1.0,
+            format: None,
+        }
+    }
+}
+
+/// Downscales the images embedded as `data:` URIs to the size at which they are displayed and
+/// reencodes them, returning the amount of replaced images
+/// *The feature "image" needs to be enabled for this*
+///
+/// # Note / Arguments
+/// Only PNG, JPEG and WebP images of `<image>` elements with a width and height in user units
+/// are considered, and an image is only replaced if it becomes smaller. The `preserveAspectRatio`
+/// of the element is taken into account, but transforms are not, so images within transformed
+/// elements are left alone. The same goes for images within `<symbol>`, `<pattern>`, `<marker>`
+/// and nested `<svg>` elements, which start a new viewport that may scale their content. Images
+/// with transparency are never converted into JPEG.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::optimize::{self, RasterFormat, RasterOptions};
+/// use svg_definitions::prelude::*;
+///
+/// let mut svg = SVGElem::new(Tag::Svg).append(
+///     SVGElem::new(Tag::Image)
+///         .set(Attr::Width, 100)
+///         .set(Attr::Height, 100)
+///         .set(Attr::Href, "data:image/png;base64,..."),
+/// );
+///
+/// let options = RasterOptions { scale: 2.0, format: Some(RasterFormat::Jpeg(85)) };
+/// optimize::downscale_images(&mut svg, &options);
+/// ```
+pub fn downscale_images(root: &mut Element, options: &RasterOptions) -> usize {
+    if root.attributes.contains_key(&Attribute::Transform) {
+        return 0;
+    }
+
+    let mut replaced = 0;
+    if root.tag_name == TagName::Image {
+        for attribute in &[Attribute::Href, Attribute::XlinkHref] {
+            let downscaled = root
+                .attributes
+                .get(attribute)
+                .and_then(|href| downscale_image(root, href, options));
+            if let Some(href) = downscaled {
+                root.set_attribute(attribute.clone(), href);
+                replaced += 1;
+            }
+        }
+    }
+
+    for child in root.children.iter_mut() {
+        if !matches!(
+            child.tag_name,
+            TagName::Symbol | TagName::Pattern | TagName::Marker | TagName::Svg
+        ) {
+            replaced += downscale_images(child, options);
+        }
+    }
+
+    replaced
+}
+
+/// Returns the downscaled `data:` URI of an image, or None if it should not be replaced
+fn downscale_image(element: &Element, href: &str, options: &RasterOptions) -> Option<String> {
+    let width = user_units(element.attributes.get(&Attribute::Width)?)? * options.scale;
+    let height = user_units(element.attributes.get(&Attribute::Height)?)? * options.scale;
+    if !(width > 0.0 && height > 0.0) {
+        return None;
+    }
+
+    let encoded = href.trim().strip_prefix("data:")?;
+    let (header, encoded) = encoded.split_once(',')?;
+    let header = header.strip_suffix(";base64")?;
+    let source = match header.split(';').next()?.trim() {
+        "image/png" => RasterFormat::Png,
+        "image/jpeg" | "image/jpg" => RasterFormat::Jpeg(85),
+        "image/webp" => RasterFormat::WebP,
+        _ => return None,
+    };
+
+    let image = image::load_from_memory(&base64::decode(encoded)?).ok()?;
+    let (source_width, source_height) = (f64::from(image.width()), f64::from(image.height()));
+
+    let preserve = element
+        .attributes
+        .get(&Attribute::PreserveAspectRatio)
+        .map(|value| value.trim())
+        .unwrap_or("");
+    let resized = if preserve.starts_with("none") {
+        let target_width = (width.ceil() as u32).min(image.width());
+        let target_height = (height.ceil() as u32).min(image.height());
+        if (target_width, target_height) == (image.width(), image.height()) {
+            return None;
+        }
+
+        image.resize_exact(target_width, target_height, FilterType::Lanczos3)
+    } else {
+        let fit = if preserve.ends_with("slice") {
+            (width / source_width).max(height / source_height)
+        } else {
+            (width / source_width).min(height / source_height)
+        };
+        if fit >= 1.0 {
+            return None;
+        }
+
+        image.resize(
+            ((source_width * fit).ceil() as u32).max(1),
+            ((source_height * fit).ceil() as u32).max(1),
+            FilterType::Lanczos3,
+        )
+    };
+
+    let format = match options.format {
+        Some(RasterFormat::Jpeg(_)) if resized.color().has_alpha() => source,
+        Some(format) => format,
+        None => source,
+    };
+    let data = format.encode(&resized)?;
+
+    let downscaled = format!(
+        "data:{};base64,{}",
+        format.mime_type(),
+        base64::encode(&data)
+    );
+    if downscaled.len() < href.len() {
+        Some(downscaled)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{downscale_images, RasterFormat, RasterOptions};
+    use crate::attributes::Attribute;
+    use crate::base64;
+    use crate::tag_name::TagName;
+    use crate::Element;
+    use image::{DynamicImage, ImageFormat, RgbImage, RgbaImage};
+    use std::io::Cursor;
+
+    fn data_uri(image: DynamicImage) -> String {
+        let mut encoded = Cursor::new(Vec::new());
+        image.write_to(&mut encoded, ImageFormat::Png).unwrap();
+        format!(
+            "data:image/png;base64,{}",
+            base64::encode(&encoded.into_inner())
+        )
+    }
+
+    fn decode(href: &str) -> DynamicImage {
+        let (_, encoded) = href.split_once(',').unwrap();
+        image::load_from_memory(&base64::decode(encoded).unwrap()).unwrap()
+    }
+
+    /// Returns a noisy image, which compresses about as badly as a photo
+    fn photo() -> DynamicImage {
+        DynamicImage::ImageRgb8(RgbImage::from_fn(200, 100, |x, y| {
+            let noise = (x * 7919 + y * 104_729) ^ (x * y);
+            image::Rgb([noise as u8, (noise >> 8) as u8, x as u8])
+        }))
+    }
+
+    #[test]
+    fn test_downscale_images() {
+        let image = |width: u32, height: u32, href: String| {
+            Element::new(TagName::Image)
+                .set(Attribute::Width, width)
+                .set(Attribute::Height, height)
+                .set(Attribute::Href, href)
+        };
+
+        let mut root = Element::new(TagName::Svg)
+            .append(image(50, 50, data_uri(photo())))
+            .append(image(50, 50, data_uri(photo())).set(Attribute::PreserveAspectRatio, "none"))
+            .append(image(400, 200, data_uri(photo())))
+            .append(
+                Element::new(TagName::G)
+                    .set(Attribute::Transform, "scale(4)")
+                    .append(image(50, 50, data_uri(photo()))),
+            )
+            .append(image(50, 50, String::from("photo.png")))
+            .append(
+                Element::new(TagName::Svg)
+                    .set(Attribute::ViewBox, "0 0 10 10")
+                    .set(Attribute::Width, 200)
+                    .append(image(10, 10, data_uri(photo()))),
+            )
+            .append(
+                Element::new(TagName::Symbol)
+                    .set(Attribute::ViewBox, "0 0 10 10")
+                    .append(image(10, 10, data_uri(photo()))),
+            );
+        let original = root.clone();
+
+        assert_eq!(downscale_images(&mut root, &RasterOptions::default()), 2);
+        let children = root.get_children();
+        let size = |element: &Element| {
+            let image = decode(&element.get_attributes()[&Attribute::Href]);
+            (image.width(), image.height())
+        };
+        assert_eq!(size(&children[0]), (50, 25));
+        assert_eq!(size(&children[1]), (50, 50));
+        assert_eq!(size(&children[2]), (200, 100));
+        assert_eq!(size(&children[3].get_children()[0]), (200, 100));
+        assert_eq!(size(&children[5].get_children()[0]), (200, 100));
+        assert_eq!(size(&children[6].get_children()[0]), (200, 100));
+
+        let mut root = original;
+        let options = RasterOptions {
+            scale: 2.0,
+            format: Some(RasterFormat::Jpeg(80)),
+        };
+        assert_eq!(downscale_images(&mut root, &options), 2);
+        let href = &root.get_children()[0].get_attributes()[&Attribute::Href];
+        assert!(href.starts_with("data:image/jpeg;base64,"));
+        assert_eq!(decode(href).width(), 100);
+
+        let transparent = DynamicImage::ImageRgba8(RgbaImage::new(200, 200));
+        let mut root = image(10, 10, data_uri(transparent));
+        assert_eq!(downscale_images(&mut root, &options), 1);
+        assert!(root.get_attributes()[&Attribute::Href].starts_with("data:image/png;"));
+    }
+}
@@ -0,0 +1,151 @@
+//! This module generates lists of [Colors](../color/struct.Color.html) for charts, like
+//! [categorical](fn.categorical.html) palettes for series and [sequential](fn.sequential.html)
+//! and [diverging](fn.diverging.html) ramps for values.
+//!
+//! # Examples
+//! ## Coloring the series of a chart
+//! ```
+//! use svg_definitions::color::Color;
+//! use svg_definitions::palette;
+//! use svg_definitions::prelude::*;
+//!
+//! let series = [3.0, 5.0, 2.0];
+//! let colors = palette::categorical(&Color::parse("#1f77b4").unwrap(), series.len());
+//!
+//! let mut chart = SVGElem::new(Tag::Svg);
+//! for (index, (value, color)) in series.iter().zip(colors).enumerate() {
+//!     chart = chart.append(
+//!         SVGElem::new(Tag::Rect)
+//!             .set(Attr::X, index * 20)
+//!             .set(Attr::Width, 15)
+//!             .set(Attr::Height, value * 10.0)
+//!             .set(Attr::Fill, color),
+//!     );
+//! }
+//! ```
+
+use crate::color::Color;
+
+/// Returns `count` colors with evenly spaced hues, starting at a base color and keeping its
+/// saturation, lightness and alpha
+///
+/// # Note / Arguments
+/// The colors are as distinct as possible for their saturation and lightness, which makes them
+/// suited for unrelated categories. A gray base color gives only grays.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::color::Color;
+/// use svg_definitions::palette;
+///
+/// let colors = palette::categorical(&Color::rgb(255, 0, 0), 3);
+/// assert_eq!(colors, vec![Color::rgb(255, 0, 0), Color::rgb(0, 255, 0), Color::rgb(0, 0, 255)]);
+/// ```
+pub fn categorical(base: &Color, count: usize) -> Vec<Color> {
+    let hsl = base.to_hsl();
+    (0..count)
+        .map(|index| {
+            let mut color = hsl;
+            color.hue += 360.0 * index as f64 / count as f64;
+            Color::from_hsl(&color)
+        })
+        .collect()
+}
+
+/// Returns `count` colors evenly spaced from one color to another, including both
+///
+/// # Note / Arguments
+/// The colors are interpolated like [mix](../color/struct.Color.html#method.mix) does. A single
+/// color is the start color.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::color::Color;
+/// use svg_definitions::palette;
+///
+/// let ramp = palette::sequential(&Color::rgb(255, 255, 255), &Color::rgb(0, 0, 255), 3);
+/// assert_eq!(ramp[1], Color::rgb(128, 128, 255));
+/// ```
+pub fn sequential(from: &Color, to: &Color, count: usize) -> Vec<Color> {
+    (0..count)
+        .map(|index| from.mix(to, fraction(index, count)))
+        .collect()
+}
+
+/// Returns `count` colors evenly spaced from a low color through a middle color to a high color,
+/// for values which deviate in two directions from a midpoint
+///
+/// # Note / Arguments
+/// The middle color is included for an odd `count`, for an even `count` the two colors closest
+/// to the midpoint are equally far from it.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::color::Color;
+/// use svg_definitions::palette;
+///
+/// let (red, white, blue) = (Color::rgb(255, 0, 0), Color::rgb(255, 255, 255), Color::rgb(0, 0, 255));
+/// let ramp = palette::diverging(&red, &white, &blue, 5);
+///
+/// assert_eq!(ramp[0], red);
+/// assert_eq!(ramp[2], white);
+/// assert_eq!(ramp[3], Color::rgb(128, 128, 255));
+/// ```
+pub fn diverging(low: &Color, middle: &Color, high: &Color, count: usize) -> Vec<Color> {
+    (0..count)
+        .map(|index| {
+            let t = fraction(index, count);
+            if t < 0.5 {
+                low.mix(middle, 2.0 * t)
+            } else {
+                middle.mix(high, 2.0 * t - 1.0)
+            }
+        })
+        .collect()
+}
+
+/// Returns the position of a color in a ramp of `count` colors, from 0 to 1
+fn fraction(index: usize, count: usize) -> f64 {
+    if count > 1 {
+        index as f64 / (count - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{categorical, diverging, sequential};
+    use crate::color::Color;
+
+    #[test]
+    fn test_palettes() {
+        let base = Color::rgba(204, 51, 51, 0.5);
+        let colors = categorical(&base, 4);
+        assert_eq!(colors[0], base);
+        assert_eq!(colors[2], Color::rgba(51, 204, 204, 0.5));
+        for color in colors.iter() {
+            let hsl = color.to_hsl();
+            assert!((hsl.saturation - 0.6).abs() < 0.01 && (hsl.lightness - 0.5).abs() < 0.01);
+        }
+        assert!(categorical(&base, 0).is_empty());
+
+        let (black, white) = (Color::rgb(0, 0, 0), Color::rgb(255, 255, 255));
+        assert_eq!(
+            sequential(&black, &white, 4),
+            vec![
+                black,
+                Color::rgb(85, 85, 85),
+                Color::rgb(170, 170, 170),
+                white
+            ]
+        );
+        assert_eq!(sequential(&black, &white, 1), vec![black]);
+
+        let ramp = diverging(&black, &Color::rgb(100, 0, 0), &white, 4);
+        assert_eq!(ramp[0], black);
+        assert_eq!(ramp[1], Color::rgb(67, 0, 0));
+        assert_eq!(ramp[2], Color::rgb(152, 85, 85));
+        assert_eq!(ramp[3], white);
+    }
+}
@@ -27,353 +27,126 @@
 #[derive(Debug)]
 pub enum ParseError {
     RoxmltreeError(roxmltree::Error),
+    #[deprecated(note = "unknown tags are parsed as TagName::Custom")]
     TagNotFound(String),
     NoElement,
     FileError(std::io::Error),
+    /// Several elements have the same id, with [IdPolicy::Error](enum.IdPolicy.html)
+    DuplicateId(String),
+    /// A change given to [edit_source](fn.edit_source.html) addresses an element or child which
+    /// does not exist, with the index of the change
+    InvalidEdit(usize),
 }
 
-fn string_to_tag(string: &str) -> Option<crate::tag_name::TagName> {
-    use crate::tag_name::TagName::*;
-
-    let string = string.to_lowercase();
-
-    match &string[..] {
-        "a" => Some(A),
-        "animate" => Some(Animate),
-        "animateMotion" => Some(AnimateMotion),
-        "animateTransform" => Some(AnimateTransform),
-        "circle" => Some(Circle),
-        "clipPath" => Some(ClipPath),
-        "color-profile" => Some(ColorProfile),
-        "defs" => Some(Defs),
-        "desc" => Some(Desc),
-        "discard" => Some(Discard),
-        "ellipse" => Some(Ellipse),
-        "feBlend" => Some(FeBlend),
-        "feColorMatrix" => Some(FeColorMatrix),
-        "feComponentTransfer" => Some(FeComponentTransfer),
-        "feComposite" => Some(FeComposite),
-        "feConvolveMatrix" => Some(FeConvolveMatrix),
-        "feDiffuseLighting" => Some(FeDiffuseLighting),
-        "feDisplacementMap" => Some(FeDisplacementMap),
-        "feDistantLight" => Some(FeDistantLight),
-        "feDropShadow" => Some(FeDropShadow),
-        "feFlood" => Some(FeFlood),
-        "feFuncA" => Some(FeFuncA),
-        "feFuncB" => Some(FeFuncB),
-        "feFuncG" => Some(FeFuncG),
-        "feFuncR" => Some(FeFuncR),
-        "feGaussianBlur" => Some(FeGaussianBlur),
-        "feImage" => Some(FeImage),
-        "feMerge" => Some(FeMerge),
-        "feMergeNode" => Some(FeMergeNode),
-        "feMorphology" => Some(FeMorphology),
-        "feOffset" => Some(FeOffset),
-        "fePointLight" => Some(FePointLight),
-        "feSpecularLighting" => Some(FeSpecularLighting),
-        "feSpotLight" => Some(FeSpotLight),
-        "feTile" => Some(FeTile),
-        "feTurbulence" => Some(FeTurbulence),
-        "filter" => Some(Filter),
-        "foreignObject" => Some(ForeignObject),
-        "g" => Some(G),
-        "hatch" => Some(Hatch),
-        "hatchpath" => Some(Hatchpath),
-        "image" => Some(Image),
-        "line" => Some(Line),
-        "linearGradient" => Some(LinearGradient),
-        "marker" => Some(Marker),
-        "mask" => Some(Mask),
-        "mesh" => Some(Mesh),
-        "meshgradient" => Some(Meshgradient),
-        "meshpatch" => Some(Meshpatch),
-        "meshrow" => Some(Meshrow),
-        "metadata" => Some(Metadata),
-        "mpath" => Some(Mpath),
-        "path" => Some(Path),
-        "pattern" => Some(Pattern),
-        "polygon" => Some(Polygon),
-        "polyline" => Some(Polyline),
-        "radialGradient" => Some(RadialGradient),
-        "rect" => Some(Rect),
-        "script" => Some(Script),
-        "set" => Some(Set),
-        "solidcolor" => Some(Solidcolor),
-        "stop" => Some(Stop),
-        "style" => Some(Style),
-        "svg" => Some(Svg),
-        "switch" => Some(Switch),
-        "symbol" => Some(Symbol),
-        "text" => Some(Text),
-        "textPath" => Some(TextPath),
-        "title" => Some(Title),
-        "tspan" => Some(Tspan),
-        "unknown" => Some(Unknown),
-        "use" => Some(Use),
-        "view" => Some(View),
-        _ => None,
+/// What to do when an element repeats an attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributePolicy {
+    /// Fail with a parse error, which is the default
+    Error,
+
+    /// Keep the first value
+    FirstWins,
+
+    /// Keep the last value
+    LastWins,
+}
+
+/// What to do when several elements have the same id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdPolicy {
+    /// Keep every id, which is the default
+    Allow,
+
+    /// Fail with [ParseError::DuplicateId](enum.ParseError.html#variant.DuplicateId)
+    Error,
+
+    /// Remove the id from every element but the first
+    FirstWins,
+
+    /// Remove the id from every element but the last
+    LastWins,
+
+    /// Give every element but the first a new id, e.g. `id-2`
+    Rename,
+}
+
+/// How to read attribute names which only differ in case from an SVG attribute, like `viewbox`
+/// or `cLip-path`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeCase {
+    /// Keep them as [Custom](../attributes/enum.Attribute.html#variant.Custom) attributes, like
+    /// browsers do, which is the default
+    Exact,
+
+    /// Read them as the SVG attribute, so they are written with the canonical name
+    Canonicalize,
+
+    /// Keep them as Custom attributes with their original spelling, so a round trip does not
+    /// change the markup, but report them as warnings
+    Preserve,
+}
+
+/// The options of [parse_text_with_options](fn.parse_text_with_options.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// What to do when an element repeats an attribute
+    pub duplicate_attributes: AttributePolicy,
+
+    /// What to do when several elements have the same id
+    pub duplicate_ids: IdPolicy,
+
+    /// How to read attribute names which differ in case from an SVG attribute
+    pub attribute_case: AttributeCase,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            duplicate_attributes: AttributePolicy::Error,
+            duplicate_ids: IdPolicy::Allow,
+            attribute_case: AttributeCase::Exact,
+        }
     }
 }
-fn string_to_attribute(string: &str) -> crate::attributes::Attribute {
-    use crate::attributes::Attribute::*;
-
-    match &string[..] {
-        "accent-height" => AccentHeight,
-        "accumulate" => Accumulate,
-        "additive" => Additive,
-        "alignment-baseline" => AlignmentBaseline,
-        "allowReorder" => AllowReorder,
-        "alphabetic" => Alphabetic,
-        "amplitude" => Amplitude,
-        "arabic-form" => ArabicForm,
-        "ascent" => Ascent,
-        "attributeName" => AttributeName,
-        "attributeType" => AttributeType,
-        "autoReverse" => AutoReverse,
-        "azimuth" => Azimuth,
-        "baseFrequency" => BaseFrequency,
-        "baseline-shift" => BaselineShift,
-        "baseProfile" => BaseProfile,
-        "bbox" => Bbox,
-        "begin" => Begin,
-        "bias" => Bias,
-        "by" => By,
-        "calcMode" => CalcMode,
-        "cap-height" => CapHeight,
-        "class" => Class,
-        "clip" => Clip,
-        "clipPathUnits" => ClipPathUnits,
-        "clip-path" => ClipPath,
-        "clip-rule" => ClipRule,
-        "color" => Color,
-        "color-interpolation" => ColorInterpolation,
-        "color-interpolation-filters" => ColorInterpolationfilters,
-        "color-profile" => ColorProfile,
-        "color-rendering" => ColorRendering,
-        "contentScriptType" => ContentScriptType,
-        "contentStyleType" => ContentStyleType,
-        "cursor" => Cursor,
-        "cx" => Cx,
-        "cy" => Cy,
-        "d" => D,
-        "decelerate" => Decelerate,
-        "descent" => Descent,
-        "diffuseConstant" => DiffuseConstant,
-        "direction" => Direction,
-        "display" => Display,
-        "divisor" => Divisor,
-        "dominant-baseline" => DominantBaseline,
-        "dur" => Dur,
-        "dx" => Dx,
-        "dy" => Dy,
-        "edgeMode" => EdgeMode,
-        "elevation" => Elevation,
-        "enable-background" => EnableBackground,
-        "end" => End,
-        "exponent" => Exponent,
-        "externalResourcesRequired" => ExternalResourcesRequired,
-        "fill" => Fill,
-        "fill-opacity" => FillOpacity,
-        "fill-rule" => FillRule,
-        "filter" => Filter,
-        "filterRes" => FilterRes,
-        "filterUnits" => FilterUnits,
-        "flood-color" => FloodColor,
-        "flood-opacity" => FloodOpacity,
-        "font-family" => FontFamily,
-        "font-size" => FontSize,
-        "font-size-adjust" => FontSizeadjust,
-        "font-stretch" => FontStretch,
-        "font-style" => FontStyle,
-        "font-variant" => FontVariant,
-        "font-weight" => FontWeight,
-        "format" => Format,
-        "from" => From,
-        "fr" => Fr,
-        "fx" => Fx,
-        "fy" => Fy,
-        "g1" => G1,
-        "g2" => G2,
-        "glyph-name" => GlyphName,
-        "glyph-orientation-horizontal" => GlyphOrientationhorizontal,
-        "glyph-orientation-vertical" => GlyphOrientationvertical,
-        "glyphRef" => GlyphRef,
-        "gradientTransform" => GradientTransform,
-        "gradientUnits" => GradientUnits,
-        "hanging" => Hanging,
-        "height" => Height,
-        "href" => Href,
-        "hreflang" => Hreflang,
-        "horiz-adv-x" => HorizAdvx,
-        "horiz-origin-x" => HorizOriginx,
-        "id" => Id,
-        "ideographic" => Ideographic,
-        "image-rendering" => ImageRendering,
-        "in" => In,
-        "in2" => In2,
-        "intercept" => Intercept,
-        "k" => K,
-        "k1" => K1,
-        "k2" => K2,
-        "k3" => K3,
-        "k4" => K4,
-        "kernelMatrix" => KernelMatrix,
-        "kernelUnitLength" => KernelUnitLength,
-        "kerning" => Kerning,
-        "keyPoints" => KeyPoints,
-        "keySplines" => KeySplines,
-        "keyTimes" => KeyTimes,
-        "lang" => Lang,
-        "lengthAdjust" => LengthAdjust,
-        "letter-spacing" => LetterSpacing,
-        "lighting-color" => LightingColor,
-        "limitingConeAngle" => LimitingConeAngle,
-        "local" => Local,
-        "marker-end" => MarkerEnd,
-        "marker-mid" => MarkerMid,
-        "marker-start" => MarkerStart,
-        "markerHeight" => MarkerHeight,
-        "markerUnits" => MarkerUnits,
-        "markerWidth" => MarkerWidth,
-        "mask" => Mask,
-        "maskContentUnits" => MaskContentUnits,
-        "maskUnits" => MaskUnits,
-        "mathematical" => Mathematical,
-        "max" => Max,
-        "media" => Media,
-        "method" => Method,
-        "min" => Min,
-        "mode" => Mode,
-        "name" => Name,
-        "numOctaves" => NumOctaves,
-        "offset" => Offset,
-        "opacity" => Opacity,
-        "operator" => Operator,
-        "order" => Order,
-        "orient" => Orient,
-        "orientation" => Orientation,
-        "origin" => Origin,
-        "overflow" => Overflow,
-        "overline-position" => OverlinePosition,
-        "overline-thickness" => OverlineThickness,
-        "panose-1" => Panose1,
-        "paint-order" => PaintOrder,
-        "path" => Path,
-        "pathLength" => PathLength,
-        "patternContentUnits" => PatternContentUnits,
-        "patternTransform" => PatternTransform,
-        "patternUnits" => PatternUnits,
-        "ping" => Ping,
-        "pointer-events" => PointerEvents,
-        "points" => Points,
-        "pointsAtX" => PointsAtX,
-        "pointsAtY" => PointsAtY,
-        "pointsAtZ" => PointsAtZ,
-        "preserveAlpha" => PreserveAlpha,
-        "preserveAspectRatio" => PreserveAspectRatio,
-        "primitiveUnits" => PrimitiveUnits,
-        "r" => R,
-        "radius" => Radius,
-        "referrerPolicy" => ReferrerPolicy,
-        "refX" => RefX,
-        "refY" => RefY,
-        "rel" => Rel,
-        "rendering-intent" => RenderingIntent,
-        "repeatCount" => RepeatCount,
-        "repeatDur" => RepeatDur,
-        "requiredExtensions" => RequiredExtensions,
-        "requiredFeatures" => RequiredFeatures,
-        "restart" => Restart,
-        "result" => Result,
-        "rotate" => Rotate,
-        "rx" => Rx,
-        "ry" => Ry,
-        "slope" => Slope,
-        "spacing" => Spacing,
-        "specularConstant" => SpecularConstant,
-        "specularExponent" => SpecularExponent,
-        "speed" => Speed,
-        "spreadMethod" => SpreadMethod,
-        "startOffset" => StartOffset,
-        "stdDeviation" => StdDeviation,
-        "stemh" => Stemh,
-        "stemv" => Stemv,
-        "stitchTiles" => StitchTiles,
-        "stop-color" => StopColor,
-        "stop-opacity" => StopOpacity,
-        "strikethrough-position" => StrikethroughPosition,
-        "strikethrough-thickness" => StrikethroughThickness,
-        "string" => String,
-        "stroke" => Stroke,
-        "stroke-dasharray" => StrokeDasharray,
-        "stroke-dashoffset" => StrokeDashoffset,
-        "stroke-linecap" => StrokeLinecap,
-        "stroke-linejoin" => StrokeLinejoin,
-        "stroke-miterlimit" => StrokeMiterlimit,
-        "stroke-opacity" => StrokeOpacity,
-        "stroke-width" => StrokeWidth,
-        "style" => Style,
-        "surfaceScale" => SurfaceScale,
-        "systemLanguage" => SystemLanguage,
-        "tabindex" => Tabindex,
-        "tableValues" => TableValues,
-        "target" => Target,
-        "targetX" => TargetX,
-        "targetY" => TargetY,
-        "text-anchor" => TextAnchor,
-        "text-decoration" => TextDecoration,
-        "text-rendering" => TextRendering,
-        "textLength" => TextLength,
-        "to" => To,
-        "transform" => Transform,
-        "type" => Type,
-        "u1" => U1,
-        "u2" => U2,
-        "underline-position" => UnderlinePosition,
-        "underline-thickness" => UnderlineThickness,
-        "unicode" => Unicode,
-        "unicode-bidi" => UnicodeBidi,
-        "unicode-range" => UnicodeRange,
-        "units-per-em" => UnitsPerem,
-        "v-alphabetic" => VAlphabetic,
-        "v-hanging" => VHanging,
-        "v-ideographic" => VIdeographic,
-        "v-mathematical" => VMathematical,
-        "values" => Values,
-        "vector-effect" => VectorEffect,
-        "version" => Version,
-        "vert-adv-y" => VertAdvy,
-        "vert-origin-x" => VertOriginx,
-        "vert-origin-y" => VertOriginy,
-        "viewBox" => ViewBox,
-        "viewTarget" => ViewTarget,
-        "visibility" => Visibility,
-        "width" => Width,
-        "widths" => Widths,
-        "word-spacing" => WordSpacing,
-        "writing-mode" => WritingMode,
-        "x" => X,
-        "x-height" => XHeight,
-        "x1" => X1,
-        "x2" => X2,
-        "xChannelSelector" => XChannelSelector,
-        "xlink:actuate" => XlinkActuate,
-        "xlink:arcrole" => XlinkArcrole,
-        "xlink:href" => XlinkHref,
-        "xlink:role" => XlinkRole,
-        "xlink:show" => XlinkShow,
-        "xlink:title" => XlinkTitle,
-        "xlink:type" => XlinkType,
-        "xml:base" => XmlBase,
-        "xml:lang" => XmlLang,
-        "xml:space" => XmlSpace,
-        "y" => Y,
-        "y1" => Y1,
-        "y2" => Y2,
-        "yChannelSelector" => YChannelSelector,
-        "z" => Z,
-        "zoomAndPan" => ZoomAndPan,
-        attr => UnmappedAttribute(std::string::String::from(attr)),
+
+/// A problem in the markup which did not stop the parsing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseWarning {
+    /// An attribute was repeated on an element, and the occurrence at a byte offset was dropped
+    DuplicateAttribute { attribute: String, offset: usize },
+
+    /// An id was already used by an earlier element, with the path of the element that lost
+    /// its id or got a new one
+    DuplicateId {
+        id: String,
+        path: crate::tree::ElementPath,
+        renamed: Option<String>,
+    },
+
+    /// An attribute name differs in case from an SVG attribute, with the path of its element,
+    /// with [AttributeCase::Canonicalize](enum.AttributeCase.html#variant.Canonicalize) and
+    /// [AttributeCase::Preserve](enum.AttributeCase.html#variant.Preserve)
+    AttributeCase {
+        attribute: String,
+        canonical: crate::attributes::Attribute,
+        path: crate::tree::ElementPath,
+    },
+}
+
+/// Returns the TagName of a node, where elements from other namespaces become Custom tag names
+/// including their prefix
+fn node_tag_name(node: roxmltree::Node) -> crate::tag_name::TagName {
+    let name = node.tag_name().name();
+    let prefix = node
+        .tag_name()
+        .namespace()
+        .filter(|uri| *uri != crate::namespace::SVG)
+        .and_then(|uri| node.lookup_prefix(uri));
+
+    match prefix {
+        Some(prefix) => crate::tag_name::TagName::Custom(format!("{}:{}", prefix, name)),
+        None => crate::tag_name::TagName::from_name(name)
+            .unwrap_or_else(|| crate::tag_name::TagName::Custom(String::from(name))),
     }
 }
 
@@ -384,11 +157,25 @@ fn node_to_element(root: roxmltree::Node) -> Result<Option<crate::Element>, Pars
 
     let mut inner = String::from("");
 
-    let tag = root.tag_name().name();
-    let mut element: crate::Element =
-        crate::Element::new(string_to_tag(tag).ok_or(ParseError::TagNotFound(String::from(tag)))?);
+    let mut element: crate::Element = crate::Element::new(node_tag_name(root));
+
+    // Namespaces in scope include those of the ancestors, so only keep the new ones
+    let inherited = root.parent().map_or(&[][..], |parent| parent.namespaces());
+    for namespace in root.namespaces() {
+        if !inherited.contains(namespace) {
+            element = element.set_namespace(namespace.name().unwrap_or(""), namespace.uri());
+        }
+    }
+
     for attribute in root.attributes().iter() {
-        element = element.set(string_to_attribute(attribute.name()), attribute.value());
+        let prefix = attribute
+            .namespace()
+            .and_then(|uri| root.lookup_prefix(uri));
+        let name = match prefix {
+            Some(prefix) => crate::attributes::Attribute::namespaced(prefix, attribute.name()),
+            None => crate::attributes::Attribute::custom(attribute.name()),
+        };
+        element = element.set(name, attribute.value());
     }
 
     for child in root.children() {
@@ -396,17 +183,12 @@ fn node_to_element(root: roxmltree::Node) -> Result<Option<crate::Element>, Pars
             inner = format!("{}{}", inner, child.text().unwrap());
         }
 
-        let child_element = node_to_element(child)?;
-
-        match child_element {
-            Some(child_element) => {
-                element = element.append(child_element);
-            }
-            None => (),
-        };
+        if let Some(child_element) = node_to_element(child)? {
+            element = element.append(child_element);
+        }
     }
 
-    if inner != "" {
+    if !inner.is_empty() {
         element = element.set_inner(&inner[..]);
     }
 
@@ -425,8 +207,658 @@ fn node_to_element(root: roxmltree::Node) -> Result<Option<crate::Element>, Pars
 /// // ...
 /// ```
 pub fn parse_text(xml: &str) -> Result<crate::Element, ParseError> {
-    let doc = roxmltree::Document::parse(xml).map_err(|err| ParseError::RoxmltreeError(err))?;
-    return node_to_element(doc.root_element())?.ok_or(ParseError::NoElement);
+    let doc = roxmltree::Document::parse(xml).map_err(ParseError::RoxmltreeError)?;
+    node_to_element(doc.root_element())?.ok_or(ParseError::NoElement)
+}
+
+/// Parsing a whole document from a pure string, keeping the XML declaration and the processing
+/// instructions before the root element
+///
+/// # Examples
+/// *The feature "parsing" needs to be enabled for this*
+/// ```
+/// use svg_definitions::parser::parse_document;
+///
+/// let document = parse_document(
+///     "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+///      <?xml-stylesheet href=\"style.css\"?>\n\
+///      <svg/>",
+/// )
+/// .unwrap();
+///
+/// let targets: Vec<&str> = document
+///     .get_processing_instructions()
+///     .iter()
+///     .map(|instruction| &instruction.target[..])
+///     .collect();
+/// assert_eq!(targets, ["xml", "xml-stylesheet"]);
+/// ```
+pub fn parse_document(xml: &str) -> Result<crate::document::Document, ParseError> {
+    use crate::document::ProcessingInstruction;
+
+    let doc = roxmltree::Document::parse(xml).map_err(ParseError::RoxmltreeError)?;
+    let root = doc.root_element();
+    let mut document =
+        crate::document::Document::new(node_to_element(root)?.ok_or(ParseError::NoElement)?);
+
+    // The XML declaration is not a node in roxmltree, so it is read from the text itself
+    let declaration = xml
+        .trim_start_matches('\u{feff}')
+        .strip_prefix("<?xml")
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .and_then(|rest| rest.split_once("?>"));
+    if let Some((value, _)) = declaration {
+        document.add_processing_instruction(ProcessingInstruction {
+            target: String::from("xml"),
+            value: Some(String::from(value.trim())),
+        });
+    }
+
+    for node in doc.root().children().take_while(|node| *node != root) {
+        if let Some(pi) = node.pi() {
+            document.add_processing_instruction(ProcessingInstruction {
+                target: String::from(pi.target),
+                value: pi.value.map(|value| String::from(value.trim())),
+            });
+        }
+    }
+
+    Ok(document)
+}
+
+/// Parsing from a pure string like [parse_text](fn.parse_text.html), with policies for
+/// repeated attributes, ids and the case of attribute names, returning what was found as
+/// warnings
+///
+/// # Note / Arguments
+/// References to a renamed id are not rewritten, since it is ambiguous which element they mean.
+/// When an element has an attribute both with its canonical name and in another case, the
+/// canonical one is kept by [AttributeCase::Canonicalize](enum.AttributeCase.html#variant.Canonicalize).
+///
+/// # Examples
+/// *The feature "parsing" needs to be enabled for this*
+/// ```
+/// use svg_definitions::parser::{parse_text_with_options, AttributePolicy, IdPolicy, ParseOptions};
+///
+/// let options = ParseOptions {
+///     duplicate_attributes: AttributePolicy::LastWins,
+///     duplicate_ids: IdPolicy::Rename,
+///     ..ParseOptions::default()
+/// };
+/// let (svg, warnings) = parse_text_with_options(
+///     "<svg><rect id=\"a\" fill=\"red\" fill=\"blue\"/><circle id=\"a\"/></svg>",
+///     &options,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     svg.to_string(),
+///     "<svg><rect fill=\"blue\" id=\"a\"/><circle id=\"a-2\"/></svg>"
+/// );
+/// assert_eq!(warnings.len(), 2);
+/// ```
+pub fn parse_text_with_options(
+    xml: &str,
+    options: &ParseOptions,
+) -> Result<(crate::Element, Vec<ParseWarning>), ParseError> {
+    let mut warnings = Vec::new();
+    let xml = match options.duplicate_attributes {
+        AttributePolicy::Error => std::borrow::Cow::Borrowed(xml),
+        policy => remove_duplicate_attributes(xml, policy, &mut warnings),
+    };
+
+    let mut element = parse_text(&xml)?;
+    resolve_duplicate_ids(&mut element, options.duplicate_ids, &mut warnings)?;
+    if options.attribute_case != AttributeCase::Exact {
+        resolve_attribute_case(
+            &mut element,
+            options.attribute_case,
+            crate::tree::ElementPath::new(),
+            &mut warnings,
+        );
+    }
+    Ok((element, warnings))
+}
+
+/// Removes the repeated attributes which lose under a policy from the markup, leaving markup
+/// which cannot be tokenized as is for roxmltree to report
+fn remove_duplicate_attributes<'a>(
+    xml: &'a str,
+    policy: AttributePolicy,
+    warnings: &mut Vec<ParseWarning>,
+) -> std::borrow::Cow<'a, str> {
+    let mut removed = Vec::new();
+    let mut attributes: Vec<(&str, xmlparser::StrSpan)> = Vec::new();
+    for token in xmlparser::Tokenizer::from(xml) {
+        match token {
+            Ok(xmlparser::Token::ElementStart { .. }) => attributes.clear(),
+            Ok(xmlparser::Token::Attribute { span, .. }) => {
+                let name = span.as_str().split('=').next().unwrap_or("").trim();
+                match attributes.iter().position(|(other, _)| *other == name) {
+                    Some(_) if policy == AttributePolicy::FirstWins => removed.push(span),
+                    Some(index) => {
+                        removed.push(attributes[index].1);
+                        attributes[index].1 = span;
+                    }
+                    None => attributes.push((name, span)),
+                }
+            }
+            Ok(_) => (),
+            Err(_) => return std::borrow::Cow::Borrowed(xml),
+        }
+    }
+
+    if removed.is_empty() {
+        return std::borrow::Cow::Borrowed(xml);
+    }
+
+    removed.sort_by_key(|span| span.start());
+    let mut output = String::with_capacity(xml.len());
+    let mut last = 0;
+    for span in removed {
+        let name = span.as_str().split('=').next().unwrap_or("").trim();
+        warnings.push(ParseWarning::DuplicateAttribute {
+            attribute: String::from(name),
+            offset: span.start(),
+        });
+        output.push_str(&xml[last..span.start()]);
+        last = span.end();
+    }
+    output.push_str(&xml[last..]);
+    std::borrow::Cow::Owned(output)
+}
+
+/// Applies a policy to the attribute names of an element and its descendants which differ in case
+/// from an SVG attribute
+fn resolve_attribute_case(
+    element: &mut crate::Element,
+    policy: AttributeCase,
+    path: crate::tree::ElementPath,
+    warnings: &mut Vec<ParseWarning>,
+) {
+    use crate::attributes::Attribute;
+
+    let mut variants: Vec<(String, Attribute)> = element
+        .attributes
+        .keys()
+        .filter_map(|attribute| match attribute {
+            Attribute::Custom(name) => {
+                Attribute::from_name_ignore_case(name).map(|canonical| (name.clone(), canonical))
+            }
+            _ => None,
+        })
+        .collect();
+    variants.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (name, canonical) in variants {
+        if policy == AttributeCase::Canonicalize {
+            let value = element
+                .attributes
+                .remove(&Attribute::Custom(name.clone()))
+                .expect("collected attribute exists");
+            element.attributes.entry(canonical.clone()).or_insert(value);
+        }
+        warnings.push(ParseWarning::AttributeCase {
+            attribute: name,
+            canonical,
+            path: path.clone(),
+        });
+    }
+
+    for (index, child) in element.children.iter_mut().enumerate() {
+        resolve_attribute_case(child, policy, path.child(index), warnings);
+    }
+}
+
+/// Applies a policy to the elements with an id which is also used by another element
+fn resolve_duplicate_ids(
+    root: &mut crate::Element,
+    policy: IdPolicy,
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<(), ParseError> {
+    use crate::attributes::Attribute;
+    use std::collections::{HashMap, HashSet};
+
+    fn collect(
+        element: &crate::Element,
+        path: crate::tree::ElementPath,
+        ids: &mut Vec<(crate::tree::ElementPath, String)>,
+    ) {
+        if let Some(id) = element.get_attributes().get(&Attribute::Id) {
+            ids.push((path.clone(), String::from(id.as_str())));
+        }
+        for (index, child) in element.get_children().iter().enumerate() {
+            collect(child, path.child(index), ids);
+        }
+    }
+
+    let mut ids = Vec::new();
+    collect(root, crate::tree::ElementPath::new(), &mut ids);
+
+    let mut occurrences: HashMap<&str, Vec<&crate::tree::ElementPath>> = HashMap::new();
+    for (path, id) in ids.iter() {
+        occurrences.entry(id).or_default().push(path);
+    }
+    let mut taken: HashSet<String> = occurrences.keys().map(|id| id.to_string()).collect();
+
+    for (path, id) in ids.iter() {
+        let paths = &occurrences[&id[..]];
+        let (first, last) = (paths[0], paths[paths.len() - 1]);
+        if paths.len() < 2 || (policy != IdPolicy::LastWins && path == first) {
+            continue;
+        }
+
+        let element = root.get_path_mut(path).expect("collected path exists");
+        let renamed = match policy {
+            IdPolicy::Allow => None,
+            IdPolicy::Error => return Err(ParseError::DuplicateId(id.clone())),
+            IdPolicy::FirstWins => {
+                element.attributes.remove(&Attribute::Id);
+                None
+            }
+            IdPolicy::LastWins if path == last => continue,
+            IdPolicy::LastWins => {
+                element.attributes.remove(&Attribute::Id);
+                None
+            }
+            IdPolicy::Rename => {
+                let renamed = (2..)
+                    .map(|number| format!("{}-{}", id, number))
+                    .find(|renamed| !taken.contains(renamed))
+                    .expect("a free id exists");
+                taken.insert(renamed.clone());
+                element.set_attribute(Attribute::Id, renamed.clone());
+                Some(renamed)
+            }
+        };
+
+        warnings.push(ParseWarning::DuplicateId {
+            id: id.clone(),
+            path: path.clone(),
+            renamed,
+        });
+    }
+
+    Ok(())
+}
+
+/// A filter on elements for [extract](fn.extract.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selector {
+    /// Elements with a certain tag name
+    Tag(crate::tag_name::TagName),
+
+    /// The element with a certain id
+    Id(String),
+
+    /// Elements with a certain class
+    Class(String),
+}
+
+/// The start tag of an element while streaming, with the attributes needed for a Selector
+struct StartTag<'a> {
+    start: usize,
+    name: String,
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+    namespaces: Vec<(&'a str, &'a str)>,
+}
+
+impl StartTag<'_> {
+    fn matches(&self, selector: &Selector) -> bool {
+        match selector {
+            Selector::Tag(tag_name) => tag_name.name() == self.name,
+            Selector::Id(id) => self.id.map(str::trim) == Some(id.as_str()),
+            Selector::Class(class) => self
+                .class
+                .is_some_and(|classes| classes.split_whitespace().any(|name| name == class)),
+        }
+    }
+}
+
+/// Streams through the markup and parses only the elements matching a selector, so the rest of
+/// the document is never built
+///
+/// # Note / Arguments
+/// The elements are returned in the order of the markup. Matching elements within another
+/// matching element are only returned as part of it. Every returned element declares the
+/// namespaces which are in scope for it, so it can be serialized on its own.
+///
+/// # Examples
+/// *The feature "parsing" needs to be enabled for this*
+/// ```
+/// use svg_definitions::parser::{extract, Selector};
+///
+/// let sprite = "<svg xmlns=\"http://www.w3.org/2000/svg\">\
+///               <symbol id=\"home\"><path d=\"M 0 5 L 5 0 L 10 5\"/></symbol>\
+///               <symbol id=\"search\"><circle r=\"4\"/></symbol>\
+///               </svg>";
+///
+/// let icons = extract(sprite, &Selector::Id(String::from("search"))).unwrap();
+///
+/// assert_eq!(
+///     icons[0].to_string(),
+///     "<symbol id=\"search\" xmlns=\"http://www.w3.org/2000/svg\"><circle r=\"4\"/></symbol>"
+/// );
+/// ```
+pub fn extract(xml: &str, selector: &Selector) -> Result<Vec<crate::Element>, ParseError> {
+    let mut elements = Vec::new();
+    let mut scopes: Vec<Vec<(&str, &str)>> = Vec::new();
+    let mut start_tag: Option<StartTag> = None;
+    let mut matched: Option<(usize, usize)> = None;
+
+    for token in xmlparser::Tokenizer::from(xml) {
+        let token = match token {
+            Ok(token) => token,
+            Err(_) => return Err(markup_error(xml)),
+        };
+
+        match token {
+            xmlparser::Token::ElementStart {
+                prefix,
+                local,
+                span,
+            } => {
+                start_tag = Some(StartTag {
+                    start: span.start(),
+                    name: match prefix.as_str() {
+                        "" => String::from(local.as_str()),
+                        prefix => format!("{}:{}", prefix, local),
+                    },
+                    id: None,
+                    class: None,
+                    namespaces: Vec::new(),
+                });
+            }
+            xmlparser::Token::Attribute {
+                prefix,
+                local,
+                value,
+                ..
+            } => {
+                if let Some(tag) = start_tag.as_mut() {
+                    match (prefix.as_str(), local.as_str()) {
+                        ("xmlns", prefix) => tag.namespaces.push((prefix, value.as_str())),
+                        ("", "xmlns") => tag.namespaces.push(("", value.as_str())),
+                        ("", "id") => tag.id = Some(value.as_str()),
+                        ("", "class") => tag.class = Some(value.as_str()),
+                        _ => (),
+                    }
+                }
+            }
+            xmlparser::Token::ElementEnd { end, span } => match end {
+                xmlparser::ElementEnd::Open => {
+                    if let Some(tag) = start_tag.take() {
+                        if matched.is_none() && tag.matches(selector) {
+                            matched = Some((tag.start, scopes.len() + 1));
+                        }
+                        scopes.push(tag.namespaces);
+                    }
+                }
+                xmlparser::ElementEnd::Empty => {
+                    if let Some(tag) = start_tag.take() {
+                        if matched.is_none() && tag.matches(selector) {
+                            let mut scopes = scopes.clone();
+                            scopes.push(tag.namespaces);
+                            elements.push(parse_fragment(&xml[tag.start..span.end()], &scopes)?);
+                        }
+                    }
+                }
+                xmlparser::ElementEnd::Close(..) => {
+                    if let Some((start, depth)) = matched {
+                        if depth == scopes.len() {
+                            elements.push(parse_fragment(&xml[start..span.end()], &scopes)?);
+                            matched = None;
+                        }
+                    }
+                    scopes.pop();
+                }
+            },
+            _ => (),
+        }
+    }
+
+    Ok(elements)
+}
+
+/// Parses the markup of a single element with the namespaces in scope for it, which are
+/// declared on the returned element
+fn parse_fragment(
+    fragment: &str,
+    scopes: &[Vec<(&str, &str)>],
+) -> Result<crate::Element, ParseError> {
+    let mut namespaces: Vec<(&str, &str)> = Vec::new();
+    for &(prefix, uri) in scopes.iter().flatten() {
+        namespaces.retain(|(other, _)| *other != prefix);
+        namespaces.push((prefix, uri));
+    }
+
+    let declarations: String = namespaces
+        .iter()
+        .map(|(prefix, uri)| match *prefix {
+            "" => format!(" xmlns=\"{}\"", uri.replace('"', "&quot;")),
+            prefix => format!(" xmlns:{}=\"{}\"", prefix, uri.replace('"', "&quot;")),
+        })
+        .collect();
+    let wrapped = format!("<fragment{}>{}</fragment>", declarations, fragment);
+    let doc = roxmltree::Document::parse(&wrapped).map_err(ParseError::RoxmltreeError)?;
+
+    let node = doc
+        .root_element()
+        .first_element_child()
+        .ok_or(ParseError::NoElement)?;
+    let mut element = node_to_element(node)?.ok_or(ParseError::NoElement)?;
+    for namespace in doc.root_element().namespaces() {
+        let prefix = namespace.name().unwrap_or("");
+        if namespaces.iter().any(|(declared, _)| *declared == prefix) {
+            element = element.set_namespace(prefix, namespace.uri());
+        }
+    }
+    Ok(element)
+}
+
+/// Returns the error of markup which cannot be tokenized, as described by roxmltree like for
+/// every other parse function
+fn markup_error(xml: &str) -> ParseError {
+    roxmltree::Document::parse(xml)
+        .map_or_else(ParseError::RoxmltreeError, |_| ParseError::NoElement)
+}
+
+/// Where an element and its parts are in the markup
+struct ElementSpan {
+    path: Vec<usize>,
+    name: String,
+    start: usize,
+    name_end: usize,
+    attributes: Vec<(String, std::ops::Range<usize>)>,
+    /// The `>` or `/>` ending the start tag
+    tag_end: std::ops::Range<usize>,
+    /// The start of the end tag, or None for an empty element
+    close_start: Option<usize>,
+    end: usize,
+}
+
+/// Returns the spans of all elements in the markup, in the order of their start tags
+fn element_spans(xml: &str) -> Result<Vec<ElementSpan>, ParseError> {
+    let mut spans: Vec<ElementSpan> = Vec::new();
+    let mut open: Vec<(usize, usize)> = Vec::new();
+
+    for token in xmlparser::Tokenizer::from(xml) {
+        match token.map_err(|_| markup_error(xml))? {
+            xmlparser::Token::ElementStart { local, span, .. } => {
+                let path = match open.last_mut() {
+                    Some((parent, children)) => {
+                        let mut path = spans[*parent].path.clone();
+                        path.push(*children);
+                        *children += 1;
+                        path
+                    }
+                    None => Vec::new(),
+                };
+                spans.push(ElementSpan {
+                    path,
+                    name: String::from(&xml[span.start() + 1..local.end()]),
+                    start: span.start(),
+                    name_end: span.end(),
+                    attributes: Vec::new(),
+                    tag_end: 0..0,
+                    close_start: None,
+                    end: 0,
+                });
+            }
+            xmlparser::Token::Attribute { local, span, .. } => {
+                if let Some(element) = spans.last_mut() {
+                    let name = String::from(&xml[span.start()..local.end()]);
+                    element.attributes.push((name, span.range()));
+                }
+            }
+            xmlparser::Token::ElementEnd { end, span } => match end {
+                xmlparser::ElementEnd::Open => {
+                    if let Some(element) = spans.last_mut() {
+                        element.tag_end = span.range();
+                        open.push((spans.len() - 1, 0));
+                    }
+                }
+                xmlparser::ElementEnd::Empty => {
+                    if let Some(element) = spans.last_mut() {
+                        element.tag_end = span.range();
+                        element.end = span.end();
+                    }
+                }
+                xmlparser::ElementEnd::Close(..) => {
+                    if let Some((index, _)) = open.pop() {
+                        spans[index].close_start = Some(span.start());
+                        spans[index].end = span.end();
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+
+    Ok(spans)
+}
+
+/// Applies changes to the markup itself, leaving all formatting outside of the changed parts as
+/// it was
+///
+/// # Note / Arguments
+/// The changes are applied in order, with their paths counting only elements from the root
+/// element like for a parsed [Element](../struct.Element.html). Set attributes keep their
+/// position or are added after the last attribute, and inserted children are serialized without
+/// any indentation. Removing an attribute which is not set does nothing.
+///
+/// Returns [ParseError::InvalidEdit](enum.ParseError.html#variant.InvalidEdit) with the index of
+/// the first change addressing an element or child which does not exist.
+///
+/// # Examples
+/// *The feature "parsing" needs to be enabled for this*
+/// ```
+/// use svg_definitions::changes::Change;
+/// use svg_definitions::parser::edit_source;
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::tree::ElementPath;
+///
+/// let source = "<svg>\n  <!-- the logo -->\n  <rect  x='1'   fill='red'/>\n</svg>";
+///
+/// let edited = edit_source(
+///     source,
+///     &[
+///         Change::SetAttribute {
+///             path: ElementPath::from(vec![0]),
+///             attribute: Attr::Fill,
+///             value: String::from("blue"),
+///         },
+///         Change::RemoveAttribute {
+///             path: ElementPath::from(vec![0]),
+///             attribute: Attr::X,
+///         },
+///     ],
+/// )
+/// .unwrap();
+///
+/// assert_eq!(edited, "<svg>\n  <!-- the logo -->\n  <rect   fill=\"blue\"/>\n</svg>");
+/// ```
+pub fn edit_source(xml: &str, edits: &[crate::changes::Change]) -> Result<String, ParseError> {
+    use crate::changes::Change;
+    use crate::escape::escape_attribute;
+
+    let mut source = String::from(xml);
+    for (index, edit) in edits.iter().enumerate() {
+        let spans = element_spans(&source)?;
+        let element = spans
+            .iter()
+            .find(|element| element.path == edit.path().indices())
+            .ok_or(ParseError::InvalidEdit(index))?;
+        let children: Vec<&ElementSpan> = spans
+            .iter()
+            .filter(|child| {
+                child.path.len() == element.path.len() + 1 && child.path.starts_with(&element.path)
+            })
+            .collect();
+        let attribute = |attribute: &crate::attributes::Attribute| {
+            element
+                .attributes
+                .iter()
+                .find(|(name, _)| name == attribute.name())
+                .map(|(_, range)| range.clone())
+        };
+
+        let (range, replacement) = match edit {
+            Change::SetAttribute {
+                attribute: name,
+                value,
+                ..
+            } => {
+                let text = format!("{}=\"{}\"", name, escape_attribute(value));
+                match attribute(name) {
+                    Some(range) => (range, text),
+                    None => {
+                        let end = element
+                            .attributes
+                            .last()
+                            .map_or(element.name_end, |(_, range)| range.end);
+                        (end..end, format!(" {}", text))
+                    }
+                }
+            }
+            Change::RemoveAttribute {
+                attribute: name, ..
+            } => match attribute(name) {
+                Some(range) => (
+                    source[..range.start].trim_end().len()..range.end,
+                    String::new(),
+                ),
+                None => continue,
+            },
+            Change::InsertChild {
+                index: child_index,
+                child,
+                ..
+            } => match (children.get(*child_index), element.close_start) {
+                (Some(next), _) => (next.start..next.start, child.to_string()),
+                (None, Some(close)) if *child_index == children.len() => {
+                    (close..close, child.to_string())
+                }
+                (None, None) if *child_index == 0 => (
+                    element.tag_end.clone(),
+                    format!(">{}</{}>", child, element.name),
+                ),
+                _ => return Err(ParseError::InvalidEdit(index)),
+            },
+            Change::RemoveChild {
+                index: child_index, ..
+            } => {
+                let child = children
+                    .get(*child_index)
+                    .ok_or(ParseError::InvalidEdit(index))?;
+                (child.start..child.end, String::new())
+            }
+        };
+        source.replace_range(range, &replacement);
+    }
+
+    Ok(source)
 }
 
 /// Parsing from a svg file
@@ -441,6 +873,313 @@ pub fn parse_text(xml: &str) -> Result<crate::Element, ParseError> {
 /// // ...
 /// ```
 pub fn parse_file(path: &str) -> Result<crate::Element, ParseError> {
-    let string = std::fs::read_to_string(path).map_err(|err| ParseError::FileError(err))?;
-    return parse_text(&string[..]);
+    let string = std::fs::read_to_string(path).map_err(ParseError::FileError)?;
+    parse_text(&string[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        edit_source, extract, parse_document, parse_text, parse_text_with_options, AttributeCase,
+        AttributePolicy, IdPolicy, ParseError, ParseOptions, ParseWarning, Selector,
+    };
+    use crate::attributes::Attribute;
+    use crate::changes::Change;
+    use crate::namespace;
+    use crate::tag_name::TagName;
+    use crate::tree::ElementPath;
+    use crate::Element;
+
+    #[test]
+    fn test_custom_tags() {
+        let svg = parse_text(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+              xmlns:sodipodi=\"http://sodipodi.sourceforge.net/DTD/sodipodi-0.dtd\">\
+               <sodipodi:namedview/><blink/><rect/>\
+             </svg>",
+        )
+        .unwrap();
+
+        let tags: Vec<&TagName> = svg
+            .get_children()
+            .iter()
+            .map(|child| child.get_tag_name())
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                &TagName::Custom(String::from("sodipodi:namedview")),
+                &TagName::Custom(String::from("blink")),
+                &TagName::Rect
+            ]
+        );
+        assert!(svg
+            .to_string()
+            .contains("<sodipodi:namedview/><blink/><rect/>"));
+    }
+
+    #[test]
+    fn test_namespaces() {
+        let text = "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+                    xmlns:xlink=\"http://www.w3.org/1999/xlink\">\
+                    <g xmlns:inkscape=\"http://www.inkscape.org/namespaces/inkscape\" \
+                    inkscape:label=\"Layer\" xml:space=\"preserve\">\
+                    <use xlink:href=\"#a\"/></g></svg>";
+        let svg = parse_text(text).unwrap();
+
+        assert_eq!(
+            svg.get_namespaces(),
+            vec![("", namespace::SVG), ("xlink", namespace::XLINK)]
+        );
+        let group = &svg.get_children()[0];
+        assert_eq!(
+            group.get_namespaces(),
+            vec![("inkscape", namespace::INKSCAPE)]
+        );
+        assert_eq!(
+            group.get_attributes()[&Attribute::namespaced("inkscape", "label")],
+            "Layer"
+        );
+        assert!(group.get_attributes().contains_key(&Attribute::XmlSpace));
+        assert!(group.get_children()[0]
+            .get_attributes()
+            .contains_key(&Attribute::XlinkHref));
+
+        assert_eq!(
+            parse_text(&svg.to_string()).unwrap().to_string(),
+            svg.to_string()
+        );
+    }
+
+    #[test]
+    fn test_processing_instructions() {
+        let text = "\u{feff}<?xml version=\"1.0\" standalone=\"no\"?>\n\
+                    <?xml-stylesheet type=\"text/css\" href=\"a.css\"?>\n\
+                    <!-- comment -->\n\
+                    <svg><?inside?><rect/></svg>\n\
+                    <?after?>";
+        assert_eq!(parse_text(text).unwrap().to_string(), "<svg><rect/></svg>");
+
+        let document = parse_document(text).unwrap();
+        assert_eq!(
+            document.to_string(),
+            "<?xml version=\"1.0\" standalone=\"no\"?>\n\
+             <?xml-stylesheet type=\"text/css\" href=\"a.css\"?>\n\
+             <svg><rect/></svg>"
+        );
+        assert!(parse_document("<svg/>")
+            .unwrap()
+            .get_processing_instructions()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_policies() {
+        let text = "<svg><rect x=\"1\" x=\"2\" x=\"3\"/><g id=\"a\"/><g id=\"a-2\"/>\
+                    <g id=\"a\"/><g id=\"a\"/></svg>";
+        let parse = |duplicate_attributes, duplicate_ids| {
+            parse_text_with_options(
+                text,
+                &ParseOptions {
+                    duplicate_attributes,
+                    duplicate_ids,
+                    ..ParseOptions::default()
+                },
+            )
+        };
+        let ids = |svg: &crate::Element| -> Vec<Option<String>> {
+            svg.get_children()[1..]
+                .iter()
+                .map(|child| {
+                    child
+                        .get_attributes()
+                        .get(&Attribute::Id)
+                        .map(|id| id.to_string())
+                })
+                .collect()
+        };
+
+        assert!(parse_text(text).is_err());
+        assert!(parse(AttributePolicy::Error, IdPolicy::Allow).is_err());
+
+        let (svg, warnings) = parse(AttributePolicy::FirstWins, IdPolicy::Allow).unwrap();
+        assert_eq!(svg.get_children()[0].get_attributes()[&Attribute::X], "1");
+        assert_eq!(
+            warnings[..2],
+            [
+                ParseWarning::DuplicateAttribute {
+                    attribute: String::from("x"),
+                    offset: 17
+                },
+                ParseWarning::DuplicateAttribute {
+                    attribute: String::from("x"),
+                    offset: 23
+                },
+            ]
+        );
+        assert_eq!(warnings.len(), 4);
+
+        let (svg, _) = parse(AttributePolicy::LastWins, IdPolicy::FirstWins).unwrap();
+        assert_eq!(svg.get_children()[0].get_attributes()[&Attribute::X], "3");
+        let some = |id: &str| Some(String::from(id));
+        assert_eq!(ids(&svg), [some("a"), some("a-2"), None, None]);
+
+        let (svg, _) = parse(AttributePolicy::LastWins, IdPolicy::LastWins).unwrap();
+        assert_eq!(ids(&svg), [None, some("a-2"), None, some("a")]);
+
+        let (svg, warnings) = parse(AttributePolicy::LastWins, IdPolicy::Rename).unwrap();
+        assert_eq!(
+            ids(&svg),
+            [some("a"), some("a-2"), some("a-3"), some("a-4")]
+        );
+        assert_eq!(
+            warnings.last(),
+            Some(&ParseWarning::DuplicateId {
+                id: String::from("a"),
+                path: vec![4].into(),
+                renamed: some("a-4"),
+            })
+        );
+
+        match parse(AttributePolicy::LastWins, IdPolicy::Error) {
+            Err(ParseError::DuplicateId(id)) => assert_eq!(id, "a"),
+            _ => panic!("expected a duplicate id error"),
+        }
+    }
+
+    #[test]
+    fn test_attribute_case() {
+        let text = "<svg viewbox=\"0 0 1 1\"><g VIEWBOX=\"a\" viewBox=\"b\"/>\
+                    <rect cLip-path=\"url(#a)\" data-Id=\"1\"/></svg>";
+        let parse = |attribute_case| {
+            parse_text_with_options(
+                text,
+                &ParseOptions {
+                    attribute_case,
+                    ..ParseOptions::default()
+                },
+            )
+            .unwrap()
+        };
+
+        let (svg, warnings) = parse(AttributeCase::Exact);
+        assert_eq!(svg.to_string(), parse_text(text).unwrap().to_string());
+        assert!(warnings.is_empty());
+
+        let (svg, warnings) = parse(AttributeCase::Canonicalize);
+        assert_eq!(svg.get_attributes()[&Attribute::ViewBox], "0 0 1 1");
+        assert_eq!(svg.get_children()[0].get_attributes().len(), 1);
+        assert_eq!(
+            svg.get_children()[0].get_attributes()[&Attribute::ViewBox],
+            "b"
+        );
+        assert_eq!(
+            svg.get_children()[1].to_string(),
+            "<rect clip-path=\"url(#a)\" data-Id=\"1\"/>"
+        );
+        assert_eq!(
+            warnings[1],
+            ParseWarning::AttributeCase {
+                attribute: String::from("VIEWBOX"),
+                canonical: Attribute::ViewBox,
+                path: vec![0].into(),
+            }
+        );
+        assert_eq!(warnings.len(), 3);
+
+        let (preserved, warnings) = parse(AttributeCase::Preserve);
+        assert_eq!(preserved.to_string(), parse_text(text).unwrap().to_string());
+        assert_eq!(warnings.len(), 3);
+    }
+
+    #[test]
+    fn test_extract() {
+        let text = "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+                    xmlns:xlink=\"http://www.w3.org/1999/xlink\">\
+                    <g class=\"icon big\"><use xlink:href=\"#a\"/><g class=\"icon\"/></g>\
+                    <rect class=\"icon\"/><rect/></svg>";
+
+        let icons = extract(text, &Selector::Class(String::from("icon"))).unwrap();
+        assert_eq!(icons.len(), 2);
+        assert_eq!(icons[0].get_children().len(), 2);
+        assert_eq!(
+            icons[0].get_namespaces(),
+            vec![("", namespace::SVG), ("xlink", namespace::XLINK)]
+        );
+        assert!(parse_text(&icons[0].to_string()).is_ok());
+        assert_eq!(icons[1].get_tag_name(), &TagName::Rect);
+
+        let rects = extract(text, &Selector::Tag(TagName::Rect)).unwrap();
+        assert_eq!(rects.len(), 2);
+        assert!(extract(text, &Selector::Id(String::from("missing")))
+            .unwrap()
+            .is_empty());
+        assert!(extract("<svg><g></svg>", &Selector::Tag(TagName::G)).is_err());
+    }
+
+    #[test]
+    fn test_edit_source() {
+        let source =
+            "<?xml version=\"1.0\"?>\n<svg xmlns:xlink=\"http://www.w3.org/1999/xlink\">\n\
+                      \t<g>\n\t\t<use xlink:href=\"#a\" />\n\t</g>\n\t<circle/>\n</svg>\n";
+        let path = |indices: Vec<usize>| ElementPath::from(indices);
+
+        let edited = edit_source(
+            source,
+            &[
+                Change::SetAttribute {
+                    path: path(vec![0, 0]),
+                    attribute: Attribute::XlinkHref,
+                    value: String::from("#\"b\""),
+                },
+                Change::SetAttribute {
+                    path: path(vec![0, 0]),
+                    attribute: Attribute::X,
+                    value: String::from("1"),
+                },
+                Change::InsertChild {
+                    path: path(vec![1]),
+                    index: 0,
+                    child: Element::new(TagName::Title).set_inner("Dot"),
+                },
+                Change::InsertChild {
+                    path: path(vec![0]),
+                    index: 0,
+                    child: Element::new(TagName::Rect),
+                },
+                Change::RemoveChild {
+                    path: path(vec![]),
+                    index: 0,
+                },
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            edited,
+            "<?xml version=\"1.0\"?>\n<svg xmlns:xlink=\"http://www.w3.org/1999/xlink\">\n\
+             \t\n\t<circle><title>Dot</title></circle>\n</svg>\n"
+        );
+
+        let edited = edit_source(
+            source,
+            &[Change::SetAttribute {
+                path: path(vec![0, 0]),
+                attribute: Attribute::XlinkHref,
+                value: String::from("#\"b\""),
+            }],
+        )
+        .unwrap();
+        assert!(edited.contains("<use xlink:href=\"#&quot;b&quot;\" />"));
+
+        match edit_source(
+            source,
+            &[Change::RemoveChild {
+                path: path(vec![1]),
+                index: 0,
+            }],
+        ) {
+            Err(ParseError::InvalidEdit(0)) => (),
+            _ => panic!("expected an invalid edit"),
+        }
+    }
 }
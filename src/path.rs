@@ -29,53 +29,1617 @@
 //!     .close_path();
 //! ```
 
-use std::clone::Clone;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
 
-use crate::Point2D;
+use crate::context::Context;
+use crate::geometry::{arc_endpoint_to_center, BoundingBox};
+use crate::syntax::{Reader, SyntaxError};
+use crate::transform::Transform;
+use crate::IntoPoint;
 
-#[derive(Debug)]
+/// The direction in which an arc is drawn around its center
+///
+/// Angles are measured from the positive x-axis towards the positive y-axis. Since the y-axis
+/// of SVG points downwards, an increasing angle appears clockwise on screen.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum ArcDirection {
+    /// Draws the arc with increasing angles (sweep flag 1)
+    Clockwise,
+
+    /// Draws the arc with decreasing angles (sweep flag 0)
+    CounterClockwise,
+}
+
+/// Returns the point on the ellipse around `center` with `radii` at `angle` degrees
+fn point_on_ellipse((cx, cy): (f64, f64), (rx, ry): (f64, f64), angle: f64) -> (f64, f64) {
+    // Snap rounding noise like cos(90deg) = 6e-17, which would otherwise be printed as -0.00
+    let snap = |value: f64| if value.abs() < 1e-12 { 0.0 } else { value };
+
+    let angle = angle.to_radians();
+    (cx + rx * snap(angle.cos()), cy + ry * snap(angle.sin()))
+}
+
+/// An error in the commands of a [PathDefinitionString], containing the index of the command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathError {
+    /// A command which is not a move is found before the first move
+    MissingMoveTo(usize),
+
+    /// A coordinate or other number is NaN or infinite
+    NonFiniteNumber(usize),
+
+    /// An arc has a negative radius
+    NegativeRadius(usize),
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::MissingMoveTo(index) => {
+                write!(f, "command {} is drawn before the first move", index)
+            }
+            PathError::NonFiniteNumber(index) => {
+                write!(f, "command {} contains a NaN or infinite number", index)
+            }
+            PathError::NegativeRadius(index) => {
+                write!(f, "command {} is an arc with a negative radius", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// The error returned when parsing invalid path data, containing the byte offset of the error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidPathData(pub usize);
+
+impl fmt::Display for InvalidPathData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid path data at byte {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidPathData {}
+
+impl From<SyntaxError> for InvalidPathData {
+    #[inline]
+    fn from(error: SyntaxError) -> InvalidPathData {
+        InvalidPathData(error.0)
+    }
+}
+
+/// A single command of a [PathDefinitionString]
+///
+/// Relative commands have coordinates relative to where the last command ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathCommand {
+    /// `M` / `m`
+    MoveTo { relative: bool, to: (f64, f64) },
+
+    /// `L` / `l`
+    LineTo { relative: bool, to: (f64, f64) },
+
+    /// `H` / `h`
+    HorizontalLineTo { relative: bool, x: f64 },
+
+    /// `V` / `v`
+    VerticalLineTo { relative: bool, y: f64 },
+
+    /// `C` / `c`
+    CurveTo {
+        relative: bool,
+        control_1: (f64, f64),
+        control_2: (f64, f64),
+        to: (f64, f64),
+    },
+
+    /// `S` / `s`
+    SmoothCurveTo {
+        relative: bool,
+        control_2: (f64, f64),
+        to: (f64, f64),
+    },
+
+    /// `Q` / `q`
+    QuadCurveTo {
+        relative: bool,
+        control: (f64, f64),
+        to: (f64, f64),
+    },
+
+    /// `T` / `t`
+    QuadStringTo { relative: bool, to: (f64, f64) },
+
+    /// `A` / `a`
+    ArcTo {
+        relative: bool,
+        radii: (f64, f64),
+        x_axis_rotation: f64,
+        large_arc_flag: bool,
+        sweep_flag: bool,
+        to: (f64, f64),
+    },
+
+    /// `Z`
+    ClosePath,
+}
+
+impl PathCommand {
+    /// Returns whether the coordinates of this command are relative
+    pub fn is_relative(&self) -> bool {
+        use PathCommand::*;
+
+        match *self {
+            MoveTo { relative, .. }
+            | LineTo { relative, .. }
+            | HorizontalLineTo { relative, .. }
+            | VerticalLineTo { relative, .. }
+            | CurveTo { relative, .. }
+            | SmoothCurveTo { relative, .. }
+            | QuadCurveTo { relative, .. }
+            | QuadStringTo { relative, .. }
+            | ArcTo { relative, .. } => relative,
+            ClosePath => false,
+        }
+    }
+
+    /// Returns the letter of this command, lowercase for relative commands
+    pub fn letter(&self) -> char {
+        use PathCommand::*;
+
+        let letter = match self {
+            MoveTo { .. } => 'M',
+            LineTo { .. } => 'L',
+            HorizontalLineTo { .. } => 'H',
+            VerticalLineTo { .. } => 'V',
+            CurveTo { .. } => 'C',
+            SmoothCurveTo { .. } => 'S',
+            QuadCurveTo { .. } => 'Q',
+            QuadStringTo { .. } => 'T',
+            ArcTo { .. } => 'A',
+            ClosePath => 'Z',
+        };
+
+        if self.is_relative() {
+            letter.to_ascii_lowercase()
+        } else {
+            letter
+        }
+    }
+
+    /// Returns all numbers of this command, apart from the arc flags
+    fn numbers(&self) -> Vec<f64> {
+        use PathCommand::*;
+
+        match *self {
+            MoveTo { to, .. } | LineTo { to, .. } | QuadStringTo { to, .. } => vec![to.0, to.1],
+            HorizontalLineTo { x, .. } => vec![x],
+            VerticalLineTo { y, .. } => vec![y],
+            CurveTo {
+                control_1,
+                control_2,
+                to,
+                ..
+            } => vec![
+                control_1.0,
+                control_1.1,
+                control_2.0,
+                control_2.1,
+                to.0,
+                to.1,
+            ],
+            SmoothCurveTo { control_2, to, .. } => vec![control_2.0, control_2.1, to.0, to.1],
+            QuadCurveTo { control, to, .. } => vec![control.0, control.1, to.0, to.1],
+            ArcTo {
+                radii,
+                x_axis_rotation,
+                to,
+                ..
+            } => vec![radii.0, radii.1, x_axis_rotation, to.0, to.1],
+            ClosePath => Vec::new(),
+        }
+    }
+
+    /// Returns the point where this absolute command ends, given the current point and the start
+    /// of the current subpath
+    pub(crate) fn end_point(&self, current: (f64, f64), subpath_start: (f64, f64)) -> (f64, f64) {
+        use PathCommand::*;
+
+        match *self {
+            MoveTo { to, .. }
+            | LineTo { to, .. }
+            | CurveTo { to, .. }
+            | SmoothCurveTo { to, .. }
+            | QuadCurveTo { to, .. }
+            | QuadStringTo { to, .. }
+            | ArcTo { to, .. } => to,
+            HorizontalLineTo { x, .. } => (x, current.1),
+            VerticalLineTo { y, .. } => (current.0, y),
+            ClosePath => subpath_start,
+        }
+    }
+
+    /// Returns this command with all coordinates made absolute, given the current point
+    pub(crate) fn to_absolute(self, (cx, cy): (f64, f64)) -> PathCommand {
+        use PathCommand::*;
+
+        if !self.is_relative() {
+            return self;
+        }
+
+        let add = |(x, y): (f64, f64)| (x + cx, y + cy);
+        match self {
+            MoveTo { to, .. } => MoveTo {
+                relative: false,
+                to: add(to),
+            },
+            LineTo { to, .. } => LineTo {
+                relative: false,
+                to: add(to),
+            },
+            HorizontalLineTo { x, .. } => HorizontalLineTo {
+                relative: false,
+                x: x + cx,
+            },
+            VerticalLineTo { y, .. } => VerticalLineTo {
+                relative: false,
+                y: y + cy,
+            },
+            CurveTo {
+                control_1,
+                control_2,
+                to,
+                ..
+            } => CurveTo {
+                relative: false,
+                control_1: add(control_1),
+                control_2: add(control_2),
+                to: add(to),
+            },
+            SmoothCurveTo { control_2, to, .. } => SmoothCurveTo {
+                relative: false,
+                control_2: add(control_2),
+                to: add(to),
+            },
+            QuadCurveTo { control, to, .. } => QuadCurveTo {
+                relative: false,
+                control: add(control),
+                to: add(to),
+            },
+            QuadStringTo { to, .. } => QuadStringTo {
+                relative: false,
+                to: add(to),
+            },
+            ArcTo {
+                radii,
+                x_axis_rotation,
+                large_arc_flag,
+                sweep_flag,
+                to,
+                ..
+            } => ArcTo {
+                relative: false,
+                radii,
+                x_axis_rotation,
+                large_arc_flag,
+                sweep_flag,
+                to: add(to),
+            },
+            ClosePath => ClosePath,
+        }
+    }
+
+    /// Writes the command with its arguments, formatting numbers with `precision`
+    fn write(&self, output: &mut String, precision: Option<usize>) {
+        use PathCommand::*;
+
+        let number = |value: f64| format_number(value, precision);
+        let pair = |(x, y): (f64, f64)| format!("{} {}", number(x), number(y));
+        let flag = |value: bool| if value { '1' } else { '0' };
+
+        output.push(self.letter());
+        let arguments = match *self {
+            MoveTo { to, .. } | LineTo { to, .. } | QuadStringTo { to, .. } => pair(to),
+            HorizontalLineTo { x, .. } => number(x),
+            VerticalLineTo { y, .. } => number(y),
+            CurveTo {
+                control_1,
+                control_2,
+                to,
+                ..
+            } => format!("{}, {}, {}", pair(control_1), pair(control_2), pair(to)),
+            SmoothCurveTo { control_2, to, .. } => format!("{}, {}", pair(control_2), pair(to)),
+            QuadCurveTo { control, to, .. } => format!("{}, {}", pair(control), pair(to)),
+            ArcTo {
+                radii,
+                x_axis_rotation,
+                large_arc_flag,
+                sweep_flag,
+                to,
+                ..
+            } => format!(
+                "{} {} {} {} {}",
+                pair(radii),
+                number(x_axis_rotation),
+                flag(large_arc_flag),
+                flag(sweep_flag),
+                pair(to)
+            ),
+            ClosePath => return,
+        };
+        output.push(' ');
+        output.push_str(&arguments);
+    }
+}
+
+/// Formats a number with exactly 2 decimals, or with at most `precision` decimals without
+/// trailing zeros
+fn format_number(value: f64, precision: Option<usize>) -> String {
+    let mut string = format!("{:.*}", precision.unwrap_or(2), value);
+    if precision.is_some() && string.contains('.') {
+        let trimmed = string.trim_end_matches('0').trim_end_matches('.').len();
+        string.truncate(trimmed);
+    }
+
+    // Values like -0.001 would otherwise be printed as -0.00
+    if string.starts_with('-') && string.chars().all(|c| c == '-' || c == '0' || c == '.') {
+        string.remove(0);
+    }
+    string
+}
+
+/// Returns the absolute commands approximating an absolute arc command with cubic bezier curves
+fn arc_to_cubics(from: (f64, f64), arc: PathCommand) -> Vec<PathCommand> {
+    let (radii, x_axis_rotation, large_arc_flag, sweep_flag, to) = match arc {
+        PathCommand::ArcTo {
+            radii,
+            x_axis_rotation,
+            large_arc_flag,
+            sweep_flag,
+            to,
+            ..
+        } => (radii, x_axis_rotation, large_arc_flag, sweep_flag, to),
+        command => return vec![command],
+    };
+
+    let arc = match arc_endpoint_to_center(
+        from,
+        radii,
+        x_axis_rotation,
+        large_arc_flag,
+        sweep_flag,
+        to,
+    ) {
+        Some(arc) => arc,
+        None if from == to => return Vec::new(),
+        None => {
+            return vec![PathCommand::LineTo {
+                relative: false,
+                to,
+            }]
+        }
+    };
+
+    // Segments of at most 90 degrees keep the approximation error small
+    let segments = (arc.sweep_angle.abs() / std::f64::consts::FRAC_PI_2)
+        .ceil()
+        .max(1.0) as usize;
+    let delta = arc.sweep_angle / segments as f64;
+    let k = 4.0 / 3.0 * (delta / 4.0).tan();
+
+    (0..segments)
+        .map(|segment| {
+            let start = arc.start_angle + delta * segment as f64;
+            let end = start + delta;
+
+            let (x1, y1) = arc.point(start);
+            let (dx1, dy1) = arc.derivative(start);
+            let (x2, y2) = arc.point(end);
+            let (dx2, dy2) = arc.derivative(end);
+
+            PathCommand::CurveTo {
+                relative: false,
+                control_1: (x1 + k * dx1, y1 + k * dy1),
+                control_2: (x2 - k * dx2, y2 - k * dy2),
+                to: if segment + 1 == segments {
+                    to
+                } else {
+                    (x2, y2)
+                },
+            }
+        })
+        .collect()
+}
+
+/// Returns the distance of a point to the line segment between `start` and `end`
+fn distance_to_segment(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length_squared = dx * dx + dy * dy;
+
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((point.0 - start.0) * dx + (point.1 - start.1) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+
+    (point.0 - start.0 - t * dx).hypot(point.1 - start.1 - t * dy)
+}
+
+/// Marks the points of a polyline to keep with the Douglas-Peucker algorithm, where the first and
+/// last points are always kept
+fn douglas_peucker(points: &[(f64, f64)], epsilon: f64, keep: &mut [bool]) {
+    let last = points.len() - 1;
+    keep[0] = true;
+    keep[last] = true;
+
+    let farthest = (1..last)
+        .map(|index| {
+            let distance = distance_to_segment(points[index], points[0], points[last]);
+            (index, distance)
+        })
+        .fold(
+            None,
+            |farthest: Option<(usize, f64)>, (index, distance)| match farthest {
+                Some((_, max)) if max >= distance => farthest,
+                _ => Some((index, distance)),
+            },
+        );
+
+    if let Some((index, distance)) = farthest {
+        if distance > epsilon {
+            douglas_peucker(&points[..=index], epsilon, &mut keep[..=index]);
+            douglas_peucker(&points[index..], epsilon, &mut keep[index..]);
+        }
+    }
+}
+
+/// Returns the radii and x-axis rotation of an ellipse after applying a transform to it
+fn transform_ellipse(
+    transform: &Transform,
+    (rx, ry): (f64, f64),
+    x_axis_rotation: f64,
+) -> ((f64, f64), f64) {
+    let (rx, ry) = (rx.abs(), ry.abs());
+    if rx == 0.0 || ry == 0.0 {
+        // Arcs with a zero radius are drawn as straight lines
+        return ((rx, ry), x_axis_rotation);
+    }
+
+    // The transformed ellipse is the image of the unit circle under N = M * R * diag(rx, ry).
+    // Its radii are the singular values of N, which are the roots of the eigenvalues of N * N^T.
+    let (sin, cos) = x_axis_rotation.to_radians().sin_cos();
+    let (ux, uy) = transform.apply_vector((rx * cos, rx * sin));
+    let (vx, vy) = transform.apply_vector((-ry * sin, ry * cos));
+
+    let p = ux * ux + vx * vx;
+    let q = ux * uy + vx * vy;
+    let r = uy * uy + vy * vy;
+
+    let mean = (p + r) / 2.0;
+    let deviation = ((p - r) / 2.0).hypot(q);
+    let radii = (
+        (mean + deviation).max(0.0).sqrt(),
+        (mean - deviation).max(0.0).sqrt(),
+    );
+
+    // Adding 0.0 turns a negative zero into a positive zero
+    let angle = (2.0 * q).atan2(p - r).to_degrees() / 2.0 + 0.0;
+    (radii, angle)
+}
+
+/// A segment of a path with absolute coordinates
+#[derive(Debug, Clone, Copy)]
+enum Segment {
+    Line((f64, f64), (f64, f64)),
+    Cubic((f64, f64), (f64, f64), (f64, f64), (f64, f64)),
+}
+
+impl Segment {
+    /// Returns the control points of the segment as a cubic curve, where lines have their control
+    /// points at a third and two thirds
+    fn to_cubic(self) -> [(f64, f64); 4] {
+        match self {
+            Segment::Line(from, to) => {
+                let at = |t: f64| (from.0 + t * (to.0 - from.0), from.1 + t * (to.1 - from.1));
+                [from, at(1.0 / 3.0), at(2.0 / 3.0), to]
+            }
+            Segment::Cubic(p0, p1, p2, p3) => [p0, p1, p2, p3],
+        }
+    }
+
+    /// Returns the end points of the segment and the points of a curve which lie furthest to a
+    /// side, which together span the bounding box of the segment
+    fn extremes(self) -> Vec<(f64, f64)> {
+        let points = self.to_cubic();
+        let [p0, p1, p2, p3] = points;
+        let mut extremes = vec![p0, p3];
+        if let Segment::Line(..) = self {
+            return extremes;
+        }
+
+        // The roots of the derivative of each coordinate, which is a quadratic polynomial
+        let axes = [(p0.0, p1.0, p2.0, p3.0), (p0.1, p1.1, p2.1, p3.1)];
+        for (c0, c1, c2, c3) in axes.iter() {
+            let (a, b, c) = (c1 - c0, c2 - c1, c3 - c2);
+            let (quadratic, linear, constant) = (a - 2.0 * b + c, 2.0 * (b - a), a);
+
+            let roots = if quadratic.abs() < 1e-12 {
+                if linear == 0.0 {
+                    vec![]
+                } else {
+                    vec![-constant / linear]
+                }
+            } else {
+                let discriminant = linear * linear - 4.0 * quadratic * constant;
+                if discriminant < 0.0 {
+                    vec![]
+                } else {
+                    let root = discriminant.sqrt();
+                    vec![
+                        (-linear + root) / (2.0 * quadratic),
+                        (-linear - root) / (2.0 * quadratic),
+                    ]
+                }
+            };
+            extremes.extend(
+                roots
+                    .into_iter()
+                    .filter(|t| *t > 0.0 && *t < 1.0)
+                    .map(|t| cubic_until(points, t)[3]),
+            );
+        }
+        extremes
+    }
+
+    /// Returns the length of the segment
+    fn length(self) -> f64 {
+        match self {
+            Segment::Line(from, to) => distance(from, to),
+            Segment::Cubic(p0, p1, p2, p3) => cubic_length(p0, p1, p2, p3, 16),
+        }
+    }
+
+    /// Returns the point at a distance along the segment and the direction of the segment there
+    fn point_at(self, length: f64) -> ((f64, f64), (f64, f64)) {
+        let points = match self {
+            Segment::Line(from, to) => {
+                let t = if from == to {
+                    0.0
+                } else {
+                    (length / distance(from, to)).clamp(0.0, 1.0)
+                };
+                let point = (from.0 + t * (to.0 - from.0), from.1 + t * (to.1 - from.1));
+                return (point, (to.0 - from.0, to.1 - from.1));
+            }
+            Segment::Cubic(p0, p1, p2, p3) => [p0, p1, p2, p3],
+        };
+
+        // Bisect the parameter of the curve until the length up to it is close enough
+        let (mut low, mut high) = if length <= 0.0 {
+            (0.0, 0.0)
+        } else if length >= self.length() {
+            (1.0, 1.0)
+        } else {
+            (0.0, 1.0)
+        };
+        while high - low > 1e-12 {
+            let t = (low + high) / 2.0;
+            let [p0, p1, p2, p3] = cubic_until(points, t);
+            if cubic_length(p0, p1, p2, p3, 16) < length {
+                low = t;
+            } else {
+                high = t;
+            }
+        }
+        let t = (low + high) / 2.0;
+        let point = cubic_until(points, t)[3];
+
+        let [p0, p1, p2, p3] = points;
+        let weights = [(1.0 - t) * (1.0 - t), 2.0 * (1.0 - t) * t, t * t];
+        let differences = [(p0, p1), (p1, p2), (p2, p3)];
+        let derivative = weights
+            .iter()
+            .zip(differences.iter())
+            .fold((0.0, 0.0), |(x, y), (weight, (a, b))| {
+                (x + weight * (b.0 - a.0), y + weight * (b.1 - a.1))
+            });
+
+        // The derivative vanishes at cusps and at ends with coinciding control points
+        let direction = if derivative != (0.0, 0.0) {
+            derivative
+        } else {
+            (p3.0 - p0.0, p3.1 - p0.1)
+        };
+        (point, direction)
+    }
+}
+
+/// A drawn subpath, starting at a move or after closing the previous subpath
+#[derive(Debug, Clone)]
+struct Subpath {
+    start: (f64, f64),
+    segments: Vec<Segment>,
+    closed: bool,
+}
+
+/// Returns the distance between two points
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+/// Returns the length of a cubic bezier curve, subdividing it until its control polygon is
+/// almost as long as its chord
+fn cubic_length(
+    p0: (f64, f64),
+    p1: (f64, f64),
+    p2: (f64, f64),
+    p3: (f64, f64),
+    depth: usize,
+) -> f64 {
+    let chord = distance(p0, p3);
+    let polygon = distance(p0, p1) + distance(p1, p2) + distance(p2, p3);
+    if depth == 0 || polygon - chord <= 1e-9 * (1.0 + polygon) {
+        return (2.0 * chord + polygon) / 3.0;
+    }
+
+    let middle = |a: (f64, f64), b: (f64, f64)| ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+    let (p01, p12, p23) = (middle(p0, p1), middle(p1, p2), middle(p2, p3));
+    let (p012, p123) = (middle(p01, p12), middle(p12, p23));
+    let split = middle(p012, p123);
+    cubic_length(p0, p01, p012, split, depth - 1) + cubic_length(split, p123, p23, p3, depth - 1)
+}
+
+/// Returns the part of a cubic bezier curve up to a parameter, with the algorithm of De Casteljau
+fn cubic_until([p0, p1, p2, p3]: [(f64, f64); 4], t: f64) -> [(f64, f64); 4] {
+    let lerp = |a: (f64, f64), b: (f64, f64)| (a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1));
+    let (p01, p12, p23) = (lerp(p0, p1), lerp(p1, p2), lerp(p2, p3));
+    let (p012, p123) = (lerp(p01, p12), lerp(p12, p23));
+    [p0, p01, p012, lerp(p012, p123)]
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PathDefinitionString {
-    inner_string: String,
+    commands: Vec<PathCommand>,
+    precision: Option<usize>,
 }
 
-impl PathDefinitionString {
-    /// Creates a new empty instance of a PathDefinitionString
+impl PathDefinitionString {
+    /// Creates a new empty instance of a PathDefinitionString
+    ///
+    /// # Note
+    /// Eventhough, one can input f64's the actual output string will by default always output number with 2 decimals points.
+    /// Use [with_precision](#method.with_precision) to change this.
+    #[inline]
+    pub fn new() -> PathDefinitionString {
+        PathDefinitionString {
+            commands: Vec::new(),
+            precision: None,
+        }
+    }
+
+    /// Creates a new empty instance of a PathDefinitionString, which outputs whole numbers
+    ///
+    /// # Note / Arguments
+    /// This is meant for pixel art and icons on a grid, together with
+    /// [IntPoint2D](../type.IntPoint2D.html) coordinates. Numbers which are not whole are rounded,
+    /// like with a [precision](#method.with_precision) of 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let corner: IntPoint2D = (16, 16);
+    /// let path_definition_string = PathData::integer()
+    ///     .move_to((0, 0))
+    ///     .horizontal_line_to(corner.0)
+    ///     .line_to(corner)
+    ///     .close_path();
+    ///
+    /// // Will output "M 0 0 H 16 L 16 16 Z"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 0 0 H 16 L 16 16 Z"));
+    /// ```
+    #[inline]
+    pub fn integer() -> PathDefinitionString {
+        PathDefinitionString::new().with_precision(0)
+    }
+
+    /// Creates a rectangle with rounded corners, starting at `origin` (the top-left corner)
+    ///
+    /// # Note / Arguments
+    /// The radius is clamped to half of the smallest side. A radius of zero creates a plain rectangle.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let path_definition_string = PathData::rounded_rect((0.0, 0.0), (10.0, 10.0), 2.0);
+    ///
+    /// // Will output "M 2.00 0.00 H 8.00 A 2.00 2.00 0.00 0 1 10.00 2.00 V 8.00 A 2.00 2.00 0.00 0 1 8.00 10.00 H 2.00 A 2.00 2.00 0.00 0 1 0.00 8.00 V 2.00 A 2.00 2.00 0.00 0 1 2.00 0.00 Z"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 2.00 0.00 H 8.00 A 2.00 2.00 0.00 0 1 10.00 2.00 V 8.00 A 2.00 2.00 0.00 0 1 8.00 10.00 H 2.00 A 2.00 2.00 0.00 0 1 0.00 8.00 V 2.00 A 2.00 2.00 0.00 0 1 2.00 0.00 Z"));
+    /// ```
+    pub fn rounded_rect<P: IntoPoint>(origin: P, (width, height): (f64, f64), radius: f64) -> Self {
+        let (x, y) = origin.into_point();
+        let r = radius
+            .max(0.0)
+            .min(width.abs() / 2.0)
+            .min(height.abs() / 2.0);
+
+        if r == 0.0 {
+            return PathDefinitionString::new()
+                .move_to((x, y))
+                .horizontal_line_to(x + width)
+                .vertical_line_to(y + height)
+                .horizontal_line_to(x)
+                .close_path();
+        }
+
+        let (right, bottom) = (x + width, y + height);
+        PathDefinitionString::new()
+            .move_to((x + r, y))
+            .horizontal_line_to(right - r)
+            .arc_to((right, y + r), (r, r), 0.0, false, true)
+            .vertical_line_to(bottom - r)
+            .arc_to((right - r, bottom), (r, r), 0.0, false, true)
+            .horizontal_line_to(x + r)
+            .arc_to((x, bottom - r), (r, r), 0.0, false, true)
+            .vertical_line_to(y + r)
+            .arc_to((x + r, y), (r, r), 0.0, false, true)
+            .close_path()
+    }
+
+    /// Creates a pie slice around `center` with radius `r` from `start` to `end` degrees
+    ///
+    /// # Note / Arguments
+    /// The slice is drawn clockwise from `start` to `end`, see [ArcDirection]. A slice spanning
+    /// 360 degrees or more is a full circle.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let path_definition_string = PathData::pie_slice((50.0, 50.0), 10.0, 0.0, 90.0);
+    ///
+    /// // Will output "M 50.00 50.00 L 60.00 50.00 A 10.00 10.00 0.00 0 1 50.00 60.00 Z"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 50.00 50.00 L 60.00 50.00 A 10.00 10.00 0.00 0 1 50.00 60.00 Z"));
+    /// ```
+    pub fn pie_slice<P: IntoPoint>(center: P, r: f64, start: f64, end: f64) -> Self {
+        use ArcDirection::Clockwise;
+
+        let center = center.into_point();
+        if (end - start).abs() >= 360.0 {
+            return PathDefinitionString::new()
+                .arc_around(center, (r, r), start, end, Clockwise)
+                .close_path();
+        }
+
+        PathDefinitionString::new()
+            .move_to(center)
+            .line_to(point_on_ellipse(center, (r, r), start))
+            .push_arc_around(center, (r, r), start, end, Clockwise)
+            .close_path()
+    }
+
+    /// Creates a segment of a donut (a ring) around `center` between the radii `r_inner` and `r_outer`
+    /// from `start` to `end` degrees
+    ///
+    /// # Note / Arguments
+    /// The outer arc is drawn clockwise from `start` to `end`, see [ArcDirection]. A segment
+    /// spanning 360 degrees or more is a full ring, which consists of an outer and an inner subpath.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let path_definition_string = PathData::donut_segment((50.0, 50.0), 5.0, 10.0, 0.0, 90.0);
+    ///
+    /// // Will output "M 60.00 50.00 A 10.00 10.00 0.00 0 1 50.00 60.00 L 50.00 55.00 A 5.00 5.00 0.00 0 0 55.00 50.00 Z"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 60.00 50.00 A 10.00 10.00 0.00 0 1 50.00 60.00 L 50.00 55.00 A 5.00 5.00 0.00 0 0 55.00 50.00 Z"));
+    /// ```
+    pub fn donut_segment<P: IntoPoint>(
+        center: P,
+        r_inner: f64,
+        r_outer: f64,
+        start: f64,
+        end: f64,
+    ) -> Self {
+        use ArcDirection::*;
+
+        let center = center.into_point();
+        let outer = (r_outer, r_outer);
+        let inner = (r_inner, r_inner);
+
+        if (end - start).abs() >= 360.0 {
+            return PathDefinitionString::new()
+                .arc_around(center, outer, start, end, Clockwise)
+                .close_path()
+                .arc_around(center, inner, end, start, CounterClockwise)
+                .close_path();
+        }
+
+        PathDefinitionString::new()
+            .arc_around(center, outer, start, end, Clockwise)
+            .line_to(point_on_ellipse(center, inner, end))
+            .push_arc_around(center, inner, end, start, CounterClockwise)
+            .close_path()
+    }
+
+    /// Creates a smooth curve through all `points`, using a cardinal (Catmull-Rom) spline which is
+    /// emitted as cubic bezier curves
+    ///
+    /// # Note / Arguments
+    /// A `tension` of 0.0 gives a Catmull-Rom spline and a `tension` of 1.0 gives straight lines
+    /// between the points. With less than two points, only a move to the first point is emitted.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let path_definition_string = PathData::smooth_through(&[(0.0, 0.0), (10.0, 10.0), (20.0, 0.0)], 0.0);
+    ///
+    /// // Will output "M 0.00 0.00 C 1.67 1.67, 6.67 10.00, 10.00 10.00 C 13.33 10.00, 18.33 1.67, 20.00 0.00"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 0.00 0.00 C 1.67 1.67, 6.67 10.00, 10.00 10.00 C 13.33 10.00, 18.33 1.67, 20.00 0.00"));
+    /// ```
+    pub fn smooth_through<P: IntoPoint + Copy>(points: &[P], tension: f64) -> Self {
+        let mut path = PathDefinitionString::new();
+
+        let points: Vec<(f64, f64)> = points.iter().map(|point| point.into_point()).collect();
+        let first = match points.first() {
+            Some(first) => *first,
+            None => return path,
+        };
+        path = path.move_to(first);
+
+        let factor = (1.0 - tension) / 6.0;
+        let last = points.len() - 1;
+        for i in 0..last {
+            // The first and last points are duplicated to get tangents at the ends
+            let (x0, y0) = points[i.saturating_sub(1)];
+            let (x1, y1) = points[i];
+            let (x2, y2) = points[i + 1];
+            let (x3, y3) = points[(i + 2).min(last)];
+
+            let control_1 = (x1 + (x2 - x0) * factor, y1 + (y2 - y0) * factor);
+            let control_2 = (x2 - (x3 - x1) * factor, y2 - (y3 - y1) * factor);
+            path = path.curve_to((x2, y2), control_1, control_2);
+        }
+        path
+    }
+
+    /// Compares input string with PathDefinitionString and returns true if both are equal
+    ///
+    /// # Note / Arguments
+    /// For further information: [Look here](https://developer.mozilla.org/en-US/docs/Web/SVG/Tutorial/Paths#Line_commands)
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let path_definition_string = PathData::new()
+    ///     .move_to((3.0, 3.0))
+    ///     .line_to((6.0, 6.0))
+    ///     .close_path();
+    ///
+    /// // Both of these statements are equivalent
+    /// let eq_1 = path_definition_string.to_string() == String::from("M 3.00 3.00 L 6.00 6.00 Z");
+    /// # assert!(eq_1);
+    /// let eq_2 = path_definition_string.is_str("M 3.00 3.00 L 6.00 6.00 Z");
+    /// # assert!(eq_2);
+    /// # assert_eq!(eq_1, eq_2);
+    /// ```
+    #[inline]
+    pub fn is_str(&self, eq: &str) -> bool {
+        self.to_string() == eq
+    }
+
+    /// Sets the maximum amount of decimals of the numbers in the output string, trimming
+    /// trailing zeros
+    ///
+    /// # Note / Arguments
+    /// Without a precision every number is output with exactly 2 decimals.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let path_definition_string = PathData::new()
+    ///     .move_to((3.0, 3.5))
+    ///     .line_to((0.126, 10.0))
+    ///     .with_precision(2);
+    ///
+    /// // Will output "M 3 3.5 L 0.13 10"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 3 3.5 L 0.13 10"));
+    /// ```
+    #[inline]
+    pub fn with_precision(mut self, decimals: usize) -> Self {
+        self.precision = Some(decimals);
+        self
+    }
+
+    /// Sets the maximum amount of decimals to the precision of a
+    /// [Context](../context/struct.Context.html), if it has one
+    #[inline]
+    pub fn with_context(mut self, context: &Context) -> Self {
+        if context.precision.is_some() {
+            self.precision = context.precision;
+        }
+        self
+    }
+
+    /// Gets the maximum amount of decimals set with [with_precision](#method.with_precision)
+    #[inline]
+    pub fn get_precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Gets an immutable reference to the commands of this [PathDefinitionString]
+    #[inline]
+    pub fn get_commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    /// Returns a copy of this [PathDefinitionString] where all relative commands are replaced
+    /// by their absolute equivalents
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let path_definition_string = PathData::new()
+    ///     .move_to((3.0, 3.0))
+    ///     .r_line_to((7.0, 7.0))
+    ///     .r_horizontal_line_to(-2.0)
+    ///     .to_absolute();
+    ///
+    /// // Will output "M 3.00 3.00 L 10.00 10.00 H 8.00"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 3.00 3.00 L 10.00 10.00 H 8.00"));
+    /// ```
+    pub fn to_absolute(&self) -> Self {
+        let mut current = (0.0, 0.0);
+        let mut subpath_start = (0.0, 0.0);
+
+        let commands = self
+            .commands
+            .iter()
+            .map(|command| {
+                let command = command.to_absolute(current);
+                current = command.end_point(current, subpath_start);
+                if let PathCommand::MoveTo { to, .. } = command {
+                    subpath_start = to;
+                }
+                command
+            })
+            .collect();
+
+        PathDefinitionString {
+            commands,
+            precision: self.precision,
+        }
+    }
+
+    /// Returns the total length of the path, as drawn by a stroke
+    ///
+    /// # Note / Arguments
+    /// Curves and arcs are measured by subdividing them, and closing a subpath adds the line
+    /// back to its start.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let triangle = PathData::new()
+    ///     .move_to((0.0, 0.0))
+    ///     .horizontal_line_to(3.0)
+    ///     .r_vertical_line_to(4.0)
+    ///     .close_path();
+    /// assert_eq!(triangle.length(), 12.0);
+    ///
+    /// let circle: PathData = "M 10 0 A 10 10 0 0 1 -10 0 A 10 10 0 0 1 10 0".parse().unwrap();
+    /// assert!((circle.length() - 20.0 * std::f64::consts::PI).abs() < 0.01);
+    /// ```
+    pub fn length(&self) -> f64 {
+        self.subpaths()
+            .iter()
+            .flat_map(|subpath| subpath.segments.iter())
+            .map(|segment| segment.length())
+            .sum()
+    }
+
+    /// Returns the smallest bounding box enclosing the drawn path, or None for a path without
+    /// commands
+    ///
+    /// # Note / Arguments
+    /// Curves are enclosed tightly rather than by their control points. Arcs are measured on the
+    /// cubic curves approximating them, which deviate by far less than a pixel. Strokes are not
+    /// taken into account.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::geometry::BoundingBox;
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let bump = PathData::new()
+    ///     .move_to((0.0, 0.0))
+    ///     .curve_to((10.0, 0.0), (0.0, -8.0), (10.0, -8.0));
+    ///
+    /// assert_eq!(bump.bounding_box(), Some(BoundingBox::new(0.0, -6.0, 10.0, 6.0)));
+    /// ```
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let points: Vec<(f64, f64)> = self
+            .subpaths()
+            .iter()
+            .flat_map(|subpath| {
+                std::iter::once(subpath.start).chain(
+                    subpath
+                        .segments
+                        .iter()
+                        .flat_map(|segment| segment.extremes()),
+                )
+            })
+            .collect();
+        BoundingBox::from_points(&points)
+    }
+
+    /// Returns `n_points` evenly spaced points along the path, from its start to its end
+    ///
+    /// # Note / Arguments
+    /// The points are spaced by the distance along the drawn path like
+    /// [length](#method.length) measures it, so moves between subpaths do not count. A single
+    /// point is the start of the path, and a path without commands has no points.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let corner = PathData::new()
+    ///     .move_to((0.0, 0.0))
+    ///     .horizontal_line_to(10.0)
+    ///     .vertical_line_to(10.0);
+    ///
+    /// assert_eq!(
+    ///     corner.resample(5),
+    ///     vec![(0.0, 0.0), (5.0, 0.0), (10.0, 0.0), (10.0, 5.0), (10.0, 10.0)]
+    /// );
+    /// ```
+    pub fn resample(&self, n_points: usize) -> Vec<(f64, f64)> {
+        let spacing = match n_points {
+            0 => return Vec::new(),
+            1 => 0.0,
+            _ => self.length() / (n_points - 1) as f64,
+        };
+        self.points_at((0..n_points).map(|index| index as f64 * spacing))
+            .into_iter()
+            .map(|(point, _)| point)
+            .collect()
+    }
+
+    /// Returns the points at increasing distances along the path together with the direction
+    /// of the path there, where distances past the end give the end point and paths without
+    /// length have no direction
+    pub(crate) fn points_at<I: IntoIterator<Item = f64>>(
+        &self,
+        distances: I,
+    ) -> Vec<((f64, f64), (f64, f64))> {
+        let subpaths = self.subpaths();
+        let start = match subpaths.first() {
+            Some(subpath) => subpath.start,
+            None => return Vec::new(),
+        };
+
+        // Zero length segments are left out, since they have no direction
+        let segments: Vec<(Segment, f64)> = subpaths
+            .into_iter()
+            .flat_map(|subpath| subpath.segments)
+            .map(|segment| (segment, segment.length()))
+            .filter(|&(_, length)| length > 0.0)
+            .collect();
+
+        let mut index = 0;
+        let mut passed = 0.0;
+        distances
+            .into_iter()
+            .map(|distance| {
+                if segments.is_empty() {
+                    return (start, (0.0, 0.0));
+                }
+                while index + 1 < segments.len() && passed + segments[index].1 < distance {
+                    passed += segments[index].1;
+                    index += 1;
+                }
+                segments[index].0.point_at(distance - passed)
+            })
+            .collect()
+    }
+
+    /// Returns a hash of the drawn geometry, which is the same for paths which only differ in
+    /// formatting or in the commands used to draw the same shape, or None if the `tolerance` is
+    /// not positive
+    ///
+    /// # Note / Arguments
+    /// The path is normalized to absolute cubic curves, where lines, quadratic curves, smooth
+    /// curves and arcs are converted, and coordinates are rounded to multiples of `tolerance`
+    /// before hashing. A closing line which has a length of zero after rounding is left out.
+    /// Paths drawn in a different direction or from a different start point hash differently.
+    ///
+    /// The hash is computed with 64 bit FNV-1a over little endian numbers, so it is the same
+    /// on every platform and in every version, and can be stored, e.g. to find duplicate shapes
+    /// across runs.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let written: PathData = "m10,10h10v10l-10-10z".parse().unwrap();
+    /// let built = PathData::new()
+    ///     .move_to((10.0, 10.0))
+    ///     .line_to((20.0, 10.0))
+    ///     .line_to((20.0, 20.0))
+    ///     .close_path();
+    ///
+    /// assert_eq!(written.geometry_hash(0.01), built.geometry_hash(0.01));
+    /// assert_ne!(written.geometry_hash(0.01), built.line_to((0.0, 0.0)).geometry_hash(0.01));
+    /// assert_eq!(written.geometry_hash(0.0), None);
+    /// ```
+    pub fn geometry_hash(&self, tolerance: f64) -> Option<u64> {
+        if tolerance.is_nan() || tolerance <= 0.0 {
+            return None;
+        }
+
+        let round = |(x, y): (f64, f64)| {
+            (
+                (x / tolerance).round() as i64,
+                (y / tolerance).round() as i64,
+            )
+        };
+
+        let mut state = Fnv1a::new();
+        for subpath in self.subpaths() {
+            state.write(b"M");
+            state.write_point(round(subpath.start));
+
+            let last = subpath.segments.len().saturating_sub(1);
+            for (index, segment) in subpath.segments.iter().enumerate() {
+                let [from, control_1, control_2, to] = segment.to_cubic();
+                if subpath.closed && index == last && round(from) == round(to) {
+                    continue;
+                }
+                state.write(b"C");
+                for point in [control_1, control_2, to] {
+                    state.write_point(round(point));
+                }
+            }
+
+            if subpath.closed {
+                state.write(b"Z");
+            }
+        }
+        Some(state.0)
+    }
+
+    /// Returns the drawn subpaths of the path, where arcs, quadratic curves and smooth curves are
+    /// converted to cubic curves
+    fn subpaths(&self) -> Vec<Subpath> {
+        use PathCommand::*;
+
+        let reflect = |control: Option<(f64, f64)>, (x, y): (f64, f64)| {
+            control.map_or((x, y), |(cx, cy)| (2.0 * x - cx, 2.0 * y - cy))
+        };
+
+        let mut subpaths: Vec<Subpath> = Vec::new();
+        let mut current = (0.0, 0.0);
+        let mut subpath_start = (0.0, 0.0);
+        let mut cubic_control = None;
+        let mut quad_control = None;
+
+        for command in self.arcs_to_cubics().to_absolute().commands {
+            let end = command.end_point(current, subpath_start);
+            let (mut next_cubic_control, mut next_quad_control) = (None, None);
+
+            // Drawing after a closed subpath starts a new subpath at the same point
+            let start = match command {
+                MoveTo { to, .. } => Some(to),
+                _ if subpaths.last().is_none_or(|subpath| subpath.closed) => Some(current),
+                _ => None,
+            };
+            if let Some(start) = start {
+                subpaths.push(Subpath {
+                    start,
+                    segments: Vec::new(),
+                    closed: false,
+                });
+            }
+            let subpath = subpaths.last_mut().unwrap();
+            let segments = &mut subpath.segments;
+
+            match command {
+                MoveTo { to, .. } => subpath_start = to,
+                ClosePath => {
+                    segments.push(Segment::Line(current, end));
+                    subpath.closed = true;
+                }
+                LineTo { .. } | HorizontalLineTo { .. } | VerticalLineTo { .. } => {
+                    segments.push(Segment::Line(current, end))
+                }
+                CurveTo {
+                    control_1,
+                    control_2,
+                    to,
+                    ..
+                } => {
+                    segments.push(Segment::Cubic(current, control_1, control_2, to));
+                    next_cubic_control = Some(control_2);
+                }
+                SmoothCurveTo { control_2, to, .. } => {
+                    let control_1 = reflect(cubic_control, current);
+                    segments.push(Segment::Cubic(current, control_1, control_2, to));
+                    next_cubic_control = Some(control_2);
+                }
+                QuadCurveTo { .. } | QuadStringTo { .. } => {
+                    let control = match command {
+                        QuadCurveTo { control, .. } => control,
+                        _ => reflect(quad_control, current),
+                    };
+                    // A quadratic curve is a cubic curve with control points 2/3 towards its
+                    // control point
+                    let towards = |(x, y): (f64, f64)| {
+                        (
+                            x + 2.0 / 3.0 * (control.0 - x),
+                            y + 2.0 / 3.0 * (control.1 - y),
+                        )
+                    };
+                    segments.push(Segment::Cubic(current, towards(current), towards(end), end));
+                    next_quad_control = Some(control);
+                }
+                // Arcs have already been converted to cubic curves or lines
+                ArcTo { .. } => segments.push(Segment::Line(current, end)),
+            }
+
+            cubic_control = next_cubic_control;
+            quad_control = next_quad_control;
+            current = end;
+        }
+        subpaths
+    }
+
+    /// Returns a copy of this [PathDefinitionString] with a [Transform](../transform/enum.Transform.html)
+    /// applied to the coordinates of every command
+    ///
+    /// # Note / Arguments
+    /// All commands of the result are absolute. Horizontal and vertical lines become normal lines
+    /// when the transform rotates or skews, and the radii, rotation and sweep of arcs are adjusted.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    /// use svg_definitions::transform::Transform;
+    ///
+    /// let path_definition_string = PathData::new()
+    ///     .move_to((1.0, 1.0))
+    ///     .r_horizontal_line_to(2.0)
+    ///     .transformed(&Transform::Translate(10.0, 5.0));
+    ///
+    /// // Will output "M 11.00 6.00 H 13.00"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 11.00 6.00 H 13.00"));
+    /// ```
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        use PathCommand::*;
+
+        let [a, b, c, d, e, f] = transform.coefficients();
+        let axis_aligned = b == 0.0 && c == 0.0;
+        let mirrored = a * d - b * c < 0.0;
+
+        let mut current = (0.0, 0.0);
+        let mut subpath_start = (0.0, 0.0);
+
+        let commands = self
+            .to_absolute()
+            .commands
+            .into_iter()
+            .map(|command| {
+                let transformed = match command {
+                    MoveTo { to, .. } => {
+                        subpath_start = to;
+                        MoveTo {
+                            relative: false,
+                            to: transform.apply(to),
+                        }
+                    }
+                    LineTo { to, .. } => LineTo {
+                        relative: false,
+                        to: transform.apply(to),
+                    },
+                    HorizontalLineTo { x, .. } if axis_aligned => HorizontalLineTo {
+                        relative: false,
+                        x: a * x + e,
+                    },
+                    VerticalLineTo { y, .. } if axis_aligned => VerticalLineTo {
+                        relative: false,
+                        y: d * y + f,
+                    },
+                    HorizontalLineTo { .. } | VerticalLineTo { .. } => LineTo {
+                        relative: false,
+                        to: transform.apply(command.end_point(current, subpath_start)),
+                    },
+                    CurveTo {
+                        control_1,
+                        control_2,
+                        to,
+                        ..
+                    } => CurveTo {
+                        relative: false,
+                        control_1: transform.apply(control_1),
+                        control_2: transform.apply(control_2),
+                        to: transform.apply(to),
+                    },
+                    SmoothCurveTo { control_2, to, .. } => SmoothCurveTo {
+                        relative: false,
+                        control_2: transform.apply(control_2),
+                        to: transform.apply(to),
+                    },
+                    QuadCurveTo { control, to, .. } => QuadCurveTo {
+                        relative: false,
+                        control: transform.apply(control),
+                        to: transform.apply(to),
+                    },
+                    QuadStringTo { to, .. } => QuadStringTo {
+                        relative: false,
+                        to: transform.apply(to),
+                    },
+                    ArcTo {
+                        radii,
+                        x_axis_rotation,
+                        large_arc_flag,
+                        sweep_flag,
+                        to,
+                        ..
+                    } => {
+                        let (radii, x_axis_rotation) =
+                            transform_ellipse(transform, radii, x_axis_rotation);
+                        ArcTo {
+                            relative: false,
+                            radii,
+                            x_axis_rotation,
+                            large_arc_flag,
+                            sweep_flag: sweep_flag != mirrored,
+                            to: transform.apply(to),
+                        }
+                    }
+                    ClosePath => ClosePath,
+                };
+
+                current = command.end_point(current, subpath_start);
+                transformed
+            })
+            .collect();
+
+        PathDefinitionString {
+            commands,
+            precision: self.precision,
+        }
+    }
+
+    /// Returns a copy of this [PathDefinitionString] where all arcs are replaced by cubic bezier
+    /// curves approximating them
     ///
-    /// # Note
-    /// Eventhough, one can input f64's the actual output string will always output number with 2 decimals points.
-    #[inline]
-    pub fn new() -> PathDefinitionString {
+    /// # Note / Arguments
+    /// Every arc is split into curves spanning at most 90 degrees. Commands other than arcs are
+    /// kept as they are, except for smooth curves directly following an arc, which are turned
+    /// into normal curves to keep their shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let path_definition_string = PathData::new()
+    ///     .move_to((10.0, 0.0))
+    ///     .arc_to((0.0, 10.0), (10.0, 10.0), 0.0, false, true)
+    ///     .arcs_to_cubics();
+    ///
+    /// // Will output "M 10.00 0.00 C 10.00 5.52, 5.52 10.00, 0.00 10.00"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 10.00 0.00 C 10.00 5.52, 5.52 10.00, 0.00 10.00"));
+    /// ```
+    pub fn arcs_to_cubics(&self) -> Self {
+        let mut commands = Vec::with_capacity(self.commands.len());
+        let mut current = (0.0, 0.0);
+        let mut subpath_start = (0.0, 0.0);
+        let mut after_arc = false;
+
+        for command in self.commands.iter() {
+            let absolute = command.to_absolute(current);
+
+            match absolute {
+                PathCommand::ArcTo { .. } => commands.extend(arc_to_cubics(current, absolute)),
+                // The first control point of a smooth curve is only a reflection after curves
+                PathCommand::SmoothCurveTo { control_2, to, .. } if after_arc => {
+                    commands.push(PathCommand::CurveTo {
+                        relative: false,
+                        control_1: current,
+                        control_2,
+                        to,
+                    })
+                }
+                _ => commands.push(*command),
+            }
+
+            after_arc = matches!(absolute, PathCommand::ArcTo { .. });
+            current = absolute.end_point(current, subpath_start);
+            if let PathCommand::MoveTo { to, .. } = absolute {
+                subpath_start = to;
+            }
+        }
+
         PathDefinitionString {
-            inner_string: String::from(""),
+            commands,
+            precision: self.precision,
         }
     }
 
-    /// Compares input string with PathDefinitionString and returns true if both are equal
+    /// Checks the commands for mistakes which would make browsers fail to render the path,
+    /// returning all found errors
     ///
     /// # Note / Arguments
-    /// For further information: [Look here](https://developer.mozilla.org/en-US/docs/Web/SVG/Tutorial/Paths#Line_commands)
+    /// Reported are drawing commands before the first move, NaN and infinite numbers, and arcs
+    /// with negative radii. Browsers stop rendering a path at its first error, often silently.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::path::PathError;
+    /// use svg_definitions::prelude::*;
+    ///
+    /// assert!(PathData::new().move_to((0.0, 0.0)).line_to((1.0, 1.0)).validate().is_ok());
+    ///
+    /// let errors = PathData::new()
+    ///     .line_to((1.0, 1.0))
+    ///     .move_to((f32::NAN, 0.0))
+    ///     .validate()
+    ///     .unwrap_err();
+    /// assert_eq!(errors, vec![PathError::MissingMoveTo(0), PathError::NonFiniteNumber(1)]);
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<PathError>> {
+        let mut errors = Vec::new();
+        let mut moved = false;
+
+        for (index, command) in self.commands.iter().enumerate() {
+            match command {
+                PathCommand::MoveTo { .. } => moved = true,
+                _ if !moved => errors.push(PathError::MissingMoveTo(index)),
+                _ => (),
+            }
+
+            if command.numbers().iter().any(|number| !number.is_finite()) {
+                errors.push(PathError::NonFiniteNumber(index));
+            }
+
+            if let PathCommand::ArcTo { radii, .. } = command {
+                if radii.0 < 0.0 || radii.1 < 0.0 {
+                    errors.push(PathError::NegativeRadius(index));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns a copy of this [PathDefinitionString] where runs of straight lines are simplified
+    /// with the Douglas-Peucker algorithm
+    ///
+    /// # Note / Arguments
+    /// Points of consecutive line commands (`L`, `H` and `V`) are removed as long as the simplified
+    /// line stays within a distance of `epsilon` of them. The start and end of every run are kept
+    /// and simplified runs are written as absolute `L` commands. Curves and arcs are kept as
+    /// they are.
     ///
     /// # Examples
     /// ```
     /// use svg_definitions::prelude::*;
     ///
     /// let path_definition_string = PathData::new()
-    ///     .move_to((3.0, 3.0))
-    ///     .line_to((6.0, 6.0))
-    ///     .close_path();
+    ///     .move_to((0.0, 0.0))
+    ///     .line_to((5.0, 0.1))
+    ///     .line_to((10.0, 0.0))
+    ///     .line_to((10.0, 10.0))
+    ///     .simplify(0.5);
     ///
-    /// // Both of these statements are equivalent
-    /// let eq_1 = path_definition_string.to_string() == String::from("M 3.00 3.00 L 6.00 6.00 Z");
-    /// # assert!(eq_1);
-    /// let eq_2 = path_definition_string.is_str("M 3.00 3.00 L 6.00 6.00 Z");
-    /// # assert!(eq_2);
-    /// # assert_eq!(eq_1, eq_2);
+    /// // Will output "M 0.00 0.00 L 10.00 0.00 L 10.00 10.00"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 0.00 0.00 L 10.00 0.00 L 10.00 10.00"));
     /// ```
-    #[inline]
-    pub fn is_str(&self, eq: &str) -> bool {
-        self.inner_string.trim_start() == eq
+    pub fn simplify(&self, epsilon: f64) -> Self {
+        let mut commands = Vec::with_capacity(self.commands.len());
+        let mut current = (0.0, 0.0);
+        let mut subpath_start = (0.0, 0.0);
+
+        // The original commands and the points of the current run of lines, starting at the
+        // point where the run started
+        let mut run: Vec<PathCommand> = Vec::new();
+        let mut points = vec![current];
+
+        for command in self.commands.iter() {
+            let absolute = command.to_absolute(current);
+            let is_line = matches!(
+                absolute,
+                PathCommand::LineTo { .. }
+                    | PathCommand::HorizontalLineTo { .. }
+                    | PathCommand::VerticalLineTo { .. }
+            );
+
+            if !is_line {
+                Self::flush_simplified_run(&mut commands, &mut run, &mut points, epsilon);
+            }
+
+            current = absolute.end_point(current, subpath_start);
+            if let PathCommand::MoveTo { to, .. } = absolute {
+                subpath_start = to;
+            }
+
+            if is_line {
+                run.push(*command);
+                points.push(current);
+            } else {
+                commands.push(*command);
+                points = vec![current];
+            }
+        }
+        Self::flush_simplified_run(&mut commands, &mut run, &mut points, epsilon);
+
+        PathDefinitionString {
+            commands,
+            precision: self.precision,
+        }
+    }
+
+    /// Appends a simplified run of lines, or the original commands if nothing could be removed
+    fn flush_simplified_run(
+        commands: &mut Vec<PathCommand>,
+        run: &mut Vec<PathCommand>,
+        points: &mut Vec<(f64, f64)>,
+        epsilon: f64,
+    ) {
+        if run.len() < 2 {
+            commands.append(run);
+            return;
+        }
+
+        let mut keep = vec![false; points.len()];
+        douglas_peucker(points, epsilon, &mut keep);
+
+        if keep.iter().all(|&keep| keep) {
+            commands.append(run);
+        } else {
+            commands.extend(
+                points
+                    .iter()
+                    .zip(keep)
+                    .skip(1)
+                    .filter(|(_, keep)| *keep)
+                    .map(|(&to, _)| PathCommand::LineTo {
+                        relative: false,
+                        to,
+                    }),
+            );
+            run.clear();
+        }
+
+        let last = *points.last().unwrap();
+        *points = vec![last];
     }
 
     /// Appends a move to a certain point to the [PathDefinitionString]
@@ -95,8 +1659,11 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00"));
     /// ```
     #[inline]
-    pub fn move_to(mut self, (x, y): Point2D) -> Self {
-        self.inner_string.push_str(&format!(" M {:.2} {:.2}", x, y));
+    pub fn move_to<P: IntoPoint>(mut self, to: P) -> Self {
+        self.commands.push(PathCommand::MoveTo {
+            relative: false,
+            to: to.into_point(),
+        });
         self
     }
 
@@ -118,8 +1685,11 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 L 10.00 10.00"));
     /// ```
     #[inline]
-    pub fn line_to(mut self, (x, y): Point2D) -> Self {
-        self.inner_string.push_str(&format!(" L {:.2} {:.2}", x, y));
+    pub fn line_to<P: IntoPoint>(mut self, to: P) -> Self {
+        self.commands.push(PathCommand::LineTo {
+            relative: false,
+            to: to.into_point(),
+        });
         self
     }
 
@@ -141,8 +1711,11 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 H 10.00"));
     /// ```
     #[inline]
-    pub fn horizontal_line_to(mut self, x: f64) -> Self {
-        self.inner_string.push_str(&format!(" H {:.2}", x));
+    pub fn horizontal_line_to<T: Into<f64>>(mut self, x: T) -> Self {
+        self.commands.push(PathCommand::HorizontalLineTo {
+            relative: false,
+            x: x.into(),
+        });
         self
     }
 
@@ -164,8 +1737,11 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 V 10.00"));
     /// ```
     #[inline]
-    pub fn vertical_line_to(mut self, y: f64) -> Self {
-        self.inner_string.push_str(&format!(" V {:.2}", y));
+    pub fn vertical_line_to<T: Into<f64>>(mut self, y: T) -> Self {
+        self.commands.push(PathCommand::VerticalLineTo {
+            relative: false,
+            y: y.into(),
+        });
         self
     }
 
@@ -187,9 +1763,11 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 l 7.00 7.00"));
     /// ```
     #[inline]
-    pub fn r_line_to(mut self, (dx, dy): Point2D) -> Self {
-        self.inner_string
-            .push_str(&format!(" l {:.2} {:.2}", dx, dy));
+    pub fn r_line_to<P: IntoPoint>(mut self, to: P) -> Self {
+        self.commands.push(PathCommand::LineTo {
+            relative: true,
+            to: to.into_point(),
+        });
         self
     }
 
@@ -211,8 +1789,11 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 h 7.00"));
     /// ```
     #[inline]
-    pub fn r_horizontal_line_to(mut self, dx: f64) -> Self {
-        self.inner_string.push_str(&format!(" h {:.2}", dx));
+    pub fn r_horizontal_line_to<T: Into<f64>>(mut self, dx: T) -> Self {
+        self.commands.push(PathCommand::HorizontalLineTo {
+            relative: true,
+            x: dx.into(),
+        });
         self
     }
 
@@ -234,8 +1815,11 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 v 7.00"));
     /// ```
     #[inline]
-    pub fn r_vertical_line_to(mut self, dy: f64) -> Self {
-        self.inner_string.push_str(&format!(" v {:.2}", dy));
+    pub fn r_vertical_line_to<T: Into<f64>>(mut self, dy: T) -> Self {
+        self.commands.push(PathCommand::VerticalLineTo {
+            relative: true,
+            y: dy.into(),
+        });
         self
     }
 
@@ -257,11 +1841,13 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 C 15.00 20.00, 20.00 25.00, 10.00 10.00"));
     /// ```
     #[inline]
-    pub fn curve_to(mut self, (x, y): Point2D, (cx1, cy1): Point2D, (cx2, cy2): Point2D) -> Self {
-        self.inner_string.push_str(&format!(
-            " C {:.2} {:.2}, {:.2} {:.2}, {:.2} {:.2}",
-            cx1, cy1, cx2, cy2, x, y
-        ));
+    pub fn curve_to<P: IntoPoint>(mut self, to: P, control_1: P, control_2: P) -> Self {
+        self.commands.push(PathCommand::CurveTo {
+            relative: false,
+            control_1: control_1.into_point(),
+            control_2: control_2.into_point(),
+            to: to.into_point(),
+        });
         self
     }
 
@@ -283,16 +1869,13 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 c 12.00 17.00, 17.00 22.00, 7.00 7.00"));
     /// ```
     #[inline]
-    pub fn r_curve_to(
-        mut self,
-        (dx, dy): Point2D,
-        (cdx1, cdy1): Point2D,
-        (cdx2, cdy2): Point2D,
-    ) -> Self {
-        self.inner_string.push_str(&format!(
-            " c {:.2} {:.2}, {:.2} {:.2}, {:.2} {:.2}",
-            cdx1, cdy1, cdx2, cdy2, dx, dy
-        ));
+    pub fn r_curve_to<P: IntoPoint>(mut self, to: P, control_1: P, control_2: P) -> Self {
+        self.commands.push(PathCommand::CurveTo {
+            relative: true,
+            control_1: control_1.into_point(),
+            control_2: control_2.into_point(),
+            to: to.into_point(),
+        });
         self
     }
 
@@ -315,9 +1898,12 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 C 15.00 20.00, 20.00 25.00, 10.00 10.00 S -5.00 -10.00, 20.00 20.00"));
     /// ```
     #[inline]
-    pub fn smooth_curve_to(mut self, (x, y): Point2D, (cx2, cy2): Point2D) -> Self {
-        self.inner_string
-            .push_str(&format!(" S {:.2} {:.2}, {:.2} {:.2}", cx2, cy2, x, y));
+    pub fn smooth_curve_to<P: IntoPoint>(mut self, to: P, control_2: P) -> Self {
+        self.commands.push(PathCommand::SmoothCurveTo {
+            relative: false,
+            control_2: control_2.into_point(),
+            to: to.into_point(),
+        });
         self
     }
 
@@ -341,9 +1927,12 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 c 15.00 20.00, 20.00 25.00, 10.00 10.00 s -5.00 -10.00, 20.00 20.00"));
     /// ```
     #[inline]
-    pub fn r_smooth_curve_to(mut self, (dx, dy): Point2D, (cdx2, cdy2): Point2D) -> Self {
-        self.inner_string
-            .push_str(&format!(" s {:.2} {:.2}, {:.2} {:.2}", cdx2, cdy2, dx, dy));
+    pub fn r_smooth_curve_to<P: IntoPoint>(mut self, to: P, control_2: P) -> Self {
+        self.commands.push(PathCommand::SmoothCurveTo {
+            relative: true,
+            control_2: control_2.into_point(),
+            to: to.into_point(),
+        });
         self
     }
 
@@ -366,9 +1955,12 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 Q 15.00 20.00, 10.00 10.00"));
     /// ```
     #[inline]
-    pub fn quad_curve_to(mut self, (x, y): Point2D, (cx1, cy1): Point2D) -> Self {
-        self.inner_string
-            .push_str(&format!(" Q {:.2} {:.2}, {:.2} {:.2}", cx1, cy1, x, y));
+    pub fn quad_curve_to<P: IntoPoint>(mut self, to: P, control_1: P) -> Self {
+        self.commands.push(PathCommand::QuadCurveTo {
+            relative: false,
+            control: control_1.into_point(),
+            to: to.into_point(),
+        });
         self
     }
 
@@ -391,9 +1983,12 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 q 15.00 20.00, 10.00 10.00"));
     /// ```
     #[inline]
-    pub fn r_quad_curve_to(mut self, (dx, dy): Point2D, (cdx1, cdy1): Point2D) -> Self {
-        self.inner_string
-            .push_str(&format!(" q {:.2} {:.2}, {:.2} {:.2}", cdx1, cdy1, dx, dy));
+    pub fn r_quad_curve_to<P: IntoPoint>(mut self, to: P, control_1: P) -> Self {
+        self.commands.push(PathCommand::QuadCurveTo {
+            relative: true,
+            control: control_1.into_point(),
+            to: to.into_point(),
+        });
         self
     }
 
@@ -418,8 +2013,11 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 Q 15.00 20.00, 10.00 10.00 T 20.00 20.00"));
     /// ```
     #[inline]
-    pub fn quad_string_to(mut self, (x, y): Point2D) -> Self {
-        self.inner_string.push_str(&format!(" T {:.2} {:.2}", x, y));
+    pub fn quad_string_to<P: IntoPoint>(mut self, to: P) -> Self {
+        self.commands.push(PathCommand::QuadStringTo {
+            relative: false,
+            to: to.into_point(),
+        });
         self
     }
 
@@ -444,9 +2042,11 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 3.00 3.00 q 15.00 20.00, 10.00 10.00 t 20.00 20.00"));
     /// ```
     #[inline]
-    pub fn r_quad_string_to(mut self, (dx, dy): Point2D) -> Self {
-        self.inner_string
-            .push_str(&format!(" t {:.2} {:.2}", dx, dy));
+    pub fn r_quad_string_to<P: IntoPoint>(mut self, to: P) -> Self {
+        self.commands.push(PathCommand::QuadStringTo {
+            relative: true,
+            to: to.into_point(),
+        });
         self
     }
 
@@ -468,22 +2068,22 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 5.00 5.00 A 4.50 8.00 3.14 1 0 10.00 10.00"));
     /// ```
     #[inline]
-    pub fn arc_to(
+    pub fn arc_to<P: IntoPoint, R: IntoPoint>(
         mut self,
-        (x, y): Point2D,
-        (rx, ry): (f64, f64),
+        to: P,
+        radii: R,
         x_axis_rotation: f64,
         large_arc_flag: bool,
         sweep_flag: bool,
     ) -> Self {
-        let large_arc_flag = if large_arc_flag { '1' } else { '0' };
-
-        let sweep_flag = if sweep_flag { '1' } else { '0' };
-
-        self.inner_string.push_str(&format!(
-            " A {:.2} {:.2} {:.2} {} {} {:.2} {:.2}",
-            rx, ry, x_axis_rotation, large_arc_flag, sweep_flag, x, y
-        ));
+        self.commands.push(PathCommand::ArcTo {
+            relative: false,
+            radii: radii.into_point(),
+            x_axis_rotation,
+            large_arc_flag,
+            sweep_flag,
+            to: to.into_point(),
+        });
         self
     }
 
@@ -505,22 +2105,130 @@ impl PathDefinitionString {
     /// # assert!(path_definition_string.is_str("M 5.00 5.00 a 4.50 8.00 3.14 1 0 10.00 10.00"));
     /// ```
     #[inline]
-    pub fn r_arc_to(
+    pub fn r_arc_to<P: IntoPoint, R: IntoPoint>(
         mut self,
-        (dx, dy): Point2D,
-        (rx, ry): (f64, f64),
+        to: P,
+        radii: R,
         x_axis_rotation: f64,
         large_arc_flag: bool,
         sweep_flag: bool,
     ) -> Self {
-        let large_arc_flag = if large_arc_flag { '1' } else { '0' };
+        self.commands.push(PathCommand::ArcTo {
+            relative: true,
+            radii: radii.into_point(),
+            x_axis_rotation,
+            large_arc_flag,
+            sweep_flag,
+            to: to.into_point(),
+        });
+        self
+    }
+
+    /// Appends an arc around a center point from `start_angle` to `end_angle` to the [PathDefinitionString],
+    /// starting with a move to the start point of the arc
+    ///
+    /// # Note / Arguments
+    /// Angles are given in degrees. The endpoint, large-arc flag and sweep flag are computed from
+    /// the angles and the [ArcDirection]. When the arc spans 360 degrees or more a full ellipse is
+    /// drawn, which is emitted as two half arcs.
+    ///
+    /// For further information: [Look here](https://developer.mozilla.org/en-US/docs/Web/SVG/Tutorial/Paths#Arcs)
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    /// use svg_definitions::path::ArcDirection;
+    ///
+    /// let path_definition_string = PathData::new()
+    ///     .arc_around((50.0, 50.0), (10.0, 10.0), 0.0, 90.0, ArcDirection::Clockwise);
+    ///
+    /// // Will output "M 60.00 50.00 A 10.00 10.00 0.00 0 1 50.00 60.00"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 60.00 50.00 A 10.00 10.00 0.00 0 1 50.00 60.00"));
+    /// ```
+    #[inline]
+    pub fn arc_around<P: IntoPoint>(
+        mut self,
+        center: P,
+        radii: (f64, f64),
+        start_angle: f64,
+        end_angle: f64,
+        direction: ArcDirection,
+    ) -> Self {
+        let center = center.into_point();
+        self.commands.push(PathCommand::MoveTo {
+            relative: false,
+            to: point_on_ellipse(center, radii, start_angle),
+        });
+        self.push_arc_around(center, radii, start_angle, end_angle, direction)
+    }
+
+    /// Appends the arc commands of [arc_around](#method.arc_around) without the initial move,
+    /// assuming the current point is already the start point of the arc
+    fn push_arc_around(
+        mut self,
+        center: (f64, f64),
+        (rx, ry): (f64, f64),
+        start_angle: f64,
+        end_angle: f64,
+        direction: ArcDirection,
+    ) -> Self {
+        let (sign, sweep_flag) = match direction {
+            ArcDirection::Clockwise => (1.0, true),
+            ArcDirection::CounterClockwise => (-1.0, false),
+        };
 
-        let sweep_flag = if sweep_flag { '1' } else { '0' };
+        let difference = end_angle - start_angle;
+        let span = if difference.abs() >= 360.0 {
+            360.0
+        } else {
+            (sign * difference).rem_euclid(360.0)
+        };
 
-        self.inner_string.push_str(&format!(
-            " a {:.2} {:.2} {:.2} {} {} {:.2} {:.2}",
-            rx, ry, x_axis_rotation, large_arc_flag, sweep_flag, dx, dy
-        ));
+        // A single arc whose start and end coincide draws nothing, so full ellipses are split
+        let end_angles = if span >= 360.0 {
+            vec![start_angle + sign * 180.0, start_angle + sign * 360.0]
+        } else {
+            vec![start_angle + sign * span]
+        };
+        let large_arc_flag = span > 180.0 && span < 360.0;
+
+        for angle in end_angles {
+            self.commands.push(PathCommand::ArcTo {
+                relative: false,
+                radii: (rx, ry),
+                x_axis_rotation: 0.0,
+                large_arc_flag,
+                sweep_flag,
+                to: point_on_ellipse(center, (rx, ry), angle),
+            });
+        }
+        self
+    }
+
+    /// Appends all commands of another [PathDefinitionString], e.g. to combine independently
+    /// constructed subpaths into one `d` attribute
+    ///
+    /// # Note / Arguments
+    /// The precision of `self` is kept. Relative commands at the start of `other` become relative
+    /// to where `self` ended, so `other` should usually start with a move.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let outer = PathData::rounded_rect((0.0, 0.0), (10.0, 10.0), 0.0);
+    /// let hole = PathData::rounded_rect((2.0, 2.0), (6.0, 6.0), 0.0);
+    ///
+    /// let path_definition_string = outer.extend(hole);
+    ///
+    /// // Will output "M 0.00 0.00 H 10.00 V 10.00 H 0.00 Z M 2.00 2.00 H 8.00 V 8.00 H 2.00 Z"
+    /// println!("{}", path_definition_string);
+    /// # assert!(path_definition_string.is_str("M 0.00 0.00 H 10.00 V 10.00 H 0.00 Z M 2.00 2.00 H 8.00 V 8.00 H 2.00 Z"));
+    /// ```
+    #[inline]
+    pub fn extend(mut self, other: PathDefinitionString) -> Self {
+        self.commands.extend(other.commands);
         self
     }
 
@@ -545,35 +2253,167 @@ impl PathDefinitionString {
     /// ```
     #[inline]
     pub fn close_path(mut self) -> Self {
-        self.inner_string.push_str(" Z");
+        self.commands.push(PathCommand::ClosePath);
         self
     }
 }
 
 impl fmt::Display for PathDefinitionString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.inner_string.trim_start())
+        let mut output = String::new();
+        for (index, command) in self.commands.iter().enumerate() {
+            if index > 0 {
+                output.push(' ');
+            }
+            command.write(&mut output, self.precision);
+        }
+        f.write_str(&output)
     }
 }
 
-impl Into<String> for PathDefinitionString {
+impl Default for PathDefinitionString {
     #[inline]
-    fn into(self) -> String {
-        self.to_string()
+    fn default() -> PathDefinitionString {
+        PathDefinitionString::new()
     }
 }
 
-impl Clone for PathDefinitionString {
-    fn clone(&self) -> PathDefinitionString {
-        PathDefinitionString {
-            inner_string: self.inner_string.clone(),
+impl FromStr for PathDefinitionString {
+    type Err = InvalidPathData;
+
+    /// Parses path data, e.g. the `d` attribute of a `<path>`
+    ///
+    /// # Note / Arguments
+    /// Numbers and flags may be separated by whitespace and commas or not at all where the
+    /// syntax allows it, e.g. `M0-1.5.5`, and a command letter may be left out when it repeats.
+    /// Coordinates following a move are read as lines.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use PathCommand::*;
+
+        let mut reader = Reader::new(s);
+        let mut path = PathDefinitionString::new();
+        let mut letter: Option<u8> = None;
+
+        while !reader.is_at_end() {
+            let start = reader.offset();
+            if let Some(command) = reader.command() {
+                letter = Some(command);
+            } else if letter.is_none_or(|letter| letter.eq_ignore_ascii_case(&b'z')) {
+                // Only commands with arguments can be repeated
+                return Err(InvalidPathData(start));
+            }
+
+            let current = letter.expect("a command letter has been read");
+            let relative = current.is_ascii_lowercase();
+            let command = match current.to_ascii_uppercase() {
+                b'M' => {
+                    letter = Some(if relative { b'l' } else { b'L' });
+                    MoveTo {
+                        relative,
+                        to: reader.pair()?,
+                    }
+                }
+                b'L' => LineTo {
+                    relative,
+                    to: reader.pair()?,
+                },
+                b'H' => HorizontalLineTo {
+                    relative,
+                    x: reader.number()?,
+                },
+                b'V' => VerticalLineTo {
+                    relative,
+                    y: reader.number()?,
+                },
+                b'C' => CurveTo {
+                    relative,
+                    control_1: reader.pair()?,
+                    control_2: reader.pair()?,
+                    to: reader.pair()?,
+                },
+                b'S' => SmoothCurveTo {
+                    relative,
+                    control_2: reader.pair()?,
+                    to: reader.pair()?,
+                },
+                b'Q' => QuadCurveTo {
+                    relative,
+                    control: reader.pair()?,
+                    to: reader.pair()?,
+                },
+                b'T' => QuadStringTo {
+                    relative,
+                    to: reader.pair()?,
+                },
+                b'A' => ArcTo {
+                    relative,
+                    radii: reader.pair()?,
+                    x_axis_rotation: reader.number()?,
+                    large_arc_flag: reader.flag()?,
+                    sweep_flag: reader.flag()?,
+                    to: reader.pair()?,
+                },
+                b'Z' => ClosePath,
+                _ => return Err(InvalidPathData(start)),
+            };
+            path.commands.push(command);
+        }
+
+        Ok(path)
+    }
+}
+
+impl Add for PathDefinitionString {
+    type Output = PathDefinitionString;
+
+    /// Concatenates two [PathDefinitionStrings](struct.PathDefinitionString.html), see
+    /// [extend](struct.PathDefinitionString.html#method.extend)
+    #[inline]
+    fn add(self, other: PathDefinitionString) -> PathDefinitionString {
+        self.extend(other)
+    }
+}
+
+impl AddAssign for PathDefinitionString {
+    #[inline]
+    fn add_assign(&mut self, other: PathDefinitionString) {
+        self.commands.extend(other.commands);
+    }
+}
+
+impl From<PathDefinitionString> for String {
+    #[inline]
+    fn from(path: PathDefinitionString) -> String {
+        path.to_string()
+    }
+}
+
+/// The 64 bit FNV-1a hash, which unlike the hasher of the standard library is fixed
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn new() -> Fnv1a {
+        Fnv1a(Fnv1a::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(Fnv1a::PRIME);
         }
     }
+
+    fn write_point(&mut self, (x, y): (i64, i64)) {
+        self.write(&x.to_le_bytes());
+        self.write(&y.to_le_bytes());
+    }
 }
 
 impl Hash for PathDefinitionString {
     fn hash<T: Hasher>(&self, state: &mut T) {
-        self.inner_string.hash(state)
+        self.to_string().hash(state)
     }
 }
 
@@ -672,6 +2512,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_arc() {
         assert!(PathDefinitionString::new()
             .move_to((5.0, 5.0))
@@ -685,4 +2526,366 @@ mod tests {
             .close_path()
             .is_str("M 5.00 5.00 a 4.50 8.00 3.14 1 0 10.00 10.00 Z"));
     }
+
+    #[test]
+    fn test_arc_around() {
+        use super::ArcDirection::*;
+
+        assert!(PathDefinitionString::new()
+            .arc_around((50.0, 50.0), (10.0, 20.0), 30.0, 240.0, Clockwise)
+            .is_str("M 58.66 60.00 A 10.00 20.00 0.00 1 1 45.00 32.68"));
+
+        assert!(PathDefinitionString::new()
+            .arc_around((50.0, 50.0), (10.0, 20.0), 30.0, 240.0, CounterClockwise)
+            .is_str("M 58.66 60.00 A 10.00 20.00 0.00 0 0 45.00 32.68"));
+
+        assert!(PathDefinitionString::new()
+            .arc_around((50.0, 50.0), (10.0, 10.0), 0.0, 360.0, Clockwise)
+            .is_str("M 60.00 50.00 A 10.00 10.00 0.00 0 1 40.00 50.00 A 10.00 10.00 0.00 0 1 60.00 50.00"));
+    }
+
+    #[test]
+    fn test_arcs_to_cubics() {
+        // A half circle is split into two quarter circles
+        assert!(PathDefinitionString::new()
+            .move_to((10.0, 0.0))
+            .r_arc_to((-20.0, 0.0), (10.0, 10.0), 0.0, false, true)
+            .arcs_to_cubics()
+            .is_str("M 10.00 0.00 C 10.00 5.52, 5.52 10.00, 0.00 10.00 C -5.52 10.00, -10.00 5.52, -10.00 0.00"));
+
+        // Too small radii are scaled up and zero radii give straight lines
+        assert!(PathDefinitionString::new()
+            .move_to((0.0, 0.0))
+            .arc_to((2.0, 0.0), (0.5, 0.5), 0.0, false, false)
+            .arc_to((4.0, 0.0), (0.0, 1.0), 0.0, false, false)
+            .arcs_to_cubics()
+            .is_str("M 0.00 0.00 C 0.00 0.55, 0.45 1.00, 1.00 1.00 C 1.55 1.00, 2.00 0.55, 2.00 0.00 L 4.00 0.00"));
+
+        // Smooth curves after an arc keep their shape
+        assert!(PathDefinitionString::new()
+            .move_to((10.0, 0.0))
+            .arc_to((0.0, 10.0), (10.0, 10.0), 0.0, false, true)
+            .r_smooth_curve_to((5.0, 5.0), (5.0, 0.0))
+            .arcs_to_cubics()
+            .is_str("M 10.00 0.00 C 10.00 5.52, 5.52 10.00, 0.00 10.00 C 0.00 10.00, 5.00 10.00, 5.00 15.00"));
+    }
+
+    #[test]
+    fn test_validate() {
+        use super::PathError;
+
+        assert_eq!(
+            PathDefinitionString::new()
+                .close_path()
+                .move_to((0.0, 0.0))
+                .curve_to((1.0, 1.0), (0.0, f32::INFINITY), (1.0, 0.0))
+                .arc_to((2.0, 2.0), (-1.0, 1.0), 0.0, false, false)
+                .validate(),
+            Err(vec![
+                PathError::MissingMoveTo(0),
+                PathError::NonFiniteNumber(2),
+                PathError::NegativeRadius(3)
+            ])
+        );
+        assert_eq!(PathDefinitionString::new().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_simplify() {
+        // Relative and axis aligned lines are resolved, and curves split runs
+        assert!(PathDefinitionString::new()
+            .move_to((0.0, 0.0))
+            .r_line_to((1.0, 0.2))
+            .r_line_to((1.0, -0.2))
+            .horizontal_line_to(4.0)
+            .curve_to((4.0, 1.0), (5.0, 0.0), (5.0, 1.0))
+            .r_line_to((-1.0, 0.0))
+            .r_line_to((-1.0, 5.0))
+            .simplify(0.25)
+            .is_str("M 0.00 0.00 L 4.00 0.00 C 5.00 0.00, 5.00 1.00, 4.00 1.00 l -1.00 0.00 l -1.00 5.00"));
+
+        // Points further away than epsilon are kept
+        assert!(PathDefinitionString::new()
+            .move_to((0.0, 0.0))
+            .line_to((1.0, 1.0))
+            .line_to((2.0, 0.0))
+            .line_to((3.0, 0.0))
+            .line_to((4.0, 0.0))
+            .close_path()
+            .simplify(0.5)
+            .is_str("M 0.00 0.00 L 1.00 1.00 L 2.00 0.00 L 4.00 0.00 Z"));
+    }
+
+    #[test]
+    fn test_transformed() {
+        use crate::transform::Transform;
+
+        let path = PathDefinitionString::new()
+            .move_to((1.0, 0.0))
+            .r_horizontal_line_to(1.0)
+            .r_vertical_line_to(1.0)
+            .close_path();
+
+        assert!(path
+            .transformed(&Transform::Scale(2.0, 3.0))
+            .is_str("M 2.00 0.00 H 4.00 V 3.00 Z"));
+        assert!(path
+            .transformed(&Transform::Rotate(90.0, 0.0, 0.0))
+            .is_str("M 0.00 1.00 L 0.00 2.00 L -1.00 2.00 Z"));
+
+        // Mirroring flips the sweep of arcs
+        let arc = PathDefinitionString::new().move_to((0.0, 0.0)).arc_to(
+            (10.0, 0.0),
+            (5.0, 2.0),
+            0.0,
+            false,
+            true,
+        );
+        assert!(arc
+            .transformed(&Transform::Scale(-1.0, 1.0))
+            .is_str("M 0.00 0.00 A 5.00 2.00 0.00 0 0 -10.00 0.00"));
+
+        // Rotating an arc rotates its x-axis
+        assert!(arc
+            .transformed(&Transform::Rotate(30.0, 0.0, 0.0))
+            .is_str("M 0.00 0.00 A 5.00 2.00 30.00 0 1 8.66 5.00"));
+
+        // Non-uniform scaling of a rotated ellipse
+        assert!(PathDefinitionString::new()
+            .move_to((0.0, 0.0))
+            .arc_to((1.0, 1.0), (1.0, 1.0), 45.0, false, true)
+            .transformed(&Transform::Scale(2.0, 1.0))
+            .is_str("M 0.00 0.00 A 2.00 1.00 0.00 0 1 2.00 1.00"));
+    }
+
+    #[test]
+    fn test_concatenation() {
+        let square = |offset: f32| {
+            PathDefinitionString::new()
+                .move_to((offset, offset))
+                .r_line_to((1.0, 0.0))
+                .r_line_to((0.0, 1.0))
+                .close_path()
+        };
+
+        let mut path = square(0.0) + square(5.0).with_precision(0);
+        assert!(path
+            .is_str("M 0.00 0.00 l 1.00 0.00 l 0.00 1.00 Z M 5.00 5.00 l 1.00 0.00 l 0.00 1.00 Z"));
+
+        path += PathDefinitionString::new().line_to((2.0, 2.0));
+        assert!(path.is_str("M 0.00 0.00 l 1.00 0.00 l 0.00 1.00 Z M 5.00 5.00 l 1.00 0.00 l 0.00 1.00 Z L 2.00 2.00"));
+    }
+
+    #[test]
+    fn test_precision() {
+        assert!(PathDefinitionString::new()
+            .move_to((-0.001, 10.5))
+            .horizontal_line_to(2.25)
+            .with_precision(1)
+            .is_str("M 0 10.5 H 2.2"));
+
+        assert!(PathDefinitionString::new()
+            .move_to((1.4, 10.0))
+            .arc_to((20.6, 0.0), (5.0, 5.0), 0.0, false, true)
+            .with_precision(0)
+            .is_str("M 1 10 A 5 5 0 0 1 21 0"));
+
+        assert!(PathDefinitionString::new()
+            .move_to((0.123_456, 0.0))
+            .with_precision(4)
+            .is_str("M 0.1235 0"));
+    }
+
+    #[test]
+    fn test_smooth_through() {
+        assert!(PathDefinitionString::smooth_through::<(f64, f64)>(&[], 0.5).is_str(""));
+        assert!(PathDefinitionString::smooth_through(&[(1.0, 2.0)], 0.5).is_str("M 1.00 2.00"));
+
+        assert!(
+            PathDefinitionString::smooth_through(&[(0.0, 0.0), (10.0, 10.0), (20.0, 0.0)], 1.0)
+                .is_str("M 0.00 0.00 C 0.00 0.00, 10.00 10.00, 10.00 10.00 C 10.00 10.00, 20.00 0.00, 20.00 0.00")
+        );
+    }
+
+    #[test]
+    fn test_prefab_shapes() {
+        assert!(
+            PathDefinitionString::rounded_rect((1.0, 1.0), (4.0, 2.0), 0.0)
+                .is_str("M 1.00 1.00 H 5.00 V 3.00 H 1.00 Z")
+        );
+
+        // The radius is clamped to half of the smallest side
+        assert!(PathDefinitionString::rounded_rect((0.0, 0.0), (4.0, 2.0), 5.0)
+            .is_str("M 1.00 0.00 H 3.00 A 1.00 1.00 0.00 0 1 4.00 1.00 V 1.00 A 1.00 1.00 0.00 0 1 3.00 2.00 H 1.00 A 1.00 1.00 0.00 0 1 0.00 1.00 V 1.00 A 1.00 1.00 0.00 0 1 1.00 0.00 Z"));
+
+        assert!(
+            PathDefinitionString::pie_slice((0.0, 0.0), 10.0, 0.0, 270.0)
+                .is_str("M 0.00 0.00 L 10.00 0.00 A 10.00 10.00 0.00 1 1 0.00 -10.00 Z")
+        );
+
+        assert!(PathDefinitionString::donut_segment((50.0, 50.0), 5.0, 10.0, 0.0, 360.0)
+            .is_str("M 60.00 50.00 A 10.00 10.00 0.00 0 1 40.00 50.00 A 10.00 10.00 0.00 0 1 60.00 50.00 Z M 55.00 50.00 A 5.00 5.00 0.00 0 0 45.00 50.00 A 5.00 5.00 0.00 0 0 55.00 50.00 Z"));
+
+        // Positions keep their f64 precision and can be integers
+        assert!(
+            PathDefinitionString::pie_slice((16_777_217.25, 0.0), 1.0, 0.0, 90.0).is_str(
+                "M 16777217.25 0.00 L 16777218.25 0.00 A 1.00 1.00 0.00 0 1 16777217.25 1.00 Z"
+            )
+        );
+        assert!(PathDefinitionString::rounded_rect((1, 1), (4.0, 2.0), 0.0)
+            .is_str("M 1.00 1.00 H 5.00 V 3.00 H 1.00 Z"));
+    }
+
+    #[test]
+    fn test_from_str() {
+        use super::InvalidPathData;
+
+        let path: PathDefinitionString = "M0-1.5.5 10,10 10h-5e1v1e-1 z m 1 1 Q 2 2 3 3 T 4 4"
+            .parse()
+            .unwrap();
+        assert!(path.is_str(
+            "M 0.00 -1.50 L 0.50 10.00 L 10.00 10.00 h -50.00 v 0.10 Z m 1.00 1.00 \
+             Q 2.00 2.00, 3.00 3.00 T 4.00 4.00"
+        ));
+
+        // Flags do not need to be separated
+        let arc: PathDefinitionString = "M 0 0 a 5 5 0 1020 0".parse().unwrap();
+        assert!(arc.is_str("M 0.00 0.00 a 5.00 5.00 0.00 1 0 20.00 0.00"));
+
+        assert_eq!(
+            "M 0 0 L 1".parse::<PathDefinitionString>().unwrap_err(),
+            InvalidPathData(9)
+        );
+        assert_eq!(
+            "M 0 0 Z 1 1".parse::<PathDefinitionString>().unwrap_err(),
+            InvalidPathData(8)
+        );
+        assert_eq!(
+            "10 10".parse::<PathDefinitionString>().unwrap_err(),
+            InvalidPathData(0)
+        );
+        assert_eq!(
+            "M 0 0 X 1".parse::<PathDefinitionString>().unwrap_err(),
+            InvalidPathData(6)
+        );
+        assert!(""
+            .parse::<PathDefinitionString>()
+            .unwrap()
+            .get_commands()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_length() {
+        let length = |d: &str| d.parse::<PathDefinitionString>().unwrap().length();
+
+        assert_eq!(length("M 0 0 h 3 v 4 Z M 10 10"), 12.0);
+        // A straight cubic and quadratic curve
+        assert!((length("M 0 0 C 1 0 2 0 3 0") - 3.0).abs() < 1e-9);
+        assert!((length("M 0 0 Q 1 0 2 0 T 4 0") - 4.0).abs() < 1e-9);
+        // A quarter circle as an arc and the smooth curve reflecting it
+        assert!((length("M 10 0 A 10 10 0 0 1 0 10") - 5.0 * std::f64::consts::PI).abs() < 0.01);
+        assert!((length("M 0 0 C 0 5 5 10 10 10 S 20 5 20 0") - 31.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_geometry_hash() {
+        let parse = |value: &str| value.parse::<PathDefinitionString>().unwrap();
+        let hash = |value: &str| parse(value).geometry_hash(0.01).unwrap();
+
+        // Formatting, relative commands and the kind of command do not matter
+        assert_eq!(hash("M0 0L10 0L10 10Z"), hash("m 0,0 h 10 v 10 z"));
+        assert_eq!(hash("M0 0L10 0L10 10L0 0Z"), hash("M0 0 10 0 10 10Z"));
+        assert_eq!(hash("M0 0L9 0"), hash("M0 0C3 0 6 0 9 0"));
+        assert_eq!(
+            hash("M0 0Q5 5 10 0"),
+            hash("M0 0C3.333333 3.333333 6.666667 3.333333 10 0")
+        );
+        assert_eq!(
+            hash("M10 0A10 10 0 0 1 0 10"),
+            parse("M10 0A10 10 0 0 1 0 10")
+                .arcs_to_cubics()
+                .geometry_hash(0.01)
+                .unwrap()
+        );
+        assert_eq!(hash("M0 0L1.001 0"), hash("M0 0L1 0"));
+
+        // The subpaths and the direction do
+        assert_ne!(hash("M0 0L10 0L10 10Z"), hash("M0 0L10 0L10 10"));
+        assert_ne!(hash("M0 0L1 0L2 0"), hash("M0 0L1 0M1 0L2 0"));
+        assert_ne!(hash("M0 0L1 0"), hash("M1 0L0 0"));
+        assert_eq!(
+            parse("M0 0L1 0").geometry_hash(10.0),
+            parse("M0 0L2 0").geometry_hash(10.0)
+        );
+        assert_eq!(hash("M0 0L1 0Z L0 1"), hash("M0 0L1 0Z M0 0L0 1"));
+
+        // The hash is fixed, so it can be stored
+        assert_eq!(hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(hash("M0 0L1 0"), 0x82dd_6442_5623_0287);
+
+        // Tolerances which are not positive have no hash
+        for tolerance in [0.0, -1.0, f64::NAN] {
+            assert_eq!(parse("M0 0L1 0").geometry_hash(tolerance), None);
+        }
+    }
+
+    #[test]
+    fn test_resample() {
+        let close =
+            |a: (f64, f64), b: (f64, f64)| (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6;
+
+        // Moves between subpaths are not part of the length
+        let path: PathDefinitionString = "M0 0h4M10 10v-4Z".parse().unwrap();
+        let points = path.resample(4);
+        assert_eq!(points.len(), 4);
+        for (point, expected) in
+            points
+                .into_iter()
+                .zip(vec![(0.0, 0.0), (4.0, 0.0), (10.0, 6.0), (10.0, 10.0)])
+        {
+            assert!(close(point, expected), "{:?} != {:?}", point, expected);
+        }
+
+        // Points on a half circle are evenly spaced by angle
+        let arc: PathDefinitionString = "M10 0A10 10 0 0 1 -10 0".parse().unwrap();
+        let points = arc.resample(5);
+        for (index, &(x, y)) in points.iter().enumerate() {
+            let angle = index as f64 * std::f64::consts::FRAC_PI_4;
+            assert!(
+                (x - 10.0 * angle.cos()).abs() < 0.01 && (y - 10.0 * angle.sin()).abs() < 0.01,
+                "{:?}",
+                (x, y)
+            );
+        }
+        let tangents = arc.points_at(vec![0.0, arc.length() / 2.0]);
+        assert!(tangents[0].1 .0.abs() < 1e-9 && tangents[0].1 .1 > 0.0);
+        assert!(tangents[1].1 .0 < 0.0 && tangents[1].1 .1.abs() < 1e-6);
+
+        assert_eq!(arc.resample(1), vec![(10.0, 0.0)]);
+        assert!(arc.resample(0).is_empty());
+        assert!(PathDefinitionString::new().resample(3).is_empty());
+        assert_eq!(
+            PathDefinitionString::new().move_to((1.0, 2.0)).resample(2),
+            vec![(1.0, 2.0); 2]
+        );
+    }
+
+    #[test]
+    fn test_integer_mode() {
+        let icon = PathDefinitionString::integer()
+            .move_to((2, 2))
+            .r_line_to((4, 0))
+            .r_vertical_line_to(-4)
+            .arc_to((10, 10), (3, 3), 0.0, false, true)
+            .curve_to((0.4, 1.6), (1.0, 2.0), (3.0, 4.0));
+        assert!(icon.is_str("M 2 2 l 4 0 v -4 A 3 3 0 0 1 10 10 C 1 2, 3 4, 0 2"));
+
+        // Float and integer points give the same commands
+        let mixed = PathDefinitionString::new()
+            .move_to((2.0_f32, 2.0))
+            .line_to((6.0_f64, 2.0))
+            .line_to((6, 8));
+        assert!(mixed.is_str("M 2.00 2.00 L 6.00 2.00 L 6.00 8.00"));
+    }
 }
@@ -1,11 +1,14 @@
 //! Prelude for this crate, this contains a lot of useful exports
 
+pub use crate::document::Document as SVGDoc;
 pub use crate::Element as SVGElem;
-pub use crate::Point2D;
+pub use crate::{IntPoint2D, Matrix, Point2D};
 
 pub use crate::attributes::Attribute as Attr;
+pub use crate::attributes::AttributeValue as AttrValue;
 pub use crate::tag_name::TagName as Tag;
 
+pub use crate::path::ArcDirection;
 pub use crate::path::PathDefinitionString as PathData;
 
 #[cfg(feature = "parsing")]
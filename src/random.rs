@@ -0,0 +1,136 @@
+//! This module provides deterministic pseudo-random generation for generative graphics.
+//!
+//! The same seed always produces the same sequence of values, on every platform and across
+//! runs, so generated SVGs are reproducible.
+//!
+//! # Examples
+//! ## Jittering the points of a triangle
+//! ```
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::random::Gen;
+//!
+//! let mut gen = Gen::new(42);
+//!
+//! let triangle = PathData::new()
+//!     .move_to(gen.jitter((0.0, 0.0), 1.0))
+//!     .line_to(gen.jitter((10.0, 0.0), 1.0))
+//!     .line_to(gen.jitter((0.0, 10.0), 1.0))
+//!     .close_path();
+//!
+//! let path = SVGElem::new(Tag::Path)
+//!     .set(Attr::Fill, gen.pick(&["red", "green", "blue"]).unwrap())
+//!     .set(Attr::D, triangle);
+//! ```
+
+use crate::Point2D;
+
+/// A seeded pseudo-random generator
+///
+/// # Note
+/// This generator is not cryptographically secure and is only meant for generative graphics.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Gen {
+    state: u64,
+}
+
+impl Gen {
+    /// Creates a new generator from a seed
+    #[inline]
+    pub fn new(seed: u64) -> Gen {
+        Gen { state: seed }
+    }
+
+    /// Returns the next pseudo-random 64 bit number
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64, see https://prng.di.unimi.it/splitmix64.c
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the next pseudo-random number in the range `[0, 1)`
+    #[inline]
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns the next pseudo-random number in the range `[min, max)`
+    #[inline]
+    pub fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.next_f64()
+    }
+
+    /// Moves a point by a random offset of at most `amount` in both the x and y direction
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::random::Gen;
+    ///
+    /// let mut gen = Gen::new(7);
+    /// let (x, y) = gen.jitter((10.0, 10.0), 0.5);
+    ///
+    /// assert!(x >= 9.5 && x <= 10.5);
+    /// assert!(y >= 9.5 && y <= 10.5);
+    /// ```
+    pub fn jitter(&mut self, (x, y): Point2D, amount: f32) -> Point2D {
+        let amount = f64::from(amount);
+        let dx = self.range(-amount, amount);
+        let dy = self.range(-amount, amount);
+
+        ((f64::from(x) + dx) as f32, (f64::from(y) + dy) as f32)
+    }
+
+    /// Picks a random item from a slice, or None if the slice is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::random::Gen;
+    ///
+    /// let palette = ["#264653", "#2a9d8f", "#e9c46a"];
+    /// let mut gen = Gen::new(7);
+    ///
+    /// assert!(palette.contains(gen.pick(&palette).unwrap()));
+    /// assert_eq!(gen.pick::<&str>(&[]), None);
+    /// ```
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+
+        let index = (self.next_u64() % items.len() as u64) as usize;
+        items.get(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gen;
+
+    #[test]
+    fn test_deterministic() {
+        let mut a = Gen::new(1234);
+        let mut b = Gen::new(1234);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+
+        assert_ne!(Gen::new(1).next_u64(), Gen::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut gen = Gen::new(0);
+
+        for _ in 0..1000 {
+            let value = gen.next_f64();
+            assert!((0.0..1.0).contains(&value));
+
+            let value = gen.range(-5.0, 5.0);
+            assert!((-5.0..5.0).contains(&value));
+        }
+    }
+}
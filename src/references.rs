@@ -0,0 +1,301 @@
+//! Helpers to find and rewrite references to ids and classes within an element tree.
+//!
+//! Ids are referenced with `url(#id)` in attribute values, inline styles and stylesheets, with
+//! `#id` in `href` and `xlink:href` attributes and with `#id` selectors in stylesheets. Classes
+//! are referenced by the `class` attribute and `.class` selectors in stylesheets.
+
+use crate::attributes::Attribute;
+use crate::tag_name::TagName;
+use crate::Element;
+
+/// A mapping from an old name to a new name, returning None for names which stay the same
+pub(crate) type Rename<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// Renames all ids and classes within an element and its descendants, including references to them
+pub(crate) fn rewrite_references(element: &mut Element, ids: Rename, classes: Rename) {
+    for (attribute, value) in element.attributes.iter_mut() {
+        let rewritten = match attribute {
+            Attribute::Id => ids(value.trim()),
+            Attribute::Class => {
+                let mut changed = false;
+                let names: Vec<String> = value
+                    .split_whitespace()
+                    .map(|name| match classes(name) {
+                        Some(new_name) => {
+                            changed = true;
+                            new_name
+                        }
+                        None => name.to_string(),
+                    })
+                    .collect();
+
+                if changed {
+                    Some(names.join(" "))
+                } else {
+                    None
+                }
+            }
+            Attribute::Href | Attribute::XlinkHref => value
+                .trim()
+                .strip_prefix('#')
+                .and_then(ids)
+                .map(|id| format!("#{}", id)),
+            _ => Some(rewrite_url_ids(value, ids)).filter(|rewritten| rewritten != value),
+        };
+
+        if let Some(rewritten) = rewritten {
+            *value = rewritten.into();
+        }
+    }
+
+    if element.tag_name == TagName::Style {
+        if let Some(css) = &element.inner {
+            element.inner = Some(rewrite_css(css, ids, classes));
+        }
+    }
+
+    for child in element.children.iter_mut() {
+        rewrite_references(child, ids, classes);
+    }
+}
+
+/// Returns whether a character can be part of a CSS identifier
+fn is_ident_char(character: char) -> bool {
+    character.is_alphanumeric() || character == '-' || character == '_' || !character.is_ascii()
+}
+
+/// Rewrites the ids of all `url(#id)` references in a value
+pub(crate) fn rewrite_url_ids(value: &str, ids: Rename) -> String {
+    let mut output = String::new();
+    let mut rest = value;
+
+    while let Some(start) = find_ignore_case(rest, "url(") {
+        let (before, after) = rest.split_at(start + "url(".len());
+        output.push_str(before);
+
+        let inner = after.trim_start_matches(|c: char| c.is_whitespace() || c == '"' || c == '\'');
+        output.push_str(&after[..after.len() - inner.len()]);
+
+        rest = inner;
+        if let Some(reference) = inner.strip_prefix('#') {
+            let end = reference
+                .find(|c: char| c == ')' || c == '"' || c == '\'' || c.is_whitespace())
+                .unwrap_or(reference.len());
+            let id = &reference[..end];
+
+            output.push('#');
+            output.push_str(&ids(id).unwrap_or_else(|| id.to_string()));
+            rest = &reference[end..];
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// Returns the targets of all `url(...)` references and `@import` rules in a value, without
+/// quotes
+pub(crate) fn url_targets(value: &str) -> Vec<&str> {
+    let mut targets = Vec::new();
+
+    for (prefix, closing) in [("url(", ')'), ("@import", ';')].iter() {
+        let mut rest = value;
+        while let Some(start) = find_ignore_case(rest, prefix) {
+            let inner = rest[start + prefix.len()..].trim_start();
+            let (target, after) = match inner.chars().next() {
+                Some(quote) if quote == '"' || quote == '\'' => {
+                    let inner = &inner[1..];
+                    let end = inner.find(quote).unwrap_or(inner.len());
+                    (&inner[..end], &inner[end..])
+                }
+                _ => {
+                    let end = inner
+                        .find(|c: char| c == *closing || c.is_whitespace())
+                        .unwrap_or(inner.len());
+                    (&inner[..end], &inner[end..])
+                }
+            };
+
+            // `@import url(...)` is already found as an url
+            if !target.is_empty() && find_ignore_case(target, "url(") != Some(0) {
+                targets.push(target.trim());
+            }
+            rest = after;
+        }
+    }
+
+    targets
+}
+
+/// Returns the byte offset of the first occurrence of an ASCII text, ignoring ASCII case like
+/// CSS does for function names and at-rules
+fn find_ignore_case(value: &str, text: &str) -> Option<usize> {
+    value
+        .as_bytes()
+        .windows(text.len())
+        .position(|window| window.eq_ignore_ascii_case(text.as_bytes()))
+}
+
+/// Returns whether a reference points outside of the document, meaning it is an absolute URL
+/// with a scheme other than `data:` or a protocol relative URL
+pub(crate) fn is_external(reference: &str) -> bool {
+    let reference = reference.trim();
+    if reference.starts_with("//") {
+        return true;
+    }
+
+    match reference.find(':') {
+        Some(end) => {
+            let scheme = &reference[..end];
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+                && !scheme.eq_ignore_ascii_case("data")
+        }
+        None => false,
+    }
+}
+
+/// Rewrites `#id` and `.class` selectors and `url(#id)` references in a stylesheet
+pub(crate) fn rewrite_css(css: &str, ids: Rename, classes: Rename) -> String {
+    let mut output = String::new();
+    // Whether each open block contains rules (e.g. `@media`) instead of declarations
+    let mut blocks: Vec<bool> = Vec::new();
+    let mut segment = String::new();
+
+    for character in css.chars() {
+        let in_rules = blocks.last().copied().unwrap_or(true);
+
+        match character {
+            '{' if in_rules => {
+                let is_at_rule = segment.trim_start().starts_with('@');
+                if is_at_rule {
+                    output.push_str(&segment);
+                } else {
+                    output.push_str(&rewrite_selector(&segment, ids, classes));
+                }
+                output.push('{');
+                blocks.push(is_at_rule);
+                segment.clear();
+            }
+            '}' => {
+                if in_rules {
+                    output.push_str(&segment);
+                } else {
+                    output.push_str(&rewrite_url_ids(&segment, ids));
+                }
+                output.push('}');
+                blocks.pop();
+                segment.clear();
+            }
+            _ => segment.push(character),
+        }
+    }
+
+    output.push_str(&segment);
+    output
+}
+
+/// Rewrites the `#id` and `.class` parts of a selector list
+fn rewrite_selector(selector: &str, ids: Rename, classes: Rename) -> String {
+    let mut output = String::new();
+    let mut chars = selector.chars().peekable();
+    let mut in_brackets = false;
+
+    while let Some(character) = chars.next() {
+        output.push(character);
+
+        match character {
+            '[' => in_brackets = true,
+            ']' => in_brackets = false,
+            '#' | '.' if !in_brackets => {
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if !is_ident_char(next) {
+                        break;
+                    }
+                    name.push(next);
+                    chars.next();
+                }
+
+                let rename = if character == '#' { ids } else { classes };
+                output.push_str(&rename(&name).unwrap_or(name));
+            }
+            _ => (),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_external, rewrite_css, rewrite_url_ids, url_targets};
+
+    fn rename(name: &str) -> Option<String> {
+        if name == "a" {
+            Some(String::from("b"))
+        } else {
+            None
+        }
+    }
+
+    #[test]
+    fn test_rewrite_url_ids() {
+        assert_eq!(rewrite_url_ids("url(#a)", &rename), "url(#b)");
+        assert_eq!(
+            rewrite_url_ids("url( '#a' ) red", &rename),
+            "url( '#b' ) red"
+        );
+        assert_eq!(
+            rewrite_url_ids("url(#ab) url(#a)", &rename),
+            "url(#ab) url(#b)"
+        );
+        assert_eq!(rewrite_url_ids("url(image.png)", &rename), "url(image.png)");
+        assert_eq!(rewrite_url_ids("#a", &rename), "#a");
+        assert_eq!(rewrite_url_ids("URL(#a)", &rename), "URL(#b)");
+    }
+
+    #[test]
+    fn test_url_targets() {
+        assert_eq!(
+            url_targets("url(#a) url( 'https://a.b/c.png' ) url(\"x y\")"),
+            vec!["#a", "https://a.b/c.png", "x y"]
+        );
+        assert_eq!(
+            url_targets("@import 'theme.css'; @import url(//a.b/c.css);"),
+            vec!["//a.b/c.css", "theme.css"]
+        );
+        assert_eq!(
+            url_targets("URL(https://a.b/c.png) Url('d.png')"),
+            vec!["https://a.b/c.png", "d.png"]
+        );
+        assert_eq!(
+            url_targets("@IMPORT 'theme.css'; @Import URL(//a.b/c.css);"),
+            vec!["//a.b/c.css", "theme.css"]
+        );
+
+        assert!(is_external("https://example.com/image.png"));
+        assert!(is_external(" //example.com/image.png"));
+        assert!(!is_external("data:image/png;base64,AAAA"));
+        assert!(!is_external("#gradient"));
+        assert!(!is_external("images/image.png"));
+    }
+
+    #[test]
+    fn test_rewrite_css() {
+        assert_eq!(
+            rewrite_css(
+                "#a, .a > rect.ab { fill: url(#a); stroke: #a } @media print { g.a { color: red } }",
+                &rename,
+                &rename
+            ),
+            "#b, .b > rect.ab { fill: url(#b); stroke: #a } @media print { g.b { color: red } }"
+        );
+        assert_eq!(
+            rewrite_css("[href='#a'] { opacity: .5 }", &rename, &rename),
+            "[href='#a'] { opacity: .5 }"
+        );
+    }
+}
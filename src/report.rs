@@ -0,0 +1,191 @@
+//! This module provides a [Report](struct.Report.html) with statistics about the size of a
+//! [Document](../document/struct.Document.html), e.g. to show before and after numbers of
+//! optimizations.
+//!
+//! # Examples
+//! ## Printing a report
+//! ```
+//! use svg_definitions::prelude::*;
+//!
+//! let document = SVGDoc::new(
+//!     SVGElem::new(Tag::Svg)
+//!         .append(SVGElem::new(Tag::Rect).set(Attr::Width, 10.25).set(Attr::Height, 5)),
+//! );
+//!
+//! println!("{}", document.report());
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::attributes::Attribute;
+use crate::tag_name::TagName;
+use crate::Element;
+
+/// Statistics about an element tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The total amount of elements
+    pub nodes: usize,
+
+    /// The amount of elements per tag
+    pub tag_counts: HashMap<TagName, usize>,
+
+    /// The amount of serialized bytes per attribute, including the name, quotes and spacing
+    pub attribute_bytes: HashMap<Attribute, usize>,
+
+    /// The amount of definitions, meaning direct children of `<defs>` elements
+    pub defs: usize,
+
+    /// The amount of numbers in attribute values per amount of decimal places
+    pub precision_histogram: BTreeMap<usize, usize>,
+
+    /// The estimated size in bytes of the serialized tree
+    pub serialized_size: usize,
+}
+
+impl Report {
+    /// Creates a report for an element and all its descendants
+    pub fn new(root: &Element) -> Report {
+        let mut report = Report {
+            nodes: 0,
+            tag_counts: HashMap::new(),
+            attribute_bytes: HashMap::new(),
+            defs: 0,
+            precision_histogram: BTreeMap::new(),
+            serialized_size: root.to_string().len(),
+        };
+        report.add_element(root);
+        report
+    }
+
+    fn add_element(&mut self, element: &Element) {
+        self.nodes += 1;
+        *self
+            .tag_counts
+            .entry(element.get_tag_name().clone())
+            .or_insert(0) += 1;
+
+        if *element.get_tag_name() == TagName::Defs {
+            self.defs += element.get_children().len();
+        }
+
+        for (attribute, value) in element.get_attributes().iter() {
+            // ` name="value"`
+            let bytes = attribute.to_string().len() + value.len() + 4;
+            *self.attribute_bytes.entry(attribute.clone()).or_insert(0) += bytes;
+
+            for decimals in decimal_places(value) {
+                *self.precision_histogram.entry(decimals).or_insert(0) += 1;
+            }
+        }
+
+        for child in element.get_children() {
+            self.add_element(child);
+        }
+    }
+}
+
+/// Returns the amount of decimal places of every number in a string
+fn decimal_places(value: &str) -> Vec<usize> {
+    let mut places = Vec::new();
+    let mut chars = value.chars().peekable();
+
+    while let Some(character) = chars.next() {
+        if !character.is_ascii_digit() && character != '.' {
+            continue;
+        }
+
+        let mut decimals = None;
+        if character == '.' {
+            decimals = Some(0);
+        }
+        while let Some(&next) = chars.peek() {
+            match (next, decimals) {
+                ('0'..='9', Some(count)) => decimals = Some(count + 1),
+                ('0'..='9', None) => (),
+                ('.', None) => decimals = Some(0),
+                _ => break,
+            }
+            chars.next();
+        }
+
+        match decimals {
+            // A lone dot is not a number
+            Some(0) if character == '.' => (),
+            decimals => places.push(decimals.unwrap_or(0)),
+        }
+    }
+
+    places
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Nodes: {}", self.nodes)?;
+        writeln!(f, "Definitions: {}", self.defs)?;
+        writeln!(f, "Estimated size: {} bytes", self.serialized_size)?;
+
+        writeln!(f, "Tags:")?;
+        let tags: BTreeMap<String, usize> = self
+            .tag_counts
+            .iter()
+            .map(|(tag, count)| (tag.to_string(), *count))
+            .collect();
+        for (tag, count) in tags {
+            writeln!(f, "  {}: {}", tag, count)?;
+        }
+
+        writeln!(f, "Attribute bytes:")?;
+        let attributes: BTreeMap<String, usize> = self
+            .attribute_bytes
+            .iter()
+            .map(|(attribute, bytes)| (attribute.to_string(), *bytes))
+            .collect();
+        for (attribute, bytes) in attributes {
+            writeln!(f, "  {}: {}", attribute, bytes)?;
+        }
+
+        writeln!(f, "Decimal places:")?;
+        for (decimals, count) in self.precision_histogram.iter() {
+            writeln!(f, "  {}: {}", decimals, count)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decimal_places, Report};
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    #[test]
+    fn test_decimal_places() {
+        assert_eq!(decimal_places("M 3.00 3.5 L 10 .25"), vec![2, 1, 0, 2]);
+        assert_eq!(decimal_places("translate(1.125, -2)"), vec![3, 0]);
+        assert_eq!(decimal_places("url(#a.b)"), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_report() {
+        let root = Element::new(TagName::Svg)
+            .append(
+                Element::new(TagName::Defs)
+                    .append(Element::new(TagName::LinearGradient))
+                    .append(Element::new(TagName::Filter)),
+            )
+            .append(Element::new(TagName::Rect).set(Attribute::Width, "10.5"))
+            .append(Element::new(TagName::Rect).set(Attribute::Width, "3"));
+
+        let report = Report::new(&root);
+        assert_eq!(report.nodes, 6);
+        assert_eq!(report.defs, 2);
+        assert_eq!(report.tag_counts[&TagName::Rect], 2);
+        assert_eq!(report.attribute_bytes[&Attribute::Width], 13 + 10);
+        assert_eq!(report.precision_histogram[&0], 1);
+        assert_eq!(report.precision_histogram[&1], 1);
+        assert_eq!(report.serialized_size, root.to_string().len());
+    }
+}
@@ -0,0 +1,620 @@
+//! This module provides the [SpriteSheet](struct.SpriteSheet.html), which collects icons as
+//! `<symbol>` elements in a single SVG, and [use_icon](fn.use_icon.html) to place them. For
+//! consumers which do not support `<use>`, [resolve_uses](fn.resolve_uses.html) inlines them.
+//!
+//! # Examples
+//! ## Inlining icons into a page
+//! ```
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::sprites::{self, SpriteSheet};
+//!
+//! let sheet = SpriteSheet::new()
+//!     .add(
+//!         "close",
+//!         SVGElem::new(Tag::Svg)
+//!             .set(Attr::ViewBox, "0 0 24 24")
+//!             .append(SVGElem::new(Tag::Path).set(Attr::D, "M 6 6 L 18 18 M 18 6 L 6 18")),
+//!     )
+//!     .add(
+//!         "dot",
+//!         SVGElem::new(Tag::Circle).set(Attr::Cx, 5).set(Attr::Cy, 5).set(Attr::R, 5),
+//!     );
+//!
+//! // The hidden sprite is added once, after which icons are placed anywhere on the page
+//! let sprite = sheet.build();
+//! let button = SVGElem::new(Tag::Svg).append(sprites::use_icon("close", 0.0, 0.0, 16.0));
+//! # assert_eq!(sprite.get_children().len(), 2);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use crate::attributes::Attribute;
+use crate::document::{count_nodes, Document};
+use crate::geometry::{self, BoundingBox};
+use crate::limits::{LimitError, Limits};
+use crate::references;
+use crate::tag_name::TagName;
+use crate::Element;
+
+/// Returns the view box of an icon, from its `viewBox`, its size or the bounds of its content
+fn icon_view_box(icon: &Element) -> Option<BoundingBox> {
+    if icon.tag_name != TagName::Svg {
+        return geometry::bounding_box(icon);
+    }
+
+    let size = |attribute: Attribute| {
+        icon.attributes
+            .get(&attribute)
+            .and_then(|value| geometry::user_units(value))
+    };
+    geometry::view_box(icon)
+        .or_else(|| match (size(Attribute::Width), size(Attribute::Height)) {
+            (Some(width), Some(height)) => Some(BoundingBox::new(0.0, 0.0, width, height)),
+            _ => None,
+        })
+        .or_else(|| {
+            geometry::bounding_box(&Element {
+                tag_name: TagName::G,
+                ..icon.clone()
+            })
+        })
+}
+
+/// SpriteSheet collects named icons as `<symbol>` elements, which are placed with
+/// [use_icon](fn.use_icon.html)
+///
+/// # Note / Arguments
+/// Every symbol gets the name of the icon as its id and its own view box, so icons of different
+/// sizes can be placed at the same size. The ids and classes inside an icon are prefixed with
+/// its name and a dash, so icons cannot reference each other's gradients or styles.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::sprites::SpriteSheet;
+///
+/// let sheet = SpriteSheet::new().add(
+///     "square",
+///     SVGElem::new(Tag::Svg)
+///         .set(Attr::Width, 10)
+///         .set(Attr::Height, 10)
+///         .set(Attr::Fill, "red")
+///         .append(SVGElem::new(Tag::Rect).set(Attr::Width, 10).set(Attr::Height, 10)),
+/// );
+///
+/// assert!(sheet.contains("square"));
+/// assert_eq!(
+///     sheet.build().to_string(),
+///     "<svg aria-hidden=\"true\" height=\"0\" style=\"position: absolute\" width=\"0\" \
+///      xmlns=\"http://www.w3.org/2000/svg\"><symbol fill=\"red\" id=\"square\" \
+///      viewBox=\"0 0 10 10\"><rect height=\"10\" width=\"10\"/></symbol></svg>"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SpriteSheet {
+    symbols: Vec<(String, Element)>,
+}
+
+impl SpriteSheet {
+    /// Creates a new empty sprite sheet
+    pub fn new() -> SpriteSheet {
+        SpriteSheet::default()
+    }
+
+    /// Adds an icon with a certain name, replacing an earlier icon with the same name
+    ///
+    /// # Note / Arguments
+    /// The icon is either an `<svg>` element, whose content and attributes are moved into the
+    /// symbol, or a single element. The view box is taken from the `viewBox`, `width` and
+    /// `height` of an `<svg>`, or from the
+    /// [bounding_box](../geometry/fn.bounding_box.html) of the content if those are not set.
+    pub fn add(mut self, name: &str, icon: Element) -> Self {
+        let view_box = icon_view_box(&icon);
+
+        let mut document = Document::new(icon);
+        document.scope_ids(&format!("{}-", name));
+        let icon = document.into_root();
+
+        let mut symbol = if icon.tag_name == TagName::Svg {
+            let mut symbol = Element {
+                tag_name: TagName::Symbol,
+                ..icon
+            };
+            symbol.attributes.retain(|attribute, _| {
+                !matches!(
+                    attribute,
+                    Attribute::X
+                        | Attribute::Y
+                        | Attribute::Width
+                        | Attribute::Height
+                        | Attribute::Version
+                        | Attribute::BaseProfile
+                ) && !attribute.name().starts_with("xmlns")
+            });
+            symbol
+        } else {
+            Element::new(TagName::Symbol).append(icon)
+        };
+
+        symbol.set_attribute(Attribute::Id, String::from(name));
+        if let Some(view_box) = view_box {
+            symbol.set_attribute(
+                Attribute::ViewBox,
+                format!(
+                    "{} {} {} {}",
+                    view_box.x, view_box.y, view_box.width, view_box.height
+                ),
+            );
+        }
+
+        match self.symbols.iter_mut().find(|(other, _)| other == name) {
+            Some((_, existing)) => *existing = symbol,
+            None => self.symbols.push((String::from(name), symbol)),
+        }
+        self
+    }
+
+    /// Returns whether an icon with a certain name has been added
+    pub fn contains(&self, name: &str) -> bool {
+        self.symbols.iter().any(|(other, _)| other == name)
+    }
+
+    /// Returns the names of the icons in the order they were added
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.symbols.iter().map(|(name, _)| &name[..])
+    }
+
+    /// Assembles the sprite, an `<svg>` element with a `<symbol>` per icon
+    ///
+    /// # Note / Arguments
+    /// The sprite takes up no space and is hidden from assistive technology, so it can be
+    /// inlined into an HTML page or served as a file. It is not hidden with `display: none`,
+    /// which would break the gradients and filters of its icons in some browsers.
+    pub fn build(&self) -> Element {
+        self.symbols.iter().fold(
+            Element::new(TagName::Svg)
+                .set_namespace("", "http://www.w3.org/2000/svg")
+                .set(Attribute::Width, 0)
+                .set(Attribute::Height, 0)
+                .set(Attribute::Style, "position: absolute")
+                .set(Attribute::custom("aria-hidden"), true),
+            |sprite, (_, symbol)| sprite.append(symbol.clone()),
+        )
+    }
+}
+
+/// Returns a `<use>` element placing an icon of a [SpriteSheet](struct.SpriteSheet.html) in the
+/// same document at a position, scaled to fit a square of `size`
+///
+/// # Examples
+/// ```
+/// use svg_definitions::sprites;
+///
+/// assert_eq!(
+///     sprites::use_icon("close", 10.0, 20.0, 24.0).to_string(),
+///     "<use height=\"24\" href=\"#close\" width=\"24\" x=\"10\" y=\"20\"/>"
+/// );
+/// ```
+pub fn use_icon(name: &str, x: f64, y: f64, size: f64) -> Element {
+    use_external_icon("", name, x, y, size)
+}
+
+/// Returns a `<use>` element placing an icon of a [SpriteSheet](struct.SpriteSheet.html) which
+/// is served as a separate file, e.g. `icons.svg`
+///
+/// # Note / Arguments
+/// Browsers only load sprites from the same origin as the page.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::sprites;
+///
+/// let icon = sprites::use_external_icon("/static/icons.svg", "close", 0.0, 0.0, 16.0);
+/// # assert_eq!(icon.get_attributes()[&svg_definitions::prelude::Attr::Href], "/static/icons.svg#close");
+/// ```
+pub fn use_external_icon(file: &str, name: &str, x: f64, y: f64, size: f64) -> Element {
+    Element::new(TagName::Use)
+        .set(Attribute::Href, format!("{}#{}", file, name))
+        .set(Attribute::X, x)
+        .set(Attribute::Y, y)
+        .set(Attribute::Width, size)
+        .set(Attribute::Height, size)
+}
+
+/// Replaces every `<use>` element which references an element in the same tree by a copy of the
+/// referenced element, returning the amount of replaced `<use>` elements
+///
+/// # Note / Arguments
+/// This is for consumers which do not support `<use>`, like some laser cutter toolchains. Every
+/// `<use>` becomes a `<g>` with its other attributes, which is translated by its `x` and `y`. A
+/// referenced `<symbol>` becomes a nested `<svg>`, which like a referenced `<svg>` gets the
+/// `width` and `height` of the `<use>` if they are set. The ids within a copy get a number,
+/// e.g. `gradient-2`, and references within the copy are rewritten to them. Uses within the
+/// referenced elements are replaced as well.
+///
+/// Only references in attributes are rewritten, so `#id` selectors in a `<style>` element no
+/// longer match the copies. Style the copies with classes instead, which are kept.
+///
+/// References to other files, missing elements and circular references, like a use within the
+/// element it references, are left as they are, as are uses with an `x` or `y` which is not in
+/// user units. The referenced elements stay in the tree.
+///
+/// Nested uses multiply, so a small tree can expand into a huge one. Use
+/// [try_resolve_uses](fn.try_resolve_uses.html) for trees from untrusted sources.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::sprites::{self, SpriteSheet};
+///
+/// let sheet = SpriteSheet::new().add(
+///     "dot",
+///     SVGElem::new(Tag::Circle).set(Attr::Cx, 5).set(Attr::Cy, 5).set(Attr::R, 5),
+/// );
+/// let mut svg = SVGElem::new(Tag::Svg)
+///     .append(sheet.build())
+///     .append(sprites::use_icon("dot", 20.0, 0.0, 10.0));
+///
+/// assert_eq!(sprites::resolve_uses(&mut svg), 1);
+/// assert_eq!(*svg.get_children()[1].get_tag_name(), Tag::G);
+/// assert_eq!(svg.get_children()[1].get_attributes()[&Attr::Transform], "translate(20 0)");
+/// ```
+pub fn resolve_uses(root: &mut Element) -> usize {
+    try_resolve_uses(root, &Limits::default()).expect("no limits are exceeded")
+}
+
+/// Replaces the `<use>` elements like [resolve_uses](fn.resolve_uses.html), as long as the
+/// resulting tree stays within [Limits](../limits/struct.Limits.html)
+///
+/// # Note / Arguments
+/// The amount of elements is checked before every copy, so the expansion stops as soon as the
+/// tree would get too large. If the resulting tree exceeds the limits, the tree is left as it
+/// was.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::limits::{LimitError, Limits};
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::sprites;
+///
+/// // Every level uses the level below it ten times
+/// let mut svg = SVGElem::new(Tag::Svg).append(SVGElem::new(Tag::Rect).set(Attr::Id, "l0"));
+/// for level in 1..10 {
+///     let group = (0..10).fold(SVGElem::new(Tag::G).set(Attr::Id, format!("l{}", level)), |group, _| {
+///         group.append(SVGElem::new(Tag::Use).set(Attr::Href, format!("#l{}", level - 1)))
+///     });
+///     svg = svg.append(group);
+/// }
+///
+/// let limits = Limits { max_nodes: Some(10_000), ..Limits::default() };
+/// let original = svg.to_string();
+/// assert_eq!(
+///     sprites::try_resolve_uses(&mut svg, &limits),
+///     Err(LimitError::TooManyNodes { limit: 10_000 })
+/// );
+/// assert_eq!(svg.to_string(), original);
+/// ```
+pub fn try_resolve_uses(root: &mut Element, limits: &Limits) -> Result<usize, LimitError> {
+    let mut targets = HashMap::new();
+    collect_targets(root, &mut targets);
+    let mut resolver = Resolver {
+        taken: targets.keys().cloned().collect(),
+        targets,
+        nodes: count_nodes(root),
+        max_nodes: limits.max_nodes,
+    };
+
+    let mut resolved_root = root.clone();
+    let resolved = resolver.resolve_element(&mut resolved_root, &mut Vec::new())?;
+    limits.check(&resolved_root)?;
+    *root = resolved_root;
+    Ok(resolved)
+}
+
+/// Collects copies of the elements with an id, together with their amount of elements, by their
+/// id, where the first element with an id wins
+fn collect_targets(element: &Element, targets: &mut HashMap<String, (Element, usize)>) {
+    if let Some(id) = element.attributes.get(&Attribute::Id) {
+        targets
+            .entry(id.trim().to_string())
+            .or_insert_with(|| (element.clone(), count_nodes(element)));
+    }
+    for child in element.children.iter() {
+        collect_targets(child, targets);
+    }
+}
+
+/// The state of replacing the uses of a tree
+struct Resolver {
+    targets: HashMap<String, (Element, usize)>,
+    taken: HashSet<String>,
+
+    /// The amount of elements the tree has with the copies made so far
+    nodes: usize,
+    max_nodes: Option<usize>,
+}
+
+impl Resolver {
+    /// Replaces the uses within an element, where `visiting` holds the ids of the ancestors and
+    /// of the elements which are being copied, returning the amount of replaced uses
+    fn resolve_element(
+        &mut self,
+        element: &mut Element,
+        visiting: &mut Vec<String>,
+    ) -> Result<usize, LimitError> {
+        if element.tag_name == TagName::Use {
+            return Ok(match self.instantiate(element, visiting)? {
+                Some((instance, resolved)) => {
+                    *element = instance;
+                    resolved + 1
+                }
+                None => 0,
+            });
+        }
+
+        // A use within the element it references would contain itself
+        let id = element
+            .attributes
+            .get(&Attribute::Id)
+            .map(|id| id.trim().to_string());
+        let ancestors = visiting.len();
+        visiting.extend(id);
+        let resolved = element.children.iter_mut().try_fold(0, |resolved, child| {
+            Ok(resolved + self.resolve_element(child, visiting)?)
+        });
+        visiting.truncate(ancestors);
+        resolved
+    }
+
+    /// Returns the `<g>` replacing a `<use>` together with the amount of uses replaced within
+    /// it, or None if the use cannot be replaced
+    fn instantiate(
+        &mut self,
+        use_element: &Element,
+        visiting: &mut Vec<String>,
+    ) -> Result<Option<(Element, usize)>, LimitError> {
+        let attributes = &use_element.attributes;
+        let id = match attributes
+            .get(&Attribute::Href)
+            .or_else(|| attributes.get(&Attribute::XlinkHref))
+            .and_then(|href| href.trim().strip_prefix('#'))
+        {
+            Some(id) if !visiting.iter().any(|visited| visited == id) => id,
+            _ => return Ok(None),
+        };
+        let (target, size) = match self.targets.get(id) {
+            Some(target) => target,
+            None => return Ok(None),
+        };
+
+        let offset = |attribute: Attribute| match attributes.get(&attribute) {
+            Some(value) => geometry::user_units(value),
+            None => Some(0.0),
+        };
+        let (x, y) = match (offset(Attribute::X), offset(Attribute::Y)) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Ok(None),
+        };
+
+        // The use is replaced by a group containing the copy
+        self.nodes += size;
+        if let Some(limit) = self.max_nodes.filter(|limit| self.nodes > *limit) {
+            return Err(LimitError::TooManyNodes { limit });
+        }
+
+        let mut content = target.clone();
+        let resolved = self.resolve_element(&mut content, visiting)?;
+
+        // The copy must not repeat the ids of the tree
+        let mut renamed = HashMap::new();
+        let taken = &mut self.taken;
+        collect_ids(&content, &mut |id| {
+            let new_id = (2..)
+                .map(|number| format!("{}-{}", id, number))
+                .find(|new_id| !taken.contains(new_id))
+                .expect("a free id exists");
+            taken.insert(new_id.clone());
+            renamed.insert(id.to_string(), new_id);
+        });
+        references::rewrite_references(&mut content, &|id| renamed.get(id).cloned(), &|_| None);
+
+        if let TagName::Symbol | TagName::Svg = content.tag_name {
+            content.tag_name = TagName::Svg;
+            for attribute in [Attribute::Width, Attribute::Height] {
+                if let Some(size) = attributes.get(&attribute) {
+                    content.attributes.insert(attribute, size.clone());
+                }
+            }
+        }
+
+        let mut group = Element::new(TagName::G);
+        for (attribute, value) in attributes.iter() {
+            if let Attribute::Href
+            | Attribute::XlinkHref
+            | Attribute::X
+            | Attribute::Y
+            | Attribute::Width
+            | Attribute::Height = attribute
+            {
+                continue;
+            }
+            group.attributes.insert(attribute.clone(), value.clone());
+        }
+        if x != 0.0 || y != 0.0 {
+            let translate = format!("translate({} {})", x, y);
+            let transform = match attributes.get(&Attribute::Transform) {
+                Some(transform) => format!("{} {}", transform, translate),
+                None => translate,
+            };
+            group.set_attribute(Attribute::Transform, transform);
+        }
+
+        Ok(Some((group.append(content), resolved)))
+    }
+}
+
+/// Calls `found` with every id within an element and its descendants
+fn collect_ids<F: FnMut(&str)>(element: &Element, found: &mut F) {
+    if let Some(id) = element.attributes.get(&Attribute::Id) {
+        found(id.trim());
+    }
+    for child in element.children.iter() {
+        collect_ids(child, found);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_uses, try_resolve_uses, SpriteSheet};
+    use crate::attributes::Attribute;
+    use crate::document::count_nodes;
+    use crate::limits::{LimitError, Limits};
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    #[test]
+    fn test_sprite_sheet() {
+        let gradient_icon = |fill: &str| {
+            Element::new(TagName::Svg)
+                .set_namespace("", "http://www.w3.org/2000/svg")
+                .set(Attribute::ViewBox, "0 0 24 24")
+                .set(Attribute::Width, 48)
+                .append(
+                    Element::new(TagName::LinearGradient)
+                        .set(Attribute::Id, "gradient")
+                        .append(Element::new(TagName::Stop).set(Attribute::StopColor, fill)),
+                )
+                .append(Element::new(TagName::Rect).set(Attribute::Fill, "url(#gradient)"))
+        };
+
+        let sheet = SpriteSheet::new()
+            .add("a", gradient_icon("red"))
+            .add("b", gradient_icon("blue"))
+            .add("a", gradient_icon("green"))
+            .add("c", Element::new(TagName::Svg));
+        assert_eq!(sheet.names().collect::<Vec<_>>(), ["a", "b", "c"]);
+
+        let sprite = sheet.build();
+        let symbols = sprite.get_children();
+        assert_eq!(
+            symbols[0].to_string(),
+            "<symbol id=\"a\" viewBox=\"0 0 24 24\"><linearGradient id=\"a-gradient\">\
+             <stop stop-color=\"green\"/></linearGradient><rect fill=\"url(#a-gradient)\"/>\
+             </symbol>"
+        );
+        assert_eq!(
+            symbols[1].get_children()[1].get_attributes()[&Attribute::Fill],
+            "url(#b-gradient)"
+        );
+        assert_eq!(symbols[2].to_string(), "<symbol id=\"c\"/>");
+    }
+
+    #[test]
+    fn test_resolve_uses() {
+        let symbol = Element::new(TagName::Symbol)
+            .set(Attribute::Id, "icon")
+            .set(Attribute::ViewBox, "0 0 10 10")
+            .append(
+                Element::new(TagName::LinearGradient)
+                    .set(Attribute::Id, "fill")
+                    .append(Element::new(TagName::Stop).set(Attribute::StopColor, "red")),
+            )
+            .append(
+                Element::new(TagName::Rect)
+                    .set(Attribute::Id, "box")
+                    .set(Attribute::Fill, "url(#fill)"),
+            )
+            .append(Element::new(TagName::Use).set(Attribute::Href, "#box"));
+        let mut svg = Element::new(TagName::Svg)
+            .append(Element::new(TagName::Defs).append(symbol))
+            .append(
+                Element::new(TagName::Use)
+                    .set(Attribute::XlinkHref, "#icon")
+                    .set(Attribute::Y, 5)
+                    .set(Attribute::Width, 20)
+                    .set(Attribute::Transform, "scale(2)")
+                    .set(Attribute::Class, "icon"),
+            )
+            .append(Element::new(TagName::Use).set(Attribute::Href, "#icon"))
+            .append(
+                Element::new(TagName::G)
+                    .set(Attribute::Id, "loop")
+                    .append(Element::new(TagName::Use).set(Attribute::Href, "#loop")),
+            )
+            .append(Element::new(TagName::Use).set(Attribute::Href, "icons.svg#icon"))
+            .append(
+                Element::new(TagName::Use)
+                    .set(Attribute::Href, "#box")
+                    .set(Attribute::X, "50%"),
+            );
+
+        // The symbol and both copies of it contain a resolved use of the box
+        assert_eq!(resolve_uses(&mut svg), 5);
+        let children = svg.get_children();
+        assert_eq!(
+            children[1].to_string(),
+            "<g class=\"icon\" transform=\"scale(2) translate(0 5)\">\
+               <svg id=\"icon-2\" viewBox=\"0 0 10 10\" width=\"20\">\
+                 <linearGradient id=\"fill-2\"><stop stop-color=\"red\"/></linearGradient>\
+                 <rect fill=\"url(#fill-2)\" id=\"box-4\"/>\
+                 <g><rect fill=\"url(#fill-2)\" id=\"box-3-2\"/></g>\
+               </svg>\
+             </g>"
+        );
+        assert_eq!(
+            children[2].get_children()[0].get_attributes()[&Attribute::Id],
+            "icon-3"
+        );
+
+        // The circular, external and percentage uses are kept
+        assert_eq!(*children[3].get_children()[0].get_tag_name(), TagName::Use);
+        assert_eq!(*children[4].get_tag_name(), TagName::Use);
+        assert_eq!(*children[5].get_tag_name(), TagName::Use);
+    }
+
+    #[test]
+    fn test_try_resolve_uses() {
+        let svg = Element::new(TagName::Svg)
+            .append(
+                Element::new(TagName::G)
+                    .set(Attribute::Id, "pair")
+                    .append(Element::new(TagName::Rect))
+                    .append(Element::new(TagName::Rect)),
+            )
+            .append(Element::new(TagName::Use).set(Attribute::Href, "#pair"))
+            .append(Element::new(TagName::Use).set(Attribute::Href, "#pair"));
+
+        let mut resolved = svg.clone();
+        assert_eq!(resolve_uses(&mut resolved), 2);
+        let nodes = count_nodes(&resolved);
+        assert_eq!(nodes, 12);
+
+        // The limit on the amount of elements is exact
+        let limits = |max_nodes| Limits {
+            max_nodes: Some(max_nodes),
+            ..Limits::default()
+        };
+        let mut within = svg.clone();
+        assert_eq!(try_resolve_uses(&mut within, &limits(nodes)), Ok(2));
+        assert_eq!(within.to_string(), resolved.to_string());
+
+        let mut exceeding = svg.clone();
+        assert_eq!(
+            try_resolve_uses(&mut exceeding, &limits(nodes - 1)),
+            Err(LimitError::TooManyNodes { limit: nodes - 1 })
+        );
+        assert_eq!(exceeding.to_string(), svg.to_string());
+
+        // The other limits are checked on the resulting tree
+        let mut too_deep = svg.clone();
+        let limits = Limits {
+            max_depth: Some(3),
+            ..Limits::default()
+        };
+        assert_eq!(
+            try_resolve_uses(&mut too_deep, &limits),
+            Err(LimitError::TooDeep { limit: 3 })
+        );
+        assert_eq!(too_deep.to_string(), svg.to_string());
+    }
+}
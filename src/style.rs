@@ -0,0 +1,1332 @@
+//! This module provides the [ComputedStyle](struct.ComputedStyle.html) of elements, which
+//! resolves presentation attributes, stylesheets, inline styles and inheritance into the final
+//! value of every property, the [Style](struct.Style.html) of the `style` attribute, the
+//! [Stylesheet](struct.Stylesheet.html) builder for `<style>` elements and selecting elements
+//! with CSS selectors.
+//!
+//! # Note
+//! Stylesheets are matched with a subset of CSS selectors: type, universal, id, class and
+//! attribute selectors combined with descendant and child combinators. Rules with other
+//! selectors, like pseudo-classes, and rules within at-rules, like `@media`, are ignored.
+//!
+//! # Examples
+//! ## Getting the stroke width of an element
+//! ```
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::tree::ElementPath;
+//!
+//! let document = SVGDoc::new(
+//!     SVGElem::new(Tag::Svg)
+//!         .append(SVGElem::new(Tag::Style).set_inner(".thick { stroke-width: 4 }"))
+//!         .append(
+//!             SVGElem::new(Tag::G)
+//!                 .set(Attr::Class, "thick")
+//!                 .set(Attr::Stroke, "black")
+//!                 .append(SVGElem::new(Tag::Rect).set(Attr::Style, "fill: red")),
+//!         ),
+//! );
+//!
+//! let style = document.computed_style(&ElementPath::from(vec![1, 0])).unwrap();
+//! assert_eq!(style.get("fill"), Some("red"));
+//! assert_eq!(style.get("stroke"), Some("black"));
+//! assert_eq!(style.get_length("stroke-width"), Some(4.0));
+//! assert_eq!(style.get("opacity"), Some("1"));
+//! ```
+
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::attributes::{Attribute, AttributeValue};
+use crate::document::Document;
+use crate::geometry::user_units;
+use crate::tag_name::TagName;
+use crate::tree::ElementPath;
+use crate::Element;
+
+/// The properties which are inherited from the parent when they are not specified
+const INHERITED_PROPERTIES: &[&str] = &[
+    "clip-rule",
+    "color",
+    "color-interpolation",
+    "color-interpolation-filters",
+    "color-rendering",
+    "cursor",
+    "direction",
+    "dominant-baseline",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "font",
+    "font-family",
+    "font-size",
+    "font-size-adjust",
+    "font-stretch",
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "glyph-orientation-horizontal",
+    "glyph-orientation-vertical",
+    "image-rendering",
+    "kerning",
+    "letter-spacing",
+    "marker",
+    "marker-end",
+    "marker-mid",
+    "marker-start",
+    "paint-order",
+    "pointer-events",
+    "shape-rendering",
+    "stroke",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+    "text-anchor",
+    "text-rendering",
+    "visibility",
+    "white-space",
+    "word-spacing",
+    "writing-mode",
+];
+
+/// The initial values of the properties which are used most
+const INITIAL_VALUES: &[(&str, &str)] = &[
+    ("clip-path", "none"),
+    ("clip-rule", "nonzero"),
+    ("display", "inline"),
+    ("fill", "black"),
+    ("fill-opacity", "1"),
+    ("fill-rule", "nonzero"),
+    ("filter", "none"),
+    ("font-size", "medium"),
+    ("font-style", "normal"),
+    ("font-weight", "normal"),
+    ("marker-end", "none"),
+    ("marker-mid", "none"),
+    ("marker-start", "none"),
+    ("mask", "none"),
+    ("opacity", "1"),
+    ("stop-color", "black"),
+    ("stop-opacity", "1"),
+    ("stroke", "none"),
+    ("stroke-dasharray", "none"),
+    ("stroke-dashoffset", "0"),
+    ("stroke-linecap", "butt"),
+    ("stroke-linejoin", "miter"),
+    ("stroke-miterlimit", "4"),
+    ("stroke-opacity", "1"),
+    ("stroke-width", "1"),
+    ("text-anchor", "start"),
+    ("visibility", "visible"),
+];
+
+/// The final values of the style properties of an element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComputedStyle {
+    properties: BTreeMap<String, String>,
+}
+
+impl ComputedStyle {
+    /// Returns the style of the root element, consisting of the initial values
+    fn initial() -> ComputedStyle {
+        ComputedStyle {
+            properties: INITIAL_VALUES
+                .iter()
+                .map(|(property, value)| (property.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Gets the value of a property, e.g. `stroke-width`, or None if it is neither specified nor
+    /// inherited and has no known initial value
+    pub fn get(&self, property: &str) -> Option<&str> {
+        self.properties.get(property).map(String::as_str)
+    }
+
+    /// Gets the value of a property as a length in user units, or None if it is not set or not in
+    /// user units
+    pub fn get_length(&self, property: &str) -> Option<f64> {
+        user_units(self.get(property)?)
+    }
+
+    /// Iterates over all properties and their values, sorted by property
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.properties
+            .iter()
+            .map(|(property, value)| (property.as_str(), value.as_str()))
+    }
+
+    /// Returns the style of a child with certain cascaded declarations
+    fn child(&self, declarations: BTreeMap<String, String>) -> ComputedStyle {
+        let mut properties: BTreeMap<String, String> = INITIAL_VALUES
+            .iter()
+            .map(|(property, value)| (property.to_string(), value.to_string()))
+            .collect();
+        for (property, value) in self.properties.iter() {
+            if INHERITED_PROPERTIES.contains(&property.as_str()) {
+                properties.insert(property.clone(), value.clone());
+            }
+        }
+
+        for (property, value) in declarations {
+            match value.as_str() {
+                "inherit" => match self.properties.get(&property) {
+                    Some(inherited) => {
+                        properties.insert(property, inherited.clone());
+                    }
+                    None => {
+                        properties.remove(&property);
+                    }
+                },
+                "initial" => {
+                    let initial = INITIAL_VALUES
+                        .iter()
+                        .find(|(initial, _)| *initial == property);
+                    match initial {
+                        Some((_, value)) => properties.insert(property, value.to_string()),
+                        None => properties.remove(&property),
+                    };
+                }
+                _ => {
+                    properties.insert(property, value);
+                }
+            }
+        }
+
+        ComputedStyle { properties }
+    }
+}
+
+/// A single `property: value` declaration
+#[derive(Debug, Clone, PartialEq)]
+struct Declaration {
+    property: String,
+    value: String,
+    important: bool,
+}
+
+/// Splits the declarations of an inline style or the block of a rule at the semicolons which are
+/// not within a string or parentheses, like the one of `url("data:image/png;base64,...")`
+fn split_declarations(css: &str) -> Vec<&str> {
+    let mut declarations = Vec::new();
+    let mut quote = None;
+    let mut escaped = false;
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, character) in css.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match (quote, character) {
+            (_, '\\') => escaped = true,
+            (Some(open), _) if character == open => quote = None,
+            (Some(_), _) => (),
+            (None, '"') | (None, '\'') => quote = Some(character),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                declarations.push(&css[start..index]);
+                start = index + 1;
+            }
+            _ => (),
+        }
+    }
+    declarations.push(&css[start..]);
+    declarations
+}
+
+/// Parses the declarations of an inline style or the block of a rule
+///
+/// Property names are lowercased, except for custom properties, like `--gap`, which are
+/// case-sensitive.
+fn parse_declarations(css: &str) -> Vec<Declaration> {
+    split_declarations(css)
+        .into_iter()
+        .filter_map(|declaration| {
+            let (property, value) = declaration.split_once(':')?;
+            let property = property.trim();
+            let property = if property.starts_with("--") {
+                property.to_string()
+            } else {
+                property.to_lowercase()
+            };
+            let value = value.trim();
+            let (value, important) = match value.rfind('!') {
+                Some(index) if value[index + 1..].trim().eq_ignore_ascii_case("important") => {
+                    (value[..index].trim(), true)
+                }
+                _ => (value, false),
+            };
+
+            if property.is_empty() || value.is_empty() {
+                return None;
+            }
+
+            Some(Declaration {
+                property,
+                value: value.to_string(),
+                important,
+            })
+        })
+        .collect()
+}
+
+/// The error returned when selecting elements with a selector which is not supported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidSelector(pub String);
+
+impl fmt::Display for InvalidSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid or unsupported selector {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidSelector {}
+
+/// A simple selector matching a single element, e.g. `rect.shape[fill]`
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Compound {
+    tag_name: Option<String>,
+    ids: Vec<String>,
+    classes: Vec<String>,
+    attributes: Vec<(String, Option<String>)>,
+}
+
+impl Compound {
+    fn specificity(&self) -> (usize, usize, usize) {
+        (
+            self.ids.len(),
+            self.classes.len() + self.attributes.len(),
+            self.tag_name.iter().count(),
+        )
+    }
+
+    fn matches(&self, element: &Element) -> bool {
+        let attribute = |name: &str| {
+            element
+                .attributes
+                .iter()
+                .find(|(attribute, _)| attribute.name() == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        self.tag_name
+            .as_ref()
+            .is_none_or(|tag_name| *tag_name == element.tag_name.to_string())
+            && self
+                .ids
+                .iter()
+                .all(|id| attribute("id").map(str::trim) == Some(id.as_str()))
+            && self.classes.iter().all(|class| {
+                attribute("class")
+                    .is_some_and(|classes| classes.split_whitespace().any(|name| name == class))
+            })
+            && self
+                .attributes
+                .iter()
+                .all(|(name, expected)| match (attribute(name), expected) {
+                    (Some(_), None) => true,
+                    (Some(value), Some(expected)) => value == expected,
+                    (None, _) => false,
+                })
+    }
+}
+
+/// How a compound selector relates to the compound selector before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+/// A complex selector, e.g. `g > rect.shape`, with the combinator before every compound selector
+#[derive(Debug, Clone, PartialEq)]
+struct Selector {
+    parts: Vec<(Combinator, Compound)>,
+}
+
+impl Selector {
+    /// Parses a complex selector, or returns None if it contains unsupported parts
+    fn parse(selector: &str) -> Option<Selector> {
+        let mut parts = Vec::new();
+        let mut combinator = Combinator::Descendant;
+        let mut chars = selector.trim().chars().peekable();
+
+        let identifier = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+            let mut name = String::new();
+            while let Some(&next) = chars.peek() {
+                if !(next.is_alphanumeric() || next == '-' || next == '_' || !next.is_ascii()) {
+                    break;
+                }
+                name.push(next);
+                chars.next();
+            }
+            name
+        };
+
+        while chars.peek().is_some() {
+            let mut compound = Compound::default();
+            let mut empty = true;
+            while let Some(&next) = chars.peek() {
+                match next {
+                    '*' => {
+                        chars.next();
+                    }
+                    '#' | '.' => {
+                        chars.next();
+                        let name = identifier(&mut chars);
+                        if name.is_empty() {
+                            return None;
+                        }
+                        if next == '#' {
+                            compound.ids.push(name);
+                        } else {
+                            compound.classes.push(name);
+                        }
+                    }
+                    '[' => {
+                        chars.next();
+                        let mut content = String::new();
+                        for character in chars.by_ref() {
+                            if character == ']' {
+                                break;
+                            }
+                            content.push(character);
+                        }
+                        let attribute = match content.split_once('=') {
+                            Some((name, value)) => {
+                                let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+                                (name.trim().to_string(), Some(value.to_string()))
+                            }
+                            None => (content.trim().to_string(), None),
+                        };
+                        // Other operators, like `~=`, are not supported
+                        if attribute.0.is_empty() || !attribute.0.chars().all(is_name_char) {
+                            return None;
+                        }
+                        compound.attributes.push(attribute);
+                    }
+                    c if c.is_whitespace() || c == '>' => break,
+                    c if (c.is_alphanumeric() || c == '-' || c == '_') && empty => {
+                        compound.tag_name = Some(identifier(&mut chars));
+                    }
+                    _ => return None,
+                }
+                empty = false;
+            }
+            if empty {
+                return None;
+            }
+            parts.push((combinator, compound));
+
+            combinator = Combinator::Descendant;
+            while let Some(&next) = chars.peek() {
+                match next {
+                    '>' => combinator = Combinator::Child,
+                    c if c.is_whitespace() => (),
+                    _ => break,
+                }
+                chars.next();
+            }
+        }
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(Selector { parts })
+        }
+    }
+
+    fn specificity(&self) -> (usize, usize, usize) {
+        self.parts
+            .iter()
+            .map(|(_, compound)| compound.specificity())
+            .fold((0, 0, 0), |(a, b, c), (x, y, z)| (a + x, b + y, c + z))
+    }
+
+    /// Returns whether the selector matches the last element of a chain of ancestors
+    fn matches(&self, chain: &[&Element]) -> bool {
+        Self::matches_parts(&self.parts, chain)
+    }
+
+    fn matches_parts(parts: &[(Combinator, Compound)], chain: &[&Element]) -> bool {
+        let (((combinator, compound), rest), (element, ancestors)) =
+            match (parts.split_last(), chain.split_last()) {
+                (Some(parts), Some(chain)) => (parts, chain),
+                _ => return false,
+            };
+        if !compound.matches(element) {
+            return false;
+        }
+        if rest.is_empty() {
+            return true;
+        }
+
+        match combinator {
+            Combinator::Child => Self::matches_parts(rest, ancestors),
+            Combinator::Descendant => {
+                (1..=ancestors.len()).any(|end| Self::matches_parts(rest, &ancestors[..end]))
+            }
+        }
+    }
+}
+
+/// Returns whether a character can be part of an element or attribute name
+fn is_name_char(character: char) -> bool {
+    character.is_alphanumeric() || character == '-' || character == '_' || character == ':'
+}
+
+/// A style rule with its selectors and declarations
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    selectors: Vec<Selector>,
+    declarations: Vec<Declaration>,
+}
+
+/// Parses the rules of a stylesheet, skipping at-rules and rules with unsupported selectors
+fn parse_stylesheet(css: &str) -> Vec<Rule> {
+    let mut css = css.to_string();
+    while let Some(start) = css.find("/*") {
+        let end = css[start + 2..]
+            .find("*/")
+            .map_or(css.len(), |end| start + end + 4);
+        css.replace_range(start..end, "");
+    }
+
+    let mut rules = Vec::new();
+    let mut rest = css.as_str();
+    while let Some(open) = rest.find(['{', ';']) {
+        let prelude = rest[..open].trim();
+        if rest[open..].starts_with(';') {
+            // A statement at-rule, like `@import`
+            rest = &rest[open + 1..];
+            continue;
+        }
+
+        // Find the matching closing brace, skipping nested blocks
+        let mut depth = 0;
+        let mut close = rest.len();
+        for (index, character) in rest[open..].char_indices() {
+            match character {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        close = open + index;
+                        break;
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        if !prelude.starts_with('@') {
+            let selectors: Option<Vec<Selector>> =
+                prelude.split(',').map(Selector::parse).collect();
+            if let Some(selectors) = selectors {
+                rules.push(Rule {
+                    selectors,
+                    declarations: parse_declarations(&rest[open + 1..close]),
+                });
+            }
+        }
+
+        rest = rest.get(close + 1..).unwrap_or("");
+    }
+
+    rules
+}
+
+/// Returns the declarations of an element by property, in order of precedence
+fn cascade(rules: &[Rule], chain: &[&Element]) -> BTreeMap<String, String> {
+    let element = chain[chain.len() - 1];
+
+    // (important, inline, specificity, order)
+    type Precedence = (bool, bool, (usize, usize, usize), usize);
+    let mut declarations: Vec<(Precedence, &str, &str)> = Vec::new();
+
+    let mut attributes: Vec<(&Attribute, &AttributeValue)> = element
+        .attributes
+        .iter()
+        .filter(|(attribute, _)| attribute.is_presentation())
+        .collect();
+    attributes.sort_by_key(|(attribute, _)| attribute.name());
+    for (attribute, value) in attributes {
+        declarations.push(((false, false, (0, 0, 0), 0), attribute.name(), value.trim()));
+    }
+
+    for (order, rule) in rules.iter().enumerate() {
+        let specificity = rule
+            .selectors
+            .iter()
+            .filter(|selector| selector.matches(chain))
+            .map(Selector::specificity)
+            .max();
+        if let Some(specificity) = specificity {
+            for declaration in rule.declarations.iter() {
+                declarations.push((
+                    (declaration.important, false, specificity, order + 1),
+                    &declaration.property,
+                    &declaration.value,
+                ));
+            }
+        }
+    }
+
+    let inline = element
+        .attributes
+        .get(&Attribute::Style)
+        .map(|style| parse_declarations(style))
+        .unwrap_or_default();
+    for declaration in inline.iter() {
+        declarations.push((
+            (declaration.important, true, (0, 0, 0), 0),
+            &declaration.property,
+            &declaration.value,
+        ));
+    }
+
+    // Later declarations with the same precedence win, which a stable sort keeps in order
+    declarations.sort_by_key(|(precedence, _, _)| *precedence);
+    declarations
+        .into_iter()
+        .map(|(_, property, value)| (property.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Collects the stylesheets of all `<style>` elements in a tree
+fn collect_rules(element: &Element, rules: &mut Vec<Rule>) {
+    if element.tag_name == TagName::Style {
+        match &element.inner {
+            // Stylesheets with CSS like `a > b` are often wrapped in a CDATA section
+            Some(css) if element.raw_inner => rules.extend(parse_stylesheet(
+                &css.replace("<![CDATA[", "").replace("]]>", ""),
+            )),
+            Some(css) => rules.extend(parse_stylesheet(css)),
+            None => {}
+        }
+    }
+
+    for child in element.children.iter() {
+        collect_rules(child, rules);
+    }
+}
+
+impl Document {
+    /// Computes the style of the element at a certain path, or returns None if it does not exist
+    ///
+    /// # Note / Arguments
+    /// Values are taken from, in increasing order of precedence, presentation attributes,
+    /// matching stylesheet rules by specificity and inline styles, where `!important`
+    /// declarations take precedence over all others. Unspecified inherited properties, like
+    /// `fill`, take the value of the parent and others their initial value, if it is known.
+    /// Values are not converted, e.g. lengths keep their units.
+    pub fn computed_style(&self, path: &ElementPath) -> Option<ComputedStyle> {
+        let mut rules = Vec::new();
+        collect_rules(self.get_root(), &mut rules);
+
+        let mut chain = vec![self.get_root()];
+        for &index in path.indices() {
+            chain.push(chain[chain.len() - 1].children.get(index)?);
+        }
+
+        let mut style = ComputedStyle::initial();
+        for end in 1..=chain.len() {
+            style = style.child(cascade(&rules, &chain[..end]));
+        }
+
+        Some(style)
+    }
+}
+
+named_enum! {
+    /// A CSS property which can be set in the `style` attribute of an element
+    ///
+    /// # Note
+    /// Every presentation attribute is a property with the same name. Other properties, like
+    /// custom properties (`--name`), are represented by
+    /// [Custom](enum.CssProperty.html#variant.Custom) with their full name.
+    #[derive(PartialEq, Eq, Debug, Clone, Hash)]
+    pub enum CssProperty {
+        /// A property which is not in the table, with its full name
+        Custom(String);
+
+        AlignmentBaseline = "alignment-baseline",
+        Animation = "animation",
+        BaselineShift = "baseline-shift",
+        Clip = "clip",
+        ClipPath = "clip-path",
+        ClipRule = "clip-rule",
+        Color = "color",
+        ColorInterpolation = "color-interpolation",
+        ColorInterpolationFilters = "color-interpolation-filters",
+        ColorRendering = "color-rendering",
+        Cursor = "cursor",
+        Direction = "direction",
+        Display = "display",
+        DominantBaseline = "dominant-baseline",
+        Fill = "fill",
+        FillOpacity = "fill-opacity",
+        FillRule = "fill-rule",
+        Filter = "filter",
+        FloodColor = "flood-color",
+        FloodOpacity = "flood-opacity",
+        Font = "font",
+        FontFamily = "font-family",
+        FontSize = "font-size",
+        FontSizeAdjust = "font-size-adjust",
+        FontStretch = "font-stretch",
+        FontStyle = "font-style",
+        FontVariant = "font-variant",
+        FontWeight = "font-weight",
+        ImageRendering = "image-rendering",
+        Isolation = "isolation",
+        LetterSpacing = "letter-spacing",
+        LightingColor = "lighting-color",
+        Marker = "marker",
+        MarkerEnd = "marker-end",
+        MarkerMid = "marker-mid",
+        MarkerStart = "marker-start",
+        Mask = "mask",
+        MixBlendMode = "mix-blend-mode",
+        Opacity = "opacity",
+        Overflow = "overflow",
+        PaintOrder = "paint-order",
+        PointerEvents = "pointer-events",
+        ShapeRendering = "shape-rendering",
+        StopColor = "stop-color",
+        StopOpacity = "stop-opacity",
+        Stroke = "stroke",
+        StrokeDasharray = "stroke-dasharray",
+        StrokeDashoffset = "stroke-dashoffset",
+        StrokeLinecap = "stroke-linecap",
+        StrokeLinejoin = "stroke-linejoin",
+        StrokeMiterlimit = "stroke-miterlimit",
+        StrokeOpacity = "stroke-opacity",
+        StrokeWidth = "stroke-width",
+        TextAnchor = "text-anchor",
+        TextDecoration = "text-decoration",
+        TextRendering = "text-rendering",
+        Transform = "transform",
+        TransformBox = "transform-box",
+        TransformOrigin = "transform-origin",
+        Transition = "transition",
+        UnicodeBidi = "unicode-bidi",
+        VectorEffect = "vector-effect",
+        Visibility = "visibility",
+        WhiteSpace = "white-space",
+        WordSpacing = "word-spacing",
+        WritingMode = "writing-mode",
+    }
+}
+
+impl CssProperty {
+    /// Returns the property with a certain name, which is a
+    /// [Custom](enum.CssProperty.html#variant.Custom) property if it is not in the table
+    pub fn custom(name: &str) -> CssProperty {
+        let name = name.trim();
+        CssProperty::from_name(name).unwrap_or_else(|| CssProperty::Custom(String::from(name)))
+    }
+}
+
+impl fmt::Display for CssProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl From<&Attribute> for CssProperty {
+    /// Returns the property with the name of an attribute, which is only meaningful for
+    /// presentation attributes
+    fn from(attribute: &Attribute) -> Self {
+        CssProperty::custom(attribute.name())
+    }
+}
+
+/// Style is the map of properties of the `style` attribute of an element, keeping the order in
+/// which they were set
+///
+/// # Note / Arguments
+/// Values are kept as they are written, including `!important`. Declarations without a property
+/// or a value are dropped when a style is parsed.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::style::{CssProperty, Style};
+///
+/// let mut style: Style = "fill: red; stroke: blue !important".parse().unwrap();
+/// style.set(CssProperty::Fill, "green");
+/// style.set(CssProperty::custom("--accent"), "#f80");
+/// style.remove(&CssProperty::Stroke);
+///
+/// assert_eq!(style.get(&CssProperty::Fill), Some("green"));
+/// assert_eq!(style.to_string(), "fill: green; --accent: #f80");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    declarations: Vec<(CssProperty, String)>,
+}
+
+impl Style {
+    /// Creates a new empty style
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    /// Sets a property to a value, keeping its position if it was already set, and returns the
+    /// previous value
+    pub fn set<T: ToString>(&mut self, property: CssProperty, value: T) -> Option<String> {
+        let value = value.to_string().trim().to_string();
+        match self
+            .declarations
+            .iter_mut()
+            .find(|(other, _)| *other == property)
+        {
+            Some((_, existing)) => Some(std::mem::replace(existing, value)),
+            None => {
+                self.declarations.push((property, value));
+                None
+            }
+        }
+    }
+
+    /// Gets the value of a property, or None if it is not set
+    pub fn get(&self, property: &CssProperty) -> Option<&str> {
+        self.declarations
+            .iter()
+            .find(|(other, _)| other == property)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Removes a property and returns its value, or None if it was not set
+    pub fn remove(&mut self, property: &CssProperty) -> Option<String> {
+        let index = self
+            .declarations
+            .iter()
+            .position(|(other, _)| other == property)?;
+        Some(self.declarations.remove(index).1)
+    }
+
+    /// Iterates over the properties and their values in the order they were set
+    pub fn iter(&self) -> impl Iterator<Item = (&CssProperty, &str)> {
+        self.declarations
+            .iter()
+            .map(|(property, value)| (property, value.as_str()))
+    }
+
+    /// Returns the amount of properties
+    pub fn len(&self) -> usize {
+        self.declarations.len()
+    }
+
+    /// Returns whether no properties are set
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (property, value)) in self.declarations.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}: {}", property, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Style {
+    type Err = Infallible;
+
+    /// Parses the declarations of a `style` attribute, where later declarations of a property
+    /// replace earlier ones
+    fn from_str(css: &str) -> Result<Self, Self::Err> {
+        let mut style = Style::new();
+        for declaration in parse_declarations(css) {
+            let property = CssProperty::custom(&declaration.property);
+            if declaration.important {
+                style.set(property, format!("{} !important", declaration.value));
+            } else {
+                style.set(property, declaration.value);
+            }
+        }
+        Ok(style)
+    }
+}
+
+/// Stylesheet assembles the rules of a `<style>` element from selectors and typed declarations
+///
+/// # Note / Arguments
+/// Declarations for a selector which already has a rule are added to that rule, replacing
+/// earlier values of the same property. Rules are written in the order their selectors were
+/// first used. The stylesheet is wrapped in a CDATA section when it contains characters which
+/// would otherwise be escaped, like the `>` of a child combinator.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::style::{CssProperty, Stylesheet};
+///
+/// let stylesheet = Stylesheet::new()
+///     .rule(".axis", &[(CssProperty::Stroke, "#333"), (CssProperty::Fill, "none")])
+///     .declare(".axis", CssProperty::StrokeWidth, 0.5)
+///     .declare("text", CssProperty::FontFamily, "sans-serif");
+///
+/// assert_eq!(
+///     stylesheet.build().to_string(),
+///     "<style>.axis { stroke: #333; fill: none; stroke-width: 0.5 } \
+///      text { font-family: sans-serif }</style>"
+/// );
+///
+/// let nested = Stylesheet::new().declare("g > rect", CssProperty::Opacity, 0.8).build();
+/// assert_eq!(nested.to_string(), "<style><![CDATA[g > rect { opacity: 0.8 }]]></style>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Stylesheet {
+    rules: Vec<(String, Style)>,
+}
+
+impl Stylesheet {
+    /// Creates a new empty stylesheet
+    pub fn new() -> Stylesheet {
+        Stylesheet::default()
+    }
+
+    /// Adds declarations to the rule of a selector
+    pub fn rule<T: ToString>(mut self, selector: &str, declarations: &[(CssProperty, T)]) -> Self {
+        let style = self.style_mut(selector);
+        for (property, value) in declarations {
+            style.set(property.clone(), value.to_string());
+        }
+        self
+    }
+
+    /// Adds a single declaration to the rule of a selector, which allows values of different
+    /// types in one rule
+    pub fn declare<T: ToString>(mut self, selector: &str, property: CssProperty, value: T) -> Self {
+        self.style_mut(selector).set(property, value);
+        self
+    }
+
+    /// Returns the style of the rule of a selector, creating the rule if it does not exist
+    fn style_mut(&mut self, selector: &str) -> &mut Style {
+        let selector = selector.trim();
+        let index = match self.rules.iter().position(|(other, _)| other == selector) {
+            Some(index) => index,
+            None => {
+                self.rules.push((String::from(selector), Style::new()));
+                self.rules.len() - 1
+            }
+        };
+        &mut self.rules[index].1
+    }
+
+    /// Returns the style of the rule of a selector, or None if it has no rule
+    pub fn get(&self, selector: &str) -> Option<&Style> {
+        self.rules
+            .iter()
+            .find(|(other, _)| other == selector.trim())
+            .map(|(_, style)| style)
+    }
+
+    /// Iterates over the selectors and the styles of their rules
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Style)> {
+        self.rules
+            .iter()
+            .map(|(selector, style)| (selector.as_str(), style))
+    }
+
+    /// Assembles the `<style>` element
+    pub fn build(&self) -> Element {
+        let css = self.to_string();
+        let style = Element::new(TagName::Style);
+        if css.contains(['<', '>', '&']) {
+            style.set_inner_cdata(&css)
+        } else {
+            style.set_inner(&css)
+        }
+    }
+}
+
+impl fmt::Display for Stylesheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut rules = self.rules.iter().filter(|(_, style)| !style.is_empty());
+        if let Some((selector, style)) = rules.next() {
+            write!(f, "{} {{ {} }}", selector, style)?;
+        }
+        for (selector, style) in rules {
+            write!(f, " {} {{ {} }}", selector, style)?;
+        }
+        Ok(())
+    }
+}
+
+impl Element {
+    /// Returns the properties of the `style` attribute, which is empty if it is not set
+    ///
+    /// # Examples
+    /// *The feature "parsing" needs to be enabled for this*
+    /// ```
+    /// # #[cfg(feature = "parsing")]
+    /// # {
+    /// use svg_definitions::prelude::*;
+    /// use svg_definitions::style::CssProperty;
+    ///
+    /// let rect = SVGParseText("<rect style=\"fill:red;stroke-width:2\"/>").unwrap();
+    /// assert_eq!(rect.get_style().get(&CssProperty::StrokeWidth), Some("2"));
+    /// # }
+    /// ```
+    pub fn get_style(&self) -> Style {
+        self.attributes
+            .get(&Attribute::Style)
+            .map(|css| css.parse().unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// Sets a property in the `style` attribute, keeping the other properties
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    /// use svg_definitions::style::CssProperty;
+    ///
+    /// let rect = SVGElem::new(Tag::Rect)
+    ///     .set(Attr::Style, "fill: red")
+    ///     .set_style(CssProperty::Opacity, 0.5)
+    ///     .set_style(CssProperty::Fill, "blue");
+    ///
+    /// assert_eq!(rect.to_string(), "<rect style=\"fill: blue; opacity: 0.5\"/>");
+    /// ```
+    pub fn set_style<T: ToString>(mut self, property: CssProperty, value: T) -> Self {
+        let mut style = self.get_style();
+        style.set(property, value);
+        self.set_attribute(Attribute::Style, style.to_string());
+        self
+    }
+
+    /// Removes a property from the `style` attribute and returns its value, removing the
+    /// attribute when no properties are left
+    pub fn remove_style(&mut self, property: &CssProperty) -> Option<String> {
+        let mut style = self.get_style();
+        let value = style.remove(property)?;
+        if style.is_empty() {
+            self.attributes.remove(&Attribute::Style);
+        } else {
+            self.set_attribute(Attribute::Style, style.to_string());
+        }
+        Some(value)
+    }
+
+    /// Returns the self element and its descendants which match a list of CSS selectors, in
+    /// document order
+    ///
+    /// # Note / Arguments
+    /// Selectors can use types, the universal selector, ids, classes and attributes, like
+    /// `[fill]` and `[fill='none']`, combined with descendant and child combinators. Other
+    /// selectors, like pseudo-classes, are an error. Selectors only match within the self
+    /// element, so a selector like `svg > g` does not match children of a detached `<g>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// let svg = SVGElem::new(Tag::Svg)
+    ///     .append(
+    ///         SVGElem::new(Tag::G)
+    ///             .set(Attr::Class, "layer")
+    ///             .append(SVGElem::new(Tag::Path).set(Attr::Fill, "none").set(Attr::Id, "outline"))
+    ///             .append(SVGElem::new(Tag::Path).set(Attr::Fill, "red")),
+    ///     )
+    ///     .append(SVGElem::new(Tag::Path).set(Attr::Fill, "none"));
+    ///
+    /// let outlines = svg.select("g.layer > path[fill='none']").unwrap();
+    /// assert_eq!(outlines.len(), 1);
+    /// assert_eq!(outlines[0].get_attributes()[&Attr::Id], "outline");
+    ///
+    /// assert_eq!(svg.select("path, g").unwrap().len(), 4);
+    /// assert!(svg.select("path:hover").is_err());
+    /// ```
+    pub fn select(&self, selectors: &str) -> Result<Vec<&Element>, InvalidSelector> {
+        Ok(self
+            .select_with_paths(selectors)?
+            .into_iter()
+            .map(|(_, element)| element)
+            .collect())
+    }
+
+    /// Returns the paths of the self element and its descendants which match a list of CSS
+    /// selectors, in document order, see [select](#method.select)
+    ///
+    /// # Note / Arguments
+    /// The paths can be used to change the matched elements, e.g. with
+    /// [get_path_mut](#method.get_path_mut).
+    pub fn select_paths(&self, selectors: &str) -> Result<Vec<ElementPath>, InvalidSelector> {
+        Ok(self
+            .select_with_paths(selectors)?
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect())
+    }
+
+    fn select_with_paths(
+        &self,
+        selectors: &str,
+    ) -> Result<Vec<(ElementPath, &Element)>, InvalidSelector> {
+        let selectors = selectors
+            .split(',')
+            .map(Selector::parse)
+            .collect::<Option<Vec<Selector>>>()
+            .ok_or_else(|| InvalidSelector(String::from(selectors)))?;
+
+        fn visit<'a>(
+            element: &'a Element,
+            path: ElementPath,
+            chain: &mut Vec<&'a Element>,
+            selectors: &[Selector],
+            found: &mut Vec<(ElementPath, &'a Element)>,
+        ) {
+            chain.push(element);
+            if selectors.iter().any(|selector| selector.matches(chain)) {
+                found.push((path.clone(), element));
+            }
+            for (index, child) in element.children.iter().enumerate() {
+                visit(child, path.child(index), chain, selectors, found);
+            }
+            chain.pop();
+        }
+
+        let mut found = Vec::new();
+        visit(
+            self,
+            ElementPath::new(),
+            &mut Vec::new(),
+            &selectors,
+            &mut found,
+        );
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_declarations, parse_stylesheet, Selector};
+    use crate::attributes::Attribute;
+    use crate::document::Document;
+    use crate::tag_name::TagName;
+    use crate::tree::ElementPath;
+    use crate::Element;
+
+    #[test]
+    fn test_parse_stylesheet() {
+        let rules = parse_stylesheet(
+            "@import 'a.css'; /* { */ rect, g > .a { fill: red !important; stroke:blue } \
+             @media print { rect { fill: green } } a:hover { fill: pink } circle {}",
+        );
+        assert_eq!(rules.len(), 2);
+        assert!(rules[1].declarations.is_empty());
+        assert_eq!(rules[0].selectors.len(), 2);
+        assert_eq!(
+            rules[0].declarations,
+            parse_declarations("fill:red!important;stroke:blue")
+        );
+        assert!(rules[0].declarations[0].important);
+
+        let selector = Selector::parse("svg g>rect.a.b[fill='red']#x").unwrap();
+        assert_eq!(selector.parts.len(), 3);
+        assert_eq!(selector.specificity(), (1, 3, 3));
+        assert!(Selector::parse("rect::before").is_none());
+        assert!(Selector::parse("[fill~=red]").is_none());
+    }
+
+    #[test]
+    fn test_computed_style() {
+        let css = "g rect { fill: green; stroke-width: 3 } \
+                   #special { fill: purple } \
+                   svg > rect { stroke: red } \
+                   .important { stroke: orange !important }";
+        let document = Document::new(
+            Element::new(TagName::Svg)
+                .set(Attribute::Fill, "blue")
+                .append(Element::new(TagName::Style).set_inner(css))
+                .append(
+                    Element::new(TagName::G)
+                        .set(Attribute::Opacity, 0.5)
+                        .set(Attribute::Style, "stroke: black")
+                        .append(Element::new(TagName::Rect).set(Attribute::Fill, "yellow"))
+                        .append(
+                            Element::new(TagName::Rect)
+                                .set(Attribute::Id, "special")
+                                .set(Attribute::Class, "important")
+                                .set(Attribute::Style, "fill: white; stroke: white"),
+                        )
+                        .append(
+                            Element::new(TagName::Circle).set(Attribute::StrokeWidth, "inherit"),
+                        ),
+                )
+                .append(Element::new(TagName::Rect)),
+        );
+        let style = |indices: Vec<usize>| {
+            document
+                .computed_style(&ElementPath::from(indices))
+                .unwrap()
+        };
+
+        let root = style(vec![]);
+        assert_eq!(root.get("fill"), Some("blue"));
+        assert_eq!(root.get("stroke"), Some("none"));
+
+        let group = style(vec![1]);
+        assert_eq!(group.get("opacity"), Some("0.5"));
+        assert_eq!(group.get("stroke"), Some("black"));
+
+        let first = style(vec![1, 0]);
+        assert_eq!(first.get("fill"), Some("green"));
+        assert_eq!(first.get("stroke"), Some("black"));
+        assert_eq!(first.get_length("stroke-width"), Some(3.0));
+        assert_eq!(first.get("opacity"), Some("1"));
+
+        let second = style(vec![1, 1]);
+        assert_eq!(second.get("fill"), Some("white"));
+        assert_eq!(second.get("stroke"), Some("orange"));
+
+        assert_eq!(style(vec![1, 2]).get("stroke-width"), Some("1"));
+        assert_eq!(style(vec![2]).get("stroke"), Some("red"));
+        assert!(document
+            .computed_style(&ElementPath::from(vec![5]))
+            .is_none());
+    }
+
+    #[test]
+    fn test_style() {
+        use super::{CssProperty, Style};
+
+        let style: Style = " Fill :red;;stroke:; --Gap: 4px ; FILL: blue"
+            .parse()
+            .unwrap();
+        assert_eq!(style.len(), 2);
+        assert_eq!(style.to_string(), "fill: blue; --Gap: 4px");
+        assert_eq!(
+            style
+                .iter()
+                .map(|(property, _)| property.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                CssProperty::Fill,
+                CssProperty::Custom(String::from("--Gap"))
+            ]
+        );
+        assert_eq!(
+            CssProperty::from(&Attribute::StrokeWidth),
+            CssProperty::StrokeWidth
+        );
+
+        // Semicolons within strings and parentheses do not end a declaration
+        let data: Style = "fill: url(data:image/png;base64,AA==); \
+                            background: url(\"a;b.png\"); font-family: 'a;b'; stroke: red!important"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            data.get(&CssProperty::Fill),
+            Some("url(data:image/png;base64,AA==)")
+        );
+        assert_eq!(
+            data.get(&CssProperty::custom("background")),
+            Some("url(\"a;b.png\")")
+        );
+        assert_eq!(data.get(&CssProperty::FontFamily), Some("'a;b'"));
+        assert_eq!(data.get(&CssProperty::Stroke), Some("red !important"));
+
+        let mut rect = Element::new(TagName::Rect)
+            .set_style(CssProperty::Fill, "red")
+            .set_style(CssProperty::Stroke, "black");
+        assert_eq!(rect.remove_style(&CssProperty::Opacity), None);
+        assert_eq!(
+            rect.remove_style(&CssProperty::Fill),
+            Some(String::from("red"))
+        );
+        assert_eq!(rect.to_string(), "<rect style=\"stroke: black\"/>");
+        rect.remove_style(&CssProperty::Stroke);
+        assert_eq!(rect.to_string(), "<rect/>");
+    }
+
+    #[test]
+    fn test_stylesheet() {
+        use super::{CssProperty, Stylesheet};
+
+        let stylesheet = Stylesheet::new()
+            .rule(
+                " .a ",
+                &[(CssProperty::Fill, "red"), (CssProperty::Fill, "blue")],
+            )
+            .rule::<&str>(".empty", &[])
+            .declare(".b", CssProperty::custom("content"), "'&'");
+        assert_eq!(
+            stylesheet.get(".a").unwrap().get(&CssProperty::Fill),
+            Some("blue")
+        );
+        assert_eq!(stylesheet.iter().count(), 3);
+        assert_eq!(
+            stylesheet.build().to_string(),
+            "<style><![CDATA[.a { fill: blue } .b { content: '&' }]]></style>"
+        );
+
+        // The generated stylesheet is understood by the computed style
+        let document = Document::new(
+            Element::new(TagName::Svg)
+                .append(stylesheet.build())
+                .append(Element::new(TagName::Rect).set(Attribute::Class, "a")),
+        );
+        let style = document
+            .computed_style(&ElementPath::from(vec![1]))
+            .unwrap();
+        assert_eq!(style.get("fill"), Some("blue"));
+    }
+
+    #[test]
+    fn test_select() {
+        use super::InvalidSelector;
+
+        let svg = Element::new(TagName::Svg).append(
+            Element::new(TagName::G)
+                .set(Attribute::Id, "outer")
+                .append(
+                    Element::new(TagName::G)
+                        .append(Element::new(TagName::Rect).set(Attribute::Class, "a b")),
+                )
+                .append(Element::new(TagName::Circle).set(Attribute::Class, "a")),
+        );
+
+        assert_eq!(
+            svg.select_paths("#outer .a").unwrap(),
+            vec![
+                ElementPath::from(vec![0, 0, 0]),
+                ElementPath::from(vec![0, 1])
+            ]
+        );
+        assert_eq!(
+            svg.select_paths("#outer > .a, .b").unwrap(),
+            vec![
+                ElementPath::from(vec![0, 0, 0]),
+                ElementPath::from(vec![0, 1])
+            ]
+        );
+        assert_eq!(svg.select("svg").unwrap().len(), 1);
+        assert_eq!(svg.select("*").unwrap().len(), 5);
+        assert_eq!(svg.select("g g rect.a.b").unwrap().len(), 1);
+        assert_eq!(svg.select("rect[class]").unwrap().len(), 1);
+        assert_eq!(
+            svg.select("rect ~ circle").unwrap_err(),
+            InvalidSelector(String::from("rect ~ circle"))
+        );
+        assert!(svg.select("").is_err());
+    }
+}
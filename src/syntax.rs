@@ -0,0 +1,252 @@
+//! This module provides the tokenizer of the micro syntaxes of SVG attribute values, which reads
+//! numbers, arc flags, command letters and names as they are written in path data and
+//! transforms. It can be used to parse other values with the same grammar, like lists of points.
+//!
+//! # Note
+//! The grammar follows the SVG specification: numbers may have a sign, a fraction and an
+//! exponent, and can be separated by whitespace with at most one comma, or not at all where the
+//! syntax allows it, e.g. `1-2.5.5` are the numbers `1`, `-2.5` and `.5`.
+//!
+//! # Examples
+//! ## Parsing the points of a polygon
+//! ```
+//! use svg_definitions::syntax::{Reader, SyntaxError};
+//!
+//! fn points(value: &str) -> Result<Vec<(f64, f64)>, SyntaxError> {
+//!     let mut reader = Reader::new(value);
+//!     let mut points = Vec::new();
+//!     while !reader.is_at_end() {
+//!         points.push(reader.pair()?);
+//!     }
+//!     Ok(points)
+//! }
+//!
+//! assert_eq!(points("0,0 10-5 1e1,.5"), Ok(vec![(0.0, 0.0), (10.0, -5.0), (10.0, 0.5)]));
+//! assert_eq!(points("0,0 10"), Err(SyntaxError(6)));
+//! ```
+
+use std::fmt;
+
+/// The error returned when a value does not follow the syntax, containing the byte offset of
+/// the error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxError(pub usize);
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid syntax at byte {}", self.0)
+    }
+}
+
+impl std::error::Error for SyntaxError {}
+
+/// Reads the tokens of a value from start to end
+///
+/// # Note / Arguments
+/// Every method reading a token first skips a separator, which is whitespace with at most one
+/// comma, so tokens can be read one after the other. On an error the reader stays at the
+/// offset of the error.
+#[derive(Debug, Clone)]
+pub struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Creates a reader at the start of a value
+    #[inline]
+    pub fn new(value: &'a str) -> Reader<'a> {
+        Reader {
+            data: value.as_bytes(),
+            offset: 0,
+        }
+    }
+
+    /// Returns the byte offset of the next token
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the next byte without reading it
+    #[inline]
+    pub fn peek(&self) -> Option<u8> {
+        self.data.get(self.offset).copied()
+    }
+
+    /// Returns whether only whitespace is left
+    pub fn is_at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.offset == self.data.len()
+    }
+
+    /// Skips whitespace
+    pub fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.offset += 1;
+        }
+    }
+
+    /// Skips whitespace with at most one comma
+    pub fn skip_separator(&mut self) {
+        self.skip_whitespace();
+        if self.peek() == Some(b',') {
+            self.offset += 1;
+            self.skip_whitespace();
+        }
+    }
+
+    /// Skips whitespace and reads a certain byte, e.g. a parenthesis
+    pub fn expect(&mut self, byte: u8) -> Result<(), SyntaxError> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(SyntaxError(self.offset));
+        }
+        self.offset += 1;
+        Ok(())
+    }
+
+    /// Reads a number, e.g. `-1.5e3` or `.5`
+    ///
+    /// # Note / Arguments
+    /// An exponent is only read when digits follow, so `1em` is the number `1` followed by `em`.
+    pub fn number(&mut self) -> Result<f64, SyntaxError> {
+        self.skip_separator();
+        let start = self.offset;
+        if let Some(b'+') | Some(b'-') = self.peek() {
+            self.offset += 1;
+        }
+
+        let mut digits = self.digits();
+        if self.peek() == Some(b'.') {
+            self.offset += 1;
+            digits += self.digits();
+        }
+        if digits == 0 {
+            self.offset = start;
+            return Err(SyntaxError(start));
+        }
+
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            let mantissa_end = self.offset;
+            self.offset += 1;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.offset += 1;
+            }
+            if self.digits() == 0 {
+                self.offset = mantissa_end;
+            }
+        }
+
+        match std::str::from_utf8(&self.data[start..self.offset])
+            .ok()
+            .and_then(|number| number.parse::<f64>().ok())
+            .filter(|number| number.is_finite())
+        {
+            Some(number) => Ok(number),
+            None => {
+                self.offset = start;
+                Err(SyntaxError(start))
+            }
+        }
+    }
+
+    /// Reads two numbers, e.g. a coordinate pair
+    pub fn pair(&mut self) -> Result<(f64, f64), SyntaxError> {
+        Ok((self.number()?, self.number()?))
+    }
+
+    /// Reads an arc flag, `0` or `1`, which does not need to be separated from the next token
+    pub fn flag(&mut self) -> Result<bool, SyntaxError> {
+        self.skip_separator();
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(SyntaxError(self.offset)),
+        };
+        self.offset += 1;
+        Ok(flag)
+    }
+
+    /// Reads a command letter of path data, or returns None if the next token is not a letter
+    pub fn command(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        let letter = self.peek().filter(|byte| byte.is_ascii_alphabetic())?;
+        self.offset += 1;
+        Some(letter)
+    }
+
+    /// Reads a name of ASCII letters, like the name of a transform operation
+    pub fn name(&mut self) -> Result<&'a str, SyntaxError> {
+        self.skip_separator();
+        let start = self.offset;
+        while self.peek().is_some_and(|byte| byte.is_ascii_alphabetic()) {
+            self.offset += 1;
+        }
+        if self.offset == start {
+            return Err(SyntaxError(start));
+        }
+
+        Ok(std::str::from_utf8(&self.data[start..self.offset]).expect("ASCII letters are UTF-8"))
+    }
+
+    /// Reads digits, returning how many were read
+    fn digits(&mut self) -> usize {
+        let start = self.offset;
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.offset += 1;
+        }
+        self.offset - start
+    }
+}
+
+/// Parses a list of numbers, separated like in path data
+///
+/// # Examples
+/// ```
+/// use svg_definitions::syntax;
+///
+/// assert_eq!(syntax::numbers("5, 1 -2.5"), Ok(vec![5.0, 1.0, -2.5]));
+/// assert!(syntax::numbers("5,,1").is_err());
+/// ```
+pub fn numbers(value: &str) -> Result<Vec<f64>, SyntaxError> {
+    let mut reader = Reader::new(value);
+    let mut numbers = Vec::new();
+    while !reader.is_at_end() {
+        numbers.push(reader.number()?);
+    }
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Reader, SyntaxError};
+
+    #[test]
+    fn test_reader() {
+        let mut reader = Reader::new(" M0-1.5.5e1 1em,A 10 10 0 1010,5 x");
+        assert_eq!(reader.command(), Some(b'M'));
+        assert_eq!(reader.pair(), Ok((0.0, -1.5)));
+        assert_eq!(reader.number(), Ok(5.0));
+        assert_eq!(reader.number(), Ok(1.0));
+        assert_eq!(reader.name(), Ok("em"));
+        assert_eq!(reader.command(), None);
+        assert_eq!(reader.number(), Err(SyntaxError(16)));
+
+        let mut reader = Reader::new(", A 10 10 0 1010,5 x");
+        reader.skip_separator();
+        assert_eq!(reader.command(), Some(b'A'));
+        assert_eq!(reader.pair(), Ok((10.0, 10.0)));
+        assert_eq!(reader.number(), Ok(0.0));
+        assert_eq!((reader.flag(), reader.flag()), (Ok(true), Ok(false)));
+        assert_eq!(reader.pair(), Ok((10.0, 5.0)));
+        assert!(!reader.is_at_end());
+        assert_eq!(reader.flag(), Err(SyntaxError(19)));
+        assert_eq!(reader.expect(b'('), Err(SyntaxError(19)));
+        assert_eq!(reader.peek(), Some(b'x'));
+
+        for value in &["+", ".", "-.e1", "1e999"] {
+            assert_eq!(Reader::new(value).number(), Err(SyntaxError(0)));
+        }
+    }
+}
@@ -1,305 +1,321 @@
-/// TagName provides tags for SVG creation
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
-pub enum TagName {
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/a)
-    A,
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/animate)
-    Animate,
+named_enum! {
+    /// TagName provides tags for SVG creation
+    ///
+    /// # Note
+    /// TagNames can be looked up by their names with `TagName::from_name("linearGradient")` or
+    /// parsed with `"linearGradient".parse()`. With the feature "serde" enabled, they are
+    /// serialized as their names.
+    ///
+    /// Elements which are not part of SVG, e.g. `sodipodi:namedview`, are represented by
+    /// [Custom](enum.TagName.html#variant.Custom) with their full name.
+    #[derive(Clone, PartialEq, Eq, Debug, Hash)]
+    pub enum TagName {
+        /// An element which is not part of SVG, with its full name including any prefix
+        Custom(String);
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/animateMotion)
-    AnimateMotion,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/a)
+        A = "a",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/animateTransform)
-    AnimateTransform,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/animate)
+        Animate = "animate",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/circle)
-    Circle,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/animateMotion)
+        AnimateMotion = "animateMotion",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/clipPath)
-    ClipPath,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/animateTransform)
+        AnimateTransform = "animateTransform",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/color-profile)
-    ColorProfile,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/circle)
+        Circle = "circle",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/defs)
-    Defs,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/clipPath)
+        ClipPath = "clipPath",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/desc)
-    Desc,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/color-profile)
+        ColorProfile = "color-profile",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/discard)
-    Discard,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/defs)
+        Defs = "defs",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/ellipse)
-    Ellipse,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/desc)
+        Desc = "desc",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feBlend)
-    FeBlend,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/discard)
+        Discard = "discard",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feColorMatrix)
-    FeColorMatrix,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/ellipse)
+        Ellipse = "ellipse",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feComponentTransfer)
-    FeComponentTransfer,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feBlend)
+        FeBlend = "feBlend",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feComposite)
-    FeComposite,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feColorMatrix)
+        FeColorMatrix = "feColorMatrix",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feConvolveMatrix)
-    FeConvolveMatrix,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feComponentTransfer)
+        FeComponentTransfer = "feComponentTransfer",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDiffuseLighting)
-    FeDiffuseLighting,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feComposite)
+        FeComposite = "feComposite",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDisplacementMap)
-    FeDisplacementMap,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feConvolveMatrix)
+        FeConvolveMatrix = "feConvolveMatrix",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDistantLight)
-    FeDistantLight,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDiffuseLighting)
+        FeDiffuseLighting = "feDiffuseLighting",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDropShadow)
-    FeDropShadow,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDisplacementMap)
+        FeDisplacementMap = "feDisplacementMap",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feFlood)
-    FeFlood,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDistantLight)
+        FeDistantLight = "feDistantLight",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feFuncA)
-    FeFuncA,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feDropShadow)
+        FeDropShadow = "feDropShadow",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feFuncB)
-    FeFuncB,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feFlood)
+        FeFlood = "feFlood",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feFuncG)
-    FeFuncG,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feFuncA)
+        FeFuncA = "feFuncA",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feFuncR)
-    FeFuncR,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feFuncB)
+        FeFuncB = "feFuncB",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feGaussianBlur)
-    FeGaussianBlur,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feFuncG)
+        FeFuncG = "feFuncG",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feImage)
-    FeImage,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feFuncR)
+        FeFuncR = "feFuncR",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feMerge)
-    FeMerge,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feGaussianBlur)
+        FeGaussianBlur = "feGaussianBlur",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feMergeNode)
-    FeMergeNode,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feImage)
+        FeImage = "feImage",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feMorphology)
-    FeMorphology,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feMerge)
+        FeMerge = "feMerge",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feOffset)
-    FeOffset,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feMergeNode)
+        FeMergeNode = "feMergeNode",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/fePointLight)
-    FePointLight,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feMorphology)
+        FeMorphology = "feMorphology",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feSpecularLighting)
-    FeSpecularLighting,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feOffset)
+        FeOffset = "feOffset",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feSpotLight)
-    FeSpotLight,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/fePointLight)
+        FePointLight = "fePointLight",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feTile)
-    FeTile,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feSpecularLighting)
+        FeSpecularLighting = "feSpecularLighting",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feTurbulence)
-    FeTurbulence,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feSpotLight)
+        FeSpotLight = "feSpotLight",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/filter)
-    Filter,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feTile)
+        FeTile = "feTile",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/foreignObject)
-    ForeignObject,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/feTurbulence)
+        FeTurbulence = "feTurbulence",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/g)
-    G,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/filter)
+        Filter = "filter",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/hatch)
-    Hatch,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/foreignObject)
+        ForeignObject = "foreignObject",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/hatchpath)
-    Hatchpath,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/g)
+        G = "g",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/image)
-    Image,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/hatch)
+        Hatch = "hatch",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/line)
-    Line,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/hatchpath)
+        Hatchpath = "hatchpath",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/linearGradient)
-    LinearGradient,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/image)
+        Image = "image",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/marker)
-    Marker,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/line)
+        Line = "line",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/mask)
-    Mask,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/linearGradient)
+        LinearGradient = "linearGradient",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element)
-    Mesh,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/marker)
+        Marker = "marker",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element)
-    Meshgradient,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/mask)
+        Mask = "mask",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element)
-    Meshpatch,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element)
+        Mesh = "mesh",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element)
-    Meshrow,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element)
+        Meshgradient = "meshgradient",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/metadata)
-    Metadata,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element)
+        Meshpatch = "meshpatch",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/mpath)
-    Mpath,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element)
+        Meshrow = "meshrow",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/path)
-    Path,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/metadata)
+        Metadata = "metadata",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/pattern)
-    Pattern,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/mpath)
+        Mpath = "mpath",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/polygon)
-    Polygon,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/path)
+        Path = "path",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/polyline)
-    Polyline,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/pattern)
+        Pattern = "pattern",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/radialGradient)
-    RadialGradient,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/polygon)
+        Polygon = "polygon",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/rect)
-    Rect,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/polyline)
+        Polyline = "polyline",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/script)
-    Script,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/radialGradient)
+        RadialGradient = "radialGradient",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/set)
-    Set,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/rect)
+        Rect = "rect",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/solidcolor)
-    Solidcolor,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/script)
+        Script = "script",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/stop)
-    Stop,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/set)
+        Set = "set",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/style)
-    Style,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/solidcolor)
+        Solidcolor = "solidcolor",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/svg)
-    Svg,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/stop)
+        Stop = "stop",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/switch)
-    Switch,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/style)
+        Style = "style",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/symbol)
-    Symbol,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/svg)
+        Svg = "svg",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/text)
-    Text,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/switch)
+        Switch = "switch",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/textPath)
-    TextPath,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/symbol)
+        Symbol = "symbol",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/title)
-    Title,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/text)
+        Text = "text",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/tspan)
-    Tspan,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/textPath)
+        TextPath = "textPath",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element)
-    Unknown,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/title)
+        Title = "title",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/use)
-    Use,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/tspan)
+        Tspan = "tspan",
 
-    /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/view)
-    View,
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element)
+        Unknown = "unknown",
+
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/use)
+        Use = "use",
+
+        /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/SVG/Element/view)
+        View = "view",
+    }
 }
 
 // Implementation of Tagname
-impl ToString for TagName {
-    fn to_string(&self) -> String {
-        use TagName::*;
-
-        String::from(match self {
-            A => "a",
-            Animate => "animate",
-            AnimateMotion => "animateMotion",
-            AnimateTransform => "animateTransform",
-            Circle => "circle",
-            ClipPath => "clipPath",
-            ColorProfile => "color-profile",
-            Defs => "defs",
-            Desc => "desc",
-            Discard => "discard",
-            Ellipse => "ellipse",
-            FeBlend => "feBlend",
-            FeColorMatrix => "feColorMatrix",
-            FeComponentTransfer => "feComponentTransfer",
-            FeComposite => "feComposite",
-            FeConvolveMatrix => "feConvolveMatrix",
-            FeDiffuseLighting => "feDiffuseLighting",
-            FeDisplacementMap => "feDisplacementMap",
-            FeDistantLight => "feDistantLight",
-            FeDropShadow => "feDropShadow",
-            FeFlood => "feFlood",
-            FeFuncA => "feFuncA",
-            FeFuncB => "feFuncB",
-            FeFuncG => "feFuncG",
-            FeFuncR => "feFuncR",
-            FeGaussianBlur => "feGaussianBlur",
-            FeImage => "feImage",
-            FeMerge => "feMerge",
-            FeMergeNode => "feMergeNode",
-            FeMorphology => "feMorphology",
-            FeOffset => "feOffset",
-            FePointLight => "fePointLight",
-            FeSpecularLighting => "feSpecularLighting",
-            FeSpotLight => "feSpotLight",
-            FeTile => "feTile",
-            FeTurbulence => "feTurbulence",
-            Filter => "filter",
-            ForeignObject => "foreignObject",
-            G => "g",
-            Hatch => "hatch",
-            Hatchpath => "hatchpath",
-            Image => "image",
-            Line => "line",
-            LinearGradient => "linearGradient",
-            Marker => "marker",
-            Mask => "mask",
-            Mesh => "mesh",
-            Meshgradient => "meshgradient",
-            Meshpatch => "meshpatch",
-            Meshrow => "meshrow",
-            Metadata => "metadata",
-            Mpath => "mpath",
-            Path => "path",
-            Pattern => "pattern",
-            Polygon => "polygon",
-            Polyline => "polyline",
-            RadialGradient => "radialGradient",
-            Rect => "rect",
-            Script => "script",
-            Set => "set",
-            Solidcolor => "solidcolor",
-            Stop => "stop",
-            Style => "style",
-            Svg => "svg",
-            Switch => "switch",
-            Symbol => "symbol",
-            Text => "text",
-            TextPath => "textPath",
-            Title => "title",
-            Tspan => "tspan",
-            Unknown => "unknown",
-            Use => "use",
-            View => "view",
-        })
+impl fmt::Display for TagName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The error returned when parsing an unknown tag name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownTagName(pub String);
+
+impl fmt::Display for UnknownTagName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown tag name {:?}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownTagName {}
+
+impl FromStr for TagName {
+    type Err = UnknownTagName;
+
+    /// Parses a tag name, e.g. `linearGradient`
+    ///
+    /// # Note / Arguments
+    /// This only accepts the names of SVG elements, so it never returns a
+    /// [Custom](enum.TagName.html#variant.Custom) tag name.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::prelude::*;
+    ///
+    /// assert_eq!("linearGradient".parse(), Ok(Tag::LinearGradient));
+    /// assert!("LinearGradient".parse::<Tag>().is_err());
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        TagName::from_name(name).ok_or_else(|| UnknownTagName(name.to_string()))
+    }
+}
+
+impl TryFrom<&str> for TagName {
+    type Error = UnknownTagName;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TagName {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TagName {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        string_to_tag_or_custom(&name).ok_or_else(|| serde::de::Error::custom(UnknownTagName(name)))
+    }
+}
+
+/// Returns whether a string is a valid XML element name, e.g. `inkscape:grid`
+pub(crate) fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == ':')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == ':' || c == '-' || c == '.')
+}
+
+/// Returns the TagName with a certain name, falling back to a Custom tag name for unknown but
+/// valid names
+pub(crate) fn string_to_tag_or_custom(string: &str) -> Option<TagName> {
+    match TagName::from_name(string) {
+        Some(tag) => Some(tag),
+        None if is_valid_name(string) => Some(TagName::Custom(String::from(string))),
+        None => None,
     }
 }
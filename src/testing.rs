@@ -0,0 +1,189 @@
+//! Testing module, enabled with "parsing" feature
+//!
+//! This module provides helpers to compare generated [Elements](../struct.Element.html) against
+//! expected SVG markup in tests. Both sides are brought into a canonical form before comparing,
+//! so attribute order and whitespace between elements do not matter.
+//!
+//! # Examples
+//! ## Comparing an element against markup
+//! *The feature "parsing" needs to be enabled for this*
+//! ```
+//! use svg_definitions::assert_svg_eq;
+//! use svg_definitions::prelude::*;
+//!
+//! let group = SVGElem::new(Tag::G)
+//!     .append(SVGElem::new(Tag::Rect).set(Attr::Width, 10).set(Attr::Height, 5));
+//!
+//! assert_svg_eq!(group, r#"
+//!     <g>
+//!         <rect height="5" width="10" />
+//!     </g>
+//! "#);
+//! ```
+
+use crate::escape::{escape_attribute, escape_text};
+use crate::Element;
+
+/// Asserts that an [Element](struct.Element.html) is equal to the given SVG markup after
+/// canonical normalization of both, printing a line diff when they are not
+///
+/// *The feature "parsing" needs to be enabled for this*
+///
+/// # Panics
+/// Panics when the element and the markup are not equal or when the markup cannot be parsed.
+#[macro_export]
+macro_rules! assert_svg_eq {
+    ($element:expr, $expected:expr $(,)?) => {
+        if let Err(message) = $crate::testing::compare(&$element, $expected) {
+            panic!("{}", message);
+        }
+    };
+}
+
+/// Returns the canonical form of an element
+///
+/// Every element is put on its own line and indented by its depth, attributes are sorted by
+/// name and inner text and attribute values are trimmed.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::testing::canonicalize;
+///
+/// let group = SVGElem::new(Tag::G)
+///     .append(SVGElem::new(Tag::Rect).set(Attr::Width, 10).set(Attr::Height, 5));
+///
+/// assert_eq!(canonicalize(&group), "<g>\n  <rect height=\"5\" width=\"10\"/>\n</g>\n");
+/// ```
+pub fn canonicalize(element: &Element) -> String {
+    let mut output = String::new();
+    write_canonical(element, 0, &mut output);
+    output
+}
+
+/// Compares an element with SVG markup in canonical form, returning a message with a line
+/// diff if they are not equal
+pub fn compare(element: &Element, expected: &str) -> Result<(), String> {
+    let expected = crate::parser::parse_text(expected.trim())
+        .map_err(|err| format!("Expected SVG could not be parsed: {:?}", err))?;
+
+    let expected = canonicalize(&expected);
+    let actual = canonicalize(element);
+
+    if expected == actual {
+        return Ok(());
+    }
+
+    Err(format!(
+        "SVG elements are not equal (- expected, + actual):\n{}",
+        diff(&expected, &actual)
+    ))
+}
+
+/// Creates a line diff between two strings, prefixing removed lines with `-`, added lines with
+/// `+` and unchanged lines with a space
+///
+/// # Examples
+/// ```
+/// use svg_definitions::testing::diff;
+///
+/// assert_eq!(diff("a\nb\nc", "a\nc\nd"), "  a\n- b\n  c\n+ d\n");
+/// ```
+pub fn diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Longest common subsequence lengths of the suffixes of both sides
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut output = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            output.push_str(&format!("  {}\n", old[i]));
+            i += 1;
+            j += 1;
+        } else if j >= new.len() || (i < old.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            output.push_str(&format!("- {}\n", old[i]));
+            i += 1;
+        } else {
+            output.push_str(&format!("+ {}\n", new[j]));
+            j += 1;
+        }
+    }
+    output
+}
+
+fn write_canonical(element: &Element, depth: usize, output: &mut String) {
+    let indent = "  ".repeat(depth);
+
+    let mut attributes: Vec<(String, &str)> = element
+        .get_attributes()
+        .iter()
+        .map(|(attribute, value)| (attribute.to_string(), value.trim()))
+        .collect();
+    attributes.sort();
+
+    output.push_str(&indent);
+    output.push('<');
+    output.push_str(&element.get_tag_name().to_string());
+    for (name, value) in attributes {
+        output.push_str(&format!(" {}=\"{}\"", name, escape_attribute(value)));
+    }
+
+    let inner = element
+        .get_inner()
+        .as_deref()
+        .map(str::trim)
+        .filter(|inner| !inner.is_empty());
+
+    if inner.is_none() && element.get_children().is_empty() {
+        output.push_str("/>\n");
+        return;
+    }
+
+    output.push_str(">\n");
+    match inner {
+        Some(inner) if element.is_inner_raw() => {
+            output.push_str(&format!("{}  {}\n", indent, inner))
+        }
+        Some(inner) => output.push_str(&format!("{}  {}\n", indent, escape_text(inner))),
+        None => (),
+    }
+    for child in element.get_children() {
+        write_canonical(child, depth + 1, output);
+    }
+    output.push_str(&format!("{}</{}>\n", indent, element.get_tag_name()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compare;
+    use crate::attributes::Attribute;
+    use crate::tag_name::TagName;
+    use crate::Element;
+
+    #[test]
+    fn test_compare() {
+        let rect = Element::new(TagName::Rect)
+            .set(Attribute::Width, 10)
+            .set(Attribute::Fill, "red");
+
+        assert!(compare(&rect, "<rect fill='red'  width=' 10 '/>").is_ok());
+
+        let message = compare(&rect, "<rect fill=\"blue\" width=\"10\"/>").unwrap_err();
+        assert!(message.contains("- <rect fill=\"blue\" width=\"10\"/>"));
+        assert!(message.contains("+ <rect fill=\"red\" width=\"10\"/>"));
+
+        assert!(compare(&rect, "<rect").is_err());
+    }
+}
@@ -0,0 +1,433 @@
+//! This module provides the [TextBuilder](struct.TextBuilder.html), which lays out multiline
+//! text as a `<text>` element with a `<tspan>` per line, and [fit_into](fn.fit_into.html), which
+//! picks the largest font size at which a text fits a rectangle.
+//!
+//! # Examples
+//! ## Wrapping a paragraph
+//! ```
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::text::TextBuilder;
+//!
+//! let text = TextBuilder::new((10.0, 20.0))
+//!     .set(Attr::FontSize, 16)
+//!     .wrap("The quick brown fox jumps over the lazy dog", 100.0, 8.0)
+//!     .build();
+//! # assert_eq!(text.get_children().len(), 4);
+//! ```
+//!
+//! ## Fitting a label into a tile
+//! ```
+//! use svg_definitions::geometry::BoundingBox;
+//! use svg_definitions::text::{self, ApproximateMetrics};
+//!
+//! let tile = BoundingBox::new(0.0, 0.0, 120.0, 40.0);
+//! let label = text::fit_into("Revenue", &tile, &ApproximateMetrics::default(), 8.0, 32.0);
+//! # assert!(label.is_some());
+//! ```
+
+use std::fmt;
+
+use crate::attributes::Attribute;
+use crate::context::Context;
+use crate::geometry::BoundingBox;
+use crate::locale::Localizer;
+use crate::tag_name::TagName;
+use crate::{Element, Point2D};
+
+/// The alignment of text relative to its position, i.e. the `text-anchor` property
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextAnchor {
+    /// The text starts at its position
+    Start,
+
+    /// The text is centered on its position
+    Middle,
+
+    /// The text ends at its position
+    End,
+}
+
+impl fmt::Display for TextAnchor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TextAnchor::Start => "start",
+            TextAnchor::Middle => "middle",
+            TextAnchor::End => "end",
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Line {
+    text: String,
+    dy: Option<f64>,
+    anchor: Option<TextAnchor>,
+}
+
+/// TextBuilder assembles a `<text>` element from lines, which are positioned below each other
+/// by `<tspan>` elements
+///
+/// # Note / Arguments
+/// Every line starts at the x coordinate of the text and is moved down by the line height, in
+/// multiples of the font size (`em`). Empty lines are kept as extra distance to the next line,
+/// since an empty `<tspan>` does not move the text.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::text::{TextAnchor, TextBuilder};
+///
+/// let text = TextBuilder::new((50.0, 10.0))
+///     .anchor(TextAnchor::Middle)
+///     .line("Title")
+///     .line("")
+///     .line("Subtitle")
+///     .dy(2.0)
+///     .line("Footnote")
+///     .line_anchor(TextAnchor::End)
+///     .build();
+///
+/// assert_eq!(
+///     text.to_string(),
+///     "<text text-anchor=\"middle\" x=\"50\" y=\"10\">\
+///      <tspan x=\"50\">Title</tspan>\
+///      <tspan dy=\"3.2em\" x=\"50\">Subtitle</tspan>\
+///      <tspan dy=\"1.2em\" text-anchor=\"end\" x=\"50\">Footnote</tspan></text>"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextBuilder {
+    text: Element,
+    x: f32,
+    line_height: f64,
+    lines: Vec<Line>,
+}
+
+impl TextBuilder {
+    /// Creates a new builder for text starting at a certain position, with a line height of
+    /// 1.2em
+    pub fn new((x, y): Point2D) -> TextBuilder {
+        TextBuilder {
+            text: Element::new(TagName::Text)
+                .set(Attribute::X, x)
+                .set(Attribute::Y, y),
+            x,
+            line_height: 1.2,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Sets an attribute of the `<text>` element, e.g. the font size
+    pub fn set<T>(mut self, attribute: Attribute, value: T) -> Self
+    where
+        T: ToString,
+    {
+        self.text = self.text.set(attribute, value);
+        self
+    }
+
+    /// Sets the fill and the font family of the text to those of the theme of a
+    /// [Context](../context/struct.Context.html)
+    pub fn context(mut self, context: &Context) -> Self {
+        self = self.set(Attribute::Fill, &context.theme.foreground);
+        if let Some(font_family) = &context.theme.font_family {
+            self = self.set(Attribute::FontFamily, font_family);
+        }
+        self
+    }
+
+    /// Sets the distance between lines in multiples of the font size
+    pub fn line_height(mut self, line_height: f64) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Sets the alignment of all lines
+    pub fn anchor(self, anchor: TextAnchor) -> Self {
+        self.set(Attribute::TextAnchor, anchor)
+    }
+
+    /// Adds a line of text
+    pub fn line(mut self, text: &str) -> Self {
+        self.lines.push(Line {
+            text: String::from(text.trim()),
+            dy: None,
+            anchor: None,
+        });
+        self
+    }
+
+    /// Adds a line with the translation of a message key by a
+    /// [Localizer](../locale/trait.Localizer.html)
+    pub fn localized_line<L>(self, localizer: &L, key: &str) -> Self
+    where
+        L: Localizer + ?Sized,
+    {
+        self.line(&localizer.t(key))
+    }
+
+    /// Adds a text wrapped into lines of at most `width`, given the average width of a character
+    ///
+    /// # Note / Arguments
+    /// Lines are only broken between words and at newlines, so a word which is wider than
+    /// `width` gets a line of its own.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::text::TextBuilder;
+    ///
+    /// let text = TextBuilder::new((0.0, 0.0)).wrap("one two three\nfour", 70.0, 10.0).build();
+    /// let lines: Vec<_> = text
+    ///     .get_children()
+    ///     .iter()
+    ///     .map(|tspan| tspan.get_inner().clone().unwrap())
+    ///     .collect();
+    ///
+    /// assert_eq!(lines, ["one two", "three", "four"]);
+    /// ```
+    pub fn wrap(mut self, text: &str, width: f64, char_width: f64) -> Self {
+        let measure = |line: &str| line.chars().count() as f64 * char_width;
+        for line in wrap_lines(text, width, measure) {
+            self = self.line(&line);
+        }
+        self
+    }
+
+    /// Sets the distance of the last line to the line before it, in multiples of the font size
+    ///
+    /// # Panics
+    /// If no line has been added yet
+    pub fn dy(mut self, dy: f64) -> Self {
+        self.lines.last_mut().expect("no line to set the dy of").dy = Some(dy);
+        self
+    }
+
+    /// Sets the alignment of the last line
+    ///
+    /// # Panics
+    /// If no line has been added yet
+    pub fn line_anchor(mut self, anchor: TextAnchor) -> Self {
+        self.lines
+            .last_mut()
+            .expect("no line to set the anchor of")
+            .anchor = Some(anchor);
+        self
+    }
+
+    /// Assembles the `<text>` element
+    pub fn build(self) -> Element {
+        let mut text = self.text;
+        let mut dy = 0.0;
+        for (index, line) in self.lines.into_iter().enumerate() {
+            dy += line
+                .dy
+                .unwrap_or(if index == 0 { 0.0 } else { self.line_height });
+            if line.text.is_empty() {
+                continue;
+            }
+
+            let mut tspan = Element::new(TagName::Tspan)
+                .set(Attribute::X, self.x)
+                .set_inner(&line.text);
+            if dy != 0.0 {
+                tspan = tspan.set(Attribute::Dy, format!("{}em", (dy * 1e4).round() / 1e4));
+            }
+            if let Some(anchor) = line.anchor {
+                tspan = tspan.set(Attribute::TextAnchor, anchor);
+            }
+            text.children.push(tspan);
+            dy = 0.0;
+        }
+        text
+    }
+}
+
+/// Breaks a text into lines between words and at newlines, so every line is at most `width`
+/// wide unless it is a single word
+fn wrap_lines<F: Fn(&str) -> f64>(text: &str, width: f64, measure: F) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && measure(&format!("{} {}", line, word)) > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// The measurements of a font which are needed to lay out text without rendering it
+///
+/// # Note / Arguments
+/// Measurements are in multiples of the font size. With the feature "fonts" this is
+/// implemented for the faces of `ttf-parser`, which measures text with the advances of its
+/// glyphs.
+pub trait FontMetrics {
+    /// Returns the width of a line of text
+    fn text_width(&self, text: &str) -> f64;
+
+    /// Returns the height of the font above the baseline
+    fn ascent(&self) -> f64 {
+        0.8
+    }
+}
+
+/// Metrics which approximate every character by the same width, for when the font is not known
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApproximateMetrics {
+    /// The width of a character in multiples of the font size
+    pub char_width: f64,
+}
+
+impl Default for ApproximateMetrics {
+    /// An average character width of 0.6em, which is a little wide for most sans-serif fonts
+    fn default() -> Self {
+        ApproximateMetrics { char_width: 0.6 }
+    }
+}
+
+impl FontMetrics for ApproximateMetrics {
+    fn text_width(&self, text: &str) -> f64 {
+        text.chars().count() as f64 * self.char_width
+    }
+}
+
+/// Returns a `<text>` element with the largest font size between `min_size` and `max_size` at
+/// which a text fits a rectangle, or None if it does not fit at `min_size`
+///
+/// # Note / Arguments
+/// The text is wrapped between words like [wrap](struct.TextBuilder.html#method.wrap) does, with
+/// a line height of 1.2em, and starts at the top-left corner of the rectangle. A word which is
+/// wider than the rectangle at `min_size` does not fit. The font size is searched with a
+/// precision of 0.01.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::geometry::BoundingBox;
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::text::{self, ApproximateMetrics};
+///
+/// let metrics = ApproximateMetrics { char_width: 0.5 };
+/// let cell = BoundingBox::new(10.0, 10.0, 100.0, 50.0);
+/// let label = text::fit_into("Total sales", &cell, &metrics, 6.0, 48.0).unwrap();
+///
+/// // "Total sales" is 5.5em wide and fits the width at 18.18, or two lines fit the height at 20.83
+/// assert_eq!(label.get_attributes()[&Attr::FontSize], "20.83");
+/// assert_eq!(label.get_children().len(), 2);
+///
+/// assert!(text::fit_into("Total sales", &cell, &metrics, 30.0, 48.0).is_none());
+/// ```
+pub fn fit_into<M: FontMetrics + ?Sized>(
+    content: &str,
+    rect: &BoundingBox,
+    font: &M,
+    min_size: f64,
+    max_size: f64,
+) -> Option<Element> {
+    let lines = |size: f64| wrap_lines(content, rect.width, |line| font.text_width(line) * size);
+    let fits = |size: f64| {
+        let lines = lines(size);
+        lines.len() as f64 * size * 1.2 <= rect.height
+            && lines
+                .iter()
+                .all(|line| font.text_width(line) * size <= rect.width)
+    };
+
+    // Search in hundredths, so the size is formatted without rounding errors
+    let (mut low, mut high) = ((min_size * 100.0).ceil(), (max_size * 100.0).floor());
+    if low > high || !fits(low / 100.0) {
+        return None;
+    }
+    while low < high {
+        let middle = ((low + high) / 2.0).ceil();
+        if fits(middle / 100.0) {
+            low = middle;
+        } else {
+            high = middle - 1.0;
+        }
+    }
+
+    let size = low / 100.0;
+    let mut builder = TextBuilder::new((rect.x as f32, (rect.y + font.ascent() * size) as f32))
+        .set(Attribute::FontSize, size);
+    for line in lines(size) {
+        builder = builder.line(&line);
+    }
+    Some(builder.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fit_into, ApproximateMetrics, TextAnchor, TextBuilder};
+    use crate::attributes::Attribute;
+    use crate::geometry::BoundingBox;
+
+    #[test]
+    fn test_text_builder() {
+        let text = TextBuilder::new((0.0, 0.0))
+            .line_height(1.5)
+            .line("")
+            .line("a")
+            .line("")
+            .line("")
+            .line("b")
+            .dy(1.0)
+            .line("c")
+            .line_anchor(TextAnchor::End)
+            .build();
+        let children = text.get_children();
+        let dy = |index: usize| {
+            children[index]
+                .get_attributes()
+                .get(&Attribute::Dy)
+                .map(|dy| dy.to_string())
+        };
+        assert_eq!(children.len(), 3);
+        assert_eq!(dy(0), Some(String::from("1.5em")));
+        assert_eq!(dy(1), Some(String::from("4em")));
+        assert_eq!(dy(2), Some(String::from("1.5em")));
+        assert_eq!(children[2].get_attributes()[&Attribute::TextAnchor], "end");
+
+        let text = TextBuilder::new((0.0, 0.0))
+            .wrap("a verylongword b c", 30.0, 10.0)
+            .build();
+        let lines: Vec<_> = text
+            .get_children()
+            .iter()
+            .map(|tspan| tspan.get_inner().clone().unwrap())
+            .collect();
+        assert_eq!(lines, ["a", "verylongword", "b c"]);
+    }
+
+    #[test]
+    fn test_fit_into() {
+        let metrics = ApproximateMetrics { char_width: 0.5 };
+        let rect = BoundingBox::new(0.0, 0.0, 40.0, 100.0);
+
+        // The longest word limits the size, even though more lines would fit
+        let text = fit_into("a bb cccc", &rect, &metrics, 1.0, 50.0).unwrap();
+        assert_eq!(text.get_attributes()[&Attribute::FontSize], "20");
+        assert_eq!(text.get_attributes()[&Attribute::Y], "16");
+        assert_eq!(text.get_children().len(), 2);
+
+        // The maximum size is kept when everything fits
+        let text = fit_into("a", &rect, &metrics, 1.0, 12.5).unwrap();
+        assert_eq!(text.get_attributes()[&Attribute::FontSize], "12.5");
+
+        assert!(fit_into("a", &rect, &metrics, 20.0, 10.0).is_none());
+        assert!(fit_into(
+            "a",
+            &BoundingBox::new(0.0, 0.0, 0.0, 0.0),
+            &metrics,
+            1.0,
+            10.0
+        )
+        .is_none());
+    }
+}
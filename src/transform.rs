@@ -0,0 +1,324 @@
+//! This module provides the [Transform](enum.Transform.html) operations of the SVG
+//! `transform` attribute.
+//!
+//! # Examples
+//! ## Rotating a group
+//! ```
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::transform::Transform;
+//!
+//! let group = SVGElem::new(Tag::G)
+//!     .set(Attr::Transform, Transform::Rotate(45.0, 5.0, 5.0));
+//! # assert_eq!(group.get_attributes()[&Attr::Transform], "rotate(45 5 5)");
+//! ```
+//!
+//! ## Inspecting the transform of a parsed element
+//! ```
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::transform::{Transform, TransformList};
+//!
+//! let group = SVGElem::new(Tag::G).set(Attr::Transform, "translate(10, 20) scale(2)");
+//! let mut transforms = group.get_transform().unwrap();
+//!
+//! assert_eq!(transforms.0, vec![Transform::Translate(10.0, 20.0), Transform::Scale(2.0, 2.0)]);
+//! assert_eq!(transforms.apply((1.0, 1.0)), (12.0, 22.0));
+//!
+//! transforms.0.push(Transform::Rotate(90.0, 0.0, 0.0));
+//! let group = group.set(Attr::Transform, transforms);
+//! # assert_eq!(group.get_attributes()[&Attr::Transform], "translate(10 20) scale(2 2) rotate(90)");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::attributes::Attribute;
+use crate::syntax::Reader;
+use crate::{Element, Matrix};
+
+/// The error returned when parsing a value which is not a transform
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTransform(pub String);
+
+impl fmt::Display for InvalidTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid transform {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidTransform {}
+
+/// A single transform operation
+///
+/// # Note / Arguments
+/// For further information: [Look here](https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/transform)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transform {
+    /// `matrix(a b c d e f)`, mapping (x, y) to (a * x + c * y + e, b * x + d * y + f)
+    Matrix(f64, f64, f64, f64, f64, f64),
+
+    /// `translate(x y)`
+    Translate(f64, f64),
+
+    /// `scale(x y)`
+    Scale(f64, f64),
+
+    /// `rotate(angle cx cy)`, rotating by an angle in degrees around the point (cx, cy)
+    Rotate(f64, f64, f64),
+
+    /// `skewX(angle)`, with the angle in degrees
+    SkewX(f64),
+
+    /// `skewY(angle)`, with the angle in degrees
+    SkewY(f64),
+}
+
+impl Transform {
+    /// Returns the coefficients `[a, b, c, d, e, f]` of the matrix of this transform
+    pub(crate) fn coefficients(&self) -> [f64; 6] {
+        use Transform::*;
+
+        match *self {
+            Matrix(a, b, c, d, e, f) => [a, b, c, d, e, f],
+            Translate(x, y) => [1.0, 0.0, 0.0, 1.0, x, y],
+            Scale(x, y) => [x, 0.0, 0.0, y, 0.0, 0.0],
+            Rotate(angle, cx, cy) => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                [
+                    cos,
+                    sin,
+                    -sin,
+                    cos,
+                    cx - cos * cx + sin * cy,
+                    cy - sin * cx - cos * cy,
+                ]
+            }
+            SkewX(angle) => [1.0, 0.0, angle.to_radians().tan(), 1.0, 0.0, 0.0],
+            SkewY(angle) => [1.0, angle.to_radians().tan(), 0.0, 1.0, 0.0, 0.0],
+        }
+    }
+
+    /// Applies this transform to a point
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::transform::Transform;
+    ///
+    /// assert_eq!(Transform::Translate(5.0, -5.0).apply((1.0, 1.0)), (6.0, -4.0));
+    /// assert_eq!(Transform::Scale(2.0, 3.0).apply((1.0, 1.0)), (2.0, 3.0));
+    /// ```
+    pub fn apply(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        let [a, b, c, d, e, f] = self.coefficients();
+        (a * x + c * y + e, b * x + d * y + f)
+    }
+
+    /// Applies only the linear part of this transform to a vector, ignoring the translation
+    pub(crate) fn apply_vector(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        let [a, b, c, d, _, _] = self.coefficients();
+        (a * x + c * y, b * x + d * y)
+    }
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Transform::*;
+
+        match *self {
+            Matrix(a, b, c, d, e, g) => write!(f, "matrix({} {} {} {} {} {})", a, b, c, d, e, g),
+            Translate(x, y) => write!(f, "translate({} {})", x, y),
+            Scale(x, y) => write!(f, "scale({} {})", x, y),
+            Rotate(angle, cx, cy) if cx == 0.0 && cy == 0.0 => write!(f, "rotate({})", angle),
+            Rotate(angle, cx, cy) => write!(f, "rotate({} {} {})", angle, cx, cy),
+            SkewX(angle) => write!(f, "skewX({})", angle),
+            SkewY(angle) => write!(f, "skewY({})", angle),
+        }
+    }
+}
+
+impl FromStr for Transform {
+    type Err = InvalidTransform;
+
+    /// Parses a single transform operation, e.g. `rotate(45, 5, 5)`
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match parse_operations(value).as_deref() {
+            Some([transform]) => Ok(*transform),
+            _ => Err(InvalidTransform(value.to_string())),
+        }
+    }
+}
+
+/// The list of transform operations of a `transform` attribute
+///
+/// # Note / Arguments
+/// Like in SVG, the operations are applied from right to left, so the last operation is applied
+/// to a point first. An empty list is the identity transform.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TransformList(pub Vec<Transform>);
+
+impl TransformList {
+    /// Creates an empty list, which is the identity transform
+    #[inline]
+    pub fn new() -> TransformList {
+        TransformList(Vec::new())
+    }
+
+    /// Composes the operations into a single [Matrix](../struct.Matrix.html), which can be
+    /// turned into a [Transform::Matrix](enum.Transform.html#variant.Matrix) with `into`
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::transform::{Transform, TransformList};
+    /// use svg_definitions::Matrix;
+    ///
+    /// let transforms: TransformList = "translate(5 0) scale(2, 3)".parse().unwrap();
+    /// assert_eq!(transforms.to_matrix(), Matrix::new(2.0, 0.0, 0.0, 3.0, 5.0, 0.0));
+    /// assert_eq!(
+    ///     Transform::from(transforms.to_matrix()),
+    ///     Transform::Matrix(2.0, 0.0, 0.0, 3.0, 5.0, 0.0)
+    /// );
+    /// ```
+    pub fn to_matrix(&self) -> Matrix {
+        self.0.iter().fold(Matrix::IDENTITY, |matrix, transform| {
+            matrix * Matrix::from(*transform)
+        })
+    }
+
+    /// Applies all operations to a point
+    #[inline]
+    pub fn apply(&self, point: (f64, f64)) -> (f64, f64) {
+        self.to_matrix().apply(point)
+    }
+}
+
+impl From<Vec<Transform>> for TransformList {
+    #[inline]
+    fn from(transforms: Vec<Transform>) -> TransformList {
+        TransformList(transforms)
+    }
+}
+
+impl From<Transform> for TransformList {
+    #[inline]
+    fn from(transform: Transform) -> TransformList {
+        TransformList(vec![transform])
+    }
+}
+
+impl fmt::Display for TransformList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, transform) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", transform)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TransformList {
+    type Err = InvalidTransform;
+
+    /// Parses operations separated by whitespace and/or commas, where the optional arguments of
+    /// `translate`, `scale` and `rotate` may be left out
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_operations(value)
+            .map(TransformList)
+            .ok_or_else(|| InvalidTransform(value.to_string()))
+    }
+}
+
+/// Parses a list of transform operations, or None if it is malformed
+fn parse_operations(value: &str) -> Option<Vec<Transform>> {
+    let mut reader = Reader::new(value);
+    let mut transforms = Vec::new();
+
+    reader.skip_separator();
+    while !reader.is_at_end() {
+        let name = reader.name().ok()?;
+        reader.expect(b'(').ok()?;
+        let mut arguments = Vec::new();
+        while reader.expect(b')').is_err() {
+            arguments.push(reader.number().ok()?);
+        }
+
+        transforms.push(match (name, &arguments[..]) {
+            ("matrix", &[a, b, c, d, e, f]) => Transform::Matrix(a, b, c, d, e, f),
+            ("translate", &[x]) => Transform::Translate(x, 0.0),
+            ("translate", &[x, y]) => Transform::Translate(x, y),
+            ("scale", &[x]) => Transform::Scale(x, x),
+            ("scale", &[x, y]) => Transform::Scale(x, y),
+            ("rotate", &[angle]) => Transform::Rotate(angle, 0.0, 0.0),
+            ("rotate", &[angle, cx, cy]) => Transform::Rotate(angle, cx, cy),
+            ("skewX", &[angle]) => Transform::SkewX(angle),
+            ("skewY", &[angle]) => Transform::SkewY(angle),
+            _ => return None,
+        });
+        reader.skip_separator();
+    }
+
+    Some(transforms)
+}
+
+impl Element {
+    /// Returns the `transform` attribute as a [TransformList](transform/struct.TransformList.html),
+    /// or None if it is not set or malformed
+    pub fn get_transform(&self) -> Option<TransformList> {
+        self.attributes.get(&Attribute::Transform)?.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidTransform, Transform, TransformList};
+    use crate::Matrix;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "matrix(1,0,0,1,5,6)".parse(),
+            Ok(Transform::Matrix(1.0, 0.0, 0.0, 1.0, 5.0, 6.0))
+        );
+        assert_eq!(
+            "rotate (-45 1e1,5)".parse(),
+            Ok(Transform::Rotate(-45.0, 10.0, 5.0))
+        );
+        assert_eq!(
+            " skewX(10),skewY(-5.5)  translate(3) ".parse(),
+            Ok(TransformList(vec![
+                Transform::SkewX(10.0),
+                Transform::SkewY(-5.5),
+                Transform::Translate(3.0, 0.0)
+            ]))
+        );
+        assert_eq!(" ".parse(), Ok(TransformList::new()));
+
+        for value in &[
+            "scale",
+            "scale()",
+            "rotate(1 2)",
+            "translate(1 2",
+            "skewx(1)",
+            "scale(1) x",
+        ] {
+            assert_eq!(
+                value.parse::<TransformList>(),
+                Err(InvalidTransform(value.to_string()))
+            );
+        }
+        assert!("scale(1) scale(2)".parse::<Transform>().is_err());
+    }
+
+    #[test]
+    fn test_compose() {
+        let transforms = TransformList(vec![
+            Transform::Rotate(90.0, 0.0, 0.0),
+            Transform::Translate(1.0, 0.0),
+        ]);
+        let (x, y) = transforms.apply((1.0, 0.0));
+        assert!(x.abs() < 1e-12 && (y - 2.0).abs() < 1e-12);
+
+        let round_trip: TransformList = transforms.to_string().parse().unwrap();
+        assert_eq!(round_trip, transforms);
+        assert_eq!(TransformList::new().to_matrix(), Matrix::IDENTITY);
+    }
+}
@@ -0,0 +1,515 @@
+//! This module provides ways to address and traverse elements within an
+//! [Element](../struct.Element.html) tree without parent pointers.
+//!
+//! An [ElementPath](struct.ElementPath.html) addresses a descendant by the child indices leading
+//! to it, and a [Cursor](struct.Cursor.html) moves through a tree and edits it in place. A
+//! [Zipper](struct.Zipper.html) moves through a tree and edits it without mutating it, returning
+//! new versions which share their unchanged parts, e.g. to keep an undo history in an editor.
+//!
+//! # Examples
+//! ## Editing the second child of a group
+//! ```
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::tree::Cursor;
+//!
+//! let mut svg = SVGElem::new(Tag::Svg).append(
+//!     SVGElem::new(Tag::G)
+//!         .append(SVGElem::new(Tag::Rect))
+//!         .append(SVGElem::new(Tag::Circle)),
+//! );
+//!
+//! let mut cursor = Cursor::new(&mut svg);
+//! assert!(cursor.down(0));
+//! assert!(cursor.down(0));
+//! assert!(cursor.next_sibling());
+//! cursor.get_mut().set_attribute(Attr::Fill, "red");
+//!
+//! assert_eq!(cursor.path().indices(), &[0, 1]);
+//! assert_eq!(*cursor.get().get_tag_name(), Tag::Circle);
+//! ```
+
+use std::cell::OnceCell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::Element;
+
+/// The address of a descendant of an element, as a sequence of child indices
+///
+/// The empty path addresses the element itself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct ElementPath {
+    indices: Vec<usize>,
+}
+
+impl ElementPath {
+    /// Creates a new empty path, addressing the root
+    #[inline]
+    pub fn new() -> ElementPath {
+        ElementPath {
+            indices: Vec::new(),
+        }
+    }
+
+    /// Gets the child indices of this path
+    #[inline]
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns whether this path addresses the root
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the amount of steps from the root
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns the path of the child at a certain index of the addressed element
+    #[inline]
+    pub fn child(&self, index: usize) -> ElementPath {
+        let mut indices = self.indices.clone();
+        indices.push(index);
+        ElementPath { indices }
+    }
+
+    /// Returns the path of the parent, or None for the root
+    #[inline]
+    pub fn parent(&self) -> Option<ElementPath> {
+        let (_, parent) = self.indices.split_last()?;
+        Some(ElementPath {
+            indices: parent.to_vec(),
+        })
+    }
+
+    /// Returns whether this path addresses `other` or one of its descendants
+    #[inline]
+    pub fn starts_with(&self, other: &ElementPath) -> bool {
+        self.indices.starts_with(&other.indices)
+    }
+}
+
+impl From<Vec<usize>> for ElementPath {
+    #[inline]
+    fn from(indices: Vec<usize>) -> ElementPath {
+        ElementPath { indices }
+    }
+}
+
+impl From<&[usize]> for ElementPath {
+    #[inline]
+    fn from(indices: &[usize]) -> ElementPath {
+        ElementPath {
+            indices: indices.to_vec(),
+        }
+    }
+}
+
+impl fmt::Display for ElementPath {
+    /// Formats the path as its indices separated by slashes, e.g. `/0/2/1`, with `/` for the root
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.indices.is_empty() {
+            return f.write_str("/");
+        }
+
+        for index in self.indices.iter() {
+            write!(f, "/{}", index)?;
+        }
+        Ok(())
+    }
+}
+
+/// A cursor pointing at an element within a tree, which can move through the tree and edit it
+#[derive(Debug)]
+pub struct Cursor<'a> {
+    root: &'a mut Element,
+    path: ElementPath,
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a new cursor pointing at the root
+    #[inline]
+    pub fn new(root: &'a mut Element) -> Cursor<'a> {
+        Cursor {
+            root,
+            path: ElementPath::new(),
+        }
+    }
+
+    /// Gets the path of the element the cursor points at
+    #[inline]
+    pub fn path(&self) -> &ElementPath {
+        &self.path
+    }
+
+    /// Gets an immutable reference to the element the cursor points at
+    pub fn get(&self) -> &Element {
+        self.root
+            .get_path(&self.path)
+            .expect("Cursor path always points at an element")
+    }
+
+    /// Gets a mutable reference to the element the cursor points at, for editing it in place
+    pub fn get_mut(&mut self) -> &mut Element {
+        self.root
+            .get_path_mut(&self.path)
+            .expect("Cursor path always points at an element")
+    }
+
+    /// Moves to the child at a certain index, returning false and staying put if it does not exist
+    pub fn down(&mut self, index: usize) -> bool {
+        if index >= self.get().get_children().len() {
+            return false;
+        }
+
+        self.path = self.path.child(index);
+        true
+    }
+
+    /// Moves to the parent, returning false and staying put at the root
+    pub fn up(&mut self) -> bool {
+        match self.path.parent() {
+            Some(parent) => {
+                self.path = parent;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves to the next sibling, returning false and staying put if there is none
+    pub fn next_sibling(&mut self) -> bool {
+        let index = match self.path.indices().last() {
+            Some(index) => index + 1,
+            None => return false,
+        };
+
+        self.up();
+        if self.down(index) {
+            return true;
+        }
+        self.path = self.path.child(index - 1);
+        false
+    }
+
+    /// Moves to the previous sibling, returning false and staying put if there is none
+    pub fn previous_sibling(&mut self) -> bool {
+        match self.path.indices().last() {
+            Some(&index) if index > 0 => {
+                self.up();
+                self.down(index - 1)
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes the element the cursor points at and moves to its parent, returning the removed
+    /// element, or None at the root
+    pub fn remove(&mut self) -> Option<Element> {
+        let index = *self.path.indices().last()?;
+        self.up();
+        Some(self.get_mut().remove_child(index))
+    }
+
+    /// Replaces the element the cursor points at, returning the old element
+    pub fn replace(&mut self, element: Element) -> Element {
+        std::mem::replace(self.get_mut(), element)
+    }
+}
+
+/// An element within a [Zipper], whose children are shared between the versions of the tree
+#[derive(Debug)]
+struct Node {
+    /// The element without its children
+    element: Element,
+    children: Vec<Rc<Node>>,
+    /// The element with its children, once it was needed
+    tree: OnceCell<Element>,
+}
+
+impl Node {
+    fn new(mut element: Element) -> Node {
+        let children = std::mem::take(&mut element.children)
+            .into_iter()
+            .map(|child| Rc::new(Node::new(child)))
+            .collect();
+
+        Node {
+            element,
+            children,
+            tree: OnceCell::new(),
+        }
+    }
+
+    /// Returns the element with its children
+    fn tree(&self) -> &Element {
+        self.tree.get_or_init(|| self.build())
+    }
+
+    /// Builds the element with its children, without keeping the elements of the children
+    fn build(&self) -> Element {
+        if let Some(tree) = self.tree.get() {
+            return tree.clone();
+        }
+
+        let mut element = self.element.clone();
+        element.children = self.children.iter().map(|child| child.build()).collect();
+        element
+    }
+}
+
+/// The parent an element was focused from within a [Zipper]
+#[derive(Debug)]
+struct Crumb {
+    parent: Rc<Node>,
+    parent_changed: bool,
+    index: usize,
+    crumbs: Option<Rc<Crumb>>,
+}
+
+/// A persistent cursor pointing at an element within a tree
+///
+/// # Note / Arguments
+/// Moving and editing never changes a zipper, but returns a new one. The tree is split into
+/// shared nodes once, when the zipper is created. After that, cloning a zipper and moving down
+/// take constant time, and all versions share the subtrees which were not edited. Moving up from
+/// an edit copies only the parent without its descendants, and an edit only copies the element in
+/// focus with its descendants.
+///
+/// [focus](#method.focus) and [root](#method.root) build the element with its descendants,
+/// which takes time linear in their amount. `focus` keeps the built element, so repeated calls
+/// are cheap.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::tree::Zipper;
+///
+/// let zipper = Zipper::new(
+///     SVGElem::new(Tag::Svg)
+///         .append(SVGElem::new(Tag::Rect))
+///         .append(SVGElem::new(Tag::Circle)),
+/// );
+///
+/// let edited = zipper
+///     .down(1)
+///     .unwrap()
+///     .edit(|circle| {
+///         circle.set_attribute(Attr::Fill, "red");
+///     });
+///
+/// // The original version is kept, e.g. to undo the edit
+/// assert!(zipper.root().get_children()[1].get_attributes().is_empty());
+/// assert_eq!(edited.root().get_children()[1].get_attributes()[&Attr::Fill], "red");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Zipper {
+    focus: Rc<Node>,
+    changed: bool,
+    crumbs: Option<Rc<Crumb>>,
+}
+
+impl Zipper {
+    /// Creates a new zipper focusing on the root of a tree
+    pub fn new(root: Element) -> Zipper {
+        Zipper {
+            focus: Rc::new(Node::new(root)),
+            changed: false,
+            crumbs: None,
+        }
+    }
+
+    /// Gets the element in focus
+    #[inline]
+    pub fn focus(&self) -> &Element {
+        self.focus.tree()
+    }
+
+    /// Returns the path of the element in focus from the root
+    pub fn path(&self) -> ElementPath {
+        let mut indices = Vec::new();
+        let mut crumbs = &self.crumbs;
+        while let Some(crumb) = crumbs {
+            indices.push(crumb.index);
+            crumbs = &crumb.crumbs;
+        }
+        indices.reverse();
+        ElementPath { indices }
+    }
+
+    /// Returns whether the root is in focus
+    #[inline]
+    pub fn is_root(&self) -> bool {
+        self.crumbs.is_none()
+    }
+
+    /// Focuses on the child at a certain index, returning None if it does not exist
+    pub fn down(&self, index: usize) -> Option<Zipper> {
+        let child = self.focus.children.get(index)?.clone();
+
+        Some(Zipper {
+            focus: child,
+            changed: false,
+            crumbs: Some(Rc::new(Crumb {
+                parent: self.focus.clone(),
+                parent_changed: self.changed,
+                index,
+                crumbs: self.crumbs.clone(),
+            })),
+        })
+    }
+
+    /// Focuses on the parent, returning None at the root
+    pub fn up(&self) -> Option<Zipper> {
+        let crumb = self.crumbs.as_ref()?;
+
+        // Unchanged parents are shared, and changed parents share their other children
+        let parent = if self.changed {
+            let mut children = crumb.parent.children.clone();
+            children[crumb.index] = self.focus.clone();
+            Rc::new(Node {
+                element: crumb.parent.element.clone(),
+                children,
+                tree: OnceCell::new(),
+            })
+        } else {
+            crumb.parent.clone()
+        };
+
+        Some(Zipper {
+            focus: parent,
+            changed: self.changed || crumb.parent_changed,
+            crumbs: crumb.crumbs.clone(),
+        })
+    }
+
+    /// Focuses on the next sibling, returning None if there is none
+    pub fn next_sibling(&self) -> Option<Zipper> {
+        let index = self.crumbs.as_ref()?.index;
+        self.up()?.down(index + 1)
+    }
+
+    /// Focuses on the previous sibling, returning None if there is none
+    pub fn previous_sibling(&self) -> Option<Zipper> {
+        let index = self.crumbs.as_ref()?.index.checked_sub(1)?;
+        self.up()?.down(index)
+    }
+
+    /// Returns a new zipper where the element in focus is edited by a function
+    pub fn edit<F>(&self, f: F) -> Zipper
+    where
+        F: FnOnce(&mut Element),
+    {
+        let mut focus = self.focus.build();
+        f(&mut focus);
+        self.replace(focus)
+    }
+
+    /// Returns a new zipper where the element in focus is replaced
+    pub fn replace(&self, element: Element) -> Zipper {
+        Zipper {
+            focus: Rc::new(Node::new(element)),
+            changed: true,
+            crumbs: self.crumbs.clone(),
+        }
+    }
+
+    /// Builds the tree of this version, including all edits
+    pub fn root(&self) -> Element {
+        let mut zipper = self.clone();
+        while let Some(parent) = zipper.up() {
+            zipper = parent;
+        }
+        zipper.focus.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cursor, ElementPath, Zipper};
+    use crate::tag_name::TagName;
+    use crate::Element;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_element_path() {
+        let path = ElementPath::from(vec![0, 2]);
+
+        assert_eq!(path.child(1).indices(), &[0, 2, 1]);
+        assert_eq!(path.parent(), Some(ElementPath::from(vec![0])));
+        assert_eq!(ElementPath::new().parent(), None);
+        assert!(path.child(1).starts_with(&path));
+        assert_eq!(path.to_string(), "/0/2");
+        assert_eq!(ElementPath::new().to_string(), "/");
+    }
+
+    #[test]
+    fn test_cursor() {
+        let mut root = Element::new(TagName::Svg)
+            .append(Element::new(TagName::Rect))
+            .append(Element::new(TagName::Circle));
+        let mut cursor = Cursor::new(&mut root);
+
+        assert!(!cursor.up());
+        assert!(!cursor.next_sibling());
+        assert!(!cursor.down(2));
+
+        assert!(cursor.down(1));
+        assert!(!cursor.next_sibling());
+        assert_eq!(cursor.path().indices(), &[1]);
+        assert!(cursor.previous_sibling());
+        assert!(!cursor.previous_sibling());
+
+        let old = cursor.replace(Element::new(TagName::Line));
+        assert_eq!(*old.get_tag_name(), TagName::Rect);
+
+        let removed = cursor.remove().unwrap();
+        assert_eq!(*removed.get_tag_name(), TagName::Line);
+        assert!(cursor.path().is_root());
+        assert!(cursor.remove().is_none());
+
+        assert_eq!(root.get_children().len(), 1);
+    }
+
+    #[test]
+    fn test_zipper() {
+        let root = Element::new(TagName::Svg)
+            .append(Element::new(TagName::G).append(Element::new(TagName::Rect)))
+            .append(Element::new(TagName::Circle));
+        let zipper = Zipper::new(root.clone());
+
+        assert!(zipper.up().is_none());
+        assert!(zipper.down(2).is_none());
+        assert!(zipper.down(0).unwrap().previous_sibling().is_none());
+
+        let rect = zipper.down(0).unwrap().down(0).unwrap();
+        assert_eq!(rect.path().indices(), &[0, 0]);
+
+        let edited = rect.replace(Element::new(TagName::Line));
+        let circle = edited.up().unwrap().next_sibling().unwrap();
+        assert_eq!(*circle.focus().get_tag_name(), TagName::Circle);
+
+        // Edits are kept while moving through the tree
+        let edited_root = circle.root();
+        assert_eq!(
+            *edited_root.get_children()[0].get_children()[0].get_tag_name(),
+            TagName::Line
+        );
+        assert_eq!(rect.root().to_string(), root.to_string());
+
+        // The versions share the subtrees which were not edited
+        let shares_focus = |left: &Zipper, right: &Zipper| Rc::ptr_eq(&left.focus, &right.focus);
+        let unedited = zipper.down(1).unwrap();
+        assert!(shares_focus(&circle, &unedited));
+        assert!(!shares_focus(
+            &edited.up().unwrap(),
+            &zipper.down(0).unwrap()
+        ));
+        let edited_root = edited.up().unwrap().up().unwrap();
+        assert!(shares_focus(&edited_root.down(1).unwrap(), &unedited));
+    }
+}
@@ -0,0 +1,570 @@
+//! This module checks documents against the SVG profiles which restricted viewers implement,
+//! like SVG Tiny 1.2 on embedded devices or SVG 1.1 in print workflows, with
+//! [profile](fn.profile.html).
+//!
+//! # Examples
+//! ## Checking a graphic for an embedded device
+//! ```
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::validate::{self, Profile, ViolationKind};
+//!
+//! let document = SVGDoc::new(
+//!     SVGElem::new(Tag::Svg)
+//!         .append(SVGElem::new(Tag::Filter).set(Attr::Id, "blur"))
+//!         .append(SVGElem::new(Tag::Path).set(Attr::D, "M 0 0 A 5 5 0 0 1 10 0")),
+//! );
+//!
+//! let violations = validate::profile(&document, Profile::Tiny12);
+//! let kinds: Vec<&ViolationKind> = violations.iter().map(|violation| &violation.kind).collect();
+//!
+//! assert_eq!(kinds, vec![&ViolationKind::Element(Tag::Filter), &ViolationKind::ArcCommand]);
+//! assert!(validate::profile(&document, Profile::Full11).is_empty());
+//! ```
+
+use std::fmt;
+
+use crate::attributes::Attribute;
+use crate::document::Document;
+use crate::path::{PathCommand, PathDefinitionString};
+use crate::tag_name::TagName;
+use crate::tree::ElementPath;
+use crate::Element;
+
+/// The elements of SVG Tiny 1.2
+const TINY12_ELEMENTS: [TagName; 30] = [
+    TagName::A,
+    TagName::Animate,
+    TagName::AnimateMotion,
+    TagName::AnimateTransform,
+    TagName::Circle,
+    TagName::Defs,
+    TagName::Desc,
+    TagName::Discard,
+    TagName::Ellipse,
+    TagName::ForeignObject,
+    TagName::G,
+    TagName::Image,
+    TagName::Line,
+    TagName::LinearGradient,
+    TagName::Metadata,
+    TagName::Mpath,
+    TagName::Path,
+    TagName::Polygon,
+    TagName::Polyline,
+    TagName::RadialGradient,
+    TagName::Rect,
+    TagName::Script,
+    TagName::Set,
+    TagName::Stop,
+    TagName::Svg,
+    TagName::Switch,
+    TagName::Text,
+    TagName::Title,
+    TagName::Tspan,
+    TagName::Use,
+];
+
+/// The elements which were added in SVG 2
+const SVG2_ELEMENTS: [TagName; 9] = [
+    TagName::Discard,
+    TagName::FeDropShadow,
+    TagName::Hatch,
+    TagName::Hatchpath,
+    TagName::Mesh,
+    TagName::Meshgradient,
+    TagName::Meshpatch,
+    TagName::Meshrow,
+    TagName::Solidcolor,
+];
+
+/// The attributes of SVG Tiny 1.2
+const TINY12_ATTRIBUTES: [Attribute; 131] = [
+    Attribute::AccentHeight,
+    Attribute::Accumulate,
+    Attribute::Additive,
+    Attribute::AllowReorder,
+    Attribute::Alphabetic,
+    Attribute::ArabicForm,
+    Attribute::Ascent,
+    Attribute::AttributeName,
+    Attribute::AttributeType,
+    Attribute::BaseProfile,
+    Attribute::Bbox,
+    Attribute::Begin,
+    Attribute::By,
+    Attribute::CalcMode,
+    Attribute::CapHeight,
+    Attribute::Class,
+    Attribute::Color,
+    Attribute::ColorRendering,
+    Attribute::ContentScriptType,
+    Attribute::Cx,
+    Attribute::Cy,
+    Attribute::D,
+    Attribute::Descent,
+    Attribute::Direction,
+    Attribute::Display,
+    Attribute::Dur,
+    Attribute::End,
+    Attribute::ExternalResourcesRequired,
+    Attribute::Fill,
+    Attribute::FillOpacity,
+    Attribute::FillRule,
+    Attribute::FontFamily,
+    Attribute::FontSize,
+    Attribute::FontStyle,
+    Attribute::FontVariant,
+    Attribute::FontWeight,
+    Attribute::From,
+    Attribute::G1,
+    Attribute::G2,
+    Attribute::GlyphName,
+    Attribute::GradientUnits,
+    Attribute::Hanging,
+    Attribute::Height,
+    Attribute::HorizAdvx,
+    Attribute::HorizOriginx,
+    Attribute::Id,
+    Attribute::Ideographic,
+    Attribute::ImageRendering,
+    Attribute::K,
+    Attribute::KeyPoints,
+    Attribute::KeySplines,
+    Attribute::KeyTimes,
+    Attribute::Mathematical,
+    Attribute::Max,
+    Attribute::Min,
+    Attribute::Offset,
+    Attribute::Opacity,
+    Attribute::Origin,
+    Attribute::OverlinePosition,
+    Attribute::OverlineThickness,
+    Attribute::Panose1,
+    Attribute::Path,
+    Attribute::PathLength,
+    Attribute::PointerEvents,
+    Attribute::Points,
+    Attribute::PreserveAspectRatio,
+    Attribute::R,
+    Attribute::Rel,
+    Attribute::RepeatCount,
+    Attribute::RepeatDur,
+    Attribute::RequiredExtensions,
+    Attribute::RequiredFeatures,
+    Attribute::Restart,
+    Attribute::Rotate,
+    Attribute::Rx,
+    Attribute::Ry,
+    Attribute::Slope,
+    Attribute::Stemh,
+    Attribute::Stemv,
+    Attribute::StopColor,
+    Attribute::StopOpacity,
+    Attribute::StrikethroughPosition,
+    Attribute::StrikethroughThickness,
+    Attribute::Stroke,
+    Attribute::StrokeDasharray,
+    Attribute::StrokeDashoffset,
+    Attribute::StrokeLinecap,
+    Attribute::StrokeLinejoin,
+    Attribute::StrokeMiterlimit,
+    Attribute::StrokeOpacity,
+    Attribute::StrokeWidth,
+    Attribute::SystemLanguage,
+    Attribute::Target,
+    Attribute::TextAnchor,
+    Attribute::TextRendering,
+    Attribute::To,
+    Attribute::Transform,
+    Attribute::Type,
+    Attribute::U1,
+    Attribute::U2,
+    Attribute::UnderlinePosition,
+    Attribute::UnderlineThickness,
+    Attribute::Unicode,
+    Attribute::UnicodeBidi,
+    Attribute::UnicodeRange,
+    Attribute::UnitsPerem,
+    Attribute::Values,
+    Attribute::VectorEffect,
+    Attribute::Version,
+    Attribute::ViewBox,
+    Attribute::Visibility,
+    Attribute::Width,
+    Attribute::Widths,
+    Attribute::X,
+    Attribute::XHeight,
+    Attribute::X1,
+    Attribute::X2,
+    Attribute::XlinkActuate,
+    Attribute::XlinkArcrole,
+    Attribute::XlinkHref,
+    Attribute::XlinkRole,
+    Attribute::XlinkShow,
+    Attribute::XlinkTitle,
+    Attribute::XlinkType,
+    Attribute::XmlBase,
+    Attribute::XmlLang,
+    Attribute::XmlSpace,
+    Attribute::Y,
+    Attribute::Y1,
+    Attribute::Y2,
+    Attribute::ZoomAndPan,
+];
+
+/// The attributes which were added in SVG 2, or which are not part of SVG 1.1 otherwise
+const SVG2_ATTRIBUTES: [Attribute; 11] = [
+    Attribute::AllowReorder,
+    Attribute::Fr,
+    Attribute::Href,
+    Attribute::Hreflang,
+    Attribute::Lang,
+    Attribute::PaintOrder,
+    Attribute::Ping,
+    Attribute::ReferrerPolicy,
+    Attribute::Rel,
+    Attribute::Tabindex,
+    Attribute::VectorEffect,
+];
+
+/// The attributes of SVG 1.1 which were removed in SVG 2
+const REMOVED_ATTRIBUTES: [Attribute; 21] = [
+    Attribute::BaseProfile,
+    Attribute::ColorProfile,
+    Attribute::ContentScriptType,
+    Attribute::ContentStyleType,
+    Attribute::EnableBackground,
+    Attribute::ExternalResourcesRequired,
+    Attribute::FilterRes,
+    Attribute::GlyphOrientationhorizontal,
+    Attribute::GlyphOrientationvertical,
+    Attribute::GlyphRef,
+    Attribute::Kerning,
+    Attribute::RenderingIntent,
+    Attribute::RequiredFeatures,
+    Attribute::Version,
+    Attribute::XlinkActuate,
+    Attribute::XlinkArcrole,
+    Attribute::XlinkRole,
+    Attribute::XlinkShow,
+    Attribute::XlinkTitle,
+    Attribute::XlinkType,
+    Attribute::XmlBase,
+];
+
+/// A profile of SVG which a viewer implements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Profile {
+    /// SVG Tiny 1.2, for mobile and embedded devices
+    ///
+    /// It has no clipping, masking, filters, markers, patterns, symbols or CSS, and its paths
+    /// have no arcs.
+    Tiny12,
+
+    /// The full profile of SVG 1.1, which most print workflows and older renderers implement
+    Full11,
+
+    /// SVG 2, which drops some features of SVG 1.1, like `color-profile`
+    Svg2,
+}
+
+impl Profile {
+    /// Returns whether an element of SVG is part of the profile
+    fn allows_element(&self, tag_name: &TagName) -> bool {
+        match self {
+            Profile::Tiny12 => TINY12_ELEMENTS.contains(tag_name),
+            Profile::Full11 => !SVG2_ELEMENTS.contains(tag_name) && *tag_name != TagName::Unknown,
+            Profile::Svg2 => *tag_name != TagName::ColorProfile,
+        }
+    }
+
+    /// Returns whether an attribute of SVG is part of the profile
+    fn allows_attribute(&self, attribute: &Attribute) -> bool {
+        match self {
+            Profile::Tiny12 => TINY12_ATTRIBUTES.contains(attribute),
+            Profile::Full11 => !SVG2_ATTRIBUTES.contains(attribute),
+            Profile::Svg2 => {
+                !REMOVED_ATTRIBUTES.contains(attribute) && *attribute != Attribute::AllowReorder
+            }
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Profile::Tiny12 => "SVG Tiny 1.2",
+            Profile::Full11 => "SVG 1.1",
+            Profile::Svg2 => "SVG 2",
+        })
+    }
+}
+
+/// A kind of construct which is not part of a profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// An element which the profile does not have
+    Element(TagName),
+
+    /// An attribute which the profile does not have
+    Attribute(Attribute),
+
+    /// Path data with an arc command, which SVG Tiny 1.2 does not have
+    ArcCommand,
+}
+
+/// A construct outside of a profile, as returned by [profile](fn.profile.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// The path of the element with the construct
+    pub path: ElementPath,
+
+    /// The construct
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ViolationKind::Element(tag_name) => write!(f, "element <{}>", tag_name)?,
+            ViolationKind::Attribute(attribute) => write!(f, "attribute {}", attribute)?,
+            ViolationKind::ArcCommand => f.write_str("arc in path data")?,
+        }
+        write!(f, " at {}", self.path)
+    }
+}
+
+/// Returns the constructs of a document which are not part of a profile, in document order
+///
+/// # Note / Arguments
+/// The elements and attributes of SVG are checked, and for SVG Tiny 1.2 also the commands of
+/// path data. The descendants of an element outside of the profile are not reported, as the
+/// element is not rendered anyway. Neither are elements and attributes which are not part of
+/// SVG, like `data-*` or `inkscape:label`, nor the content of `<foreignObject>` and
+/// `<metadata>`.
+///
+/// # Examples
+/// ```
+/// use svg_definitions::prelude::*;
+/// use svg_definitions::tree::ElementPath;
+/// use svg_definitions::validate::{self, Profile, Violation, ViolationKind};
+///
+/// let document = SVGDoc::new(
+///     SVGElem::new(Tag::Svg)
+///         .set(Attr::Version, "1.1")
+///         .append(SVGElem::new(Tag::Rect).set(Attr::PaintOrder, "stroke")),
+/// );
+///
+/// assert_eq!(
+///     validate::profile(&document, Profile::Full11),
+///     vec![Violation {
+///         path: ElementPath::from(vec![0]),
+///         kind: ViolationKind::Attribute(Attr::PaintOrder),
+///     }]
+/// );
+/// assert_eq!(validate::profile(&document, Profile::Svg2)[0].to_string(), "attribute version at /");
+/// ```
+pub fn profile(document: &Document, profile: Profile) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    check_element(
+        document.get_root(),
+        &ElementPath::new(),
+        profile,
+        &mut violations,
+    );
+    violations
+}
+
+/// Adds the constructs of an element and its descendants which are not part of a profile
+fn check_element(
+    element: &Element,
+    path: &ElementPath,
+    profile: Profile,
+    violations: &mut Vec<Violation>,
+) {
+    let mut report = |kind| {
+        violations.push(Violation {
+            path: path.clone(),
+            kind,
+        })
+    };
+
+    match &element.tag_name {
+        TagName::Custom(_) => {}
+        tag_name if !profile.allows_element(tag_name) => {
+            report(ViolationKind::Element(tag_name.clone()));
+            return;
+        }
+        _ => {}
+    }
+
+    // Sorted, as the order of attributes is not kept
+    let mut attributes: Vec<&Attribute> = element
+        .attributes
+        .keys()
+        .filter(|attribute| {
+            !matches!(attribute, Attribute::Custom(_)) && !profile.allows_attribute(attribute)
+        })
+        .collect();
+    attributes.sort_by_key(|attribute| attribute.to_string());
+    for attribute in attributes {
+        report(ViolationKind::Attribute(attribute.clone()));
+    }
+
+    if profile == Profile::Tiny12 && element.tag_name == TagName::Path {
+        let has_arc = element
+            .attributes
+            .get(&Attribute::D)
+            .and_then(|d| d.parse::<PathDefinitionString>().ok())
+            .is_some_and(|d| {
+                d.get_commands()
+                    .iter()
+                    .any(|command| matches!(command, PathCommand::ArcTo { .. }))
+            });
+        if has_arc {
+            report(ViolationKind::ArcCommand);
+        }
+    }
+
+    if matches!(element.tag_name, TagName::ForeignObject | TagName::Metadata) {
+        return;
+    }
+    for (index, child) in element.children.iter().enumerate() {
+        check_element(child, &path.child(index), profile, violations);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{profile, Profile, Violation, ViolationKind};
+    use crate::attributes::Attribute;
+    use crate::document::Document;
+    use crate::tag_name::TagName;
+    use crate::tree::ElementPath;
+    use crate::Element;
+
+    fn kinds(document: &Document, target: Profile) -> Vec<ViolationKind> {
+        profile(document, target)
+            .into_iter()
+            .map(|violation| violation.kind)
+            .collect()
+    }
+
+    #[test]
+    fn test_profile() {
+        let document = Document::new(
+            Element::new(TagName::Svg)
+                .set(Attribute::Version, "1.1")
+                .set(Attribute::custom("data-name"), "logo")
+                .append(
+                    Element::new(TagName::Defs)
+                        .append(Element::new(TagName::ClipPath).append(Element::new(TagName::Rect)))
+                        .append(
+                            Element::new(TagName::RadialGradient)
+                                .set(Attribute::Fr, "10%")
+                                .set(Attribute::Fx, "50%"),
+                        )
+                        .append(Element::new(TagName::Meshgradient)),
+                )
+                .append(
+                    Element::new(TagName::Path)
+                        .set(Attribute::D, "M 0 0 a 1 1 0 0 0 2 0")
+                        .set(Attribute::Style, "fill:red")
+                        .set(Attribute::VectorEffect, "non-scaling-stroke"),
+                )
+                .append(
+                    Element::new(TagName::ForeignObject)
+                        .append(Element::new(TagName::Custom(String::from("div")))),
+                )
+                .append(Element::new(TagName::Custom(String::from(
+                    "sodipodi:namedview",
+                )))),
+        );
+
+        assert_eq!(
+            profile(&document, Profile::Tiny12),
+            vec![
+                Violation {
+                    path: ElementPath::from(vec![0, 0]),
+                    kind: ViolationKind::Element(TagName::ClipPath),
+                },
+                Violation {
+                    path: ElementPath::from(vec![0, 1]),
+                    kind: ViolationKind::Attribute(Attribute::Fr),
+                },
+                Violation {
+                    path: ElementPath::from(vec![0, 1]),
+                    kind: ViolationKind::Attribute(Attribute::Fx),
+                },
+                Violation {
+                    path: ElementPath::from(vec![0, 2]),
+                    kind: ViolationKind::Element(TagName::Meshgradient),
+                },
+                Violation {
+                    path: ElementPath::from(vec![1]),
+                    kind: ViolationKind::Attribute(Attribute::Style),
+                },
+                Violation {
+                    path: ElementPath::from(vec![1]),
+                    kind: ViolationKind::ArcCommand,
+                },
+            ]
+        );
+        assert_eq!(
+            kinds(&document, Profile::Full11),
+            vec![
+                ViolationKind::Attribute(Attribute::Fr),
+                ViolationKind::Element(TagName::Meshgradient),
+                ViolationKind::Attribute(Attribute::VectorEffect),
+            ]
+        );
+        assert_eq!(
+            kinds(&document, Profile::Svg2),
+            vec![ViolationKind::Attribute(Attribute::Version)]
+        );
+
+        let old =
+            Document::new(Element::new(TagName::Svg).append(Element::new(TagName::ColorProfile)));
+        assert_eq!(
+            profile(&old, Profile::Svg2)[0].to_string(),
+            "element <color-profile> at /0"
+        );
+        assert!(profile(&old, Profile::Full11).is_empty());
+    }
+
+    #[test]
+    fn test_tiny_properties() {
+        let document = Document::new(
+            Element::new(TagName::Svg)
+                .set(Attribute::XmlBase, "https://example.com/")
+                .append(
+                    Element::new(TagName::Image)
+                        .set(Attribute::ImageRendering, "optimizeSpeed")
+                        .set(Attribute::PointerEvents, "none")
+                        .set(Attribute::XlinkShow, "embed")
+                        .set(Attribute::XlinkHref, "icon.png"),
+                )
+                .append(
+                    Element::new(TagName::Text)
+                        .set(Attribute::TextRendering, "optimizeLegibility")
+                        .set(Attribute::GlyphOrientationvertical, "0"),
+                ),
+        );
+
+        assert_eq!(
+            kinds(&document, Profile::Tiny12),
+            vec![ViolationKind::Attribute(
+                Attribute::GlyphOrientationvertical
+            )]
+        );
+        assert_eq!(
+            kinds(&document, Profile::Svg2),
+            vec![
+                ViolationKind::Attribute(Attribute::XmlBase),
+                ViolationKind::Attribute(Attribute::XlinkShow),
+                ViolationKind::Attribute(Attribute::GlyphOrientationvertical),
+            ]
+        );
+        assert!(profile(&document, Profile::Full11).is_empty());
+    }
+}
@@ -0,0 +1,242 @@
+//! This module provides [ViewBoxProps](struct.ViewBoxProps.html), the value of the `viewBox`
+//! attribute, with the arithmetic to compute a view box from generated geometry instead of
+//! hard-coding it.
+//!
+//! # Examples
+//! ## Showing everything that was drawn
+//! ```
+//! use svg_definitions::geometry::BoundingBox;
+//! use svg_definitions::prelude::*;
+//! use svg_definitions::view_box::ViewBoxProps;
+//!
+//! let points = [(12.0, 40.0), (80.0, 10.0), (140.0, 65.0)];
+//! let view_box = ViewBoxProps::fit_points(&points).unwrap().expand(10.0);
+//!
+//! let svg = SVGElem::new(Tag::Svg)
+//!     .set(Attr::ViewBox, view_box)
+//!     .append(SVGElem::new(Tag::Polyline).set(Attr::Points, "12,40 80,10 140,65"));
+//!
+//! assert_eq!(svg.get_attributes()[&Attr::ViewBox], "2 0 148 75");
+//! assert_eq!(svg.get_view_box(), Some(view_box));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::attributes::Attribute;
+use crate::geometry::BoundingBox;
+use crate::Element;
+
+/// The error returned when parsing a value which is not a view box
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidViewBox(pub String);
+
+impl fmt::Display for InvalidViewBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid view box {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidViewBox {}
+
+/// The area of user space which is shown in a viewport, as set by the `viewBox` attribute
+///
+/// # Note / Arguments
+/// View boxes are formatted as they are written in SVG, e.g. `0 0 100 50`, so they can be
+/// passed to [set](../struct.Element.html#method.set) directly. A view box with a width or
+/// height of 0 disables rendering of the element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewBoxProps {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl ViewBoxProps {
+    /// Creates a new view box from its top-left corner and size
+    #[inline]
+    pub fn new(min_x: f64, min_y: f64, width: f64, height: f64) -> ViewBoxProps {
+        ViewBoxProps {
+            min_x,
+            min_y,
+            width,
+            height,
+        }
+    }
+
+    /// Creates the view box showing exactly a bounding box
+    #[inline]
+    pub fn fit(bounds: &BoundingBox) -> ViewBoxProps {
+        ViewBoxProps::new(bounds.x, bounds.y, bounds.width, bounds.height)
+    }
+
+    /// Creates the smallest view box showing a list of points, or None for no points
+    #[inline]
+    pub fn fit_points(points: &[(f64, f64)]) -> Option<ViewBoxProps> {
+        BoundingBox::from_points(points).map(|bounds| ViewBoxProps::fit(&bounds))
+    }
+
+    /// Returns the area shown by the view box
+    #[inline]
+    pub fn to_bounds(&self) -> BoundingBox {
+        BoundingBox::new(self.min_x, self.min_y, self.width, self.height)
+    }
+
+    /// Returns the width divided by the height, which is infinite or NaN for an empty height
+    #[inline]
+    pub fn aspect_ratio(&self) -> f64 {
+        self.width / self.height
+    }
+
+    /// Returns this view box grown by a margin on every side, or shrunk for a negative margin
+    #[inline]
+    pub fn expand(&self, margin: f64) -> ViewBoxProps {
+        ViewBoxProps::fit(&self.to_bounds().inflate(margin))
+    }
+
+    /// Returns the smallest view box showing both this view box and `other`
+    #[inline]
+    pub fn union(&self, other: &ViewBoxProps) -> ViewBoxProps {
+        ViewBoxProps::fit(&self.to_bounds().union(&other.to_bounds()))
+    }
+
+    /// Returns this view box grown around its center to an aspect ratio (width divided by
+    /// height), so it fills a viewport of that shape without leaving space at the sides
+    ///
+    /// # Panics
+    /// If the aspect ratio is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use svg_definitions::view_box::ViewBoxProps;
+    ///
+    /// // A wide chart in a square viewport
+    /// let content = ViewBoxProps::new(0.0, 0.0, 100.0, 50.0);
+    /// assert_eq!(content.with_aspect_ratio(1.0).to_string(), "0 -25 100 100");
+    /// ```
+    pub fn with_aspect_ratio(&self, ratio: f64) -> ViewBoxProps {
+        assert!(ratio > 0.0, "the aspect ratio has to be positive");
+
+        let (center_x, center_y) = (
+            self.min_x + self.width / 2.0,
+            self.min_y + self.height / 2.0,
+        );
+        let (width, height) = if self.width < self.height * ratio {
+            (self.height * ratio, self.height)
+        } else {
+            (self.width, self.width / ratio)
+        };
+        ViewBoxProps::new(
+            center_x - width / 2.0,
+            center_y - height / 2.0,
+            width,
+            height,
+        )
+    }
+}
+
+impl From<BoundingBox> for ViewBoxProps {
+    #[inline]
+    fn from(bounds: BoundingBox) -> ViewBoxProps {
+        ViewBoxProps::fit(&bounds)
+    }
+}
+
+impl fmt::Display for ViewBoxProps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.min_x, self.min_y, self.width, self.height
+        )
+    }
+}
+
+impl FromStr for ViewBoxProps {
+    type Err = InvalidViewBox;
+
+    /// Parses four numbers separated by commas and/or whitespace, where the width and height may
+    /// not be negative
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let numbers = value
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|part| !part.is_empty())
+            .map(|part| part.parse::<f64>().ok().filter(|number| number.is_finite()))
+            .collect::<Option<Vec<f64>>>();
+
+        match numbers.as_deref() {
+            Some(&[min_x, min_y, width, height]) if width >= 0.0 && height >= 0.0 => {
+                Ok(ViewBoxProps::new(min_x, min_y, width, height))
+            }
+            _ => Err(InvalidViewBox(value.to_string())),
+        }
+    }
+}
+
+impl Element {
+    /// Returns the `viewBox` attribute as [ViewBoxProps](view_box/struct.ViewBoxProps.html), or
+    /// None if it is not set or invalid
+    pub fn get_view_box(&self) -> Option<ViewBoxProps> {
+        self.attributes.get(&Attribute::ViewBox)?.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidViewBox, ViewBoxProps};
+    use crate::geometry::BoundingBox;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            " -5,0 20\t1e1 ".parse(),
+            Ok(ViewBoxProps::new(-5.0, 0.0, 20.0, 10.0))
+        );
+        assert_eq!(
+            "0.5 0.25 0 0".parse::<ViewBoxProps>().unwrap().to_string(),
+            "0.5 0.25 0 0"
+        );
+        for value in &[
+            "",
+            "0 0 10",
+            "0 0 10 10 10",
+            "0 0 -1 10",
+            "0 0 nan 1",
+            "a b c d",
+        ] {
+            assert_eq!(
+                value.parse::<ViewBoxProps>(),
+                Err(InvalidViewBox(value.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let view_box = ViewBoxProps::new(0.0, 0.0, 40.0, 10.0);
+        assert_eq!(view_box.aspect_ratio(), 4.0);
+        assert_eq!(
+            view_box.expand(-2.0),
+            ViewBoxProps::new(2.0, 2.0, 36.0, 6.0)
+        );
+        assert_eq!(
+            view_box.union(&ViewBoxProps::new(-10.0, 5.0, 5.0, 10.0)),
+            ViewBoxProps::new(-10.0, 0.0, 50.0, 15.0)
+        );
+        assert_eq!(
+            view_box.with_aspect_ratio(2.0),
+            ViewBoxProps::new(0.0, -5.0, 40.0, 20.0)
+        );
+        assert_eq!(view_box.with_aspect_ratio(4.0), view_box);
+
+        let bounds = BoundingBox::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(ViewBoxProps::from(bounds).to_bounds(), bounds);
+        assert_eq!(ViewBoxProps::fit_points(&[]), None);
+        assert_eq!(
+            ViewBoxProps::fit_points(&[(3.0, -1.0), (-3.0, 1.0)]),
+            Some(ViewBoxProps::new(-3.0, -1.0, 6.0, 2.0))
+        );
+    }
+}